@@ -0,0 +1,38 @@
+//! Benchmarks for audit log verification.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use openlora_core::AdapterId;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use serde_json::json;
+
+fn build_log(dir: &tempfile::TempDir, entries: usize) -> AuditLog {
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    for i in 0..entries {
+        log.append(
+            AuditEventType::AdapterActivated,
+            "bench-operator",
+            Some(&AuditTarget::Adapter(AdapterId::new(format!("adapter-{}", i)).unwrap())),
+            json!({ "index": i, "version": 3, "labels": { "team": "safety", "tier": "high" } }),
+        )
+        .unwrap();
+    }
+    log
+}
+
+fn bench_verify_integrity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verify_integrity");
+    group.sample_size(10);
+
+    for entries in [1_000, 10_000, 100_000] {
+        let dir = tempfile::tempdir().unwrap();
+        let log = build_log(&dir, entries);
+        group.bench_with_input(BenchmarkId::from_parameter(entries), &log, |b, log| {
+            b.iter(|| log.verify_integrity().unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_verify_integrity);
+criterion_main!(benches);
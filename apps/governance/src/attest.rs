@@ -0,0 +1,297 @@
+//! Audit Log Attestation
+//!
+//! Seal a verified audit log by signing its head hash, entry count and
+//! time span, so a third party can later check both the chain and the
+//! signer's claim about it.
+//!
+//! A clean attestation instead vouches for one adapter: that nothing in
+//! the log's history up to the signed head quarantined or destroyed it,
+//! or failed its signature. Reviewers require one before promotion.
+
+use crate::audit::{AuditEntry, AuditError, AuditLog, AuditTarget};
+use crate::config::TrustedSigner;
+use crate::signatures::{sign_ed25519, Signature, SignatureError, SignatureVerifier};
+use chrono::{DateTime, Utc};
+use ed25519_dalek::SigningKey;
+use openlora_core::canonical::{canonical_timestamp, to_canonical_cbor};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+/// What the signer attests about the log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttestationClaims {
+    pub head_hash: String,
+    pub count: usize,
+    pub first_ts: Option<DateTime<Utc>>,
+    pub last_ts: Option<DateTime<Utc>>,
+}
+
+impl AttestationClaims {
+    /// Canonical CBOR of the claims; this is the signed message.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        #[derive(Serialize)]
+        struct Signed<'a> {
+            head_hash: &'a str,
+            count: u64,
+            first_ts: Option<String>,
+            last_ts: Option<String>,
+        }
+
+        to_canonical_cbor(&Signed {
+            head_hash: &self.head_hash,
+            count: self.count as u64,
+            first_ts: self.first_ts.as_ref().map(canonical_timestamp),
+            last_ts: self.last_ts.as_ref().map(canonical_timestamp),
+        })
+        .expect("string and integer fields always encode")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attestation {
+    pub claims: AttestationClaims,
+    pub signature: Signature,
+}
+
+/// Result of checking an attestation against a log.
+#[derive(Debug, Clone)]
+pub struct AttestationCheck {
+    /// Entries appended after the attested prefix.
+    pub newer_entries: usize,
+}
+
+#[derive(Debug, Error)]
+pub enum AttestError {
+    #[error("Audit error: {0}")]
+    Audit(#[from] AuditError),
+    #[error("Audit log failed integrity verification")]
+    IntegrityFailed,
+    #[error("Signature error: {0}")]
+    Signature(#[from] SignatureError),
+    #[error("Attestation signature is invalid")]
+    InvalidSignature,
+    #[error("Log does not match attestation: {0}")]
+    Mismatch(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Adapter {adapter_id} is not clean: {}", incidents.join(", "))]
+    NotClean {
+        adapter_id: String,
+        /// `<event type> <entry id>` of each negative event.
+        incidents: Vec<String>,
+    },
+}
+
+/// Event types that disqualify an adapter from a clean attestation.
+pub const NEGATIVE_EVENTS: [&str; 3] = ["AdapterQuarantined", "AdapterDestroyed", "SignatureFailed"];
+
+/// What the signer attests about an adapter's history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CleanClaims {
+    pub adapter_id: String,
+    /// Head of the log when it was scanned; the claim covers every entry
+    /// up to it.
+    pub head_hash: String,
+    pub count: usize,
+}
+
+impl CleanClaims {
+    /// Canonical CBOR of the claims; this is the signed message.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        #[derive(Serialize)]
+        struct Signed<'a> {
+            statement: &'a str,
+            adapter_id: &'a str,
+            head_hash: &'a str,
+            count: u64,
+        }
+
+        to_canonical_cbor(&Signed {
+            statement: "clean",
+            adapter_id: &self.adapter_id,
+            head_hash: &self.head_hash,
+            count: self.count as u64,
+        })
+        .expect("string and integer fields always encode")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanAttestation {
+    pub claims: CleanClaims,
+    pub signature: Signature,
+}
+
+/// Result of checking a clean attestation against a log.
+#[derive(Debug, Clone)]
+pub struct CleanCheck {
+    /// Entries appended after the attested prefix.
+    pub newer_entries: usize,
+    /// Negative events about the adapter since the attestation, as in
+    /// [`AttestError::NotClean`]; the attestation is stale if any.
+    pub later_incidents: Vec<String>,
+}
+
+impl AuditLog {
+    /// Verify the log, scan its full history for [`NEGATIVE_EVENTS`]
+    /// about `adapter_id`, and if there are none sign a statement that
+    /// the adapter is clean as of the current head.
+    pub fn attest_clean(
+        &self,
+        adapter_id: &str,
+        signer_id: &str,
+        key: &SigningKey,
+    ) -> Result<CleanAttestation, AttestError> {
+        if !self.verify_integrity()? {
+            return Err(AttestError::IntegrityFailed);
+        }
+
+        let entries = self.entries()?;
+        let incidents = incidents(&entries, adapter_id);
+        if !incidents.is_empty() {
+            return Err(AttestError::NotClean {
+                adapter_id: adapter_id.to_string(),
+                incidents,
+            });
+        }
+        let claims = CleanClaims {
+            adapter_id: adapter_id.to_string(),
+            head_hash: entries.last().map_or("genesis", |e| e.hash.as_str()).to_string(),
+            count: entries.len(),
+        };
+        let signature = sign_ed25519(&claims.canonical_bytes(), signer_id, key, None);
+
+        Ok(CleanAttestation { claims, signature })
+    }
+}
+
+/// `<event type> <entry id>` of each negative event about `adapter_id`.
+fn incidents(entries: &[AuditEntry], adapter_id: &str) -> Vec<String> {
+    let target = AuditTarget::from_parts("adapter", Some(adapter_id));
+    entries
+        .iter()
+        .filter(|entry| NEGATIVE_EVENTS.contains(&entry.event_type.tag()))
+        .filter(|entry| entry.target().as_ref() == Some(&target))
+        .map(|entry| format!("{} {}", entry.event_type.tag(), entry.id))
+        .collect()
+}
+
+/// Verify `log` and sign claims about its current state.
+pub fn attest(log: &AuditLog, signer_id: &str, key: &SigningKey) -> Result<Attestation, AttestError> {
+    if !log.verify_integrity()? {
+        return Err(AttestError::IntegrityFailed);
+    }
+
+    let entries = log.entries()?;
+    let claims = AttestationClaims {
+        head_hash: log.head_hash().to_string(),
+        count: entries.len(),
+        first_ts: entries.first().map(|e| e.timestamp),
+        last_ts: entries.last().map(|e| e.timestamp),
+    };
+    let signature = sign_ed25519(&claims.canonical_bytes(), signer_id, key, None);
+
+    Ok(Attestation { claims, signature })
+}
+
+/// Re-verify `log` and check that `attestation` was signed by a trusted
+/// signer over its first `count` entries. Entries appended since are allowed.
+pub fn check_attestation(
+    log: &AuditLog,
+    attestation: &Attestation,
+    verifier: &SignatureVerifier,
+) -> Result<AttestationCheck, AttestError> {
+    let claims = &attestation.claims;
+    if !verifier.verify(&claims.canonical_bytes(), &attestation.signature)? {
+        return Err(AttestError::InvalidSignature);
+    }
+
+    if !log.verify_integrity()? {
+        return Err(AttestError::IntegrityFailed);
+    }
+
+    let entries = log.entries()?;
+    if entries.len() < claims.count {
+        return Err(AttestError::Mismatch(format!(
+            "attested {} entries but log has {}",
+            claims.count,
+            entries.len()
+        )));
+    }
+
+    let attested = &entries[..claims.count];
+    let head_hash = attested.last().map_or("genesis", |e| e.hash.as_str());
+    if head_hash != claims.head_hash {
+        return Err(AttestError::Mismatch(format!(
+            "head hash at entry {} is {}, attested {}",
+            claims.count, head_hash, claims.head_hash
+        )));
+    }
+    let first_ts = attested.first().map(|e| e.timestamp);
+    let last_ts = attested.last().map(|e| e.timestamp);
+    if first_ts != claims.first_ts || last_ts != claims.last_ts {
+        return Err(AttestError::Mismatch("entry timestamps differ from attested span".to_string()));
+    }
+
+    Ok(AttestationCheck {
+        newer_entries: entries.len() - claims.count,
+    })
+}
+
+/// Re-verify `log` and check that `attestation` was signed by a trusted
+/// signer over its first `count` entries, reporting negative events about
+/// the adapter appended since.
+pub fn check_clean_attestation(
+    log: &AuditLog,
+    attestation: &CleanAttestation,
+    verifier: &SignatureVerifier,
+) -> Result<CleanCheck, AttestError> {
+    let claims = &attestation.claims;
+    if !verifier.verify(&claims.canonical_bytes(), &attestation.signature)? {
+        return Err(AttestError::InvalidSignature);
+    }
+    if !log.verify_integrity()? {
+        return Err(AttestError::IntegrityFailed);
+    }
+
+    let entries = log.entries()?;
+    if entries.len() < claims.count {
+        return Err(AttestError::Mismatch(format!(
+            "attested {} entries but log has {}",
+            claims.count,
+            entries.len()
+        )));
+    }
+    let (attested, newer) = entries.split_at(claims.count);
+    let head_hash = attested.last().map_or("genesis", |e| e.hash.as_str());
+    if head_hash != claims.head_hash {
+        return Err(AttestError::Mismatch(format!(
+            "head hash at entry {} is {}, attested {}",
+            claims.count, head_hash, claims.head_hash
+        )));
+    }
+
+    Ok(CleanCheck {
+        newer_entries: newer.len(),
+        later_incidents: incidents(newer, &claims.adapter_id),
+    })
+}
+
+/// Read a JSON list of trusted signers.
+///
+/// Relative public key paths are resolved against the file's directory.
+pub fn load_trusted_signers(path: &Path) -> Result<Vec<TrustedSigner>, AttestError> {
+    let mut signers: Vec<TrustedSigner> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    let base = path.parent().unwrap_or(Path::new(""));
+    for signer in &mut signers {
+        if let Some(ref mut key) = signer.public_key {
+            if key.is_relative() {
+                *key = base.join(&*key);
+            }
+        }
+    }
+    Ok(signers)
+}
@@ -3,11 +3,23 @@
 //! Append-only audit log with hash chain for integrity.
 
 use chrono::{DateTime, Utc};
+use openlora_core::{AdapterId, ModelId, RunId};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
+use crate::details;
+use crate::encryption::{self, AuditKey};
+use crate::registry::AdapterLabels;
+use serde_json::error::Category;
+use serde_json::value::RawValue;
+use openlora_core::hashing::Hasher;
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::fs::{File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,16 +28,160 @@ pub enum AuditEventType {
     AdapterActivated,
     AdapterDeactivated,
     AdapterQuarantined,
+    AdapterReleased,
     AdapterDestroyed,
+    /// Summary of adapters quarantined together, e.g. from a list file.
+    QuarantineBatchCompleted,
     KillSwitchActivated,
     KillSwitchReset,
+    /// A reset by an admin that bypassed quorum and escalation.
+    KillSwitchForceReset,
+    /// Affected adapters had not confirmed stopping long after a kill.
+    KillStopOverdue,
+    SignatureCreated,
     SignatureVerified,
     SignatureFailed,
+    SignatureRevoked,
     PolicyEvaluated,
     AccessDenied,
     TrainingStarted,
     TrainingCompleted,
     TrainingFailed,
+    AuditIntegrityFailed,
+    /// An edited log was re-chained into a new log.
+    AuditLogRechained,
+    SessionIssued,
+    SessionEnded,
+    /// An expired session was presented.
+    SessionExpired,
+    /// Event type from a newer build, preserved verbatim.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl AuditEventType {
+    /// Tag of the event type as written on disk; used for hashing.
+    pub fn tag(&self) -> &str {
+        match self {
+            AuditEventType::AdapterCreated => "AdapterCreated",
+            AuditEventType::AdapterActivated => "AdapterActivated",
+            AuditEventType::AdapterDeactivated => "AdapterDeactivated",
+            AuditEventType::AdapterQuarantined => "AdapterQuarantined",
+            AuditEventType::AdapterReleased => "AdapterReleased",
+            AuditEventType::AdapterDestroyed => "AdapterDestroyed",
+            AuditEventType::QuarantineBatchCompleted => "QuarantineBatchCompleted",
+            AuditEventType::KillSwitchActivated => "KillSwitchActivated",
+            AuditEventType::KillSwitchReset => "KillSwitchReset",
+            AuditEventType::KillSwitchForceReset => "KillSwitchForceReset",
+            AuditEventType::KillStopOverdue => "KillStopOverdue",
+            AuditEventType::SignatureCreated => "SignatureCreated",
+            AuditEventType::SignatureVerified => "SignatureVerified",
+            AuditEventType::SignatureFailed => "SignatureFailed",
+            AuditEventType::SignatureRevoked => "SignatureRevoked",
+            AuditEventType::PolicyEvaluated => "PolicyEvaluated",
+            AuditEventType::AccessDenied => "AccessDenied",
+            AuditEventType::TrainingStarted => "TrainingStarted",
+            AuditEventType::TrainingCompleted => "TrainingCompleted",
+            AuditEventType::TrainingFailed => "TrainingFailed",
+            AuditEventType::AuditIntegrityFailed => "AuditIntegrityFailed",
+            AuditEventType::AuditLogRechained => "AuditLogRechained",
+            AuditEventType::SessionIssued => "SessionIssued",
+            AuditEventType::SessionEnded => "SessionEnded",
+            AuditEventType::SessionExpired => "SessionExpired",
+            AuditEventType::Unknown(tag) => tag,
+        }
+    }
+}
+
+/// What an audit entry is about.
+///
+/// Stored on disk as the entry's `target_type` and `target_id` strings;
+/// pairs this build does not recognise are kept as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "TargetParts", into = "TargetParts")]
+pub enum AuditTarget {
+    Adapter(AdapterId),
+    Model(ModelId),
+    Run(RunId),
+    Operator(String),
+    /// A kill-switch activation, by event id; `None` for the switch itself.
+    KillSwitch(Option<String>),
+    /// An audit log, by path.
+    AuditLog(String),
+    /// A signature, by encoded value.
+    Signature(String),
+    /// Adapter content, by SHA-256.
+    Content(String),
+    Other { kind: String, id: Option<String> },
+}
+
+/// The on-disk form of an [`AuditTarget`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TargetParts {
+    target_type: String,
+    target_id: Option<String>,
+}
+
+impl AuditTarget {
+    /// Rebuild a target from its stored strings.
+    pub fn from_parts(kind: &str, id: Option<&str>) -> Self {
+        let typed = match (kind, id) {
+            ("adapter", Some(id)) => AdapterId::new(id).ok().map(Self::Adapter),
+            ("model", Some(id)) => ModelId::new(id).ok().map(Self::Model),
+            ("run", Some(id)) => RunId::new(id).ok().map(Self::Run),
+            ("operator", Some(id)) => Some(Self::Operator(id.to_string())),
+            ("kill_switch", id) => Some(Self::KillSwitch(id.map(String::from))),
+            ("audit_log", Some(path)) => Some(Self::AuditLog(path.to_string())),
+            ("signature", Some(value)) => Some(Self::Signature(value.to_string())),
+            ("content", Some(hash)) => Some(Self::Content(hash.to_string())),
+            _ => None,
+        };
+        typed.unwrap_or_else(|| Self::Other {
+            kind: kind.to_string(),
+            id: id.map(String::from),
+        })
+    }
+
+    /// Value written to `target_type`.
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::Adapter(_) => "adapter",
+            Self::Model(_) => "model",
+            Self::Run(_) => "run",
+            Self::Operator(_) => "operator",
+            Self::KillSwitch(_) => "kill_switch",
+            Self::AuditLog(_) => "audit_log",
+            Self::Signature(_) => "signature",
+            Self::Content(_) => "content",
+            Self::Other { kind, .. } => kind,
+        }
+    }
+
+    /// Value written to `target_id`.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Self::Adapter(id) => Some(id.as_str()),
+            Self::Model(id) => Some(id.as_str()),
+            Self::Run(id) => Some(id.as_str()),
+            Self::Operator(id) | Self::AuditLog(id) | Self::Signature(id) | Self::Content(id) => Some(id),
+            Self::KillSwitch(id) | Self::Other { id, .. } => id.as_deref(),
+        }
+    }
+}
+
+impl From<TargetParts> for AuditTarget {
+    fn from(parts: TargetParts) -> Self {
+        Self::from_parts(&parts.target_type, parts.target_id.as_deref())
+    }
+}
+
+impl From<AuditTarget> for TargetParts {
+    fn from(target: AuditTarget) -> Self {
+        Self {
+            target_type: target.kind().to_string(),
+            target_id: target.id().map(String::from),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,11 +190,97 @@ pub struct AuditEntry {
     pub timestamp: DateTime<Utc>,
     pub event_type: AuditEventType,
     pub actor: String,
+    /// Position of this entry among the actor's entries, from 1; hashed
+    /// from v3 on. Older entries have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_seq: Option<u64>,
     pub target_type: Option<String>,
     pub target_id: Option<String>,
     pub details: serde_json::Value,
     pub previous_hash: String,
     pub hash: String,
+    /// Hash scheme used for `hash`; entries written before versioning are v1.
+    #[serde(default = "legacy_hash_version")]
+    pub hash_version: u32,
+}
+
+/// Hash scheme used for new entries: domain-separated, length-prefixed
+/// fields, including the actor's sequence number.
+pub const HASH_VERSION: u32 = 3;
+
+/// First hash scheme covering `actor_seq`.
+const ACTOR_SEQ_HASH_VERSION: u32 = 3;
+
+/// Original hash scheme: fields concatenated without separation.
+pub const LEGACY_HASH_VERSION: u32 = 1;
+
+pub(crate) fn legacy_hash_version() -> u32 {
+    LEGACY_HASH_VERSION
+}
+
+impl AuditEntry {
+    /// Chain hash of this entry under its own hash version.
+    pub fn compute_hash(&self) -> String {
+        let fields = HashedFields {
+            id: &self.id,
+            timestamp: &self.timestamp,
+            event_tag: self.event_type.tag(),
+            actor: &self.actor,
+            actor_seq: self.actor_seq,
+            previous_hash: &self.previous_hash,
+        };
+        hash_entry(self.hash_version, &fields, &self.details)
+    }
+
+    /// Typed view of `target_type` and `target_id`.
+    pub fn target(&self) -> Option<AuditTarget> {
+        self.target_type
+            .as_deref()
+            .map(|kind| AuditTarget::from_parts(kind, self.target_id.as_deref()))
+    }
+
+    /// Whether the entry's adapter, or any adapter it affected, was
+    /// labelled `key` = `value` when the entry was written.
+    pub fn has_label(&self, key: &str, value: &str) -> bool {
+        let matches = |labels: &serde_json::Value| labels.get(key).and_then(|v| v.as_str()) == Some(value);
+        self.details.get("labels").is_some_and(matches)
+            || self
+                .details
+                .get("adapter_labels")
+                .and_then(|by_adapter| by_adapter.as_object())
+                .is_some_and(|by_adapter| by_adapter.values().any(matches))
+    }
+}
+
+/// Add the labels of the adapters an entry is about to its `details`.
+fn label_details(
+    labels: &HashMap<String, AdapterLabels>,
+    target: Option<&AuditTarget>,
+    details: &mut serde_json::Value,
+) {
+    if labels.is_empty() {
+        return;
+    }
+    let Some(details) = details.as_object_mut() else {
+        return;
+    };
+
+    if let Some(AuditTarget::Adapter(id)) = target {
+        if let Some(own) = labels.get(id.as_str()) {
+            details.entry("labels").or_insert_with(|| serde_json::json!(own));
+        }
+    }
+    let affected: serde_json::Map<String, serde_json::Value> = details
+        .get("affected_adapters")
+        .and_then(|adapters| adapters.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|adapter| adapter.as_str())
+        .filter_map(|id| labels.get(id).map(|own| (id.to_string(), serde_json::json!(own))))
+        .collect();
+    if !affected.is_empty() {
+        details.entry("adapter_labels").or_insert(serde_json::Value::Object(affected));
+    }
 }
 
 #[derive(Debug, Error)]
@@ -49,39 +291,457 @@ pub enum AuditError {
     Serialization(#[from] serde_json::Error),
     #[error("Integrity violation: expected {expected}, got {actual}")]
     IntegrityViolation { expected: String, actual: String },
+    #[error("Unsupported schema version: {0}")]
+    UnsupportedSchema(u32),
+    #[error("Invalid stream name: {0:?}")]
+    InvalidStream(String),
+    #[error("Entry not found: {0}")]
+    EntryNotFound(String),
+    #[error("Unsupported hash version {version} in entry {id}")]
+    UnsupportedHashVersion { id: String, version: u32 },
+    #[error("Hash version downgrade in entry {id}: v{version} after v{previous}")]
+    HashVersionDowngrade { id: String, version: u32, previous: u32 },
+    #[error("Entry {id} links forward to {previous_hash}, which is not yet in the chain")]
+    ForwardLink { id: String, previous_hash: String },
+    #[error("Entry {id} links back to entry {target} instead of its predecessor")]
+    BackLink { id: String, target: String },
+    #[error("Entry {id} restarts the chain from genesis")]
+    GenesisMidChain { id: String },
+    #[error("Sequence gap for actor {actor:?} at entry {id}: expected {expected}, found {found}")]
+    ActorSequenceGap { id: String, actor: String, expected: u64, found: u64 },
+    #[error("Malformed line {line}: not a JSON audit entry")]
+    MalformedLine { line: usize },
+    #[error("The log holds encrypted entries; open it with its audit key")]
+    KeyRequired,
+    #[error("Cannot decrypt entry {id}: wrong key or tampered ciphertext")]
+    Decryption { id: String },
+    #[error("Invalid audit key: {0}")]
+    InvalidKey(String),
+    #[error("Line {line}: details do not match the {event_type} schema")]
+    DetailsSchemaMismatch { line: usize, event_type: String },
+}
+
+impl AuditError {
+    /// Whether the error is evidence the log was tampered with or corrupted.
+    pub fn is_integrity_failure(&self) -> bool {
+        matches!(
+            self,
+            AuditError::IntegrityViolation { .. }
+                | AuditError::Serialization(_)
+                | AuditError::ForwardLink { .. }
+                | AuditError::BackLink { .. }
+                | AuditError::GenesisMidChain { .. }
+                | AuditError::ActorSequenceGap { .. }
+                | AuditError::MalformedLine { .. }
+                | AuditError::Decryption { .. }
+        )
+    }
+}
+
+/// Retry schedule for audit writes that fail with a transient IO error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first; 1 disables retries.
+    pub max_attempts: u32,
+    /// Delay before the second attempt, doubled for each further attempt.
+    pub initial_backoff: Duration,
+    /// Upper bound on a single delay.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Fail on the first error.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Delay after failed attempt number `attempt` (1-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+/// IO errors worth retrying: the operation may succeed unchanged later.
+fn is_transient(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::Interrupted
+            | io::ErrorKind::WouldBlock
+            | io::ErrorKind::TimedOut
+            | io::ErrorKind::ResourceBusy
+            | io::ErrorKind::StaleNetworkFileHandle
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::BrokenPipe
+    )
+}
+
+/// Storage that audit lines are appended to.
+///
+/// Writes always go to the end, as with a file opened for append.
+pub trait AppendTarget: Read + Write + Seek {
+    /// Current length in bytes.
+    fn size(&self) -> io::Result<u64>;
+    /// Truncate to `len` bytes.
+    fn truncate(&self, len: u64) -> io::Result<()>;
+    /// Make written data durable.
+    fn sync(&self) -> io::Result<()>;
 }
 
+impl AppendTarget for File {
+    fn size(&self) -> io::Result<u64> {
+        Ok(self.metadata()?.len())
+    }
+
+    fn truncate(&self, len: u64) -> io::Result<()> {
+        self.set_len(len)
+    }
+
+    fn sync(&self) -> io::Result<()> {
+        self.sync_data()
+    }
+}
+
+/// Durably append `line` to the target returned by `open`, retrying
+/// transient errors per `retry`.
+///
+/// The length before the first attempt is remembered so a retry can tell
+/// what an earlier attempt left behind: a complete line is only re-synced,
+/// a partial one is truncated and rewritten. If every attempt fails the
+/// target is truncated back, so the line is either written exactly once or
+/// not at all. This relies on being the only writer: another appending
+/// between attempts would be mistaken for a partial line and truncated.
+pub fn append_exactly_once<T, O>(mut open: O, retry: &RetryPolicy, line: &[u8]) -> io::Result<()>
+where
+    T: AppendTarget,
+    O: FnMut() -> io::Result<T>,
+{
+    let mut start = None;
+    let mut attempt = 1;
+    loop {
+        match try_append(&mut open, &mut start, line) {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt < retry.max_attempts && is_transient(&e) => {
+                std::thread::sleep(retry.backoff(attempt));
+                attempt += 1;
+            }
+            Err(e) => {
+                if let Some(start) = start {
+                    // Best effort: the original error is what matters.
+                    let _ = open().and_then(|target| target.truncate(start));
+                }
+                return Err(e);
+            }
+        }
+    }
+}
+
+fn try_append<T, O>(open: &mut O, start: &mut Option<u64>, line: &[u8]) -> io::Result<()>
+where
+    T: AppendTarget,
+    O: FnMut() -> io::Result<T>,
+{
+    let mut target = open()?;
+    let len = target.size()?;
+
+    match *start {
+        None => *start = Some(len),
+        Some(start) if len > start => {
+            // An earlier attempt got some or all of the line out
+            let mut written = Vec::with_capacity(line.len());
+            target.seek(SeekFrom::Start(start))?;
+            target.read_to_end(&mut written)?;
+            if written == line {
+                return target.sync();
+            }
+            target.truncate(start)?;
+        }
+        Some(_) => {}
+    }
+
+    target.write_all(line)?;
+    target.sync()
+}
+
+/// One entry of [`AuditLog::append_batch`]: the arguments of [`AuditLog::append`].
+pub type BatchEntry<'a> = (AuditEventType, &'a str, Option<&'a AuditTarget>, serde_json::Value);
+
+/// Callback invoked with each appended entry.
+pub type AuditSubscriber = Box<dyn Fn(&AuditEntry) + Send>;
+
+/// Maximum entries queued for a single subscriber before new ones are dropped.
+pub const SUBSCRIBER_QUEUE_CAPACITY: usize = 1024;
+
+/// Handle to a registered subscriber.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+struct Subscription {
+    id: SubscriptionId,
+    tx: SyncSender<AuditEntry>,
+    dropped: Arc<AtomicU64>,
+}
+
+/// An append-only, hash-chained audit log file.
+///
+/// Only one `AuditLog`, in one process, may append to a file at a time.
+/// Appends continue the chain from the head read at open, and a retried
+/// write treats anything past its starting length as its own. Threads
+/// that append share one log behind a lock rather than each opening it.
 pub struct AuditLog {
     path: PathBuf,
     last_hash: String,
+    stream: Option<String>,
+    retry: RetryPolicy,
+    subscriptions: Vec<Subscription>,
+    next_subscription: u64,
+    /// Most recent entries, oldest first; kept contiguous for `recent`.
+    recent: VecDeque<AuditEntry>,
+    recent_capacity: usize,
+    /// Seals new entries and opens sealed ones; see [`crate::encryption`].
+    key: Option<AuditKey>,
+    /// Whether any entry on disk is sealed.
+    sealed: bool,
+    /// Whether verification also checks `details` against the event type.
+    check_details: bool,
+    /// Prefix vouched for by the last [`AuditLog::verify_incremental`].
+    verified: Option<VerifiedPrefix>,
+    /// Labels copied into new entries about each adapter.
+    adapter_labels: HashMap<String, AdapterLabels>,
+    /// Last sequence number of each actor on disk; `None` until sealed
+    /// entries can be read.
+    actor_seqs: Option<ActorSequences>,
 }
 
 impl AuditLog {
     /// Create or open an audit log.
+    ///
+    /// A log holding encrypted entries opens without its key, but only its
+    /// chain structure is readable until [`AuditLog::set_key`] is called.
     pub fn open(path: PathBuf) -> Result<Self, AuditError> {
-        let last_hash = if path.exists() {
+        let (last_hash, sealed, actor_seqs) = if path.exists() {
             Self::get_last_hash(&path)?
         } else {
-            "genesis".to_string()
+            ("genesis".to_string(), false, Some(ActorSequences::default()))
         };
 
-        Ok(Self { path, last_hash })
+        Ok(Self {
+            path,
+            last_hash,
+            stream: None,
+            retry: RetryPolicy::default(),
+            subscriptions: Vec::new(),
+            next_subscription: 0,
+            recent: VecDeque::new(),
+            recent_capacity: 0,
+            key: None,
+            sealed,
+            check_details: false,
+            verified: None,
+            adapter_labels: HashMap::new(),
+            actor_seqs,
+        })
+    }
+
+    /// Open an audit log that encrypts new entries with `key`.
+    ///
+    /// Existing plaintext entries stay readable, so a log can switch to
+    /// encryption part way through.
+    pub fn open_with_key(path: PathBuf, key: AuditKey) -> Result<Self, AuditError> {
+        let mut log = Self::open(path)?;
+        log.set_key(key)?;
+        Ok(log)
+    }
+
+    /// Encrypt new entries with `key` and use it to read sealed ones.
+    pub fn set_key(&mut self, key: AuditKey) -> Result<(), AuditError> {
+        self.key = Some(key);
+        self.set_recent_capacity(self.recent_capacity)
+    }
+
+    /// Also check each verified entry's `details` against the shape of its
+    /// event type; see [`crate::details`].
+    ///
+    /// Off by default: it parses every entry's details, which the hash
+    /// check alone avoids.
+    pub fn set_details_validation(&mut self, enabled: bool) {
+        self.check_details = enabled;
+    }
+
+    /// Record adapters' labels in new entries about them, e.g. from
+    /// [`Registry::labels`](crate::registry::Registry::labels).
+    ///
+    /// An entry targeting a labelled adapter gets its labels under
+    /// `labels`; an entry listing `affected_adapters` gets each labelled
+    /// one's under `adapter_labels`. Keys the caller already set are left
+    /// alone. Labels are part of the hashed entry, so they record what
+    /// the adapter was labelled at the time.
+    pub fn set_adapter_labels(&mut self, labels: HashMap<String, AdapterLabels>) {
+        self.adapter_labels = labels;
+    }
+
+    /// Whether new entries are encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// Open an audit log keeping its last `capacity` entries in memory.
+    pub fn open_with_recent(path: PathBuf, capacity: usize) -> Result<Self, AuditError> {
+        let mut log = Self::open(path)?;
+        log.set_recent_capacity(capacity)?;
+        Ok(log)
+    }
+
+    /// Create or open a named stream inside a shared audit directory.
+    ///
+    /// Each stream is an independent chain with its own head hash.
+    pub fn open_stream(dir: &Path, stream: &str) -> Result<Self, AuditError> {
+        let mut log = Self::open(stream_path(dir, stream)?)?;
+        log.stream = Some(stream.to_string());
+        Ok(log)
+    }
+
+    /// Stream namespace of this log, if opened as a stream.
+    pub fn stream(&self) -> Option<&str> {
+        self.stream.as_deref()
+    }
+
+    /// Path of the log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Current head hash of the chain.
+    pub fn head_hash(&self) -> &str {
+        &self.last_hash
+    }
+
+    /// Set how appends retry transient IO errors.
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.retry = retry;
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry
     }
 
-    fn get_last_hash(path: &PathBuf) -> Result<String, AuditError> {
+    /// Keep the last `capacity` entries in memory; 0 disables the buffer.
+    ///
+    /// The buffer is filled from the tail of the file, without scanning
+    /// the whole log, and then follows this handle's appends. Entries
+    /// appended through other handles are not seen.
+    pub fn set_recent_capacity(&mut self, capacity: usize) -> Result<(), AuditError> {
+        self.recent = read_tail(&self.path, capacity, self.key.as_ref())?.into();
+        self.recent_capacity = capacity;
+        Ok(())
+    }
+
+    /// Up to `n` of the most recent entries, oldest first.
+    ///
+    /// Served from memory, so at most the configured capacity.
+    pub fn recent(&self, n: usize) -> &[AuditEntry] {
+        let (all, _) = self.recent.as_slices();
+        &all[all.len().saturating_sub(n)..]
+    }
+
+    /// Head hash of the log at `path`, whether any entry is sealed, and
+    /// each actor's last sequence number unless a sealed entry hides it.
+    fn get_last_hash(path: &PathBuf) -> Result<(String, bool, Option<ActorSequences>), AuditError> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
         let mut last_hash = "genesis".to_string();
+        let mut sealed = false;
+        let mut actor_seqs = ActorSequences::default();
 
-        for line in reader.lines() {
+        for (index, line) in reader.lines().enumerate() {
             let line = line?;
-            if !line.trim().is_empty() {
-                let entry: AuditEntry = serde_json::from_str(&line)?;
+            if encryption::is_sealed(&line) {
+                last_hash = encryption::sealed_hash(&line)?;
+                sealed = true;
+            } else if let Some(entry) = parse_line::<AuditEntry>(&line, index + 1)? {
+                actor_seqs.record(&entry.actor, hashed_seq(entry.hash_version, entry.actor_seq));
                 last_hash = entry.hash;
             }
         }
 
-        Ok(last_hash)
+        Ok((last_hash, sealed, (!sealed).then_some(actor_seqs)))
+    }
+
+    /// Each actor's last sequence number, read with the key if the log
+    /// holds sealed entries.
+    fn load_actor_seqs(&self) -> Result<ActorSequences, AuditError> {
+        let mut actor_seqs = ActorSequences::default();
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut plain = String::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if let Some(entry) = self.parse_stored::<BorrowedEntry>(&line, index + 1, &mut plain)? {
+                actor_seqs.record(&entry.actor, hashed_seq(entry.hash_version, entry.actor_seq));
+            }
+        }
+        Ok(actor_seqs)
+    }
+
+    /// Parse line `number`, first decrypting it into `plain` if sealed.
+    fn parse_stored<'a, T: Deserialize<'a>>(
+        &self,
+        line: &'a str,
+        number: usize,
+        plain: &'a mut String,
+    ) -> Result<Option<T>, AuditError> {
+        if !encryption::is_sealed(line) {
+            return parse_line(line, number);
+        }
+        let key = self.key.as_ref().ok_or(AuditError::KeyRequired)?;
+        *plain = key.unseal(line)?;
+        let plain: &'a String = plain;
+        parse_line(plain, number)
+    }
+
+    /// Read all entries in chain order.
+    pub fn entries(&self) -> Result<Vec<AuditEntry>, AuditError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut entries = Vec::new();
+        let mut plain = String::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            entries.extend(self.parse_stored::<AuditEntry>(&line, index + 1, &mut plain)?);
+        }
+        Ok(entries)
+    }
+
+    /// Entries about `target`, in chain order.
+    pub fn entries_for(&self, target: &AuditTarget) -> Result<Vec<AuditEntry>, AuditError> {
+        let mut entries = self.entries()?;
+        entries.retain(|entry| entry.target().as_ref() == Some(target));
+        Ok(entries)
+    }
+
+    /// Entries labelled `key` = `value`, directly or through an affected
+    /// adapter; see [`AuditEntry::has_label`].
+    pub fn entries_with_label(&self, key: &str, value: &str) -> Result<Vec<AuditEntry>, AuditError> {
+        let mut entries = self.entries()?;
+        entries.retain(|entry| entry.has_label(key, value));
+        Ok(entries)
     }
 
     /// Append an audit entry (immutable - cannot be modified).
@@ -89,105 +749,1163 @@ impl AuditLog {
         &mut self,
         event_type: AuditEventType,
         actor: &str,
-        target_type: Option<&str>,
-        target_id: Option<&str>,
+        target: Option<&AuditTarget>,
         details: serde_json::Value,
     ) -> Result<AuditEntry, AuditError> {
-        let id = uuid::Uuid::new_v4().to_string();
-        let timestamp = Utc::now();
-        let previous_hash = self.last_hash.clone();
+        let mut entries = self.append_batch(vec![(event_type, actor, target, details)])?;
+        Ok(entries.pop().expect("one entry appended"))
+    }
 
-        // Compute hash
-        let hash = self.compute_hash(&id, &timestamp, &event_type, actor, &details, &previous_hash);
+    /// Append several entries as one all-or-nothing write.
+    ///
+    /// The entries are chained in order and written with a single write
+    /// and fsync; if that fails, nothing is appended and the head hash is
+    /// unchanged. Once written they are ordinary chained entries.
+    ///
+    /// Each entry gets the next sequence number of its actor, so a
+    /// missing entry from any one actor shows as a gap; see
+    /// [`AuditError::ActorSequenceGap`]. Numbers follow the entries
+    /// already on disk when the log was opened and this handle's appends.
+    pub fn append_batch(&mut self, batch: Vec<BatchEntry<'_>>) -> Result<Vec<AuditEntry>, AuditError> {
+        let mut entries = Vec::with_capacity(batch.len());
+        let mut lines = Vec::new();
+        let mut previous_hash = self.last_hash.clone();
 
-        let entry = AuditEntry {
-            id,
-            timestamp,
-            event_type,
-            actor: actor.to_string(),
-            target_type: target_type.map(String::from),
-            target_id: target_id.map(String::from),
-            details,
-            previous_hash,
-            hash: hash.clone(),
+        if self.sealed && self.key.is_none() {
+            // Never mix plaintext into an encrypted log.
+            return Err(AuditError::KeyRequired);
+        }
+        // Taken until the write succeeds; after a failure they are
+        // re-read from disk by the next append.
+        let mut actor_seqs = match self.actor_seqs.take() {
+            Some(actor_seqs) => actor_seqs,
+            None if self.path.exists() => self.load_actor_seqs()?,
+            None => ActorSequences::default(),
         };
 
-        // Append to file
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.path)?;
+        for (event_type, actor, target, mut details) in batch {
+            label_details(&self.adapter_labels, target, &mut details);
+            let id = uuid::Uuid::new_v4().to_string();
+            let timestamp = Utc::now();
+            let actor_seq = actor_seqs.next(actor);
+            let hash = hash_entry(
+                HASH_VERSION,
+                &HashedFields {
+                    id: &id,
+                    timestamp: &timestamp,
+                    event_tag: event_type.tag(),
+                    actor,
+                    actor_seq: Some(actor_seq),
+                    previous_hash: &previous_hash,
+                },
+                &details,
+            );
+
+            let entry = AuditEntry {
+                id,
+                timestamp,
+                event_type,
+                actor: actor.to_string(),
+                actor_seq: Some(actor_seq),
+                target_type: target.map(|t| t.kind().to_string()),
+                target_id: target.and_then(AuditTarget::id).map(String::from),
+                details,
+                previous_hash,
+                hash: hash.clone(),
+                hash_version: HASH_VERSION,
+            };
+            match self.key {
+                Some(ref key) => lines.extend(key.seal(&entry)?),
+                None => serde_json::to_writer(&mut lines, &entry)?,
+            }
+            lines.push(b'\n');
+            entries.push(entry);
+            previous_hash = hash;
+        }
+        if entries.is_empty() {
+            self.actor_seqs = Some(actor_seqs);
+            return Ok(entries);
+        }
+
+        self.write_line(&lines)?;
 
-        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        self.actor_seqs = Some(actor_seqs);
+        self.last_hash = previous_hash;
+        self.sealed |= self.key.is_some();
+        for entry in &entries {
+            if self.recent_capacity > 0 {
+                if self.recent.len() == self.recent_capacity {
+                    self.recent.pop_front();
+                }
+                self.recent.push_back(entry.clone());
+            }
+            self.publish(entry);
+        }
+        self.recent.make_contiguous();
 
-        self.last_hash = hash;
+        Ok(entries)
+    }
 
-        Ok(entry)
+    /// Durably append one or more lines, retrying transient errors per the
+    /// policy; see [`append_exactly_once`].
+    fn write_line(&self, line: &[u8]) -> Result<(), AuditError> {
+        let open = || OpenOptions::new().create(true).read(true).append(true).open(&self.path);
+        Ok(append_exactly_once(open, &self.retry, line)?)
     }
 
-    fn compute_hash(
-        &self,
-        id: &str,
-        timestamp: &DateTime<Utc>,
-        event_type: &AuditEventType,
-        actor: &str,
-        details: &serde_json::Value,
-        previous_hash: &str,
-    ) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(id.as_bytes());
-        hasher.update(timestamp.to_rfc3339().as_bytes());
-        hasher.update(format!("{:?}", event_type).as_bytes());
-        hasher.update(actor.as_bytes());
-        hasher.update(details.to_string().as_bytes());
-        hasher.update(previous_hash.as_bytes());
-        format!("{:x}", hasher.finalize())[..16].to_string()
+    /// Register a callback invoked with every entry after it is durably appended.
+    ///
+    /// Each subscriber runs on its own thread behind a bounded queue, so a
+    /// slow subscriber only delays itself (entries beyond the queue capacity
+    /// are dropped for it and counted) and a panicking one is unsubscribed.
+    pub fn subscribe(&mut self, callback: AuditSubscriber) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription);
+        self.next_subscription += 1;
+
+        let (tx, rx) = sync_channel::<AuditEntry>(SUBSCRIBER_QUEUE_CAPACITY);
+        std::thread::spawn(move || {
+            for entry in rx {
+                callback(&entry);
+            }
+        });
+
+        self.subscriptions.push(Subscription {
+            id,
+            tx,
+            dropped: Arc::new(AtomicU64::new(0)),
+        });
+        id
+    }
+
+    /// Remove a subscriber; its thread exits once its queue drains.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.subscriptions.len();
+        self.subscriptions.retain(|s| s.id != id);
+        self.subscriptions.len() != before
+    }
+
+    /// Entries dropped for a subscriber because its queue was full.
+    pub fn dropped_for(&self, id: SubscriptionId) -> Option<u64> {
+        self.subscriptions
+            .iter()
+            .find(|s| s.id == id)
+            .map(|s| s.dropped.load(Ordering::Relaxed))
+    }
+
+    fn publish(&mut self, entry: &AuditEntry) {
+        self.subscriptions.retain(|sub| match sub.tx.try_send(entry.clone()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                sub.dropped.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            // The subscriber thread has exited (its callback panicked).
+            Err(TrySendError::Disconnected(_)) => false,
+        });
     }
 
     /// Verify integrity of the entire audit log.
+    ///
+    /// Each entry is checked under its own hash version, so logs written
+    /// before domain separation still verify. Versions may only increase
+    /// along the chain; a later legacy entry is rejected as a downgrade.
+    /// Each actor's sequence numbers must run on from 1 without a gap.
+    ///
+    /// Entries are borrowed from a single reused line buffer; only
+    /// `details` is parsed, to hash its canonical form. The entries that
+    /// verify are exactly those that verify when parsed whole: stored
+    /// `details` that are not canonical do not, even under a hash of
+    /// their raw bytes.
     pub fn verify_integrity(&self) -> Result<bool, AuditError> {
+        self.verify_integrity_from("genesis")
+    }
+
+    /// Verify a log that continues another chain: its first entry must
+    /// link to `start`, the head of the segment before it, instead of
+    /// `genesis`. Checks are otherwise those of
+    /// [`AuditLog::verify_integrity`].
+    pub fn verify_integrity_from(&self, start: &str) -> Result<bool, AuditError> {
+        self.verify_window(start, None, None)?;
+        Ok(true)
+    }
+
+    /// Verify the whole log without stopping at the first violation,
+    /// returning every issue found; empty when the log is intact.
+    ///
+    /// Report-only: for assessing the damage to a log, e.g. a legacy one
+    /// being imported, before deciding what to do about it. Each entry
+    /// gets at most one issue, and checking resumes from its stored hash
+    /// and sequence number, so one tampered entry does not cascade into
+    /// the rest. Unreadable lines are issues too; only failures to read
+    /// the file at all, or a missing key, are errors.
+    pub fn verify_report(&self) -> Result<Vec<IntegrityIssue>, AuditError> {
+        self.verify_report_from("genesis")
+    }
+
+    /// [`AuditLog::verify_report`] for a log continuing another chain
+    /// from `start`; see [`AuditLog::verify_integrity_from`].
+    pub fn verify_report_from(&self, start: &str) -> Result<Vec<IntegrityIssue>, AuditError> {
+        let mut issues = Vec::new();
         if !self.path.exists() {
-            return Ok(true);
+            return Ok(issues);
         }
 
-        let file = File::open(&self.path)?;
-        let reader = BufReader::new(file);
-        let mut expected_prev = "genesis".to_string();
+        let mut reader = BufReader::with_capacity(64 * 1024, File::open(&self.path)?);
+        let mut expected_prev = start.to_string();
+        let mut max_version = LEGACY_HASH_VERSION;
+        let mut actor_seqs = match start {
+            "genesis" => ActorSequences::default(),
+            _ => ActorSequences::continuing(),
+        };
+        let mut line = String::new();
+        let mut plain = String::new();
+        let mut position = 0;
+        let mut number = 0;
 
-        for line in reader.lines() {
-            let line = line?;
-            if line.trim().is_empty() {
-                continue;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            number += 1;
+            let entry = match self.parse_stored::<BorrowedEntry>(&line, number, &mut plain) {
+                Ok(Some(entry)) => entry,
+                Ok(None) => continue,
+                Err(e) if e.is_integrity_failure() => {
+                    issues.push(IntegrityIssue {
+                        line: number,
+                        entry_id: None,
+                        error: e,
+                    });
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            position += 1;
+
+            let error = match check_entry(&entry, &expected_prev, &mut max_version, &mut actor_seqs) {
+                Err(AuditError::IntegrityViolation { .. }) if entry.previous_hash != expected_prev => {
+                    Some(self.classify_broken_link(position - 1, &entry, &expected_prev)?)
+                }
+                Err(e) => Some(e),
+                Ok(()) if self.check_details && details::check(&entry.event_type, entry.details.get()).is_err() => {
+                    Some(AuditError::DetailsSchemaMismatch {
+                        line: number,
+                        event_type: entry.event_type.tag().to_string(),
+                    })
+                }
+                Ok(()) => None,
+            };
+            if let Some(error) = error {
+                issues.push(IntegrityIssue {
+                    line: number,
+                    entry_id: Some(entry.id.to_string()),
+                    error,
+                });
+                max_version = max_version.max(entry.hash_version.min(HASH_VERSION));
+                actor_seqs.record(&entry.actor, hashed_seq(entry.hash_version, entry.actor_seq));
+            }
+
+            expected_prev.clear();
+            expected_prev.push_str(&entry.hash);
+        }
+        Ok(issues)
+    }
+
+    /// Verify the whole log like [`AuditLog::verify_integrity`], except
+    /// for the entries named in `overrides`.
+    ///
+    /// Each named entry is taken to be known-bad: it is not checked, and
+    /// the chain continues from the corrected hash it maps to, which the
+    /// next entry must link to in place of the entry's stored hash.
+    ///
+    /// An advanced recovery tool for a log with a legitimately corrupt
+    /// entry, never a default path. Every override used is reported, as
+    /// is every one naming no entry in the log.
+    pub fn verify_integrity_with_overrides(
+        &self,
+        overrides: &HashMap<String, String>,
+    ) -> Result<OverriddenVerification, AuditError> {
+        let mut report = OverriddenVerification::default();
+        let mut unused: BTreeSet<&str> = overrides.keys().map(String::as_str).collect();
+        if self.path.exists() {
+            let mut reader = BufReader::with_capacity(64 * 1024, File::open(&self.path)?);
+            let mut expected_prev = "genesis".to_string();
+            let mut max_version = LEGACY_HASH_VERSION;
+            let mut actor_seqs = ActorSequences::default();
+            let mut line = String::new();
+            let mut plain = String::new();
+            let mut position = 0;
+            let mut number = 0;
+
+            loop {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 {
+                    break;
+                }
+                number += 1;
+                let Some(entry) = self.parse_stored::<BorrowedEntry>(&line, number, &mut plain)? else {
+                    continue;
+                };
+                position += 1;
+
+                if let Some(corrected) = overrides.get(&*entry.id) {
+                    unused.remove(&*entry.id);
+                    report.applied.push(AppliedOverride {
+                        line: number,
+                        entry_id: entry.id.to_string(),
+                        stored_hash: entry.hash.to_string(),
+                        corrected_hash: corrected.clone(),
+                    });
+                    max_version = max_version.max(entry.hash_version.min(HASH_VERSION));
+                    actor_seqs.record(&entry.actor, hashed_seq(entry.hash_version, entry.actor_seq));
+                    expected_prev.clone_from(corrected);
+                    continue;
+                }
+
+                if let Err(e) = check_entry(&entry, &expected_prev, &mut max_version, &mut actor_seqs) {
+                    return Err(match e {
+                        AuditError::IntegrityViolation { .. } if entry.previous_hash != expected_prev => {
+                            self.classify_broken_link(position - 1, &entry, &expected_prev)?
+                        }
+                        e => e,
+                    });
+                }
+                if self.check_details && details::check(&entry.event_type, entry.details.get()).is_err() {
+                    return Err(AuditError::DetailsSchemaMismatch {
+                        line: number,
+                        event_type: entry.event_type.tag().to_string(),
+                    });
+                }
+                report.verified += 1;
+
+                expected_prev.clear();
+                expected_prev.push_str(&entry.hash);
             }
+        }
+
+        report.unused = unused.into_iter().map(String::from).collect();
+        Ok(report)
+    }
+
+    /// Verify only the entries timestamped within `[since, until]`.
+    ///
+    /// The window starts at the first entry at or after `since` and ends
+    /// before the first entry after `until`. Its first entry must still
+    /// link to the entry immediately before the window (or genesis), whose
+    /// own hash is checked too, but nothing else outside the window is:
+    /// this is a scoped, not a full, verification. Hash versions and actor
+    /// sequences are checked from the window's start, as for a segment.
+    pub fn verify_range(
+        &self,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<RangeVerification, AuditError> {
+        self.verify_window("genesis", since, until)
+    }
+
+    /// Verify only entries appended since the last call, then remember the
+    /// new head.
+    ///
+    /// The first call verifies the whole log. Later calls trust the prefix
+    /// already verified, after checking that the file has not shrunk and
+    /// that the last verified line still carries the cached head hash; if
+    /// either check fails the whole log is verified again. A line without
+    /// its trailing newline is still being written and is left for the
+    /// next call.
+    ///
+    /// Edits inside the cached prefix that keep its last line intact are
+    /// not noticed here; [`AuditLog::verify_integrity`] still checks
+    /// everything. On error the cache is dropped.
+    pub fn verify_incremental(&mut self) -> Result<IncrementalVerification, AuditError> {
+        let result = self.extend_verified();
+        if result.is_err() {
+            self.verified = None;
+        }
+        result
+    }
+
+    /// Forget the verified prefix, so the next incremental verification
+    /// covers the whole log.
+    pub fn invalidate_verification(&mut self) {
+        self.verified = None;
+    }
 
-            let entry: AuditEntry = serde_json::from_str(&line)?;
+    fn extend_verified(&mut self) -> Result<IncrementalVerification, AuditError> {
+        if !self.path.exists() {
+            self.verified = Some(VerifiedPrefix::default());
+            return Ok(IncrementalVerification {
+                verified: 0,
+                appended: 0,
+                head: "genesis".to_string(),
+                rescanned: false,
+            });
+        }
+
+        let mut file = File::open(&self.path)?;
+        let cached = match self.verified.take() {
+            Some(prefix) if prefix_intact(&mut file, &prefix)? => Some(prefix),
+            _ => None,
+        };
+        let rescanned = cached.is_none();
+        let mut prefix = cached.unwrap_or_default();
+        let start = prefix.entries;
+
+        file.seek(SeekFrom::Start(prefix.len))?;
+        let mut reader = BufReader::with_capacity(64 * 1024, file);
+        let mut line = String::new();
+        let mut plain = String::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 || !line.ends_with('\n') {
+                break;
+            }
+            let line_start = prefix.len;
+            prefix.len += line.len() as u64;
+            prefix.lines += 1;
+            let Some(entry) = self.parse_stored::<BorrowedEntry>(&line, prefix.lines, &mut plain)? else {
+                continue;
+            };
 
-            if entry.previous_hash != expected_prev {
-                return Err(AuditError::IntegrityViolation {
-                    expected: expected_prev,
-                    actual: entry.previous_hash,
+            if let Err(e) = check_entry(&entry, &prefix.head, &mut prefix.max_version, &mut prefix.actor_seqs) {
+                return Err(match e {
+                    AuditError::IntegrityViolation { .. } if entry.previous_hash != prefix.head => {
+                        self.classify_broken_link(prefix.entries, &entry, &prefix.head)?
+                    }
+                    e => e,
+                });
+            }
+            if self.check_details && details::check(&entry.event_type, entry.details.get()).is_err() {
+                return Err(AuditError::DetailsSchemaMismatch {
+                    line: prefix.lines,
+                    event_type: entry.event_type.tag().to_string(),
                 });
             }
+            prefix.entries += 1;
+            prefix.last_line = line_start;
+            prefix.head.clear();
+            prefix.head.push_str(&entry.hash);
+        }
 
-            let computed = self.compute_hash(
-                &entry.id,
-                &entry.timestamp,
-                &entry.event_type,
-                &entry.actor,
-                &entry.details,
-                &entry.previous_hash,
-            );
+        let report = IncrementalVerification {
+            verified: prefix.entries,
+            appended: prefix.entries - start,
+            head: prefix.head.clone(),
+            rescanned,
+        };
+        self.verified = Some(prefix);
+        Ok(report)
+    }
+
+    /// Explain why the entry at `position` does not link to its predecessor.
+    ///
+    /// Only called once the chain is already broken, so the extra pass
+    /// costs nothing on intact logs. A link to an earlier entry covers
+    /// reordering, forks and cycles; anything unresolvable is reported
+    /// as a plain integrity violation.
+    fn classify_broken_link(
+        &self,
+        position: usize,
+        entry: &BorrowedEntry,
+        expected_prev: &str,
+    ) -> Result<AuditError, AuditError> {
+        if entry.previous_hash == "genesis" && position > 0 {
+            return Ok(AuditError::GenesisMidChain { id: entry.id.to_string() });
+        }
+
+        let reader = BufReader::with_capacity(64 * 1024, File::open(&self.path)?);
+        let mut index = 0;
+        let mut plain = String::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            match self.parse_stored::<BorrowedEntry>(&line, number + 1, &mut plain) {
+                Ok(None) => continue,
+                Ok(Some(other)) if other.hash == entry.previous_hash => {
+                    return Ok(if index < position {
+                        AuditError::BackLink {
+                            id: entry.id.to_string(),
+                            target: other.id.to_string(),
+                        }
+                    } else {
+                        AuditError::ForwardLink {
+                            id: entry.id.to_string(),
+                            previous_hash: entry.previous_hash.to_string(),
+                        }
+                    });
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+
+        Ok(AuditError::IntegrityViolation {
+            expected: expected_prev.to_string(),
+            actual: entry.previous_hash.to_string(),
+        })
+    }
+
+    fn verify_window(
+        &self,
+        start: &str,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<RangeVerification, AuditError> {
+        let mut report = RangeVerification::default();
+        if !self.path.exists() {
+            return Ok(report);
+        }
+
+        let file = File::open(&self.path)?;
+        let mut reader = BufReader::with_capacity(64 * 1024, file);
+        let mut expected_prev = start.to_string();
+        let mut max_version = LEGACY_HASH_VERSION;
+        let mut actor_seqs = match start {
+            "genesis" => ActorSequences::default(),
+            _ => ActorSequences::continuing(),
+        };
+        let mut line = String::new();
+        let mut plain = String::new();
+        let mut in_window = since.is_none();
+        let mut position = 0;
+        let mut number = 0;
+        // The last entry before the window, kept to check what it links to.
+        let mut before = String::new();
+        let mut before_plain = String::new();
+        let mut before_number = 0;
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            number += 1;
+            let Some(entry) = self.parse_stored::<BorrowedEntry>(&line, number, &mut plain)? else {
+                continue;
+            };
+            position += 1;
+
+            if !in_window {
+                if since.is_some_and(|since| entry.timestamp < since) {
+                    // Only the link to the window's first entry matters
+                    report.skipped_before += 1;
+                    expected_prev.clear();
+                    expected_prev.push_str(&entry.hash);
+                    before.clone_from(&line);
+                    before_number = number;
+                    continue;
+                }
+                in_window = true;
+                if report.skipped_before > 0 {
+                    // Nothing before the window is trusted but this entry's
+                    // own hash; actor numbering restarts as in a segment.
+                    let preceding = self
+                        .parse_stored::<BorrowedEntry>(&before, before_number, &mut before_plain)?
+                        .expect("a skipped line held an entry");
+                    check_hash(&preceding)?;
+                    max_version = preceding.hash_version;
+                    actor_seqs = ActorSequences::continuing();
+                }
+            }
+            if until.is_some_and(|until| entry.timestamp > until) {
+                break;
+            }
 
-            if computed != entry.hash {
-                return Err(AuditError::IntegrityViolation {
-                    expected: computed,
-                    actual: entry.hash,
+            if let Err(e) = check_entry(&entry, &expected_prev, &mut max_version, &mut actor_seqs) {
+                return Err(match e {
+                    AuditError::IntegrityViolation { .. } if entry.previous_hash != expected_prev => {
+                        self.classify_broken_link(position - 1, &entry, &expected_prev)?
+                    }
+                    e => e,
                 });
             }
+            if self.check_details && details::check(&entry.event_type, entry.details.get()).is_err() {
+                return Err(AuditError::DetailsSchemaMismatch {
+                    line: number,
+                    event_type: entry.event_type.tag().to_string(),
+                });
+            }
+            report.verified += 1;
 
-            expected_prev = entry.hash;
+            expected_prev.clear();
+            expected_prev.push_str(&entry.hash);
         }
 
-        Ok(true)
+        Ok(report)
+    }
+}
+
+/// A problem found by [`AuditLog::verify_report`].
+#[derive(Debug)]
+pub struct IntegrityIssue {
+    /// 1-based line of the log file.
+    pub line: usize,
+    /// The entry's id; `None` if the line could not be read as one.
+    pub entry_id: Option<String>,
+    pub error: AuditError,
+}
+
+impl std::fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.entry_id {
+            Some(ref id) => write!(f, "line {} (entry {}): {}", self.line, id, self.error),
+            None => write!(f, "line {}: {}", self.line, self.error),
+        }
+    }
+}
+
+/// Outcome of [`AuditLog::verify_integrity_with_overrides`].
+#[derive(Debug, Clone, Default)]
+pub struct OverriddenVerification {
+    /// Entries checked and found intact.
+    pub verified: usize,
+    /// Overrides applied, in log order.
+    pub applied: Vec<AppliedOverride>,
+    /// Overridden ids that no entry carries, sorted.
+    pub unused: Vec<String>,
+}
+
+/// An entry skipped by [`AuditLog::verify_integrity_with_overrides`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedOverride {
+    /// 1-based line of the log file.
+    pub line: usize,
+    pub entry_id: String,
+    /// The hash stored in the entry, not trusted.
+    pub stored_hash: String,
+    /// The hash the chain continued from instead.
+    pub corrected_hash: String,
+}
+
+/// Outcome of a scoped verification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RangeVerification {
+    /// Entries inside the window, all verified.
+    pub verified: usize,
+    /// Entries before the window, read for linkage only.
+    pub skipped_before: usize,
+}
+
+/// Outcome of [`AuditLog::verify_incremental`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncrementalVerification {
+    /// Entries verified in total, cached and new.
+    pub verified: usize,
+    /// Entries verified by this call.
+    pub appended: usize,
+    /// Hash of the last verified entry, or `genesis`.
+    pub head: String,
+    /// Whether the cache was missing or stale, so the whole log was read.
+    pub rescanned: bool,
+}
+
+/// Verified prefix of a log file, cached between incremental verifications.
+#[derive(Debug, Clone)]
+struct VerifiedPrefix {
+    /// Length in bytes; always ends just after a newline.
+    len: u64,
+    /// Offset of the line holding the last verified entry.
+    last_line: u64,
+    lines: usize,
+    entries: usize,
+    head: String,
+    max_version: u32,
+    actor_seqs: ActorSequences,
+}
+
+impl Default for VerifiedPrefix {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            last_line: 0,
+            lines: 0,
+            entries: 0,
+            head: "genesis".to_string(),
+            max_version: LEGACY_HASH_VERSION,
+            actor_seqs: ActorSequences::default(),
+        }
+    }
+}
+
+/// Just the `hash` of a line, which sealed lines also keep in the clear.
+#[derive(Deserialize)]
+struct LineHash<'a> {
+    #[serde(borrow)]
+    hash: Cow<'a, str>,
+}
+
+/// Whether `file` still starts with `prefix`, judged by its length and
+/// the hash on the prefix's last entry line.
+fn prefix_intact(file: &mut File, prefix: &VerifiedPrefix) -> Result<bool, AuditError> {
+    if file.metadata()?.len() < prefix.len {
+        return Ok(false);
+    }
+    if prefix.entries == 0 {
+        return Ok(true);
+    }
+
+    let mut bytes = Vec::new();
+    file.seek(SeekFrom::Start(prefix.last_line))?;
+    Read::by_ref(file).take(prefix.len - prefix.last_line).read_to_end(&mut bytes)?;
+    let Ok(line) = std::str::from_utf8(&bytes) else {
+        return Ok(false);
+    };
+    // Only the line's own hash is compared; it may be followed by blank lines.
+    let line = line.trim_start_matches(BOM).lines().next().unwrap_or_default();
+    Ok(serde_json::from_str::<LineHash>(line).is_ok_and(|parsed| parsed.hash == prefix.head))
+}
+
+/// Incremental verifier for entries arriving one at a time.
+///
+/// Holds only the running head and hash version, so it can check a log
+/// that is still being written, e.g. entries from an
+/// [`AuditFollower`](crate::follow::AuditFollower). Each entry gets the
+/// same checks as [`AuditLog::verify_integrity`].
+#[derive(Debug, Clone)]
+pub struct ChainVerifier {
+    head: String,
+    max_version: u32,
+    actor_seqs: ActorSequences,
+    verified: usize,
+}
+
+impl Default for ChainVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainVerifier {
+    /// Expect the first entry of a log.
+    pub fn new() -> Self {
+        Self {
+            head: "genesis".to_string(),
+            max_version: LEGACY_HASH_VERSION,
+            actor_seqs: ActorSequences::default(),
+            verified: 0,
+        }
+    }
+
+    /// Check `entry` against the running head and advance past it.
+    ///
+    /// After an error the head is unchanged, so every later entry fails too.
+    pub fn verify(&mut self, entry: &AuditEntry) -> Result<(), AuditError> {
+        let mut max_version = self.max_version;
+        check_link(&entry.id, entry.hash_version, &entry.previous_hash, &self.head, &mut max_version).map_err(
+            |e| match e {
+                AuditError::IntegrityViolation { .. } if self.verified > 0 && entry.previous_hash == "genesis" => {
+                    AuditError::GenesisMidChain { id: entry.id.clone() }
+                }
+                e => e,
+            },
+        )?;
+
+        let computed = entry.compute_hash();
+        if computed != entry.hash {
+            return Err(AuditError::IntegrityViolation {
+                expected: computed,
+                actual: entry.hash.clone(),
+            });
+        }
+        self.actor_seqs
+            .advance(&entry.id, &entry.actor, hashed_seq(entry.hash_version, entry.actor_seq))?;
+
+        self.max_version = max_version;
+        self.head.clone_from(&entry.hash);
+        self.verified += 1;
+        Ok(())
+    }
+
+    /// Hash of the last verified entry, or `genesis`.
+    pub fn head(&self) -> &str {
+        &self.head
+    }
+
+    /// Entries verified so far.
+    pub fn verified(&self) -> usize {
+        self.verified
+    }
+}
+
+/// Check an entry's hash version and its link to `expected_prev`.
+fn check_link(
+    id: &str,
+    hash_version: u32,
+    previous_hash: &str,
+    expected_prev: &str,
+    max_version: &mut u32,
+) -> Result<(), AuditError> {
+    if hash_version > HASH_VERSION {
+        return Err(AuditError::UnsupportedHashVersion {
+            id: id.to_string(),
+            version: hash_version,
+        });
+    }
+    if hash_version < *max_version {
+        return Err(AuditError::HashVersionDowngrade {
+            id: id.to_string(),
+            version: hash_version,
+            previous: *max_version,
+        });
+    }
+    *max_version = hash_version;
+
+    if previous_hash != expected_prev {
+        return Err(AuditError::IntegrityViolation {
+            expected: expected_prev.to_string(),
+            actual: previous_hash.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Check one entry's hash version, link to `expected_prev`, hash, and
+/// place in its actor's sequence.
+fn check_entry(
+    entry: &BorrowedEntry,
+    expected_prev: &str,
+    max_version: &mut u32,
+    actor_seqs: &mut ActorSequences,
+) -> Result<(), AuditError> {
+    check_link(&entry.id, entry.hash_version, &entry.previous_hash, expected_prev, max_version)?;
+    check_hash(entry)?;
+    actor_seqs.advance(&entry.id, &entry.actor, hashed_seq(entry.hash_version, entry.actor_seq))
+}
+
+/// Check that an entry's stored hash matches its contents, with `details`
+/// in canonical form.
+fn check_hash(entry: &BorrowedEntry) -> Result<(), AuditError> {
+    let fields = HashedFields {
+        id: &entry.id,
+        timestamp: &entry.timestamp,
+        event_tag: entry.event_type.tag(),
+        actor: &entry.actor,
+        actor_seq: entry.actor_seq,
+        previous_hash: &entry.previous_hash,
+    };
+    let details: serde_json::Value = serde_json::from_str(entry.details.get())?;
+    let computed = hash_entry(entry.hash_version, &fields, &details);
+    if computed != entry.hash {
+        return Err(AuditError::IntegrityViolation {
+            expected: computed,
+            actual: entry.hash.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Last sequence number seen from each actor.
+#[derive(Debug, Clone, Default)]
+struct ActorSequences {
+    last: HashMap<String, u64>,
+    /// Whether an actor's first number may be anything, as in a segment
+    /// continuing another chain; otherwise it must be 1.
+    open_start: bool,
+}
+
+impl ActorSequences {
+    fn continuing() -> Self {
+        Self {
+            last: HashMap::new(),
+            open_start: true,
+        }
+    }
+
+    /// Note `seq` as `actor`'s latest number without checking it.
+    fn record(&mut self, actor: &str, seq: Option<u64>) {
+        let Some(seq) = seq else {
+            return;
+        };
+        match self.last.get_mut(actor) {
+            Some(last) => *last = seq,
+            None => {
+                self.last.insert(actor.to_string(), seq);
+            }
+        }
+    }
+
+    /// Hand out and record `actor`'s next number.
+    fn next(&mut self, actor: &str) -> u64 {
+        let next = self.last.get(actor).map_or(1, |last| last.saturating_add(1));
+        self.record(actor, Some(next));
+        next
+    }
+
+    /// Check that `seq` directly follows `actor`'s last number, then
+    /// record it. A skipped, repeated or earlier number is a gap.
+    fn advance(&mut self, id: &str, actor: &str, seq: Option<u64>) -> Result<(), AuditError> {
+        let Some(found) = seq else {
+            return Ok(());
+        };
+        let expected = match self.last.get(actor) {
+            Some(last) => last.saturating_add(1),
+            None if self.open_start => found,
+            None => 1,
+        };
+        if found != expected {
+            return Err(AuditError::ActorSequenceGap {
+                id: id.to_string(),
+                actor: actor.to_string(),
+                expected,
+                found,
+            });
+        }
+        self.record(actor, Some(found));
+        Ok(())
+    }
+}
+
+/// `seq` if the entry's hash covers it; numbers on older entries could
+/// have been added afterwards, so they are ignored.
+fn hashed_seq(hash_version: u32, seq: Option<u64>) -> Option<u64> {
+    seq.filter(|_| hash_version >= ACTOR_SEQ_HASH_VERSION)
+}
+
+/// Audit entry borrowed from a line buffer, holding only the hashed fields.
+#[derive(Deserialize)]
+struct BorrowedEntry<'a> {
+    #[serde(borrow)]
+    id: Cow<'a, str>,
+    timestamp: DateTime<Utc>,
+    event_type: AuditEventType,
+    #[serde(borrow)]
+    actor: Cow<'a, str>,
+    #[serde(default)]
+    actor_seq: Option<u64>,
+    #[serde(borrow)]
+    details: &'a RawValue,
+    #[serde(borrow)]
+    previous_hash: Cow<'a, str>,
+    #[serde(borrow)]
+    hash: Cow<'a, str>,
+    #[serde(default = "legacy_hash_version")]
+    hash_version: u32,
+}
+
+/// Parse line `number` (counted from 1) of a log; `None` if it is blank.
+///
+/// A UTF-8 byte-order mark is stripped from the first line. Text that is
+/// not JSON at all, or has anything after the entry, is reported as
+/// [`AuditError::MalformedLine`]; JSON that is not an entry stays a
+/// serialization error.
+fn parse_line<'a, T: Deserialize<'a>>(line: &'a str, number: usize) -> Result<Option<T>, AuditError> {
+    let line = match number {
+        1 => line.strip_prefix(BOM).unwrap_or(line),
+        _ => line,
+    };
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_str(line).map(Some).map_err(|e| match e.classify() {
+        Category::Syntax | Category::Eof => AuditError::MalformedLine { line: number },
+        _ => e.into(),
+    })
+}
+
+/// UTF-8 byte-order mark, tolerated at the very start of a log.
+pub(crate) const BOM: &str = "\u{feff}";
+
+/// Last `n` entries of the log at `path`, read backwards from the end.
+fn read_tail(path: &Path, n: usize, key: Option<&AuditKey>) -> Result<Vec<AuditEntry>, AuditError> {
+    if n == 0 || !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = File::open(path)?;
+    let end = file.metadata()?.len();
+    read_tail_before(file, n, end, key)
+}
+
+/// Last `n` entries of `file` that end at or before byte `end`.
+pub(crate) fn read_tail_before(
+    mut file: File,
+    n: usize,
+    end: u64,
+    key: Option<&AuditKey>,
+) -> Result<Vec<AuditEntry>, AuditError> {
+    const CHUNK: u64 = 64 * 1024;
+
+    let non_blank = |buf: &[u8]| {
+        buf.split(|b| *b == b'\n')
+            .filter(|line| !line.trim_ascii().is_empty())
+            .count()
+    };
+
+    // Read whole chunks until the buffer holds n lines plus a possibly
+    // partial one in front, or the start of the file.
+    let mut pos = end;
+    let mut buf = Vec::new();
+    while pos > 0 && non_blank(&buf) <= n {
+        let start = pos.saturating_sub(CHUNK);
+        let mut chunk = vec![0; (pos - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&buf);
+        buf = chunk;
+        pos = start;
+    }
+
+    let complete = if pos > 0 {
+        buf.iter().position(|b| *b == b'\n').map_or(buf.len(), |i| i + 1)
+    } else if buf.starts_with(BOM.as_bytes()) {
+        BOM.len()
+    } else {
+        0
+    };
+    let lines: Vec<&[u8]> = buf[complete..]
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .collect();
+    lines[lines.len().saturating_sub(n)..]
+        .iter()
+        .map(|line| decode_entry(line, key))
+        .collect()
+}
+
+/// Parse one complete line read as raw bytes, decrypting it if sealed.
+pub(crate) fn decode_entry(line: &[u8], key: Option<&AuditKey>) -> Result<AuditEntry, AuditError> {
+    match std::str::from_utf8(line) {
+        Ok(text) if encryption::is_sealed(text) => {
+            let plain = key.ok_or(AuditError::KeyRequired)?.unseal(text)?;
+            Ok(serde_json::from_str(&plain)?)
+        }
+        _ => Ok(serde_json::from_slice(line)?),
+    }
+}
+
+/// Path of a named stream inside an audit directory.
+pub fn stream_path(dir: &Path, stream: &str) -> Result<PathBuf, AuditError> {
+    let valid = !stream.is_empty()
+        && stream
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(AuditError::InvalidStream(stream.to_string()));
+    }
+    Ok(dir.join(format!("{}.jsonl", stream)))
+}
+
+/// Independent audit chains sharing one directory, selected by stream key.
+pub struct AuditStreams {
+    dir: PathBuf,
+    retry: RetryPolicy,
+    streams: HashMap<String, AuditLog>,
+}
+
+impl AuditStreams {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            retry: RetryPolicy::default(),
+            streams: HashMap::new(),
+        }
+    }
+
+    /// Set the retry policy for every stream, open or not yet opened.
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.retry = retry;
+        for log in self.streams.values_mut() {
+            log.set_retry_policy(retry);
+        }
+    }
+
+    /// Get a stream, opening it on first use.
+    pub fn stream(&mut self, stream: &str) -> Result<&mut AuditLog, AuditError> {
+        if !self.streams.contains_key(stream) {
+            let mut log = AuditLog::open_stream(&self.dir, stream)?;
+            log.set_retry_policy(self.retry);
+            self.streams.insert(stream.to_string(), log);
+        }
+        Ok(self.streams.get_mut(stream).expect("stream just opened"))
+    }
+
+    /// Append an entry to a named stream.
+    pub fn append(
+        &mut self,
+        stream: &str,
+        event_type: AuditEventType,
+        actor: &str,
+        target: Option<&AuditTarget>,
+        details: serde_json::Value,
+    ) -> Result<AuditEntry, AuditError> {
+        self.stream(stream)?.append(event_type, actor, target, details)
+    }
+
+    /// Append several entries to a named stream as one all-or-nothing write.
+    pub fn append_batch(&mut self, stream: &str, batch: Vec<BatchEntry<'_>>) -> Result<Vec<AuditEntry>, AuditError> {
+        self.stream(stream)?.append_batch(batch)
+    }
+
+    /// Verify integrity of a named stream.
+    pub fn verify_integrity(&mut self, stream: &str) -> Result<bool, AuditError> {
+        self.stream(stream)?.verify_integrity()
+    }
+
+    /// Names of all streams present in the directory.
+    pub fn list(&self) -> Result<Vec<String>, AuditError> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut names = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                if stream_path(&self.dir, name).is_ok() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+}
+
+/// Fields of an entry covered by its chain hash, besides `details`.
+///
+/// The event is given by its tag, the variant name as written on disk,
+/// so entries whose event type is not known to this build can still be
+/// hashed.
+pub(crate) struct HashedFields<'a> {
+    pub id: &'a str,
+    pub timestamp: &'a DateTime<Utc>,
+    pub event_tag: &'a str,
+    pub actor: &'a str,
+    pub actor_seq: Option<u64>,
+    pub previous_hash: &'a str,
+}
+
+/// Compute the chain hash of an entry under hash scheme `version`.
+pub(crate) fn hash_entry(version: u32, fields: &HashedFields, details: &serde_json::Value) -> String {
+    hash_entry_raw(version, fields, &details.to_string())
+}
+
+/// Compute the chain hash with `details` already serialized to compact JSON.
+///
+/// v1 concatenates the fields, so different field splits can share a
+/// preimage, and keeps only a truncated digest. v2 prefixes a domain tag
+/// and each field's length, and keeps the full SHA-256. v3 adds the
+/// actor's sequence number, empty when absent.
+fn hash_entry_raw(version: u32, fields: &HashedFields, details_json: &str) -> String {
+    let timestamp = fields.timestamp.to_rfc3339();
+    let actor_seq = fields.actor_seq.map(|seq| seq.to_string()).unwrap_or_default();
+    let parts = [
+        fields.id.as_bytes(),
+        timestamp.as_bytes(),
+        fields.event_tag.as_bytes(),
+        fields.actor.as_bytes(),
+        details_json.as_bytes(),
+        fields.previous_hash.as_bytes(),
+    ];
+
+    match version {
+        LEGACY_HASH_VERSION => parts.into_iter().fold(Hasher::new(), Hasher::update).finish_short(),
+        2 => parts.into_iter().fold(Hasher::domain("openlora.audit.v2"), Hasher::field).finish(),
+        _ => parts
+            .into_iter()
+            .fold(Hasher::domain("openlora.audit.v3"), Hasher::field)
+            .field(actor_seq.as_bytes())
+            .finish(),
     }
 }
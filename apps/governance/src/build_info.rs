@@ -0,0 +1,47 @@
+//! Build Information
+//!
+//! What this binary was built from, for support tickets.
+
+use crate::audit::HASH_VERSION;
+use crate::signatures::ED25519_ALGORITHM;
+
+/// Crate version.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Short git revision at build time, or `unknown` outside a checkout.
+pub const GIT_HASH: &str = env!("OPENLORA_GIT_HASH");
+
+/// Hash algorithm chaining new audit entries.
+pub const AUDIT_HASH_ALGORITHM: &str = "sha256";
+
+/// Audit hash scheme version written to new entries.
+pub const AUDIT_HASH_VERSION: u32 = HASH_VERSION;
+
+/// Optional cargo features compiled into this build.
+pub fn enabled_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "object-store") {
+        features.push("object-store");
+    }
+    if cfg!(feature = "webhook") {
+        features.push("webhook");
+    }
+    if cfg!(feature = "deadman") {
+        features.push("deadman");
+    }
+    if cfg!(feature = "timezone") {
+        features.push("timezone");
+    }
+    if cfg!(feature = "trust-registry") {
+        features.push("trust-registry");
+    }
+    if cfg!(feature = "protobuf") {
+        features.push("protobuf");
+    }
+    features
+}
+
+/// Signature algorithms this build can verify.
+pub fn signature_algorithms() -> Vec<&'static str> {
+    vec![ED25519_ALGORITHM, "sha256 (legacy)"]
+}
@@ -2,57 +2,623 @@
 //!
 //! Command-line interface for governance operations.
 
-use clap::{Parser, Subcommand};
+use crate::migrate::CURRENT_SCHEMA_VERSION;
+use crate::quarantine::DEFAULT_QUARANTINE_PATH;
+use crate::rechain::DEFAULT_RECHAIN_LOG;
+use crate::registry::DEFAULT_REGISTRY_PATH;
+use crate::revocation::DEFAULT_REVOCATION_PATH;
+use crate::session::DEFAULT_SESSION_TTL_SECS;
+use crate::signatures::DEFAULT_SIGNER_METRICS_PATH;
+use crate::trust::{DEFAULT_TRUST_CACHE_PATH, DEFAULT_TRUST_TTL_SECS};
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::io::BufRead;
+
+/// Default location of the persisted kill-switch state.
+pub const DEFAULT_STATE_PATH: &str = "killswitch.json";
 
 #[derive(Parser)]
 #[command(name = "openlora-gov")]
 #[command(about = "OpenLoRA Governance CLI", long_about = None)]
 pub struct Cli {
+    /// Path to governance.toml (defaults to ./governance.toml if present)
+    #[arg(long, global = true)]
+    pub config: Option<String>,
+    /// Authorized operator, overriding the config file (repeatable)
+    #[arg(long = "authorized-operator", global = true)]
+    pub authorized_operators: Vec<String>,
+    /// Trusted signer, overriding OPENLORA_TRUSTED_SIGNERS and the config
+    /// file (repeatable). Without it, OPENLORA_TRUSTED_SIGNERS, a comma-separated
+    /// list of `id` or `id=<base64 Ed25519 public key>`, overrides the config file
+    #[arg(long = "trusted-signer", global = true)]
+    pub trusted_signers: Vec<String>,
+    /// Path to the persisted kill-switch state
+    #[arg(long, global = true, default_value = DEFAULT_STATE_PATH)]
+    pub state: String,
+    /// Time zone for displayed timestamps: UTC, local, or an IANA name with
+    /// the timezone feature (defaults to TZ if set, else UTC)
+    #[arg(long, global = true)]
+    pub timezone: Option<String>,
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// Format of exported kill events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Activate kill-switch
     Kill {
         /// Operator ID
-        #[arg(short, long)]
-        operator: String,
+        #[arg(short, long, required_unless_present = "session")]
+        operator: Option<String>,
+        /// Session token, instead of an operator ID
+        #[arg(long, conflicts_with = "operator")]
+        session: Option<String>,
+        /// Second operator co-signing the activation, for the most severe kills
+        #[arg(long, requires = "operator", conflicts_with_all = ["session", "idempotency_key"])]
+        co_signer: Option<String>,
         /// Reason for kill
         #[arg(short, long)]
         reason: String,
         /// Affected adapter IDs
         #[arg(short, long)]
         adapters: Vec<String>,
+        /// Audit log recording the activation
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+        /// Adapter registry whose labels are recorded with the activation
+        #[arg(long, default_value = DEFAULT_REGISTRY_PATH)]
+        registry: String,
+        /// Key making retries idempotent: a repeated key returns the original event
+        #[arg(long)]
+        idempotency_key: Option<String>,
+        /// Record the activation without enforcing it, to trial a kill policy
+        #[arg(long)]
+        shadow: bool,
+        /// Skip the interactive confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
     },
     /// Reset kill-switch
     Reset {
         /// Operator ID
+        #[arg(short, long, required_unless_present = "sessions")]
+        operator: Option<String>,
+        /// Additional operator agreeing to the reset (repeatable); needed when escalated.
+        /// Requires authorized operators in the config; otherwise use --session per approver
+        #[arg(long = "approver")]
+        approvers: Vec<String>,
+        /// Session token, instead of operator IDs (repeatable for a quorum)
+        #[arg(long = "session", conflicts_with_all = ["operator", "approvers"])]
+        sessions: Vec<String>,
+        /// Audit log recording the reset
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+        /// Skip the interactive confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Reset the kill-switch as an admin, bypassing quorum (emergencies only)
+    ForceReset {
+        /// Admin ID; must hold the Admin role in governance.toml
+        #[arg(long)]
+        admin: String,
+        /// Why the quorum is being overridden; recorded in the audit log
+        #[arg(long)]
+        justification: String,
+        /// Audit log recording the force reset
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+        /// Skip the interactive confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Issue a time-limited session authorizing an operator
+    IssueSession {
+        /// Operator ID
+        #[arg(short, long)]
+        operator: String,
+        /// Session lifetime in seconds
+        #[arg(long, default_value_t = DEFAULT_SESSION_TTL_SECS)]
+        ttl_secs: u64,
+        /// Audit log recording the issuance
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+    },
+    /// End a session before it expires
+    EndSession {
+        /// Session ID (not the token)
+        #[arg(long)]
+        id: String,
+        /// Operator ending the session
         #[arg(short, long)]
         operator: String,
+        /// Audit log recording the end of the session
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
     },
     /// Check kill-switch status
     Status,
+    /// Print version, git revision and enabled features
+    Info,
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Block until the kill-switch activates
+    WatchKillswitch {
+        /// Keep running and print every state change
+        #[arg(short, long)]
+        follow: bool,
+        /// Give up after N seconds without a change (exit code 3)
+        #[arg(short, long)]
+        timeout: Option<u64>,
+        /// Polling interval in milliseconds
+        #[arg(long, default_value_t = 500)]
+        poll_ms: u64,
+    },
+    /// Export kill events for review
+    ExportKills {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        out: Option<String>,
+    },
+    /// Quarantine an adapter, or every adapter listed in a file
+    Quarantine {
+        /// Adapter ID
+        #[arg(short, long, required_unless_present = "from_file")]
+        adapter: Option<String>,
+        /// File of adapter IDs, one per line; blank lines and `#` comments are skipped
+        #[arg(long, conflicts_with = "adapter")]
+        from_file: Option<String>,
+        /// Reason for quarantine
+        #[arg(short, long)]
+        reason: String,
+        /// Operator ID
+        #[arg(short, long)]
+        operator: String,
+        /// Path to audit log
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+        /// Path to the quarantine list
+        #[arg(long, default_value = DEFAULT_QUARANTINE_PATH)]
+        list: String,
+        /// Adapter registry whose labels are recorded with the quarantine
+        #[arg(long, default_value = DEFAULT_REGISTRY_PATH)]
+        registry: String,
+    },
+    /// Release an adapter from quarantine
+    Release {
+        /// Adapter ID
+        #[arg(short, long)]
+        adapter: String,
+        /// Operator ID
+        #[arg(short, long)]
+        operator: String,
+        /// Path to audit log
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+        /// Path to the quarantine list
+        #[arg(long, default_value = DEFAULT_QUARANTINE_PATH)]
+        list: String,
+    },
+    /// Revoke a signature or adapter content so it never verifies again
+    Revoke {
+        /// Signature JSON file whose signature is revoked
+        #[arg(long, required_unless_present = "content_hash", conflicts_with = "content_hash")]
+        signature: Option<String>,
+        /// SHA-256 (hex) of adapter content to revoke
+        #[arg(long)]
+        content_hash: Option<String>,
+        /// Reason for revocation
+        #[arg(short, long)]
+        reason: String,
+        /// Operator ID
+        #[arg(short, long)]
+        operator: String,
+        /// Path to audit log
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+        /// Path to the revocation list
+        #[arg(long, default_value = DEFAULT_REVOCATION_PATH)]
+        list: String,
+    },
+    /// List quarantined adapters with reasons and timestamps
+    QuarantineList {
+        /// Path to the quarantine list
+        #[arg(long, default_value = DEFAULT_QUARANTINE_PATH)]
+        list: String,
+    },
     /// Verify audit log integrity
     VerifyAudit {
+        /// Path to audit log (or audit directory when --stream is given)
+        #[arg(short, long)]
+        path: String,
+        /// Named stream within the audit directory
+        #[arg(long)]
+        stream: Option<String>,
+        /// Only verify entries at or after this RFC 3339 timestamp
+        #[arg(long)]
+        since: Option<DateTime<Utc>>,
+        /// Only verify entries at or before this RFC 3339 timestamp
+        #[arg(long)]
+        until: Option<DateTime<Utc>>,
+        /// Also check each entry's details against its event type's schema
+        #[arg(long)]
+        typed_details: bool,
+        /// Head hash of the previous segment, for a log that continues
+        /// another chain (defaults to genesis)
+        #[arg(long, conflicts_with_all = ["since", "until"])]
+        start_hash: Option<String>,
+        /// List every integrity issue instead of stopping at the first
+        #[arg(long, conflicts_with_all = ["since", "until"])]
+        report: bool,
+        /// Recovery only: treat entry ID as known corrupt and continue the
+        /// chain from HASH instead of its stored hash (repeatable)
+        #[arg(long = "override", value_name = "ID=HASH", conflicts_with_all = ["since", "until", "start_hash", "report"])]
+        overrides: Vec<String>,
+    },
+    /// Show recent audit entries, optionally streaming new ones
+    Query {
+        /// Path to audit log
+        #[arg(short, long)]
+        path: String,
+        /// Show only the last N entries
+        #[arg(long)]
+        tail: Option<usize>,
+        /// Keep running and print entries as they are appended
+        #[arg(short, long)]
+        follow: bool,
+        /// Polling interval in milliseconds while following
+        #[arg(long, default_value_t = 500)]
+        poll_ms: u64,
+        /// Only show entries whose adapters were labelled KEY=VALUE
+        /// (repeatable; all must match)
+        #[arg(long = "label", value_parser = parse_label)]
+        labels: Vec<(String, String)>,
+    },
+    /// Verify an audit log, then verify and print entries as they are appended
+    ///
+    /// Exits non-zero as soon as the chain breaks.
+    TailVerify {
+        /// Path to audit log
+        #[arg(short, long)]
+        path: String,
+        /// Existing entries to print before following (all are verified)
+        #[arg(long, default_value_t = 10)]
+        tail: usize,
+        /// Polling interval in milliseconds
+        #[arg(long, default_value_t = 500)]
+        poll_ms: u64,
+    },
+    /// Verify an audit log and activate the kill-switch on tampering
+    Monitor {
+        /// Path to audit log
+        #[arg(short, long)]
+        path: String,
+        /// Separate log where integrity failures are recorded
+        #[arg(long)]
+        incident_log: String,
+        /// Re-check every N seconds instead of once
+        #[arg(long)]
+        interval: Option<u64>,
+    },
+    /// Validate audit log entries against a schema version
+    Validate {
         /// Path to audit log
         #[arg(short, long)]
         path: String,
+        /// Schema version to validate against
+        #[arg(long, default_value_t = CURRENT_SCHEMA_VERSION)]
+        schema_version: u32,
+    },
+    /// Rewrite an audit log into canonical form with a re-chained copy
+    Migrate {
+        /// Path to the source audit log (left untouched)
+        #[arg(short, long)]
+        input: String,
+        /// Path for the migrated audit log (must not exist)
+        #[arg(short, long)]
+        output: String,
     },
-    /// Sign an adapter
+    /// Export an audit log as length-delimited protobuf (proto/audit.proto)
+    #[cfg(feature = "protobuf")]
+    ExportAudit {
+        /// Path to audit log
+        #[arg(short, long)]
+        path: String,
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        out: Option<String>,
+    },
+    /// Import a protobuf export into a new JSONL audit log
+    #[cfg(feature = "protobuf")]
+    ImportAudit {
+        /// Protobuf export to read
+        #[arg(short, long)]
+        input: String,
+        /// Path for the imported audit log (must not exist)
+        #[arg(short, long)]
+        output: String,
+    },
+    /// Re-chain an audit log after an approved edit, recording the rewrite
+    Rechain {
+        /// Edited audit log (left untouched)
+        #[arg(long = "in")]
+        input: String,
+        /// Path for the re-chained audit log (must not exist)
+        #[arg(long = "out")]
+        output: String,
+        /// Signer approving the rewrite
+        #[arg(short, long)]
+        signer: String,
+        /// Signer's private key (defaults to <signer>.key)
+        #[arg(long)]
+        key: Option<String>,
+        /// Why history is being rewritten
+        #[arg(short, long)]
+        reason: String,
+        /// Meta-log recording every rechain
+        #[arg(long, default_value = DEFAULT_RECHAIN_LOG)]
+        meta_log: String,
+    },
+    /// Rebuild governance state from an audit log and compare it with live state
+    Replay {
+        /// Path to audit log
+        #[arg(short, long, default_value = "audit.jsonl")]
+        path: String,
+        /// Path to the quarantine list
+        #[arg(long, default_value = DEFAULT_QUARANTINE_PATH)]
+        list: String,
+    },
+    /// Set or remove a registered adapter's labels
+    Label {
+        /// Adapter ID
+        #[arg(short, long)]
+        adapter: String,
+        /// Label to set, as KEY=VALUE (repeatable)
+        #[arg(long = "set", value_parser = parse_label)]
+        set: Vec<(String, String)>,
+        /// Label key to remove (repeatable)
+        #[arg(long)]
+        remove: Vec<String>,
+        /// Path to the adapter registry
+        #[arg(long, default_value = DEFAULT_REGISTRY_PATH)]
+        registry: String,
+    },
+    /// Check the registry, quarantine list and kill-switch state against the audit log
+    ConsistencyCheck {
+        /// Path to audit log
+        #[arg(short, long, default_value = "audit.jsonl")]
+        path: String,
+        /// Path to the quarantine list
+        #[arg(long, default_value = DEFAULT_QUARANTINE_PATH)]
+        list: String,
+        /// Path to the adapter registry
+        #[arg(long, default_value = DEFAULT_REGISTRY_PATH)]
+        registry: String,
+    },
+    /// Compare two audit logs and report where they diverge
+    Diff {
+        /// First audit log
+        #[arg(long)]
+        a: String,
+        /// Second audit log
+        #[arg(long)]
+        b: String,
+    },
+    /// Produce a proof that an entry is included in an audit log
+    ProveInclusion {
+        /// Path to audit log
+        #[arg(short, long)]
+        path: String,
+        /// Entry ID to prove
+        #[arg(short, long)]
+        entry: String,
+    },
+    /// Verify an inclusion proof against a Merkle head
+    VerifyInclusion {
+        /// Path to proof JSON
+        #[arg(short, long)]
+        proof: String,
+        /// Trusted Merkle head hash
+        #[arg(long)]
+        head: String,
+    },
+    /// Render a provenance chain as a GraphViz DOT graph
+    ProvenanceGraph {
+        /// Provenance chain JSON file
+        #[arg(short, long)]
+        chain: String,
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        out: Option<String>,
+    },
+    /// Sign a provenance chain into a portable `.prov` bundle
+    ExportProvenance {
+        /// Provenance chain JSON file
+        #[arg(short, long)]
+        chain: String,
+        /// Exporting signer ID
+        #[arg(short, long)]
+        signer: String,
+        /// Signer's private key (defaults to <signer>.key)
+        #[arg(long)]
+        key: Option<String>,
+        /// Where to write the bundle
+        #[arg(short, long)]
+        out: String,
+    },
+    /// Verify a `.prov` bundle against the trusted signers and extract its chain
+    ImportProvenance {
+        /// Bundle file
+        #[arg(short, long)]
+        bundle: String,
+        /// JSON list of trusted signers, taking precedence over --trusted-signer,
+        /// OPENLORA_TRUSTED_SIGNERS and the config file
+        #[arg(long)]
+        trusted: Option<String>,
+        /// Where to write the verified chain JSON
+        #[arg(short, long)]
+        out: String,
+    },
+    /// Generate an Ed25519 keypair for a new signer
+    Keygen {
+        /// Signer ID (used for the key file names)
+        #[arg(short, long)]
+        signer: String,
+        /// Output directory
+        #[arg(long, default_value = ".")]
+        out: String,
+    },
+    /// Generate a key for encrypting audit logs at rest (set `audit_key_file` to use it)
+    AuditKeygen {
+        /// Key file to create
+        #[arg(long, default_value = "audit.key")]
+        out: String,
+    },
+    /// Verify an audit log and sign an attestation of its head
+    Attest {
+        /// Path to audit log
+        #[arg(short, long)]
+        path: String,
+        /// Signer ID
+        #[arg(short, long)]
+        signer: String,
+        /// Signer's private key (defaults to <signer>.key)
+        #[arg(long)]
+        key: Option<String>,
+        /// Where to write the attestation
+        #[arg(short, long)]
+        out: String,
+        /// Instead attest that this adapter was never quarantined,
+        /// destroyed or failed signature verification
+        #[arg(long, value_name = "ADAPTER")]
+        clean: Option<String>,
+    },
+    /// Re-verify an audit log against a signed attestation
+    CheckAttestation {
+        /// Path to audit log
+        #[arg(short, long)]
+        path: String,
+        /// Attestation JSON file
+        #[arg(short, long)]
+        attestation: String,
+        /// JSON list of trusted signers, taking precedence over --trusted-signer,
+        /// OPENLORA_TRUSTED_SIGNERS and the config file
+        #[arg(long)]
+        trusted: Option<String>,
+    },
+    /// Sign an adapter with the signer's Ed25519 key
     Sign {
-        /// Adapter path
+        /// Adapter path, or object-store URL (s3://, gs://) with the object-store feature
         #[arg(short, long)]
         adapter: String,
         /// Signer ID
         #[arg(short, long)]
         signer: String,
+        /// Signer's private key (defaults to <signer>.key)
+        #[arg(long)]
+        key: Option<String>,
+        /// Write a legacy sha256 signature instead, which `verify` accepts
+        /// only with `allow_legacy_signatures = true` in the config
+        #[arg(long, conflicts_with = "key")]
+        legacy: bool,
+        /// Audit log recording the signature
+        #[arg(long, default_value = "audit.jsonl")]
+        audit_log: String,
+        /// Where to write the signature JSON (default: stdout)
+        #[arg(short, long)]
+        out: Option<String>,
+        /// Refuse, rather than warn, if the adapter is writable by any user
+        #[arg(long)]
+        strict_permissions: bool,
     },
     /// Verify adapter signature
     Verify {
-        /// Adapter path
+        /// Adapter path, or object-store URL (s3://, gs://) with the object-store feature
         #[arg(short, long)]
         adapter: String,
+        /// Signature JSON file
+        #[arg(long)]
+        signature: String,
+        /// Creator of the adapter; a signature by the creator is flagged as self-signed
+        #[arg(long)]
+        creator: Option<String>,
+        /// Revocation list consulted before verifying
+        #[arg(long, default_value = DEFAULT_REVOCATION_PATH)]
+        revocations: String,
+        /// Per-signer verification counts to update
+        #[arg(long, default_value = DEFAULT_SIGNER_METRICS_PATH)]
+        metrics: String,
+        /// Trust registry serving the trusted signers, instead of the config
+        /// file (requires the trust-registry feature)
+        #[arg(long, requires = "trust_key")]
+        trust_url: Option<String>,
+        /// The trust registry's Ed25519 public key (PEM), pinned locally
+        #[arg(long)]
+        trust_key: Option<String>,
+        /// Local copy of the last trust set fetched
+        #[arg(long, default_value = DEFAULT_TRUST_CACHE_PATH)]
+        trust_cache: String,
+        /// Seconds a fetched trust set is used before asking the registry again
+        #[arg(long, default_value_t = DEFAULT_TRUST_TTL_SECS)]
+        trust_ttl_secs: u64,
+        /// Refuse, rather than warn, if the adapter or signature is writable by any user
+        #[arg(long)]
+        strict_permissions: bool,
     },
+    /// Show verification volume and failure rates per signer
+    SignerReport {
+        /// Per-signer verification counts written by `verify`
+        #[arg(long, default_value = DEFAULT_SIGNER_METRICS_PATH)]
+        metrics: String,
+        /// Print the counts as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Why a destructive command went unconfirmed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unconfirmed {
+    /// Stdin is not a terminal, so there is no one to ask.
+    NotInteractive,
+    /// The answer was not the operator id.
+    Mismatch,
+}
+
+/// Check an operator's typed confirmation of a destructive action.
+///
+/// `interactive` is whether stdin is a terminal; without one the action
+/// is refused, so scripts must pass `--yes`. Otherwise the first line of
+/// `answer` must be the operator id.
+pub fn confirm_operator<R: BufRead>(interactive: bool, mut answer: R, operator: &str) -> Result<(), Unconfirmed> {
+    if !interactive {
+        return Err(Unconfirmed::NotInteractive);
+    }
+    let mut line = String::new();
+    match answer.read_line(&mut line) {
+        Ok(_) if line.trim() == operator => Ok(()),
+        _ => Err(Unconfirmed::Mismatch),
+    }
+}
+
+/// Parse a `KEY=VALUE` label.
+fn parse_label(raw: &str) -> Result<(String, String), String> {
+    match raw.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_string(), value.trim().to_string())),
+        _ => Err(format!("expected KEY=VALUE, got {:?}", raw)),
+    }
 }
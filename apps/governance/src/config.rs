@@ -0,0 +1,430 @@
+//! Governance Configuration
+//!
+//! Loads `governance.toml`, which defines who may operate the kill-switch,
+//! which signers are trusted, and the thresholds used by governance checks.
+
+use crate::audit::RetryPolicy;
+use crate::encryption::AuditKey;
+use crate::keys::{load_audit_key, KeyError};
+use crate::killswitch::{EscalationPolicy, KillSwitch};
+use crate::notify::{NotificationDispatcher, NotifyError};
+use chrono::{DateTime, Duration, Utc};
+use crate::signatures::{SignatureError, SignatureVerifier, TrustInterval};
+use openlora_core::{validate_principal_id, ProvenanceOperation};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Default config location, relative to the working directory.
+pub const DEFAULT_CONFIG_PATH: &str = "governance.toml";
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Failed to read config {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse config {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("Invalid config: {0}")]
+    Invalid(String),
+}
+
+/// A signer trusted to sign adapters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustedSigner {
+    pub id: String,
+    /// Path to the signer's PEM/DER Ed25519 public key.
+    #[serde(default)]
+    pub public_key: Option<PathBuf>,
+    /// Signatures made before this time are not trusted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trusted_from: Option<DateTime<Utc>>,
+    /// Revocation time; signatures made at or after it are not trusted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Roles the signer holds, matched against `provenance_roles`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+}
+
+impl TrustedSigner {
+    /// The signer's trust timeline, if it is time-bounded.
+    pub fn trust_interval(&self) -> Option<TrustInterval> {
+        (self.trusted_from.is_some() || self.revoked_at.is_some()).then_some(TrustInterval {
+            from: self.trusted_from,
+            until: self.revoked_at,
+        })
+    }
+}
+
+/// Number of distinct operators required for kill-switch actions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuorumConfig {
+    #[serde(default = "one")]
+    pub activate: usize,
+    #[serde(default = "one")]
+    pub reset: usize,
+}
+
+impl Default for QuorumConfig {
+    fn default() -> Self {
+        Self {
+            activate: 1,
+            reset: 1,
+        }
+    }
+}
+
+/// Anomaly score thresholds in `[0, 1]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnomalyConfig {
+    #[serde(default = "default_quarantine_threshold")]
+    pub quarantine_threshold: f64,
+    #[serde(default = "default_kill_threshold")]
+    pub kill_threshold: f64,
+}
+
+impl Default for AnomalyConfig {
+    fn default() -> Self {
+        Self {
+            quarantine_threshold: default_quarantine_threshold(),
+            kill_threshold: default_kill_threshold(),
+        }
+    }
+}
+
+/// Escalate when the kill-switch is activated too often.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EscalationConfig {
+    /// Activations tolerated within the window before escalating.
+    pub max_activations: usize,
+    pub window_secs: u64,
+    /// Distinct operators required to reset while escalated.
+    #[serde(default = "two")]
+    pub reset_quorum: usize,
+}
+
+impl EscalationConfig {
+    pub fn policy(&self) -> EscalationPolicy {
+        EscalationPolicy {
+            max_activations: self.max_activations,
+            window: Duration::seconds(self.window_secs.min(i64::MAX as u64) as i64),
+            reset_quorum: self.reset_quorum,
+        }
+    }
+}
+
+/// Where kill-switch activations are announced.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationConfig {
+    /// URLs that receive each kill event as a JSON POST.
+    #[serde(default)]
+    pub webhooks: Vec<String>,
+}
+
+fn one() -> usize {
+    1
+}
+
+fn two() -> usize {
+    2
+}
+
+fn default_quarantine_threshold() -> f64 {
+    0.7
+}
+
+fn default_kill_threshold() -> f64 {
+    0.9
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GovernanceConfig {
+    #[serde(default)]
+    pub authorized_operators: Vec<String>,
+    /// Holders of the Admin role, who may force a reset without quorum.
+    #[serde(default)]
+    pub admins: Vec<String>,
+    #[serde(default)]
+    pub trusted_signers: Vec<TrustedSigner>,
+    #[serde(default)]
+    pub quorum: QuorumConfig,
+    #[serde(default)]
+    pub anomaly: AnomalyConfig,
+    #[serde(default)]
+    pub escalation: Option<EscalationConfig>,
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+    /// Role required of the signer of each provenance operation, e.g.
+    /// `Transferred = "reviewer"`.
+    #[serde(default)]
+    pub provenance_roles: HashMap<ProvenanceOperation, String>,
+    /// Key file encrypting audit logs at rest, from `openlora-gov audit-keygen`.
+    #[serde(default)]
+    pub audit_key_file: Option<PathBuf>,
+    /// Quarantining more adapters than this at once activates the kill-switch.
+    #[serde(default)]
+    pub max_concurrent_quarantines: Option<usize>,
+    /// Seconds a signature may be dated ahead of the local clock; 300
+    /// when unset.
+    #[serde(default)]
+    pub max_future_skew_secs: Option<u32>,
+    /// Accept legacy `sha256` signatures from signers without a public
+    /// key. Off unless set.
+    #[serde(default)]
+    pub allow_legacy_signatures: bool,
+}
+
+impl GovernanceConfig {
+    /// Load and validate a config file.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let raw = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let config: Self = toml::from_str(&raw).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Load `path` if given, otherwise the default location if it exists.
+    ///
+    /// An explicitly requested file must exist; a missing default file
+    /// yields an empty config.
+    pub fn load_or_default(path: Option<&Path>) -> Result<Self, ConfigError> {
+        match path {
+            Some(path) => Self::load(path),
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                Self::load(Path::new(DEFAULT_CONFIG_PATH))
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Apply command-line overrides. Non-empty lists replace the file's lists.
+    pub fn with_overrides(mut self, operators: Vec<String>, signers: Vec<String>) -> Result<Self, ConfigError> {
+        if !operators.is_empty() {
+            self.authorized_operators = operators;
+        }
+        if !signers.is_empty() {
+            self.trusted_signers = signers
+                .into_iter()
+                .map(|id| TrustedSigner {
+                    id,
+                    public_key: None,
+                    trusted_from: None,
+                    revoked_at: None,
+                    roles: Vec::new(),
+                })
+                .collect();
+        }
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// The operators agreeing to a reset: `operator` and the `approvers`
+    /// named alongside it.
+    ///
+    /// A named approver proves nothing by itself, so approvers are refused
+    /// unless `authorized_operators` is configured to check them against;
+    /// without it, each approver must present a session token instead.
+    pub fn reset_operators(&self, operator: String, approvers: Vec<String>) -> Result<Vec<String>, ConfigError> {
+        if !approvers.is_empty() && self.authorized_operators.is_empty() {
+            return Err(ConfigError::Invalid(
+                "approvers need authorized_operators to be checked against; use a session per approver instead"
+                    .to_string(),
+            ));
+        }
+        Ok(std::iter::once(operator).chain(approvers).collect())
+    }
+
+    /// Check the config is internally consistent.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_ids("authorized_operators", self.authorized_operators.iter())?;
+        check_ids("admins", self.admins.iter())?;
+        check_ids("trusted_signers", self.trusted_signers.iter().map(|s| &s.id))?;
+        for signer in &self.trusted_signers {
+            if let (Some(from), Some(revoked)) = (signer.trusted_from, signer.revoked_at) {
+                if revoked <= from {
+                    return Err(ConfigError::Invalid(format!(
+                        "trusted signer {:?} is revoked at {} before it is trusted from {}",
+                        signer.id, revoked, from
+                    )));
+                }
+            }
+        }
+
+        for (name, required) in [("activate", self.quorum.activate), ("reset", self.quorum.reset)] {
+            if required == 0 {
+                return Err(ConfigError::Invalid(format!("quorum.{} must be at least 1", name)));
+            }
+            if !self.authorized_operators.is_empty() && required > self.authorized_operators.len() {
+                return Err(ConfigError::Invalid(format!(
+                    "quorum.{} is {} but only {} operators are authorized",
+                    name,
+                    required,
+                    self.authorized_operators.len()
+                )));
+            }
+        }
+
+        let anomaly = &self.anomaly;
+        for (name, value) in [
+            ("quarantine_threshold", anomaly.quarantine_threshold),
+            ("kill_threshold", anomaly.kill_threshold),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(ConfigError::Invalid(format!(
+                    "anomaly.{} must be within [0, 1], got {}",
+                    name, value
+                )));
+            }
+        }
+        if anomaly.quarantine_threshold > anomaly.kill_threshold {
+            return Err(ConfigError::Invalid(format!(
+                "anomaly.quarantine_threshold ({}) exceeds anomaly.kill_threshold ({})",
+                anomaly.quarantine_threshold, anomaly.kill_threshold
+            )));
+        }
+
+        if let Some(ref escalation) = self.escalation {
+            if escalation.window_secs == 0 {
+                return Err(ConfigError::Invalid("escalation.window_secs must be at least 1".to_string()));
+            }
+            if escalation.reset_quorum == 0 {
+                return Err(ConfigError::Invalid("escalation.reset_quorum must be at least 1".to_string()));
+            }
+            if !self.authorized_operators.is_empty()
+                && escalation.reset_quorum > self.authorized_operators.len()
+            {
+                return Err(ConfigError::Invalid(format!(
+                    "escalation.reset_quorum is {} but only {} operators are authorized",
+                    escalation.reset_quorum,
+                    self.authorized_operators.len()
+                )));
+            }
+        }
+
+        for (operation, role) in &self.provenance_roles {
+            if !self.trusted_signers.is_empty() && !self.trusted_signers.iter().any(|s| s.roles.contains(role)) {
+                return Err(ConfigError::Invalid(format!(
+                    "provenance_roles.{} requires role {:?}, which no trusted signer holds",
+                    operation, role
+                )));
+            }
+        }
+
+        if self.max_concurrent_quarantines == Some(0) {
+            return Err(ConfigError::Invalid("max_concurrent_quarantines must be at least 1".to_string()));
+        }
+
+        for url in &self.notifications.webhooks {
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                return Err(ConfigError::Invalid(format!(
+                    "notifications.webhooks entry {:?} is not an http(s) URL",
+                    url
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build a kill-switch authorized for the configured operators.
+    pub fn kill_switch(&self) -> KillSwitch {
+        let mut ks = KillSwitch::new(self.authorized_operators.clone());
+        ks.set_admins(self.admins.clone());
+        ks.set_escalation_policy(self.escalation_policy());
+        ks
+    }
+
+    /// Kill-switch escalation policy, if configured.
+    pub fn escalation_policy(&self) -> Option<EscalationPolicy> {
+        self.escalation.as_ref().map(EscalationConfig::policy)
+    }
+
+    /// Dispatcher for the configured notification destinations.
+    pub fn notifier(&self) -> Result<NotificationDispatcher, NotifyError> {
+        #[cfg(feature = "webhook")]
+        {
+            let mut dispatcher = NotificationDispatcher::new(RetryPolicy::default());
+            for url in &self.notifications.webhooks {
+                dispatcher.add(crate::notify::WebhookNotifier::new(url)?);
+            }
+            Ok(dispatcher)
+        }
+        #[cfg(not(feature = "webhook"))]
+        match self.notifications.webhooks.first() {
+            Some(url) => Err(NotifyError::Unsupported(url.clone())),
+            None => Ok(NotificationDispatcher::new(RetryPolicy::default())),
+        }
+    }
+
+    /// The audit encryption key, if one is configured.
+    pub fn audit_key(&self) -> Result<Option<AuditKey>, KeyError> {
+        self.audit_key_file.as_deref().map(load_audit_key).transpose()
+    }
+
+    /// Build a verifier trusting the configured signers, loading their public keys.
+    pub fn signature_verifier(&self) -> Result<SignatureVerifier, SignatureError> {
+        Ok(self.apply_verification_policy(signature_verifier(&self.trusted_signers)?))
+    }
+
+    /// Apply the configured provenance roles, clock skew and legacy
+    /// signature policy to a verifier whose signers come from elsewhere,
+    /// e.g. [`SignatureVerifier::from_env`].
+    pub fn apply_verification_policy(&self, mut verifier: SignatureVerifier) -> SignatureVerifier {
+        for (operation, role) in &self.provenance_roles {
+            verifier.require_role(*operation, role);
+        }
+        if let Some(secs) = self.max_future_skew_secs {
+            verifier.set_max_future_skew(Some(Duration::seconds(secs.into())));
+        }
+        verifier.set_allow_legacy_sha256(self.allow_legacy_signatures);
+        verifier
+    }
+}
+
+/// Build a verifier trusting `signers`, loading their public keys.
+pub fn signature_verifier(signers: &[TrustedSigner]) -> Result<SignatureVerifier, SignatureError> {
+    let mut verifier = SignatureVerifier::try_new(signers.iter().map(|s| s.id.clone()).collect())?;
+    for signer in signers {
+        if let Some(ref key_path) = signer.public_key {
+            verifier.add_signer_from_pem(&signer.id, key_path)?;
+        }
+        if let Some(interval) = signer.trust_interval() {
+            verifier.set_trust_timeline(&signer.id, vec![interval]);
+        }
+        if !signer.roles.is_empty() {
+            verifier.set_signer_roles(&signer.id, signer.roles.iter().cloned());
+        }
+    }
+    Ok(verifier)
+}
+
+fn check_ids<'a>(field: &str, ids: impl Iterator<Item = &'a String>) -> Result<(), ConfigError> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if let Err(e) = validate_principal_id("id", id) {
+            return Err(ConfigError::Invalid(format!("{} contains {:?}: {}", field, id, e)));
+        }
+        if !seen.insert(id) {
+            return Err(ConfigError::Invalid(format!("{} lists {:?} more than once", field, id)));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Critical Sections
+//!
+//! Fails safe when a governance operation is cut short by a panic. A
+//! [`CriticalSection`] from [`KillSwitch::critical_section`] is held
+//! around a risky operation; if it is dropped while its thread is
+//! unwinding, it activates the kill-switch with
+//! `KillReason::ExternalSignal { source: "panic-guard", .. }`. A normal
+//! drop does nothing.
+//!
+//! Like the dead-man's switch, the guard fires without the switch's
+//! owner and needs no authorized operator; the event is picked up with
+//! the owner's next change. Activation drains in-flight operations, so
+//! create the section before any [`OperationGuard`](crate::drain::OperationGuard)
+//! in the same scope: it is then dropped after them, and the drain does
+//! not wait out its grace period on the panicking thread's own work.
+//!
+//! [`KillSwitch::critical_section`]: crate::killswitch::KillSwitch::critical_section
+
+use crate::killswitch::{KillReason, Trigger};
+use std::time::Duration;
+
+/// `source` of the reason and `triggered_by` of the event when a guard fires.
+pub const PANIC_GUARD_SOURCE: &str = "panic-guard";
+
+/// Activates the kill-switch if dropped during a panic.
+#[must_use = "the section ends, unguarded, as soon as the guard is dropped"]
+pub struct CriticalSection {
+    trigger: Trigger,
+    operation: String,
+    drain_grace: Duration,
+}
+
+impl CriticalSection {
+    pub(crate) fn new(trigger: Trigger, operation: &str, drain_grace: Duration) -> Self {
+        Self {
+            trigger,
+            operation: operation.to_string(),
+            drain_grace,
+        }
+    }
+
+    /// What the section guards, as named in the kill reason.
+    pub fn operation(&self) -> &str {
+        &self.operation
+    }
+}
+
+impl Drop for CriticalSection {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            return;
+        }
+        let reason = KillReason::ExternalSignal {
+            source: PANIC_GUARD_SOURCE.to_string(),
+            message: format!("panic during {}", self.operation),
+        };
+        self.trigger
+            .fire(PANIC_GUARD_SOURCE, reason, Vec::new(), None, Vec::new(), self.drain_grace);
+    }
+}
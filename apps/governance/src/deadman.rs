@@ -0,0 +1,93 @@
+//! Dead-Man's Switch
+//!
+//! Activates the kill-switch when the control plane goes quiet. Once
+//! armed, the switch expects a heartbeat at least every `timeout`; a
+//! timer task on a private runtime fires it otherwise, with
+//! `KillReason::ExternalSignal { source: "deadman", .. }`.
+//!
+//! The timer is one-shot: after it fires, or the switch is activated by
+//! other means, it must be armed again. Disarming or dropping the switch
+//! shuts the runtime down without waiting for the timer, and a timer
+//! caught mid-fire can no longer activate the switch.
+
+use crate::killswitch::{KillReason, Trigger};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+
+/// `source` of the reason and `triggered_by` of the event when the timer fires.
+pub const DEADMAN_SOURCE: &str = "deadman";
+
+pub(crate) struct Deadman {
+    trigger: Trigger,
+    armed: Arc<AtomicBool>,
+    last_beat: Arc<Mutex<Instant>>,
+    task: JoinHandle<()>,
+    /// Taken on drop to shut down without blocking.
+    runtime: Option<Runtime>,
+}
+
+impl Deadman {
+    /// Start the timer; it fires `trigger` unless beaten within `timeout`.
+    pub(crate) fn arm(trigger: Trigger, timeout: Duration, drain_grace: Duration) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("openlora-deadman")
+            .enable_time()
+            .build()?;
+        let armed = Arc::new(AtomicBool::new(true));
+        let last_beat = Arc::new(Mutex::new(Instant::now()));
+
+        let (timer_trigger, timer_armed, beats) = (trigger.clone(), Arc::clone(&armed), Arc::clone(&last_beat));
+        let task = runtime.spawn(async move {
+            loop {
+                let deadline = *beats.lock().unwrap_or_else(PoisonError::into_inner) + timeout;
+                if Instant::now() >= deadline {
+                    break;
+                }
+                tokio::time::sleep_until(deadline.into()).await;
+            }
+            if !timer_trigger.raise_if_armed(&timer_armed) {
+                return;
+            }
+            let reason = KillReason::ExternalSignal {
+                source: DEADMAN_SOURCE.to_string(),
+                message: format!("no heartbeat within {:?}", timeout),
+            };
+            // Draining blocks; keep the runtime's worker free while it does.
+            let _ = tokio::task::spawn_blocking(move || {
+                timer_trigger.record(DEADMAN_SOURCE, reason, Vec::new(), None, Vec::new(), drain_grace)
+            })
+            .await;
+        });
+
+        Ok(Self {
+            trigger,
+            armed,
+            last_beat,
+            task,
+            runtime: Some(runtime),
+        })
+    }
+
+    /// Restart the timeout from now.
+    pub(crate) fn beat(&self) {
+        *self.last_beat.lock().unwrap_or_else(PoisonError::into_inner) = Instant::now();
+    }
+
+    /// Whether the timer has yet to fire.
+    pub(crate) fn is_pending(&self) -> bool {
+        !self.task.is_finished()
+    }
+}
+
+impl Drop for Deadman {
+    fn drop(&mut self) {
+        self.trigger.disarm(&self.armed);
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Executing Decisions
+//!
+//! A [`GovernanceDecision`] is plain data from `openlora-core`, which
+//! knows nothing of kill-switches or audit logs. [`ApplyDecision`] carries
+//! one out against a [`GovernanceContext`], so a policy engine's output
+//! takes real effect. Every effect goes through [`Governance`] and is
+//! audited.
+
+use crate::error::GovernanceError;
+use crate::governance::Governance;
+use crate::quarantine::QuarantineList;
+use crate::registry::Registry;
+use openlora_core::{AdapterGovernanceStatus, GovernanceDecision};
+
+/// What a decision acts on, and as whom.
+pub struct GovernanceContext<'a> {
+    pub governance: &'a mut Governance,
+    pub quarantine: &'a mut QuarantineList,
+    /// Adapters whose status follows quarantines and destructions.
+    pub registry: &'a mut Registry,
+    /// Who the decision is carried out as.
+    pub actor: String,
+    /// Adapter the decided operation concerned: the target of a `Deny`,
+    /// and affected by a `Kill`.
+    pub adapter_id: Option<String>,
+}
+
+impl<'a> GovernanceContext<'a> {
+    pub fn new(
+        governance: &'a mut Governance,
+        quarantine: &'a mut QuarantineList,
+        registry: &'a mut Registry,
+        actor: &str,
+    ) -> Self {
+        Self {
+            governance,
+            quarantine,
+            registry,
+            actor: actor.to_string(),
+            adapter_id: None,
+        }
+    }
+
+    pub fn with_adapter(mut self, adapter_id: &str) -> Self {
+        self.adapter_id = Some(adapter_id.to_string());
+        self
+    }
+}
+
+/// Executing a [`GovernanceDecision`].
+pub trait ApplyDecision {
+    /// Perform the decided action.
+    ///
+    /// `Allow` does nothing. `Deny` records `AccessDenied`. `Quarantine`
+    /// quarantines the adapter and, unless re-verification released it,
+    /// sets its registry status; it fails if that escalates to a kill
+    /// which then fails. `Destroy` records the
+    /// destruction and marks the adapter destroyed. `Kill` activates the
+    /// kill-switch, failing if it is already active. Saving the registry
+    /// is up to the caller.
+    fn apply(&self, ctx: &mut GovernanceContext) -> Result<(), GovernanceError>;
+}
+
+impl ApplyDecision for GovernanceDecision {
+    fn apply(&self, ctx: &mut GovernanceContext) -> Result<(), GovernanceError> {
+        match self {
+            GovernanceDecision::Allow { .. } => {}
+            GovernanceDecision::Deny { reason } => {
+                ctx.governance.deny(&ctx.actor, ctx.adapter_id.as_deref(), reason);
+            }
+            GovernanceDecision::Quarantine { adapter_id, reason } => {
+                let quarantined = ctx
+                    .governance
+                    .quarantine(ctx.quarantine, adapter_id.as_str(), reason, &ctx.actor)?;
+                let still_quarantined = ctx.quarantine.is_quarantined(adapter_id.as_str());
+                if still_quarantined && ctx.registry.get(adapter_id.as_str()).is_some() {
+                    ctx.registry
+                        .set_status(adapter_id.as_str(), AdapterGovernanceStatus::Quarantined)?;
+                }
+                if let Some(Err(e)) = quarantined.escalation {
+                    return Err(e.into());
+                }
+            }
+            GovernanceDecision::Destroy { adapter_id, reason } => {
+                ctx.governance
+                    .destroy(ctx.registry, adapter_id.as_str(), reason, &ctx.actor)?;
+            }
+            GovernanceDecision::Kill { reason } => {
+                let affected = ctx.adapter_id.iter().cloned().collect();
+                ctx.governance.kill(&ctx.actor, reason.clone().into(), affected)?;
+            }
+        }
+        Ok(())
+    }
+}
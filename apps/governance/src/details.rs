@@ -0,0 +1,152 @@
+//! Typed Audit Details
+//!
+//! The `details` each event type is written with, as structs. The hash
+//! chain proves entries were not changed after writing; checking details
+//! against these shapes also catches entries that were written wrong, or
+//! by a build whose schema has drifted.
+//!
+//! Fields may be added freely: unknown fields are ignored, and fields
+//! added after an event type was introduced are optional. Event types
+//! without a fixed shape are not checked.
+
+use crate::attest::Attestation;
+use crate::audit::AuditEventType;
+use crate::killswitch::KillReason;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KillActivatedDetails {
+    pub reason: KillReason,
+    #[serde(default)]
+    pub affected_adapters: Vec<String>,
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    #[serde(default)]
+    pub co_signers: Vec<String>,
+    /// Recorded in shadow mode, without activating.
+    #[serde(default)]
+    pub shadow: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KillResetDetails {
+    pub operators: Vec<String>,
+    #[serde(default)]
+    pub downtime_secs: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ForceResetDetails {
+    pub justification: String,
+    pub bypassed_escalation: bool,
+    #[serde(default)]
+    pub downtime_secs: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StopOverdueDetails {
+    pub kill_id: String,
+    pub unacknowledged: Vec<String>,
+    pub timeout_secs: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuarantineBatchDetails {
+    pub reason: String,
+    pub quarantined: Vec<String>,
+    pub failed: Vec<FailedQuarantine>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FailedQuarantine {
+    pub adapter_id: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionIssuedDetails {
+    pub session_id: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionEndedDetails {
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionExpiredDetails {
+    pub session_id: String,
+    pub expired_at: DateTime<Utc>,
+}
+
+/// Details of quarantines and signature revocations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReasonDetails {
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleasedDetails {
+    pub quarantine_entry_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignatureCreatedDetails {
+    pub signer: String,
+    pub signature: String,
+    #[serde(default)]
+    pub content_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IntegrityFailedDetails {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RechainedDetails {
+    pub reason: String,
+    pub input: String,
+    pub entries: usize,
+    pub old_head: String,
+    pub new_head: String,
+    pub attestation: Attestation,
+}
+
+/// Check raw `details` JSON against the shape of `event_type`.
+pub fn check(event_type: &AuditEventType, details: &str) -> Result<(), serde_json::Error> {
+    fn parse<T: DeserializeOwned>(details: &str) -> Result<(), serde_json::Error> {
+        serde_json::from_str::<T>(details).map(drop)
+    }
+
+    match event_type {
+        AuditEventType::KillSwitchActivated => parse::<KillActivatedDetails>(details),
+        AuditEventType::KillSwitchReset => parse::<KillResetDetails>(details),
+        AuditEventType::KillSwitchForceReset => parse::<ForceResetDetails>(details),
+        AuditEventType::KillStopOverdue => parse::<StopOverdueDetails>(details),
+        AuditEventType::QuarantineBatchCompleted => parse::<QuarantineBatchDetails>(details),
+        AuditEventType::SessionIssued => parse::<SessionIssuedDetails>(details),
+        AuditEventType::SessionEnded => parse::<SessionEndedDetails>(details),
+        AuditEventType::SessionExpired => parse::<SessionExpiredDetails>(details),
+        AuditEventType::AdapterQuarantined | AuditEventType::SignatureRevoked => parse::<ReasonDetails>(details),
+        AuditEventType::AdapterReleased => parse::<ReleasedDetails>(details),
+        AuditEventType::SignatureCreated => parse::<SignatureCreatedDetails>(details),
+        AuditEventType::AuditIntegrityFailed => parse::<IntegrityFailedDetails>(details),
+        AuditEventType::AuditLogRechained => parse::<RechainedDetails>(details),
+        AuditEventType::AdapterCreated
+        | AuditEventType::AdapterActivated
+        | AuditEventType::AdapterDeactivated
+        | AuditEventType::AdapterDestroyed
+        | AuditEventType::SignatureVerified
+        | AuditEventType::SignatureFailed
+        | AuditEventType::PolicyEvaluated
+        | AuditEventType::AccessDenied
+        | AuditEventType::TrainingStarted
+        | AuditEventType::TrainingCompleted
+        | AuditEventType::TrainingFailed
+        | AuditEventType::Unknown(_) => Ok(()),
+    }
+}
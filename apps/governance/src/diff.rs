@@ -0,0 +1,121 @@
+//! Audit Log Diff
+//!
+//! Compare two replicas of an audit log. Because each entry hash covers
+//! its predecessor, equal hashes at a position imply equal prefixes, so
+//! the fork point is found by binary search over entry hashes. That holds
+//! only for hashes that match their entries, so both chains are verified
+//! before they are compared.
+
+use crate::audit::{AuditEntry, AuditError, AuditLog, ChainVerifier};
+use std::collections::HashSet;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DiffError {
+    /// `log` is "a" or "b"; a chain that does not verify cannot be compared.
+    #[error("Log {log} does not verify: {source}")]
+    Unverified { log: &'static str, source: AuditError },
+    #[error(transparent)]
+    Audit(#[from] AuditError),
+}
+
+/// Where two logs stop agreeing.
+#[derive(Debug, Clone)]
+pub struct Fork {
+    /// Position of the first differing entry.
+    pub index: usize,
+    /// Entry at `index` in the first log, if it has one.
+    pub a: Option<AuditEntry>,
+    /// Entry at `index` in the second log, if it has one.
+    pub b: Option<AuditEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogDiff {
+    /// Number of leading entries the logs share.
+    pub common_prefix: usize,
+    /// First divergence, or `None` if the logs are identical.
+    pub fork: Option<Fork>,
+    /// Entries after the fork whose id appears only in the first log.
+    pub only_in_a: Vec<AuditEntry>,
+    /// Entries after the fork whose id appears only in the second log.
+    pub only_in_b: Vec<AuditEntry>,
+}
+
+impl LogDiff {
+    pub fn is_identical(&self) -> bool {
+        self.fork.is_none()
+    }
+}
+
+/// Length of the shared prefix of two verified hash chains.
+fn common_prefix(a: &[AuditEntry], b: &[AuditEntry]) -> usize {
+    let shared = a.len().min(b.len());
+    if shared > 0 && a[shared - 1].hash == b[shared - 1].hash {
+        return shared;
+    }
+
+    // Invariant: first `lo` entries match, entry `hi` (if any) does not
+    let (mut lo, mut hi) = (0, shared);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if a[mid].hash == b[mid].hash {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Entries of `left` whose id does not appear in `right`.
+fn missing_from(left: &[AuditEntry], right: &[AuditEntry]) -> Vec<AuditEntry> {
+    let ids: HashSet<&str> = right.iter().map(|e| e.id.as_str()).collect();
+    left.iter()
+        .filter(|e| !ids.contains(e.id.as_str()))
+        .cloned()
+        .collect()
+}
+
+fn verify_chain(log: &'static str, entries: &[AuditEntry]) -> Result<(), DiffError> {
+    let mut verifier = ChainVerifier::new();
+    for entry in entries {
+        verifier
+            .verify(entry)
+            .map_err(|source| DiffError::Unverified { log, source })?;
+    }
+    Ok(())
+}
+
+/// Compare two entry sequences, each of which must verify as a chain.
+pub fn diff_entries(a: &[AuditEntry], b: &[AuditEntry]) -> Result<LogDiff, DiffError> {
+    verify_chain("a", a)?;
+    verify_chain("b", b)?;
+
+    let prefix = common_prefix(a, b);
+    if prefix == a.len() && prefix == b.len() {
+        return Ok(LogDiff {
+            common_prefix: prefix,
+            fork: None,
+            only_in_a: Vec::new(),
+            only_in_b: Vec::new(),
+        });
+    }
+
+    let (rest_a, rest_b) = (&a[prefix..], &b[prefix..]);
+    Ok(LogDiff {
+        common_prefix: prefix,
+        fork: Some(Fork {
+            index: prefix,
+            a: rest_a.first().cloned(),
+            b: rest_b.first().cloned(),
+        }),
+        only_in_a: missing_from(rest_a, rest_b),
+        only_in_b: missing_from(rest_b, rest_a),
+    })
+}
+
+/// Compare two audit logs.
+pub fn diff_logs(a: &AuditLog, b: &AuditLog) -> Result<LogDiff, DiffError> {
+    diff_entries(&a.entries()?, &b.entries()?)
+}
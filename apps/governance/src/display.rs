@@ -0,0 +1,94 @@
+//! CLI Display
+//!
+//! Renders stored UTC timestamps in an operator's time zone for CLI
+//! output. Storage, hashing and machine-readable exports stay in UTC;
+//! only what is printed for people changes.
+//!
+//! Named IANA zones (`Europe/Berlin`) need the `timezone` feature.
+//! Without it, `local` still follows the system zone and `TZ`.
+//!
+//! Hashes, keys and ids are printed with a short [`fingerprint`] that
+//! operators can compare by eye across systems.
+
+use chrono::{DateTime, Local, Utc};
+use openlora_core::hashing::content_hash;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ZoneError {
+    #[error("Unknown time zone: {0:?}")]
+    Unknown(String),
+    #[error("Time zone {0:?} needs the timezone feature; use UTC or local")]
+    Unsupported(String),
+}
+
+/// Zone timestamps are displayed in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisplayZone {
+    #[default]
+    Utc,
+    /// The system zone, honouring `TZ`.
+    Local,
+    #[cfg(feature = "timezone")]
+    Named(chrono_tz::Tz),
+}
+
+impl DisplayZone {
+    /// Parse `UTC`, `local`, or (with the `timezone` feature) an IANA name.
+    pub fn parse(name: &str) -> Result<Self, ZoneError> {
+        match name {
+            "UTC" | "utc" | "Z" => Ok(Self::Utc),
+            "local" | "Local" => Ok(Self::Local),
+            #[cfg(feature = "timezone")]
+            _ => name
+                .parse()
+                .map(Self::Named)
+                .map_err(|_| ZoneError::Unknown(name.to_string())),
+            #[cfg(not(feature = "timezone"))]
+            _ => Err(ZoneError::Unsupported(name.to_string())),
+        }
+    }
+
+    /// The zone named by `flag`, else the one in `TZ`, else UTC.
+    ///
+    /// A `TZ` value that is not a zone name (e.g. a POSIX rule) is left
+    /// for the system to interpret as the local zone.
+    pub fn resolve(flag: Option<&str>) -> Result<Self, ZoneError> {
+        if let Some(name) = flag {
+            return Self::parse(name);
+        }
+        match std::env::var("TZ") {
+            Ok(tz) if !tz.is_empty() => Ok(Self::parse(tz.trim_start_matches(':')).unwrap_or(Self::Local)),
+            _ => Ok(Self::Utc),
+        }
+    }
+
+    /// `timestamp` in this zone, with the zone's abbreviation or offset.
+    pub fn format(&self, timestamp: &DateTime<Utc>) -> String {
+        match self {
+            Self::Utc => timestamp.to_string(),
+            Self::Local => timestamp.with_timezone(&Local).to_string(),
+            #[cfg(feature = "timezone")]
+            Self::Named(tz) => timestamp.with_timezone(tz).to_string(),
+        }
+    }
+}
+
+/// Digest bytes shown in a [`fingerprint`].
+pub const FINGERPRINT_BYTES: usize = 8;
+
+/// Short fingerprint of `bytes`: the first [`FINGERPRINT_BYTES`] bytes of
+/// their SHA-256 as colon-separated groups of four hex digits, e.g.
+/// `3f2a:91bc:07de:44f1`.
+///
+/// Compare fingerprints with fingerprints, not with the value itself.
+/// For a public key's raw bytes, the digits start its
+/// [`keys::fingerprint`](crate::keys::fingerprint).
+pub fn fingerprint(bytes: &[u8]) -> String {
+    let hash = content_hash(bytes);
+    hash.as_bytes()[..FINGERPRINT_BYTES * 2]
+        .chunks(4)
+        .map(|group| std::str::from_utf8(group).expect("hex digits are ASCII"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
@@ -0,0 +1,125 @@
+//! Operation Draining
+//!
+//! Lets in-flight work wind down when the kill-switch fires. Subsystems
+//! hold an [`OperationGuard`] for the length of each operation; activation
+//! closes the gate to new operations, waits a bounded grace period for
+//! held guards to be released, then cancels those still running.
+//!
+//! Cancellation is cooperative: holders must poll
+//! [`OperationGuard::is_cancelled`] and stop promptly.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// Grace period used when none is configured.
+pub const DEFAULT_DRAIN_GRACE: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+struct Operations {
+    running: usize,
+    /// Cancellation flag shared by guards issued since the last cancel.
+    cancel: Arc<AtomicBool>,
+}
+
+#[derive(Debug)]
+struct InFlight {
+    operations: Mutex<Operations>,
+    released: Condvar,
+}
+
+impl InFlight {
+    fn lock(&self) -> MutexGuard<'_, Operations> {
+        self.operations.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Admits operations while the kill-switch is inactive.
+///
+/// Cheap to clone; every clone shares the kill-switch's state.
+#[derive(Debug, Clone)]
+pub struct OperationGate {
+    active: Arc<AtomicBool>,
+    in_flight: Arc<InFlight>,
+}
+
+impl OperationGate {
+    pub(crate) fn new(active: Arc<AtomicBool>) -> Self {
+        Self {
+            active,
+            in_flight: Arc::new(InFlight {
+                operations: Mutex::new(Operations {
+                    running: 0,
+                    cancel: Arc::new(AtomicBool::new(false)),
+                }),
+                released: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Start an operation; `None` while the kill-switch is active.
+    pub fn begin(&self) -> Option<OperationGuard> {
+        // The flag is read under the lock, so an activation either sees
+        // this operation when it drains or this call sees the activation.
+        let mut operations = self.in_flight.lock();
+        if self.active.load(Ordering::SeqCst) {
+            return None;
+        }
+        operations.running += 1;
+        Some(OperationGuard {
+            in_flight: Arc::clone(&self.in_flight),
+            cancelled: Arc::clone(&operations.cancel),
+        })
+    }
+
+    /// Number of operations currently holding a guard.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.lock().running
+    }
+
+    /// Wait up to `grace` for running operations to finish, then cancel
+    /// the rest. Returns how many were cancelled.
+    ///
+    /// The gate must already be closed, or new operations could keep the
+    /// count from reaching zero.
+    pub(crate) fn drain(&self, grace: Duration) -> usize {
+        let operations = self.in_flight.lock();
+        let (mut operations, _) = self
+            .in_flight
+            .released
+            .wait_timeout_while(operations, grace, |ops| ops.running > 0)
+            .unwrap_or_else(PoisonError::into_inner);
+        if operations.running == 0 {
+            return 0;
+        }
+        operations.cancel.store(true, Ordering::SeqCst);
+        // Guards issued after a later reset must not start out cancelled.
+        operations.cancel = Arc::new(AtomicBool::new(false));
+        operations.running
+    }
+}
+
+/// Held by a subsystem while an operation runs; releasing it lets a
+/// pending kill proceed.
+#[derive(Debug)]
+pub struct OperationGuard {
+    in_flight: Arc<InFlight>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl OperationGuard {
+    /// Whether the kill-switch gave up waiting and the operation must stop.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        let mut operations = self.in_flight.lock();
+        operations.running -= 1;
+        if operations.running == 0 {
+            self.in_flight.released.notify_all();
+        }
+    }
+}
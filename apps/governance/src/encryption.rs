@@ -0,0 +1,155 @@
+//! Audit Log Encryption
+//!
+//! Optional at-rest encryption of audit entries. A sealed line keeps the
+//! chain structure (id, previous hash, hash and hash version) in the
+//! clear and carries the whole entry as XChaCha20-Poly1305 ciphertext.
+//! Hashes are still computed over the plaintext entry, so verifying a
+//! sealed log requires the key.
+//!
+//! Every line gets a fresh random 192-bit nonce, which is large enough
+//! to pick at random for the life of a key, so no nonce state has to be
+//! persisted or recovered after a crash. The clear fields are bound as
+//! associated data, so ciphertext cannot be moved to another line.
+
+use crate::audit::{AuditEntry, AuditError, BOM};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// Length of an audit key in bytes.
+pub const AUDIT_KEY_LEN: usize = 32;
+
+const NONCE_LEN: usize = 24;
+
+/// Sealed lines are written with `sealed` first, so they are told apart
+/// from plaintext entries without parsing.
+const SEALED_PREFIX: &str = "{\"sealed\":";
+
+/// Domain separating this format's associated data from anything else
+/// encrypted under the same key.
+const AAD_DOMAIN: &[u8] = b"openlora-audit-seal-v1";
+
+/// Symmetric key for sealing audit entries.
+#[derive(Clone)]
+pub struct AuditKey(XChaCha20Poly1305);
+
+impl std::fmt::Debug for AuditKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AuditKey(..)")
+    }
+}
+
+/// An audit line as written under encryption.
+#[derive(Debug, Serialize, Deserialize)]
+struct SealedLine {
+    /// Base64 of the nonce followed by the ciphertext.
+    sealed: String,
+    id: String,
+    previous_hash: String,
+    hash: String,
+    hash_version: u32,
+}
+
+/// The clear fields, read back from the decrypted entry.
+#[derive(Deserialize)]
+struct ChainFields {
+    id: String,
+    previous_hash: String,
+    hash: String,
+    #[serde(default = "crate::audit::legacy_hash_version")]
+    hash_version: u32,
+}
+
+impl AuditKey {
+    pub fn from_bytes(bytes: [u8; AUDIT_KEY_LEN]) -> Self {
+        Self(XChaCha20Poly1305::new(&bytes.into()))
+    }
+
+    /// Parse a hex-encoded key, as stored in a key file.
+    pub fn from_hex(hex: &str) -> Result<Self, AuditError> {
+        let bytes: [u8; AUDIT_KEY_LEN] = hex::decode(hex.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| AuditError::InvalidKey(format!("expected {} hex-encoded bytes", AUDIT_KEY_LEN)))?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Encrypt `entry` into a sealed line, without the trailing newline.
+    pub(crate) fn seal(&self, entry: &AuditEntry) -> Result<Vec<u8>, AuditError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let aad = associated_data(&entry.id, &entry.previous_hash, &entry.hash, entry.hash_version);
+        let plaintext = serde_json::to_vec(entry)?;
+        let ciphertext = self
+            .0
+            .encrypt(XNonce::from_slice(&nonce), Payload { msg: &plaintext, aad: &aad })
+            .expect("entries are far below the cipher's length limit");
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        let line = SealedLine {
+            sealed: BASE64_STANDARD.encode(sealed),
+            id: entry.id.clone(),
+            previous_hash: entry.previous_hash.clone(),
+            hash: entry.hash.clone(),
+            hash_version: entry.hash_version,
+        };
+        Ok(serde_json::to_vec(&line)?)
+    }
+
+    /// Decrypt a sealed line back into the entry's plaintext JSON.
+    ///
+    /// A wrong key and tampered ciphertext are indistinguishable, and both
+    /// are reported as [`AuditError::Decryption`].
+    pub(crate) fn unseal(&self, line: &str) -> Result<String, AuditError> {
+        let sealed: SealedLine = serde_json::from_str(line.trim_start_matches(BOM))?;
+        let failed = || AuditError::Decryption { id: sealed.id.clone() };
+
+        let bytes = BASE64_STANDARD.decode(&sealed.sealed).map_err(|_| failed())?;
+        if bytes.len() < NONCE_LEN {
+            return Err(failed());
+        }
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+        let aad = associated_data(&sealed.id, &sealed.previous_hash, &sealed.hash, sealed.hash_version);
+        let plaintext = self
+            .0
+            .decrypt(XNonce::from_slice(nonce), Payload { msg: ciphertext, aad: &aad })
+            .map_err(|_| failed())?;
+        let plaintext = String::from_utf8(plaintext).map_err(|_| failed())?;
+
+        // Only a key holder could seal mismatched fields; still refuse them.
+        let inner: ChainFields = serde_json::from_str(&plaintext)?;
+        if inner.id != sealed.id
+            || inner.previous_hash != sealed.previous_hash
+            || inner.hash != sealed.hash
+            || inner.hash_version != sealed.hash_version
+        {
+            return Err(AuditError::IntegrityViolation {
+                expected: sealed.hash,
+                actual: inner.hash,
+            });
+        }
+        Ok(plaintext)
+    }
+}
+
+/// Whether `line` is a sealed entry.
+pub(crate) fn is_sealed(line: &str) -> bool {
+    line.trim_start_matches(BOM).trim_start().starts_with(SEALED_PREFIX)
+}
+
+/// Head hash recorded in the clear on a sealed line; no key needed.
+pub(crate) fn sealed_hash(line: &str) -> Result<String, AuditError> {
+    Ok(serde_json::from_str::<SealedLine>(line.trim_start_matches(BOM))?.hash)
+}
+
+fn associated_data(id: &str, previous_hash: &str, hash: &str, hash_version: u32) -> Vec<u8> {
+    let mut aad = AAD_DOMAIN.to_vec();
+    for field in [id.as_bytes(), previous_hash.as_bytes(), hash.as_bytes(), &hash_version.to_be_bytes()] {
+        aad.extend_from_slice(&(field.len() as u64).to_be_bytes());
+        aad.extend_from_slice(field);
+    }
+    aad
+}
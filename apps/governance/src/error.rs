@@ -0,0 +1,74 @@
+//! Governance Errors
+//!
+//! One error type spanning every module, for embedders and the CLI to
+//! match on. Each module keeps its own error enum; [`GovernanceError`]
+//! wraps them unchanged, so `?` composes calls across modules and the
+//! original error is still there to match on.
+
+use crate::attest::AttestError;
+use crate::audit::AuditError;
+use crate::config::ConfigError;
+use crate::display::ZoneError;
+use crate::keys::KeyError;
+use crate::killswitch::KillSwitchError;
+use crate::lineage::BundleError;
+use crate::notify::NotifyError;
+#[cfg(feature = "protobuf")]
+use crate::protobuf::ProtobufError;
+use crate::quarantine::QuarantineError;
+use crate::rechain::RechainError;
+use crate::registry::RegistryError;
+use crate::revocation::RevocationError;
+use crate::signatures::SignatureError;
+use crate::source::SourceError;
+use crate::trust::TrustError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GovernanceError {
+    #[error(transparent)]
+    KillSwitch(#[from] KillSwitchError),
+    #[error(transparent)]
+    Signature(#[from] SignatureError),
+    #[error(transparent)]
+    Audit(#[from] AuditError),
+    #[error(transparent)]
+    Attest(#[from] AttestError),
+    #[error(transparent)]
+    Bundle(#[from] BundleError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error(transparent)]
+    Key(#[from] KeyError),
+    #[error(transparent)]
+    Notify(#[from] NotifyError),
+    #[cfg(feature = "protobuf")]
+    #[error(transparent)]
+    Protobuf(#[from] ProtobufError),
+    #[error(transparent)]
+    Quarantine(#[from] QuarantineError),
+    #[error(transparent)]
+    Rechain(#[from] RechainError),
+    #[error(transparent)]
+    Registry(#[from] RegistryError),
+    #[error(transparent)]
+    Revocation(#[from] RevocationError),
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    #[error(transparent)]
+    Trust(#[from] TrustError),
+    #[error(transparent)]
+    Zone(#[from] ZoneError),
+}
+
+impl GovernanceError {
+    /// Whether the audit chain itself failed verification, wherever the
+    /// failure surfaced.
+    pub fn is_integrity_failure(&self) -> bool {
+        match self {
+            Self::Audit(e) => e.is_integrity_failure(),
+            Self::Attest(AttestError::Audit(e)) | Self::Rechain(RechainError::Audit(e)) => e.is_integrity_failure(),
+            _ => false,
+        }
+    }
+}
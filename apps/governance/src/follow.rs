@@ -0,0 +1,145 @@
+//! Audit Log Follower
+//!
+//! Streams entries as they are appended to an audit log, like `tail -f`.
+//! Only complete lines are returned, so an entry caught mid-write is
+//! picked up on a later poll. When the file at the path is replaced
+//! (rotated) or truncated, the rest of the old file is drained and the
+//! new one is followed from its start. Replacement is detected by inode,
+//! so only on Unix.
+
+use crate::audit::{decode_entry, read_tail_before, AuditEntry, AuditError};
+use crate::encryption::AuditKey;
+use std::fs::{File, Metadata};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+pub struct AuditFollower {
+    path: PathBuf,
+    file: Option<File>,
+    /// Identity of `file`, to notice when the path points elsewhere.
+    identity: Option<(u64, u64)>,
+    /// Byte offset of the first line not yet returned.
+    offset: u64,
+    key: Option<AuditKey>,
+}
+
+impl AuditFollower {
+    /// Follow `path` from its current end, also returning its last `tail`
+    /// entries; no entry is both in the tail and returned by a poll.
+    pub fn open(path: PathBuf, tail: usize) -> Result<(Self, Vec<AuditEntry>), AuditError> {
+        Self::open_inner(path, tail, None)
+    }
+
+    /// [`AuditFollower::open`] for a log with encrypted entries.
+    pub fn open_with_key(path: PathBuf, tail: usize, key: AuditKey) -> Result<(Self, Vec<AuditEntry>), AuditError> {
+        Self::open_inner(path, tail, Some(key))
+    }
+
+    fn open_inner(path: PathBuf, tail: usize, key: Option<AuditKey>) -> Result<(Self, Vec<AuditEntry>), AuditError> {
+        let mut follower = Self {
+            path,
+            file: None,
+            identity: None,
+            offset: 0,
+            key,
+        };
+        let Ok(file) = File::open(&follower.path) else {
+            // Not created yet: follow it from the start once it is.
+            return Ok((follower, Vec::new()));
+        };
+
+        let metadata = file.metadata()?;
+        follower.identity = identity(&metadata);
+        follower.offset = last_line_end(&file, metadata.len())?;
+        let entries = read_tail_before(file.try_clone()?, tail, follower.offset, follower.key.as_ref())?;
+        follower.file = Some(file);
+        Ok((follower, entries))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Entries appended since the last poll, oldest first.
+    pub fn poll(&mut self) -> Result<Vec<AuditEntry>, AuditError> {
+        let current = match std::fs::metadata(&self.path) {
+            Ok(metadata) => Some(metadata),
+            // Mid-rotation: the old file is gone and the new one not yet created.
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let replaced = match (&self.file, &current) {
+            (Some(_), Some(metadata)) => identity(metadata) != self.identity,
+            _ => true,
+        };
+
+        let mut entries = match self.file {
+            Some(ref mut file) => read_lines(file, &mut self.offset, self.key.as_ref())?,
+            None => Vec::new(),
+        };
+        if replaced {
+            if let Some(metadata) = current {
+                let file = File::open(&self.path)?;
+                self.identity = identity(&metadata);
+                self.offset = 0;
+                self.file = Some(file);
+                let file = self.file.as_mut().expect("file just opened");
+                entries.extend(read_lines(file, &mut self.offset, self.key.as_ref())?);
+            } else {
+                self.file = None;
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Parse the complete lines of `file` after `offset`, advancing it.
+fn read_lines(file: &mut File, offset: &mut u64, key: Option<&AuditKey>) -> Result<Vec<AuditEntry>, AuditError> {
+    if file.metadata()?.len() < *offset {
+        // Truncated in place; start over.
+        *offset = 0;
+    }
+    file.seek(SeekFrom::Start(*offset))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+
+    let complete = buf.iter().rposition(|b| *b == b'\n').map_or(0, |i| i + 1);
+    *offset += complete as u64;
+    buf[..complete]
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .map(|line| decode_entry(line, key))
+        .collect()
+}
+
+/// Offset just past the last newline at or before `len`.
+fn last_line_end(mut file: &File, len: u64) -> io::Result<u64> {
+    const CHUNK: u64 = 64 * 1024;
+
+    let mut end = len;
+    while end > 0 {
+        let start = end.saturating_sub(CHUNK);
+        let mut chunk = vec![0; (end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut chunk)?;
+        if let Some(i) = chunk.iter().rposition(|b| *b == b'\n') {
+            return Ok(start + i as u64 + 1);
+        }
+        end = start;
+    }
+    Ok(0)
+}
+
+#[cfg(unix)]
+fn identity(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+/// Without inode numbers a replaced file goes unnoticed; truncation is
+/// still handled.
+#[cfg(not(unix))]
+fn identity(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
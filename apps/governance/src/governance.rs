@@ -0,0 +1,797 @@
+//! Governance Facade
+//!
+//! Kill-switch actions paired with their audit records.
+//!
+//! Safety comes first: if the audit log cannot be written, the action
+//! still takes effect. The failed record is buffered in memory, the
+//! caller receives an `AuditWriteFailed` warning, and the buffer is
+//! written out, in order, by the next successful append or an explicit
+//! `flush_pending`.
+//!
+//! Once an activation has been recorded, configured notifiers are told
+//! about it in the background; see [`crate::notify`].
+//!
+//! Quarantines go through the facade too, so that too many at once can
+//! escalate to a full kill, and so that one over a signature can be
+//! re-verified; see [`crate::reverify`].
+//!
+//! `SharedGovernance` serializes actions across threads, so the state
+//! flip, event and audit record of one action never interleave with
+//! another's.
+
+use crate::audit::{AuditEntry, AuditError, AuditEventType, AuditLog, AuditTarget};
+use crate::encryption::AuditKey;
+use crate::killswitch::{Activation, KillEvent, KillReason, KillSwitch, KillSwitchError, OverdueStop, ResetOutcome};
+use crate::notify::{NotificationDispatcher, PendingNotifications};
+use crate::policy::PolicyEvaluation;
+use crate::quarantine::{QuarantineError, QuarantineList, QuarantineRecord};
+use crate::registry::{AdapterLabels, Registry};
+use crate::reverify::{is_signature_concern, Reverification, Reverifier};
+use crate::session::{OperatorSession, SessionRecord};
+use chrono::{DateTime, Duration, Utc};
+use openlora_core::{AdapterGovernanceStatus, GovernanceDecision};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// An audit record that could not be written yet.
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingAudit {
+    pub event_type: AuditEventType,
+    pub actor: String,
+    #[serde(flatten)]
+    pub target: Option<AuditTarget>,
+    pub details: serde_json::Value,
+    /// When the action actually happened.
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Warning that an action took effect without being recorded.
+#[derive(Debug, Clone)]
+pub struct AuditWriteFailed {
+    pub error: String,
+    /// Records now waiting in the buffer, including this one.
+    pub pending: usize,
+}
+
+impl std::fmt::Display for AuditWriteFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}; {} record(s) buffered in memory", self.error, self.pending)
+    }
+}
+
+/// Whether an action's audit record was written.
+#[derive(Debug, Clone)]
+pub enum AuditStatus {
+    Recorded(AuditEntry),
+    Deferred(AuditWriteFailed),
+    /// An idempotent retry; the original action's record stands.
+    Replayed,
+}
+
+impl AuditStatus {
+    pub fn is_recorded(&self) -> bool {
+        matches!(self, Self::Recorded(_))
+    }
+}
+
+/// A performed action together with the state of its audit record.
+#[derive(Debug, Clone)]
+pub struct Audited<T> {
+    pub outcome: T,
+    pub audit: AuditStatus,
+}
+
+/// `source` of the [`KillReason::ExternalSignal`] raised by too many
+/// concurrent quarantines.
+pub const MASS_QUARANTINE_SOURCE: &str = "mass-quarantine";
+
+/// A recorded quarantine and, if it crossed the limit, the kill it raised.
+#[derive(Debug)]
+pub struct Quarantined {
+    pub record: QuarantineRecord,
+    /// Adapters quarantined now, including this one.
+    pub quarantined: usize,
+    /// The escalation, when the limit was exceeded and the kill-switch
+    /// was not already active.
+    pub escalation: Option<Result<Audited<KillEvent>, KillSwitchError>>,
+    /// The re-verification, for a quarantine over the adapter's signature
+    /// while a [`Reverifier`] is set.
+    pub reverification: Option<Audited<Reverification>>,
+}
+
+/// Outcome of [`Governance::quarantine_batch`].
+#[derive(Debug)]
+pub struct QuarantineBatch {
+    /// Adapters quarantined, in the order given.
+    pub quarantined: Vec<String>,
+    /// Adapters that could not be quarantined, and why.
+    pub failed: Vec<(String, QuarantineError)>,
+    /// The escalation, if the batch crossed the quarantine limit.
+    pub escalation: Option<Result<Audited<KillEvent>, KillSwitchError>>,
+    /// The `QuarantineBatchCompleted` summary record.
+    pub summary: AuditStatus,
+}
+
+pub struct Governance {
+    kill_switch: KillSwitch,
+    audit_path: PathBuf,
+    audit: Option<AuditLog>,
+    audit_key: Option<AuditKey>,
+    adapter_labels: HashMap<String, AdapterLabels>,
+    pending: Vec<PendingAudit>,
+    notifier: NotificationDispatcher,
+    notifications: PendingNotifications,
+    quarantine_limit: Option<usize>,
+    reverifier: Option<Reverifier>,
+}
+
+impl Governance {
+    pub fn new(kill_switch: KillSwitch, audit: AuditLog) -> Self {
+        Self {
+            kill_switch,
+            audit_path: audit.path().to_path_buf(),
+            audit: Some(audit),
+            audit_key: None,
+            adapter_labels: HashMap::new(),
+            pending: Vec::new(),
+            notifier: NotificationDispatcher::default(),
+            notifications: PendingNotifications::default(),
+            quarantine_limit: None,
+            reverifier: None,
+        }
+    }
+
+    /// Use the audit log at `path`, opened on first write.
+    ///
+    /// Never fails: a log that cannot be opened is treated like one that
+    /// cannot be written, and records are buffered until it can.
+    pub fn open(kill_switch: KillSwitch, path: PathBuf) -> Self {
+        Self {
+            kill_switch,
+            audit: AuditLog::open(path.clone()).ok(),
+            audit_path: path,
+            audit_key: None,
+            adapter_labels: HashMap::new(),
+            pending: Vec::new(),
+            notifier: NotificationDispatcher::default(),
+            notifications: PendingNotifications::default(),
+            quarantine_limit: None,
+            reverifier: None,
+        }
+    }
+
+    /// Encrypt audit records with `key`, including when the log is reopened.
+    pub fn set_audit_key(&mut self, key: AuditKey) {
+        if let Some(ref mut audit) = self.audit {
+            if audit.set_key(key.clone()).is_err() {
+                // Reopened on the next write, like any unwritable log.
+                self.audit = None;
+            }
+        }
+        self.audit_key = Some(key);
+    }
+
+    /// Label audit records with adapters' labels, including when the log
+    /// is reopened; see [`AuditLog::set_adapter_labels`].
+    pub fn set_adapter_labels(&mut self, labels: HashMap<String, AdapterLabels>) {
+        if let Some(ref mut audit) = self.audit {
+            audit.set_adapter_labels(labels.clone());
+        }
+        self.adapter_labels = labels;
+    }
+
+    /// Notify `notifier`'s destinations of every new activation.
+    pub fn set_notifier(&mut self, notifier: NotificationDispatcher) {
+        self.notifier = notifier;
+    }
+
+    /// Wait for notifications still in flight; returns how many were delivered.
+    ///
+    /// Call before exiting, or pending deliveries are lost.
+    pub fn wait_for_notifications(&mut self) -> usize {
+        std::mem::take(&mut self.notifications).wait()
+    }
+
+    /// Activate the kill-switch once more than `limit` adapters are
+    /// quarantined at the same time.
+    pub fn set_quarantine_limit(&mut self, limit: Option<usize>) {
+        self.quarantine_limit = limit;
+    }
+
+    /// Re-verify adapters quarantined over their signature with `reverifier`.
+    pub fn set_reverifier(&mut self, reverifier: Reverifier) {
+        self.reverifier = Some(reverifier);
+    }
+
+    pub fn kill_switch(&self) -> &KillSwitch {
+        &self.kill_switch
+    }
+
+    /// The audit log, if it has been opened.
+    pub fn audit_log(&self) -> Option<&AuditLog> {
+        self.audit.as_ref()
+    }
+
+    fn audit(&mut self) -> Result<&mut AuditLog, AuditError> {
+        if self.audit.is_none() {
+            let mut audit = AuditLog::open(self.audit_path.clone())?;
+            if let Some(ref key) = self.audit_key {
+                audit.set_key(key.clone())?;
+            }
+            audit.set_adapter_labels(self.adapter_labels.clone());
+            self.audit = Some(audit);
+        }
+        Ok(self.audit.as_mut().expect("audit log just opened"))
+    }
+
+    /// Records buffered while the audit log was unwritable, oldest first.
+    pub fn pending_audit(&self) -> &[PendingAudit] {
+        &self.pending
+    }
+
+    /// Activate the kill-switch, then record it.
+    pub fn kill(
+        &mut self,
+        operator: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+    ) -> Result<Audited<KillEvent>, KillSwitchError> {
+        self.kill_idempotent(operator, reason, affected_adapters, None)
+    }
+
+    /// Activate at most once per `idempotency_key`, then record it.
+    ///
+    /// A repeated key returns the original event with
+    /// [`AuditStatus::Replayed`] and writes nothing.
+    pub fn kill_idempotent(
+        &mut self,
+        operator: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+        idempotency_key: Option<&str>,
+    ) -> Result<Audited<KillEvent>, KillSwitchError> {
+        let activation =
+            self.kill_switch.activate_idempotent(operator, reason, affected_adapters, idempotency_key)?;
+        let event = match activation {
+            Activation::Activated(event) => event,
+            Activation::Replayed(event) => {
+                return Ok(Audited {
+                    outcome: event,
+                    audit: AuditStatus::Replayed,
+                })
+            }
+        };
+        Ok(self.record_activation(event))
+    }
+
+    /// Activate on the authority of two distinct operators, then record it.
+    ///
+    /// Both are named in the audit record: `operator` as the actor and
+    /// `co_signer` in the details' `co_signers`.
+    pub fn kill_cosigned(
+        &mut self,
+        operator: &str,
+        co_signer: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+    ) -> Result<Audited<KillEvent>, KillSwitchError> {
+        let event = self.kill_switch.activate_cosigned(operator, co_signer, reason, affected_adapters)?;
+        Ok(self.record_activation(event))
+    }
+
+    /// Record a new activation and notify about it.
+    fn record_activation(&mut self, event: KillEvent) -> Audited<KillEvent> {
+        let mut details = json!({
+            "reason": event.reason,
+            "affected_adapters": event.affected_adapters,
+        });
+        if let Some(ref key) = event.idempotency_key {
+            details["idempotency_key"] = json!(key);
+        }
+        if !event.co_signers.is_empty() {
+            details["co_signers"] = json!(event.co_signers);
+        }
+        if event.shadow {
+            details["shadow"] = json!(true);
+        }
+        let audit = self.record(PendingAudit {
+            event_type: AuditEventType::KillSwitchActivated,
+            actor: event.triggered_by.clone(),
+            target: Some(AuditTarget::KillSwitch(Some(event.id.clone()))),
+            details,
+            occurred_at: event.timestamp,
+        });
+        if !event.shadow && !self.notifier.is_empty() {
+            self.notifications.extend(self.notifier.dispatch(&event));
+        }
+        Audited { outcome: event, audit }
+    }
+
+    /// Activate on the authority of a session `token`, then record it.
+    pub fn kill_with_session(
+        &mut self,
+        token: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+        idempotency_key: Option<&str>,
+    ) -> Result<Audited<KillEvent>, KillSwitchError> {
+        let operator = self.authenticate(token)?;
+        self.kill_idempotent(&operator, reason, affected_adapters, idempotency_key)
+    }
+
+    /// Reset on the authority of one or more session tokens, then record it.
+    pub fn reset_with_sessions(&mut self, tokens: &[String]) -> Result<Audited<ResetOutcome>, KillSwitchError> {
+        let operators = tokens
+            .iter()
+            .map(|token| self.authenticate(token))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.reset(&operators)
+    }
+
+    /// Issue a session for `operator`, then record it.
+    pub fn issue_session(
+        &mut self,
+        operator: &str,
+        ttl: Duration,
+    ) -> Result<Audited<OperatorSession>, KillSwitchError> {
+        let session = self.kill_switch.issue_session(operator, ttl)?;
+        let audit = self.record(PendingAudit {
+            event_type: AuditEventType::SessionIssued,
+            actor: operator.to_string(),
+            target: Some(AuditTarget::Operator(operator.to_string())),
+            details: json!({
+                "session_id": session.id,
+                "expires_at": session.expires_at,
+            }),
+            occurred_at: session.issued_at,
+        });
+        Ok(Audited { outcome: session, audit })
+    }
+
+    /// End a session before it expires, then record it.
+    pub fn end_session(&mut self, id: &str, actor: &str) -> Option<Audited<SessionRecord>> {
+        let record = self.kill_switch.end_session(id)?;
+        let audit = self.record(PendingAudit {
+            event_type: AuditEventType::SessionEnded,
+            actor: actor.to_string(),
+            target: Some(AuditTarget::Operator(record.operator.clone())),
+            details: json!({ "session_id": record.id }),
+            occurred_at: self.kill_switch.now(),
+        });
+        Some(Audited { outcome: record, audit })
+    }
+
+    /// Resolve a session token, recording attempts to use an expired one.
+    fn authenticate(&mut self, token: &str) -> Result<String, KillSwitchError> {
+        let result = self.kill_switch.session_operator(token);
+        if let Err(KillSwitchError::SessionExpired {
+            ref session,
+            ref operator,
+            expired_at,
+        }) = result
+        {
+            self.record(PendingAudit {
+                event_type: AuditEventType::SessionExpired,
+                actor: operator.clone(),
+                target: Some(AuditTarget::Operator(operator.clone())),
+                details: json!({
+                    "session_id": session,
+                    "expired_at": expired_at,
+                }),
+                occurred_at: self.kill_switch.now(),
+            });
+        }
+        result
+    }
+
+    /// Reset the kill-switch with the agreement of `operators`, then record it.
+    pub fn reset(&mut self, operators: &[String]) -> Result<Audited<ResetOutcome>, KillSwitchError> {
+        let outcome = match operators {
+            [operator] => self.kill_switch.reset(operator)?,
+            _ => self.kill_switch.reset_with_quorum(operators)?,
+        };
+        let audit = self.record(PendingAudit {
+            event_type: AuditEventType::KillSwitchReset,
+            actor: operators.join(", "),
+            target: Some(AuditTarget::KillSwitch(None)),
+            details: json!({
+                "operators": operators,
+                "downtime_secs": outcome.downtime.map(|d| d.num_seconds()),
+            }),
+            occurred_at: outcome.reset_at,
+        });
+        Ok(Audited { outcome, audit })
+    }
+
+    /// Force a reset as `admin`, bypassing quorum and escalation.
+    ///
+    /// Recorded as a separate `KillSwitchForceReset` event carrying the
+    /// justification, so overrides stand out from ordinary resets.
+    pub fn force_reset(&mut self, admin: &str, justification: &str) -> Result<Audited<ResetOutcome>, KillSwitchError> {
+        let escalated = self.kill_switch.is_escalated();
+        let outcome = self.kill_switch.force_reset(admin, justification)?;
+        let audit = self.record(PendingAudit {
+            event_type: AuditEventType::KillSwitchForceReset,
+            actor: admin.to_string(),
+            target: Some(AuditTarget::KillSwitch(None)),
+            details: json!({
+                "justification": justification,
+                "bypassed_escalation": escalated,
+                "downtime_secs": outcome.downtime.map(|d| d.num_seconds()),
+            }),
+            occurred_at: outcome.reset_at,
+        });
+        Ok(Audited { outcome, audit })
+    }
+
+    /// Confirm that an adapter affected by the current kill has stopped,
+    /// then record it as `AdapterDeactivated`.
+    ///
+    /// `actor` is the subsystem that stopped it. A repeated
+    /// acknowledgement changes nothing and is [`AuditStatus::Replayed`].
+    pub fn acknowledge_stopped(&mut self, actor: &str, adapter_id: &str) -> Result<Audited<bool>, KillSwitchError> {
+        if !self.kill_switch.acknowledge_stopped(adapter_id)? {
+            return Ok(Audited {
+                outcome: false,
+                audit: AuditStatus::Replayed,
+            });
+        }
+        let kill_id = self.kill_switch.current_event().map(|event| event.id.clone());
+        let audit = self.record(PendingAudit {
+            event_type: AuditEventType::AdapterDeactivated,
+            actor: actor.to_string(),
+            target: Some(AuditTarget::from_parts("adapter", Some(adapter_id))),
+            details: json!({ "kill_id": kill_id, "acknowledged_stop": true }),
+            occurred_at: self.kill_switch.now(),
+        });
+        Ok(Audited { outcome: true, audit })
+    }
+
+    /// Record a `KillStopOverdue` warning, once per activation, if some
+    /// affected adapters have not acknowledged stopping `timeout` after
+    /// the kill. Meant to be polled; `None` when there is nothing to report.
+    pub fn escalate_unacknowledged(&mut self, actor: &str, timeout: Duration) -> Option<Audited<OverdueStop>> {
+        let overdue = self.kill_switch.take_overdue(timeout)?;
+        eprintln!(
+            "⚠️  {} adapter(s) have not confirmed stopping: {}",
+            overdue.unacknowledged.len(),
+            overdue.unacknowledged.join(", ")
+        );
+        let audit = self.record(PendingAudit {
+            event_type: AuditEventType::KillStopOverdue,
+            actor: actor.to_string(),
+            target: Some(AuditTarget::KillSwitch(Some(overdue.kill_id.clone()))),
+            details: json!({
+                "kill_id": overdue.kill_id,
+                "unacknowledged": overdue.unacknowledged,
+                "timeout_secs": timeout.num_seconds(),
+            }),
+            occurred_at: self.kill_switch.now(),
+        });
+        Some(Audited { outcome: overdue, audit })
+    }
+
+    /// Quarantine an adapter in `list`, escalating to a kill if too many are.
+    ///
+    /// Unlike kills, a quarantine is refused if it cannot be recorded.
+    /// Once it is, a quarantine over the adapter's signature is
+    /// re-verified if a [`Reverifier`] is set, and possibly released.
+    /// Then exceeding the quarantine limit activates the kill-switch
+    /// as `operator`, with a [`MASS_QUARANTINE_SOURCE`] external signal
+    /// naming every quarantined adapter. A failed escalation does not undo
+    /// the quarantine; it is returned in [`Quarantined::escalation`].
+    pub fn quarantine(
+        &mut self,
+        list: &mut QuarantineList,
+        adapter_id: &str,
+        reason: &str,
+        operator: &str,
+    ) -> Result<Quarantined, QuarantineError> {
+        self.flush_pending()?;
+        let record = list.quarantine(self.audit()?, adapter_id, reason, operator)?;
+        let reverification = self.reverify(list, adapter_id, &record, operator);
+        let quarantined = list.len();
+
+        let escalation = match self.quarantine_limit {
+            Some(limit) if quarantined > limit && !self.kill_switch.is_active() => {
+                let reason = KillReason::ExternalSignal {
+                    source: MASS_QUARANTINE_SOURCE.to_string(),
+                    message: format!("{} adapters quarantined, limit is {}", quarantined, limit),
+                };
+                let adapters = list.iter().map(|(id, _)| id.clone()).collect();
+                Some(self.kill(operator, reason, adapters))
+            }
+            _ => None,
+        };
+        Ok(Quarantined {
+            record,
+            quarantined,
+            escalation,
+            reverification,
+        })
+    }
+
+    /// Run the reverifier on a quarantine over `adapter_id`'s signature,
+    /// record the outcome and release the adapter if it verifies and
+    /// auto-release is on.
+    fn reverify(
+        &mut self,
+        list: &mut QuarantineList,
+        adapter_id: &str,
+        record: &QuarantineRecord,
+        operator: &str,
+    ) -> Option<Audited<Reverification>> {
+        let reverifier = self.reverifier.as_ref().filter(|_| is_signature_concern(&record.reason))?;
+        let verified = reverifier.verify(adapter_id);
+        let auto_release = reverifier.auto_release();
+
+        let target = Some(AuditTarget::from_parts("adapter", Some(adapter_id)));
+        let (event_type, details, outcome) = match verified {
+            Ok(signature) => (
+                AuditEventType::SignatureVerified,
+                json!({ "quarantine_entry_id": record.audit_entry_id, "signer": signature.signer_id }),
+                Reverification::Verified {
+                    signer_id: signature.signer_id,
+                    released: None,
+                },
+            ),
+            Err(e) => (
+                AuditEventType::SignatureFailed,
+                json!({ "quarantine_entry_id": record.audit_entry_id, "error": e.to_string() }),
+                Reverification::Failed(e),
+            ),
+        };
+        let audit = self.record(PendingAudit {
+            event_type,
+            actor: operator.to_string(),
+            target,
+            details,
+            occurred_at: self.kill_switch.now(),
+        });
+        let outcome = match outcome {
+            Reverification::Verified { signer_id, .. } if auto_release => Reverification::Verified {
+                signer_id,
+                released: Some(self.release(list, adapter_id, operator)),
+            },
+            outcome => outcome,
+        };
+        Some(Audited { outcome, audit })
+    }
+
+    /// Release an adapter from quarantine in `list`.
+    ///
+    /// Like a quarantine, refused if it cannot be recorded.
+    pub fn release(
+        &mut self,
+        list: &mut QuarantineList,
+        adapter_id: &str,
+        operator: &str,
+    ) -> Result<QuarantineRecord, QuarantineError> {
+        self.flush_pending()?;
+        list.release(self.audit()?, adapter_id, operator)
+    }
+
+    /// Quarantine each of `adapter_ids`, continuing past failures, then
+    /// record a `QuarantineBatchCompleted` summary.
+    ///
+    /// Each adapter goes through [`Governance::quarantine`], so gets its
+    /// own `AdapterQuarantined` entry and counts towards the quarantine
+    /// limit; the first to cross it escalates to a kill.
+    pub fn quarantine_batch(
+        &mut self,
+        list: &mut QuarantineList,
+        adapter_ids: &[String],
+        reason: &str,
+        operator: &str,
+    ) -> QuarantineBatch {
+        let mut quarantined = Vec::new();
+        let mut failed = Vec::new();
+        let mut escalation = None;
+        for adapter_id in adapter_ids {
+            match self.quarantine(list, adapter_id, reason, operator) {
+                Ok(outcome) => {
+                    quarantined.push(adapter_id.clone());
+                    escalation = escalation.or(outcome.escalation);
+                }
+                Err(e) => failed.push((adapter_id.clone(), e)),
+            }
+        }
+
+        let failures: Vec<_> = failed
+            .iter()
+            .map(|(adapter_id, e)| json!({ "adapter_id": adapter_id, "error": e.to_string() }))
+            .collect();
+        let summary = self.record(PendingAudit {
+            event_type: AuditEventType::QuarantineBatchCompleted,
+            actor: operator.to_string(),
+            target: None,
+            details: json!({ "reason": reason, "quarantined": quarantined, "failed": failures }),
+            occurred_at: self.kill_switch.now(),
+        });
+        QuarantineBatch {
+            quarantined,
+            failed,
+            escalation,
+            summary,
+        }
+    }
+
+    /// Destroy an adapter: record `AdapterDestroyed`, then mark it
+    /// `Destroyed` in `registry` if it is registered there.
+    ///
+    /// Like a quarantine, refused if it cannot be recorded. Saving the
+    /// registry is up to the caller.
+    pub fn destroy(
+        &mut self,
+        registry: &mut Registry,
+        adapter_id: &str,
+        reason: &str,
+        operator: &str,
+    ) -> Result<AuditEntry, AuditError> {
+        self.flush_pending()?;
+        let entry = self.audit()?.append(
+            AuditEventType::AdapterDestroyed,
+            operator,
+            Some(&AuditTarget::from_parts("adapter", Some(adapter_id))),
+            json!({ "reason": reason }),
+        )?;
+        if registry.get(adapter_id).is_some() {
+            registry
+                .set_status(adapter_id, AdapterGovernanceStatus::Destroyed)
+                .expect("adapter checked above");
+        }
+        Ok(entry)
+    }
+
+    /// Record that `actor` was refused an operation, on `adapter_id` if
+    /// it concerned one, as `AccessDenied`.
+    pub fn deny(&mut self, actor: &str, adapter_id: Option<&str>, reason: &str) -> AuditStatus {
+        self.record(PendingAudit {
+            event_type: AuditEventType::AccessDenied,
+            actor: actor.to_string(),
+            target: adapter_id.map(|id| AuditTarget::from_parts("adapter", Some(id))),
+            details: json!({ "reason": reason }),
+            occurred_at: self.kill_switch.now(),
+        })
+    }
+
+    /// Record a policy decision about `target` as `PolicyEvaluated`.
+    ///
+    /// The whole decision goes into the details under `decision`, so an
+    /// `Allow` keeps the trace of rules that let it through. Nothing is
+    /// enforced here: acting on the decision is up to the caller.
+    pub fn record_decision(
+        &mut self,
+        actor: &str,
+        target: Option<AuditTarget>,
+        decision: &GovernanceDecision,
+    ) -> AuditStatus {
+        self.record_policy(actor, target, json!({ "decision": decision }))
+    }
+
+    /// Record a [`PolicyEngine`](crate::policy::PolicyEngine) evaluation
+    /// as `PolicyEvaluated`.
+    ///
+    /// Besides the decision, a denial keeps its trace under `trace`, and a
+    /// decision no rule covered is marked `default_decision: true`, so
+    /// gaps in policy coverage can be found in the log.
+    pub fn record_evaluation(
+        &mut self,
+        actor: &str,
+        target: Option<AuditTarget>,
+        evaluation: &PolicyEvaluation,
+    ) -> AuditStatus {
+        let mut details = json!({ "decision": evaluation.decision });
+        if !matches!(evaluation.decision, GovernanceDecision::Allow { .. }) {
+            details["trace"] = json!(evaluation.trace);
+        }
+        if evaluation.from_default {
+            details["default_decision"] = json!(true);
+        }
+        self.record_policy(actor, target, details)
+    }
+
+    fn record_policy(&mut self, actor: &str, target: Option<AuditTarget>, details: serde_json::Value) -> AuditStatus {
+        self.record(PendingAudit {
+            event_type: AuditEventType::PolicyEvaluated,
+            actor: actor.to_string(),
+            target,
+            details,
+            occurred_at: self.kill_switch.now(),
+        })
+    }
+
+    /// Write buffered records in order; stops at the first failure.
+    ///
+    /// Returns how many were written.
+    pub fn flush_pending(&mut self) -> Result<usize, AuditError> {
+        let mut written = 0;
+        while let Some(pending) = self.pending.first().cloned() {
+            append(self.audit()?, &pending)?;
+            self.pending.remove(0);
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    fn record(&mut self, record: PendingAudit) -> AuditStatus {
+        // Earlier records go first so the log keeps action order.
+        let flushed = self.flush_pending();
+        let result = flushed
+            .and_then(|_| self.audit())
+            .and_then(|audit| append(audit, &record));
+
+        match result {
+            Ok(entry) => AuditStatus::Recorded(entry),
+            Err(e) => {
+                self.pending.push(record);
+                let warning = AuditWriteFailed {
+                    error: e.to_string(),
+                    pending: self.pending.len(),
+                };
+                eprintln!("⚠️  AUDIT WRITE FAILED: {}", warning);
+                AuditStatus::Deferred(warning)
+            }
+        }
+    }
+}
+
+/// Thread-safe handle to a [`Governance`] facade.
+///
+/// Each action holds one lock for its whole critical section. A panic in
+/// another holder does not disable the kill-switch: a poisoned lock is
+/// recovered rather than propagated.
+#[derive(Clone)]
+pub struct SharedGovernance {
+    inner: Arc<Mutex<Governance>>,
+}
+
+impl SharedGovernance {
+    pub fn new(governance: Governance) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(governance)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Governance> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn kill(
+        &self,
+        operator: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+    ) -> Result<Audited<KillEvent>, KillSwitchError> {
+        self.lock().kill(operator, reason, affected_adapters)
+    }
+
+    pub fn reset(&self, operators: &[String]) -> Result<Audited<ResetOutcome>, KillSwitchError> {
+        self.lock().reset(operators)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.lock().kill_switch().is_active()
+    }
+
+    /// Run `f` with exclusive access to the facade.
+    pub fn with<R>(&self, f: impl FnOnce(&mut Governance) -> R) -> R {
+        f(&mut self.lock())
+    }
+}
+
+fn append(audit: &mut AuditLog, record: &PendingAudit) -> Result<AuditEntry, AuditError> {
+    let mut details = record.details.clone();
+    if let Some(map) = details.as_object_mut() {
+        map.insert("occurred_at".to_string(), json!(record.occurred_at));
+    }
+    audit.append(
+        record.event_type.clone(),
+        &record.actor,
+        record.target.as_ref(),
+        details,
+    )
+}
@@ -0,0 +1,151 @@
+//! Proof of Inclusion
+//!
+//! Merkle checkpoints over audit entry hashes, so a lightweight client can
+//! check that a single entry belongs to the log without downloading it.
+
+use crate::audit::{AuditEntry, AuditError, AuditLog};
+use serde::{Deserialize, Serialize};
+use openlora_core::hashing::Hasher;
+
+/// Which side of the running hash a sibling sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// One step of the path from a leaf to the Merkle head.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofStep {
+    pub side: Side,
+    pub hash: String,
+}
+
+/// Compact proof that an entry is part of the log at `index`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub entry: AuditEntry,
+    pub index: usize,
+    pub leaf_count: usize,
+    pub path: Vec<ProofStep>,
+}
+
+fn leaf_hash(entry_hash: &str) -> String {
+    Hasher::new().update([0x00]).update(entry_hash).finish()
+}
+
+fn node_hash(left: &str, right: &str) -> String {
+    Hasher::new().update([0x01]).update(left).update(right).finish()
+}
+
+/// Merkle head over a sequence of entry hashes.
+///
+/// An odd node at the end of a level is promoted unchanged.
+pub fn merkle_head(entry_hashes: &[String]) -> Option<String> {
+    let mut level: Vec<String> = entry_hashes.iter().map(|h| leaf_hash(h)).collect();
+    if level.is_empty() {
+        return None;
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node_hash(left, right),
+                [single] => single.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    level.pop()
+}
+
+impl AuditLog {
+    /// Merkle head over every entry currently in the log.
+    pub fn merkle_head(&self) -> Result<Option<String>, AuditError> {
+        let hashes: Vec<String> = self.entries()?.into_iter().map(|e| e.hash).collect();
+        Ok(merkle_head(&hashes))
+    }
+
+    /// Produce a proof that the entry with `entry_id` is part of the log.
+    pub fn prove_inclusion(&self, entry_id: &str) -> Result<InclusionProof, AuditError> {
+        let entries = self.entries()?;
+        let index = entries
+            .iter()
+            .position(|e| e.id == entry_id)
+            .ok_or_else(|| AuditError::EntryNotFound(entry_id.to_string()))?;
+
+        let mut level: Vec<String> = entries.iter().map(|e| leaf_hash(&e.hash)).collect();
+        let mut position = index;
+        let mut path = Vec::new();
+
+        while level.len() > 1 {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                path.push(ProofStep {
+                    side: if sibling < position { Side::Left } else { Side::Right },
+                    hash: level[sibling].clone(),
+                });
+            }
+
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            position /= 2;
+        }
+
+        Ok(InclusionProof {
+            leaf_count: entries.len(),
+            entry: entries.into_iter().nth(index).expect("index in range"),
+            index,
+            path,
+        })
+    }
+}
+
+/// Verify an inclusion proof against a trusted Merkle head.
+///
+/// The entry's own hash is recomputed from its fields, so a proof cannot
+/// vouch for an entry whose contents were altered. The shape of the path
+/// follows from `index` and `leaf_count`: each step's side is derived
+/// from them, not taken from the proof, and a path with missing, extra
+/// or wrongly-sided steps is rejected.
+pub fn verify_inclusion(proof: &InclusionProof, head_hash: &str) -> bool {
+    let entry = &proof.entry;
+    let computed = entry.compute_hash();
+    if computed != entry.hash || proof.index >= proof.leaf_count {
+        return false;
+    }
+
+    let mut running = leaf_hash(&entry.hash);
+    let mut steps = proof.path.iter();
+    let mut position = proof.index;
+    let mut width = proof.leaf_count;
+    while width > 1 {
+        let sibling = position ^ 1;
+        // The last node of an odd level is promoted without a sibling.
+        if sibling < width {
+            let Some(step) = steps.next() else {
+                return false;
+            };
+            let side = if sibling < position { Side::Left } else { Side::Right };
+            if step.side != side {
+                return false;
+            }
+            running = match side {
+                Side::Left => node_hash(&step.hash, &running),
+                Side::Right => node_hash(&running, &step.hash),
+            };
+        }
+        position /= 2;
+        width = width.div_ceil(2);
+    }
+
+    steps.next().is_none() && running == head_hash
+}
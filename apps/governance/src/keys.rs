@@ -0,0 +1,132 @@
+//! Signer Key Management
+//!
+//! Ed25519 keypair generation for onboarding new signers, and the
+//! symmetric keys that encrypt audit logs at rest.
+
+use ed25519_dalek::pkcs8::spki::der::pem::LineEnding;
+use ed25519_dalek::pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey};
+use crate::encryption::{AuditKey, AUDIT_KEY_LEN};
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use rand_core::{OsRng, RngCore};
+use openlora_core::hashing::content_hash;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum KeyError {
+    #[error("IO error accessing {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Key encoding error: {0}")]
+    Encoding(String),
+    #[error("Signer id {0:?} cannot be used as a file name")]
+    InvalidSignerId(String),
+}
+
+/// Files written for a new signer.
+#[derive(Debug, Clone)]
+pub struct GeneratedKey {
+    pub private_key_path: PathBuf,
+    pub public_key_path: PathBuf,
+    pub public_key: VerifyingKey,
+    pub fingerprint: String,
+}
+
+/// SHA-256 fingerprint of a public key's raw bytes.
+pub fn fingerprint(key: &VerifyingKey) -> String {
+    format!("SHA256:{}", content_hash(key.as_bytes()))
+}
+
+/// Generate an Ed25519 keypair for `signer_id` in `out_dir`.
+///
+/// Writes `<signer_id>.key` (PKCS#8 PEM, mode 0600 on Unix) and
+/// `<signer_id>.pub` (SubjectPublicKeyInfo PEM). Existing files are
+/// never overwritten.
+pub fn generate_keypair(signer_id: &str, out_dir: &Path) -> Result<GeneratedKey, KeyError> {
+    let valid = !signer_id.is_empty()
+        && !signer_id.starts_with('.')
+        && signer_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '@'));
+    if !valid {
+        return Err(KeyError::InvalidSignerId(signer_id.to_string()));
+    }
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let verifying_key = signing_key.verifying_key();
+
+    let private_pem = signing_key
+        .to_pkcs8_pem(LineEnding::LF)
+        .map_err(|e| KeyError::Encoding(e.to_string()))?;
+    let public_pem = verifying_key
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|e| KeyError::Encoding(e.to_string()))?;
+
+    let private_key_path = out_dir.join(format!("{}.key", signer_id));
+    let public_key_path = out_dir.join(format!("{}.pub", signer_id));
+
+    write_new(&private_key_path, private_pem.as_bytes(), 0o600)?;
+    write_new(&public_key_path, public_pem.as_bytes(), 0o644)?;
+
+    Ok(GeneratedKey {
+        private_key_path,
+        public_key_path,
+        public_key: verifying_key,
+        fingerprint: fingerprint(&verifying_key),
+    })
+}
+
+/// Load an Ed25519 private key from a PKCS#8 PEM file written by [`generate_keypair`].
+pub fn load_signing_key(path: &Path) -> Result<SigningKey, KeyError> {
+    let pem = std::fs::read_to_string(path).map_err(|source| KeyError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    SigningKey::from_pkcs8_pem(&pem)
+        .map_err(|e| KeyError::Encoding(format!("{}: {}", path.display(), e)))
+}
+
+/// Generate a random audit encryption key at `path`.
+///
+/// The key is written hex encoded, mode 0600 on Unix; an existing file is
+/// never overwritten. Losing the key makes sealed entries unreadable.
+pub fn generate_audit_key(path: &Path) -> Result<AuditKey, KeyError> {
+    let mut bytes = [0u8; AUDIT_KEY_LEN];
+    OsRng.fill_bytes(&mut bytes);
+    write_new(path, format!("{}\n", hex::encode(bytes)).as_bytes(), 0o600)?;
+    Ok(AuditKey::from_bytes(bytes))
+}
+
+/// Load an audit encryption key written by [`generate_audit_key`].
+pub fn load_audit_key(path: &Path) -> Result<AuditKey, KeyError> {
+    let hex = std::fs::read_to_string(path).map_err(|source| KeyError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    AuditKey::from_hex(&hex).map_err(|e| KeyError::Encoding(format!("{}: {}", path.display(), e)))
+}
+
+fn write_new(path: &Path, contents: &[u8], mode: u32) -> Result<(), KeyError> {
+    let io_err = |source| KeyError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(mode);
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+
+    let mut file = options.open(path).map_err(io_err)?;
+    file.write_all(contents).map_err(io_err)?;
+    file.sync_all().map_err(io_err)
+}
@@ -2,24 +2,127 @@
 //!
 //! Hard kill-switch for adapter and training termination.
 //! INVARIANT: This can only be triggered by Rust, never by Python.
+//!
+//! Each `KillSwitch` owns its own active flag. The process-wide
+//! [`is_killed`] is derived from them: it is true exactly while at least
+//! one live instance is active. Instances only change the global through
+//! their own transitions, so resetting one instance never clears a kill
+//! held by another, and the two views cannot disagree.
+//!
+//! Activation drains in-flight operations (see [`crate::drain`]) before
+//! the event is recorded and subscribers are told.
+//!
+//! With the `deadman` feature, a switch can also be armed to activate by
+//! itself when heartbeats stop (see [`crate::deadman`]). A panic inside a
+//! [`CriticalSection`] activates it too (see [`crate::critical`]).
+//!
+//! Every time-dependent decision reads the switch's [`Clock`], so tests
+//! can drive session expiry and escalation windows deterministically.
 
-use chrono::{DateTime, Utc};
+use crate::clock::{Clock, SystemClock};
+use crate::critical::CriticalSection;
+#[cfg(feature = "deadman")]
+use crate::deadman::Deadman;
+use crate::drain::{OperationGate, OperationGuard, DEFAULT_DRAIN_GRACE};
+use crate::session::{OperatorSession, SessionRecord, Sessions};
+use chrono::{DateTime, Duration, Utc};
+use openlora_core::{validate_principal_id, IdError};
 use serde::{Deserialize, Serialize};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::borrow::Cow;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
 use thiserror::Error;
 
-/// Global kill-switch state.
-static KILL_SWITCH_ACTIVE: AtomicBool = AtomicBool::new(false);
+/// How many recent kill events are searched for a repeated idempotency key.
+pub const IDEMPOTENCY_WINDOW: usize = 1024;
+
+/// Number of live `KillSwitch` instances that are active.
+static ACTIVE_INSTANCES: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum KillReason {
-    ManualTrigger { operator: String },
+    ManualTrigger {
+        operator: String,
+        /// Why the operator pulled the switch.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
     AnomalyDetected { adapter_id: String, score: f64 },
     RewardHacking { adapter_id: String },
     UnauthorizedEscalation { actor: String },
     ProvenanceViolation { adapter_id: String },
     ExternalSignal { source: String, message: String },
+    /// Reason from a newer build, preserved verbatim.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl KillReason {
+    /// Short machine-readable name of the reason.
+    pub fn code(&self) -> String {
+        match self {
+            KillReason::ManualTrigger { .. } => "ManualTrigger".to_string(),
+            KillReason::AnomalyDetected { .. } => "AnomalyDetected".to_string(),
+            KillReason::RewardHacking { .. } => "RewardHacking".to_string(),
+            KillReason::UnauthorizedEscalation { .. } => "UnauthorizedEscalation".to_string(),
+            KillReason::ProvenanceViolation { .. } => "ProvenanceViolation".to_string(),
+            KillReason::ExternalSignal { .. } => "ExternalSignal".to_string(),
+            // Externally tagged like the known variants, when possible
+            KillReason::Other(value) => match value.as_object() {
+                Some(map) if map.len() == 1 => map.keys().next().cloned().unwrap_or_default(),
+                _ => "Other".to_string(),
+            },
+        }
+    }
+
+    /// Human-readable detail of the reason.
+    pub fn detail(&self) -> String {
+        match self {
+            KillReason::ManualTrigger { operator, reason: None } => format!("operator={}", operator),
+            KillReason::ManualTrigger { operator, reason: Some(reason) } => {
+                format!("operator={} reason={}", operator, reason)
+            }
+            KillReason::AnomalyDetected { adapter_id, score } => {
+                format!("adapter_id={} score={}", adapter_id, score)
+            }
+            KillReason::RewardHacking { adapter_id } | KillReason::ProvenanceViolation { adapter_id } => {
+                format!("adapter_id={}", adapter_id)
+            }
+            KillReason::UnauthorizedEscalation { actor } => format!("actor={}", actor),
+            KillReason::ExternalSignal { source, message } => format!("source={} message={}", source, message),
+            KillReason::Other(value) => value.to_string(),
+        }
+    }
+}
+
+/// The reason of a [`GovernanceDecision::Kill`](openlora_core::GovernanceDecision::Kill).
+impl From<openlora_core::KillReason> for KillReason {
+    fn from(reason: openlora_core::KillReason) -> Self {
+        use openlora_core::KillReason as Core;
+        match reason {
+            Core::ManualTrigger { operator } => KillReason::ManualTrigger { operator, reason: None },
+            Core::AnomalyDetected { adapter_id, score } => KillReason::AnomalyDetected {
+                adapter_id: adapter_id.into(),
+                score,
+            },
+            Core::RewardHacking { adapter_id } => KillReason::RewardHacking {
+                adapter_id: adapter_id.into(),
+            },
+            Core::UnauthorizedEscalation { actor } => KillReason::UnauthorizedEscalation { actor },
+            Core::ProvenanceViolation { adapter_id } => KillReason::ProvenanceViolation {
+                adapter_id: adapter_id.into(),
+            },
+            Core::ExternalSignal { source, message } => KillReason::ExternalSignal { source, message },
+            Core::Other(value) => KillReason::Other(value),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +132,172 @@ pub struct KillEvent {
     pub timestamp: DateTime<Utc>,
     pub triggered_by: String,
     pub affected_adapters: Vec<String>,
+    /// Caller-supplied key that makes retried activations idempotent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// Operators who co-signed the activation besides `triggered_by`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub co_signers: Vec<String>,
+    /// Affected adapters confirmed stopped, and when; see
+    /// [`KillSwitch::acknowledge_stopped`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub acknowledged: BTreeMap<String, DateTime<Utc>>,
+    /// Whether adapters that never acknowledged were already reported by
+    /// [`KillSwitch::take_overdue`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub overdue_reported: bool,
+    /// Recorded in shadow mode: the switch was not actually activated.
+    /// See [`KillSwitch::set_shadow_mode`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub shadow: bool,
+}
+
+impl KillEvent {
+    /// Affected adapters not yet confirmed stopped.
+    pub fn unacknowledged(&self) -> Vec<String> {
+        self.affected_adapters
+            .iter()
+            .filter(|adapter| !self.acknowledged.contains_key(*adapter))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Affected adapters still running well after an activation.
+#[derive(Debug, Clone)]
+pub struct OverdueStop {
+    pub kill_id: String,
+    pub activated_at: DateTime<Utc>,
+    pub unacknowledged: Vec<String>,
+}
+
+/// Result of an idempotent activation.
+#[derive(Debug, Clone)]
+pub enum Activation {
+    /// The kill-switch was activated by this call.
+    Activated(KillEvent),
+    /// The key was already processed; nothing changed.
+    Replayed(KillEvent),
+}
+
+impl Activation {
+    pub fn event(&self) -> &KillEvent {
+        match self {
+            Activation::Activated(event) | Activation::Replayed(event) => event,
+        }
+    }
+
+    pub fn into_event(self) -> KillEvent {
+        match self {
+            Activation::Activated(event) | Activation::Replayed(event) => event,
+        }
+    }
+
+    pub fn is_replayed(&self) -> bool {
+        matches!(self, Activation::Replayed(_))
+    }
+}
+
+/// How an admitted activation proceeds.
+enum Admission<'a> {
+    /// Return the event the idempotency key already produced.
+    Replay(&'a KillEvent),
+    /// Record a shadow event without raising the switch.
+    Shadow,
+    /// Raise the switch.
+    Fire,
+}
+
+/// Result of resetting the kill-switch.
+#[derive(Debug, Clone)]
+pub struct ResetOutcome {
+    pub reset_at: DateTime<Utc>,
+    /// When the switch was activated, from the most recent activation event.
+    pub activated_at: Option<DateTime<Utc>>,
+    /// How long the system was killed; `None` if the activation time is unknown.
+    pub downtime: Option<Duration>,
+}
+
+/// A change of kill-switch state, delivered to subscribers.
+#[derive(Debug, Clone)]
+pub enum KillSwitchChange {
+    Activated(KillEvent),
+    Reset(ResetOutcome),
+}
+
+/// Kill-switch state persisted between processes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KillState {
+    pub active: bool,
+    pub events: Vec<KillEvent>,
+    #[serde(default, skip_serializing_if = "Sessions::is_empty")]
+    pub sessions: Sessions,
+}
+
+impl KillState {
+    /// Load persisted state; a missing file means the switch was never used.
+    pub fn load(path: &Path) -> Result<Self, KillSwitchError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Persist state atomically: the temporary file is synced before it is
+    /// renamed into place, so a crash never leaves a truncated state behind.
+    pub fn save(&self, path: &Path) -> Result<(), KillSwitchError> {
+        let tmp = path.with_extension("tmp");
+        let mut file = File::create(&tmp)?;
+        file.write_all(serde_json::to_string_pretty(self)?.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)?;
+        sync_parent(path)?;
+        Ok(())
+    }
+
+    /// Poll the state persisted at `path` until its `active` flag differs
+    /// from `active`, checking every `poll`; `None` once `deadline` passes.
+    pub fn wait_for_change(
+        path: &Path,
+        active: bool,
+        poll: std::time::Duration,
+        deadline: Option<Instant>,
+    ) -> Result<Option<Self>, KillSwitchError> {
+        loop {
+            let state = Self::load(path)?;
+            if state.active != active {
+                return Ok(Some(state));
+            }
+            if deadline.is_some_and(|d| Instant::now() >= d) {
+                return Ok(None);
+            }
+            std::thread::sleep(poll);
+        }
+    }
+}
+
+/// Sync the directory holding `path` so a rename into it survives a crash.
+pub(crate) fn sync_parent(path: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+        File::open(dir)?.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+/// Escalation when the switch is toggled suspiciously often.
+///
+/// More than `max_activations` activations within `window` is itself
+/// treated as an anomaly: single-operator resets are refused until
+/// `reset_quorum` distinct operators agree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscalationPolicy {
+    pub max_activations: usize,
+    pub window: Duration,
+    pub reset_quorum: usize,
 }
 
 #[derive(Debug, Error)]
@@ -39,90 +308,922 @@ pub enum KillSwitchError {
     NotActive,
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
+    #[error("Kill-switch escalated: {activations} activations in the last {window_secs}s; reset requires {quorum} operators")]
+    Escalated {
+        activations: usize,
+        window_secs: i64,
+        quorum: usize,
+    },
+    #[error("Quorum not met: {required} distinct operators required, got {got}")]
+    QuorumNotMet { required: usize, got: usize },
+    #[error("Force reset requires the Admin role: {0}")]
+    NotAdmin(String),
+    #[error("Force reset requires a justification")]
+    MissingJustification,
+    #[error("Session {session} of {operator} expired at {expired_at}")]
+    SessionExpired {
+        session: String,
+        operator: String,
+        expired_at: DateTime<Utc>,
+    },
+    #[error("Unknown or ended session")]
+    UnknownSession,
+    #[error("Co-signer must be a different operator than {0}")]
+    CoSignerNotDistinct(String),
+    #[error("Adapter {0} is not affected by the current activation")]
+    NotAffected(String),
+    #[error(transparent)]
+    InvalidId(#[from] IdError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
 }
 
-pub struct KillSwitch {
+/// The parts of a switch needed to activate it, shared with the
+/// dead-man's-switch timer so it can fire without the owner.
+#[derive(Clone)]
+pub(crate) struct Trigger {
     active: Arc<AtomicBool>,
+    /// Held while flipping `active`, keeping [`ACTIVE_INSTANCES`] in step.
+    transition: Arc<Mutex<()>>,
+    operations: OperationGate,
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+    /// Activations not yet moved into the owner's event list.
+    fired: Arc<Mutex<Vec<KillEvent>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Trigger {
+    fn new(active: bool) -> Self {
+        let active = Arc::new(AtomicBool::new(active));
+        Self {
+            operations: OperationGate::new(Arc::clone(&active)),
+            active,
+            transition: Arc::new(Mutex::new(())),
+            subscribers: Arc::new(Mutex::new(Vec::new())),
+            fired: Arc::new(Mutex::new(Vec::new())),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    /// Set the flag and count this instance as active; false if already set.
+    fn raise(&self) -> bool {
+        let _transition = lock(&self.transition);
+        self.raise_locked()
+    }
+
+    #[cfg(feature = "deadman")]
+    /// [`Trigger::raise`], unless `armed` has been cleared by [`Trigger::disarm`].
+    pub(crate) fn raise_if_armed(&self, armed: &AtomicBool) -> bool {
+        let _transition = lock(&self.transition);
+        armed.load(Ordering::SeqCst) && self.raise_locked()
+    }
+
+    #[cfg(feature = "deadman")]
+    /// Clear `armed`; no [`Trigger::raise_if_armed`] with it succeeds afterwards.
+    pub(crate) fn disarm(&self, armed: &AtomicBool) {
+        let _transition = lock(&self.transition);
+        armed.store(false, Ordering::SeqCst);
+    }
+
+    fn raise_locked(&self) -> bool {
+        if self.active.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+        ACTIVE_INSTANCES.fetch_add(1, Ordering::SeqCst);
+        true
+    }
+
+    /// Clear the flag and stop counting this instance; false if not set.
+    fn lower(&self) -> bool {
+        let _transition = lock(&self.transition);
+        if !self.active.swap(false, Ordering::SeqCst) {
+            return false;
+        }
+        // Only this instance's share; other active instances keep the process killed
+        ACTIVE_INSTANCES.fetch_sub(1, Ordering::SeqCst);
+        true
+    }
+
+    /// Activate, drain and notify, queueing the event in `fired`.
+    ///
+    /// `None` if the switch was already active.
+    pub(crate) fn fire(
+        &self,
+        operator: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+        idempotency_key: Option<&str>,
+        co_signers: Vec<String>,
+        drain_grace: std::time::Duration,
+    ) -> Option<KillEvent> {
+        if !self.raise() {
+            return None;
+        }
+        Some(self.record(operator, reason, affected_adapters, idempotency_key, co_signers, drain_grace))
+    }
+
+    /// Drain, queue the event in `fired` and notify, once raised.
+    pub(crate) fn record(
+        &self,
+        operator: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+        idempotency_key: Option<&str>,
+        co_signers: Vec<String>,
+        drain_grace: std::time::Duration,
+    ) -> KillEvent {
+        let timestamp = self.clock.now();
+
+        let cancelled = self.operations.drain(drain_grace);
+        if cancelled > 0 {
+            eprintln!(
+                "⚠️  {} operation(s) still running after {:?}; cancelled",
+                cancelled, drain_grace
+            );
+        }
+
+        let event = KillEvent {
+            id: uuid::Uuid::new_v4().to_string(),
+            reason,
+            timestamp,
+            triggered_by: operator.to_string(),
+            affected_adapters,
+            idempotency_key: idempotency_key.map(str::to_string),
+            co_signers,
+            acknowledged: BTreeMap::new(),
+            overdue_reported: false,
+            shadow: false,
+        };
+
+        lock(&self.fired).push(event.clone());
+
+        eprintln!("🚨 KILL-SWITCH ACTIVATED by {} at {}", operator, event.timestamp);
+
+        self.notify(KillSwitchChange::Activated(event.clone()));
+        event
+    }
+
+    fn notify(&self, change: KillSwitchChange) {
+        // Drop subscribers whose receiver has gone away.
+        lock(&self.subscribers).retain_mut(|subscriber| subscriber.notify(&change));
+    }
+}
+
+/// Reason filter of a [`KillSwitch::subscribe_filtered`] subscription.
+pub type ReasonFilter = Box<dyn Fn(&KillReason) -> bool + Send>;
+
+struct Subscriber {
+    tx: Sender<KillSwitchChange>,
+    filter: Option<ReasonFilter>,
+    /// Whether the current activation was delivered, so its reset is too.
+    told_active: bool,
+}
+
+impl Subscriber {
+    /// Deliver `change` if it concerns this subscriber; false once disconnected.
+    fn notify(&mut self, change: &KillSwitchChange) -> bool {
+        let wanted = match (change, &self.filter) {
+            (_, None) => true,
+            (KillSwitchChange::Activated(event), Some(filter)) => {
+                self.told_active = filter(&event.reason);
+                self.told_active
+            }
+            (KillSwitchChange::Reset(_), Some(_)) => std::mem::take(&mut self.told_active),
+        };
+        !wanted || self.tx.send(change.clone()).is_ok()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub struct KillSwitch {
+    trigger: Trigger,
     events: Vec<KillEvent>,
     authorized_operators: Vec<String>,
+    /// Holders of the Admin role, who alone may force a reset.
+    admins: Vec<String>,
+    escalation: Option<EscalationPolicy>,
+    sessions: Sessions,
+    drain_grace: std::time::Duration,
+    /// Record would-be activations without enforcing them.
+    shadow: bool,
+    #[cfg(feature = "deadman")]
+    deadman: Option<Deadman>,
 }
 
 impl KillSwitch {
     pub fn new(authorized_operators: Vec<String>) -> Self {
+        Self::from_state(authorized_operators, KillState::default())
+    }
+
+    /// Like [`KillSwitch::new`], but refuses empty, padded or
+    /// control-character operator ids; see [`validate_principal_id`].
+    pub fn try_new(authorized_operators: Vec<String>) -> Result<Self, KillSwitchError> {
+        Self::try_from_state(authorized_operators, KillState::default())
+    }
+
+    /// Like [`KillSwitch::from_state`], but refuses degenerate operator ids.
+    pub fn try_from_state(authorized_operators: Vec<String>, state: KillState) -> Result<Self, KillSwitchError> {
+        for operator in &authorized_operators {
+            validate_principal_id("operator id", operator)?;
+        }
+        Ok(Self::from_state(authorized_operators, state))
+    }
+
+    /// Restore a kill-switch from persisted state.
+    pub fn from_state(authorized_operators: Vec<String>, state: KillState) -> Self {
+        if state.active {
+            ACTIVE_INSTANCES.fetch_add(1, Ordering::SeqCst);
+        }
         Self {
-            active: Arc::new(AtomicBool::new(false)),
-            events: Vec::new(),
+            trigger: Trigger::new(state.active),
+            events: state.events,
             authorized_operators,
+            admins: Vec::new(),
+            escalation: None,
+            sessions: state.sessions,
+            drain_grace: DEFAULT_DRAIN_GRACE,
+            shadow: false,
+            #[cfg(feature = "deadman")]
+            deadman: None,
+        }
+    }
+
+    /// Use `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.trigger.clock = Arc::new(clock);
+        self
+    }
+
+    /// The current time according to this switch's clock.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.trigger.clock.now()
+    }
+
+    /// Snapshot of the state to persist.
+    pub fn state(&self) -> KillState {
+        let mut events = self.events.clone();
+        events.extend(lock(&self.trigger.fired).iter().cloned());
+        KillState {
+            active: self.is_active(),
+            events,
+            sessions: self.sessions.clone(),
         }
     }
 
+    /// Move activations made by a background timer into `events`.
+    fn collect_fired(&mut self) {
+        self.events.append(&mut lock(&self.trigger.fired));
+    }
+
+    /// Grant the Admin role, replacing earlier grants.
+    pub fn set_admins(&mut self, admins: Vec<String>) {
+        self.admins = admins;
+    }
+
+    pub fn is_admin(&self, operator: &str) -> bool {
+        self.admins.iter().any(|admin| admin == operator)
+    }
+
+    /// Escalate when activations exceed the policy's rate; `None` disables it.
+    pub fn set_escalation_policy(&mut self, policy: Option<EscalationPolicy>) {
+        self.escalation = policy;
+    }
+
+    /// How long activation waits for in-flight operations before cancelling them.
+    pub fn set_drain_grace(&mut self, grace: std::time::Duration) {
+        self.drain_grace = grace;
+    }
+
+    /// In shadow mode, activations are authorized and recorded as events
+    /// marked `shadow`, but the switch is not raised: nothing is drained,
+    /// refused or notified. For trialling kill policies in production.
+    ///
+    /// Shadow events never count towards escalation, idempotent replay of
+    /// real activations, or the current activation.
+    pub fn set_shadow_mode(&mut self, shadow: bool) {
+        self.shadow = shadow;
+    }
+
+    pub fn is_shadow_mode(&self) -> bool {
+        self.shadow
+    }
+
+    /// Gate that subsystems use to register in-flight operations.
+    pub fn operations(&self) -> OperationGate {
+        self.trigger.operations.clone()
+    }
+
+    /// Start an operation; `None` while the kill-switch is active.
+    pub fn begin_operation(&self) -> Option<OperationGuard> {
+        self.trigger.operations.begin()
+    }
+
+    /// Guard `operation`: the switch activates if the guard is dropped
+    /// while its thread panics.
+    pub fn critical_section(&self, operation: &str) -> CriticalSection {
+        CriticalSection::new(self.trigger.clone(), operation, self.drain_grace)
+    }
+
+    /// Activate the switch unless [`KillSwitch::heartbeat`] is called at
+    /// least every `timeout`, replacing any earlier arming.
+    ///
+    /// The timer fires once; arm again after it has, or after a reset.
+    #[cfg(feature = "deadman")]
+    pub fn arm_deadman(&mut self, timeout: std::time::Duration) -> Result<(), KillSwitchError> {
+        self.deadman = None;
+        self.deadman = Some(Deadman::arm(self.trigger.clone(), timeout, self.drain_grace)?);
+        Ok(())
+    }
+
+    /// Stop the dead-man's switch timer, if armed.
+    #[cfg(feature = "deadman")]
+    pub fn disarm_deadman(&mut self) {
+        self.deadman = None;
+    }
+
+    /// Whether an armed dead-man's switch has yet to fire.
+    #[cfg(feature = "deadman")]
+    pub fn is_deadman_armed(&self) -> bool {
+        self.deadman.as_ref().is_some_and(Deadman::is_pending)
+    }
+
+    /// Record a heartbeat from the control plane; no-op unless armed.
+    #[cfg(feature = "deadman")]
+    pub fn heartbeat(&self) {
+        if let Some(ref deadman) = self.deadman {
+            deadman.beat();
+        }
+    }
+
+    /// Number of activations within `window` before now.
+    pub fn activation_rate(&self, window: Duration) -> usize {
+        let since = self.now() - window;
+        // Events are appended in activation order, and fired ones are later
+        let fired = lock(&self.trigger.fired);
+        self.events
+            .iter()
+            .chain(fired.iter())
+            .rev()
+            .take_while(|event| event.timestamp >= since)
+            .filter(|event| !event.shadow)
+            .count()
+    }
+
+    /// Whether the activation rate currently exceeds the escalation policy.
+    pub fn is_escalated(&self) -> bool {
+        self.escalation
+            .is_some_and(|policy| self.activation_rate(policy.window) > policy.max_activations)
+    }
+
+    /// Receive every subsequent activation and reset.
+    pub fn subscribe(&mut self) -> Receiver<KillSwitchChange> {
+        self.add_subscriber(None)
+    }
+
+    /// Receive subsequent activations whose reason matches `filter`, and
+    /// the resets that end them.
+    ///
+    /// Other activations, and resets of them, are not delivered.
+    pub fn subscribe_filtered<F>(&mut self, filter: F) -> Receiver<KillSwitchChange>
+    where
+        F: Fn(&KillReason) -> bool + Send + 'static,
+    {
+        self.add_subscriber(Some(Box::new(filter)))
+    }
+
+    fn add_subscriber(&mut self, filter: Option<ReasonFilter>) -> Receiver<KillSwitchChange> {
+        let (tx, rx) = channel();
+        lock(&self.trigger.subscribers).push(Subscriber {
+            tx,
+            filter,
+            told_active: false,
+        });
+        rx
+    }
+
     /// Activate the kill-switch.
     ///
     /// CRITICAL: This immediately terminates all adapter operations.
+    /// New operations are refused at once; running ones get the drain
+    /// grace period to finish before they are cancelled. A caller holding
+    /// an [`OperationGuard`] itself waits out the full grace period.
     pub fn activate(
         &mut self,
         operator: &str,
         reason: KillReason,
         affected_adapters: Vec<String>,
     ) -> Result<KillEvent, KillSwitchError> {
-        // Verify operator is authorized
-        if !self.authorized_operators.contains(&operator.to_string()) {
-            return Err(KillSwitchError::Unauthorized(operator.to_string()));
+        self.activate_idempotent(operator, reason, affected_adapters, None)
+            .map(Activation::into_event)
+    }
+
+    /// Activate the kill-switch at most once per `idempotency_key`.
+    ///
+    /// A key that already produced one of the last [`IDEMPOTENCY_WINDOW`]
+    /// events returns that event as `Replayed`, whatever the current
+    /// state. Keys persist with the events in [`KillState`].
+    pub fn activate_idempotent(
+        &mut self,
+        operator: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+        idempotency_key: Option<&str>,
+    ) -> Result<Activation, KillSwitchError> {
+        self.collect_fired();
+        match self.admit(operator, idempotency_key)? {
+            Admission::Replay(event) => return Ok(Activation::Replayed(event.clone())),
+            Admission::Shadow => {
+                return Ok(Activation::Activated(self.record_shadow(
+                    operator,
+                    reason,
+                    affected_adapters,
+                    idempotency_key,
+                    Vec::new(),
+                )))
+            }
+            Admission::Fire => {}
         }
 
-        // Set global kill state
-        if self.active.swap(true, Ordering::SeqCst) {
-            return Err(KillSwitchError::AlreadyActive);
+        let event = self
+            .trigger
+            .fire(operator, reason, affected_adapters, idempotency_key, Vec::new(), self.drain_grace)
+            .ok_or(KillSwitchError::AlreadyActive)?;
+        self.collect_fired();
+
+        Ok(Activation::Activated(event))
+    }
+
+    /// Activate on behalf of an automated safeguard, such as the tamper
+    /// monitor, recorded as triggered by `source`.
+    ///
+    /// Like the dead-man's switch, a safeguard acts on the system's own
+    /// evidence and needs no authorized operator.
+    pub(crate) fn activate_unattended(&mut self, source: &str, reason: KillReason) -> Result<KillEvent, KillSwitchError> {
+        self.collect_fired();
+        if self.shadow {
+            return Ok(self.record_shadow(source, reason, Vec::new(), None, Vec::new()));
+        }
+
+        let event = self
+            .trigger
+            .fire(source, reason, Vec::new(), None, Vec::new(), self.drain_grace)
+            .ok_or(KillSwitchError::AlreadyActive)?;
+        self.collect_fired();
+        Ok(event)
+    }
+
+    /// Activate on the authority of two distinct authorized operators.
+    ///
+    /// For the most severe manual kills; single-operator [`KillSwitch::activate`]
+    /// stays available. `operator` is recorded as `triggered_by` and
+    /// `co_signer` in `co_signers`.
+    pub fn activate_cosigned(
+        &mut self,
+        operator: &str,
+        co_signer: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+    ) -> Result<KillEvent, KillSwitchError> {
+        self.authorize(operator)?;
+        self.authorize(co_signer)?;
+        if operator == co_signer {
+            return Err(KillSwitchError::CoSignerNotDistinct(operator.to_string()));
         }
 
-        // Also set static flag for cross-module access
-        KILL_SWITCH_ACTIVE.store(true, Ordering::SeqCst);
+        self.collect_fired();
+        if self.shadow {
+            return Ok(self.record_shadow(operator, reason, affected_adapters, None, vec![co_signer.to_string()]));
+        }
+        let event = self
+            .trigger
+            .fire(
+                operator,
+                reason,
+                affected_adapters,
+                None,
+                vec![co_signer.to_string()],
+                self.drain_grace,
+            )
+            .ok_or(KillSwitchError::AlreadyActive)?;
+        self.collect_fired();
 
+        Ok(event)
+    }
+
+    /// Record the event an activation would have produced, without raising.
+    fn record_shadow(
+        &mut self,
+        operator: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+        idempotency_key: Option<&str>,
+        co_signers: Vec<String>,
+    ) -> KillEvent {
         let event = KillEvent {
             id: uuid::Uuid::new_v4().to_string(),
             reason,
-            timestamp: Utc::now(),
+            timestamp: self.now(),
             triggered_by: operator.to_string(),
             affected_adapters,
+            idempotency_key: idempotency_key.map(str::to_string),
+            co_signers,
+            acknowledged: BTreeMap::new(),
+            overdue_reported: false,
+            shadow: true,
         };
-
+        eprintln!("🧪 SHADOW KILL by {} at {} (not enforced)", operator, event.timestamp);
         self.events.push(event.clone());
+        event
+    }
 
-        eprintln!("🚨 KILL-SWITCH ACTIVATED by {} at {}", operator, event.timestamp);
+    /// Whether `operator` could activate the switch now, without doing so.
+    ///
+    /// Makes the checks [`KillSwitch::activate`] makes: false for operators
+    /// without authority, and while the switch is already active unless in
+    /// shadow mode.
+    pub fn can_activate(&self, operator: &str) -> bool {
+        self.admit(operator, None).is_ok()
+    }
 
-        Ok(event)
+    /// Decide how an activation by `operator` proceeds, or why it is refused.
+    fn admit(&self, operator: &str, idempotency_key: Option<&str>) -> Result<Admission<'_>, KillSwitchError> {
+        self.authorize(operator)?;
+        if let Some(event) = idempotency_key.and_then(|key| self.event_for_key(key)) {
+            return Ok(Admission::Replay(event));
+        }
+        if self.shadow {
+            return Ok(Admission::Shadow);
+        }
+        if self.is_active() {
+            return Err(KillSwitchError::AlreadyActive);
+        }
+        Ok(Admission::Fire)
     }
 
-    /// Reset the kill-switch (requires authorization).
-    pub fn reset(&mut self, operator: &str) -> Result<(), KillSwitchError> {
-        if !self.authorized_operators.contains(&operator.to_string()) {
+    /// Whether `operator` alone could reset the switch now, without doing so.
+    ///
+    /// Agrees with [`KillSwitch::reset`]: false for operators without
+    /// authority, while escalated past a single-operator reset, and while
+    /// the switch is not active.
+    pub fn can_reset(&self, operator: &str) -> bool {
+        self.authorize_reset(operator).is_ok() && self.is_active()
+    }
+
+    /// [`KillSwitch::can_activate`] for the holder of a session `token`.
+    pub fn can_activate_with_session(&self, token: &str) -> bool {
+        self.session_operator(token)
+            .is_ok_and(|operator| self.can_activate(&operator))
+    }
+
+    /// [`KillSwitch::can_reset`] for the holder of a session `token`.
+    pub fn can_reset_with_session(&self, token: &str) -> bool {
+        self.session_operator(token)
+            .is_ok_and(|operator| self.can_reset(&operator))
+    }
+
+    /// Refuse operators who are not authorized.
+    fn authorize(&self, operator: &str) -> Result<(), KillSwitchError> {
+        if !self.authorized_operators.iter().any(|op| op == operator) {
             return Err(KillSwitchError::Unauthorized(operator.to_string()));
         }
+        Ok(())
+    }
 
-        if !self.active.swap(false, Ordering::SeqCst) {
+    /// Refuse a single-operator reset that [`KillSwitch::reset`] would.
+    fn authorize_reset(&self, operator: &str) -> Result<(), KillSwitchError> {
+        self.authorize(operator)?;
+
+        if let Some(policy) = self.escalation {
+            if policy.reset_quorum > 1 && self.is_escalated() {
+                return Err(KillSwitchError::Escalated {
+                    activations: self.activation_rate(policy.window),
+                    window_secs: policy.window.num_seconds(),
+                    quorum: policy.reset_quorum,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Issue a session giving `operator` authority for `ttl`.
+    pub fn issue_session(&mut self, operator: &str, ttl: Duration) -> Result<OperatorSession, KillSwitchError> {
+        self.authorize(operator)?;
+        Ok(self.sessions.issue(operator, ttl, self.now()))
+    }
+
+    /// End a session before it expires.
+    pub fn end_session(&mut self, id: &str) -> Option<SessionRecord> {
+        self.sessions.end(id)
+    }
+
+    pub fn sessions(&self) -> &Sessions {
+        &self.sessions
+    }
+
+    /// The operator a live session `token` was issued to.
+    pub fn session_operator(&self, token: &str) -> Result<String, KillSwitchError> {
+        let record = self.sessions.find(token).ok_or(KillSwitchError::UnknownSession)?;
+        if record.is_expired_at(self.now()) {
+            return Err(KillSwitchError::SessionExpired {
+                session: record.id.clone(),
+                operator: record.operator.clone(),
+                expired_at: record.expires_at,
+            });
+        }
+        Ok(record.operator.clone())
+    }
+
+    /// Activate on the authority of a session `token`.
+    pub fn activate_with_session(
+        &mut self,
+        token: &str,
+        reason: KillReason,
+        affected_adapters: Vec<String>,
+    ) -> Result<KillEvent, KillSwitchError> {
+        let operator = self.session_operator(token)?;
+        self.activate(&operator, reason, affected_adapters)
+    }
+
+    /// Emergency reset by a single admin, bypassing quorum and escalation.
+    ///
+    /// Requires the Admin role and a justification, which callers must
+    /// record prominently; see `Governance::force_reset`.
+    pub fn force_reset(&mut self, admin: &str, justification: &str) -> Result<ResetOutcome, KillSwitchError> {
+        if !self.is_admin(admin) {
+            return Err(KillSwitchError::NotAdmin(admin.to_string()));
+        }
+        if justification.trim().is_empty() {
+            return Err(KillSwitchError::MissingJustification);
+        }
+
+        eprintln!("⚠️  FORCE RESET by admin {}, bypassing quorum: {}", admin, justification);
+        self.finish_reset(admin)
+    }
+
+    /// Reset on the authority of one or more session tokens.
+    pub fn reset_with_sessions(&mut self, tokens: &[String]) -> Result<ResetOutcome, KillSwitchError> {
+        let operators = tokens
+            .iter()
+            .map(|token| self.session_operator(token))
+            .collect::<Result<Vec<_>, _>>()?;
+        match operators.as_slice() {
+            [operator] => self.reset(operator),
+            _ => self.reset_with_quorum(&operators),
+        }
+    }
+
+    /// The recent event produced under `key`, if any.
+    ///
+    /// Only events of the current mode count: a key used in shadow mode
+    /// does not stop a real activation with the same key.
+    pub fn event_for_key(&self, key: &str) -> Option<&KillEvent> {
+        self.events
+            .iter()
+            .rev()
+            .take(IDEMPOTENCY_WINDOW)
+            .find(|event| event.idempotency_key.as_deref() == Some(key) && event.shadow == self.shadow)
+    }
+
+    /// Reset the kill-switch (requires authorization).
+    ///
+    /// Refused while escalated; use [`KillSwitch::reset_with_quorum`].
+    pub fn reset(&mut self, operator: &str) -> Result<ResetOutcome, KillSwitchError> {
+        self.authorize_reset(operator)?;
+        self.finish_reset(operator)
+    }
+
+    /// Reset with the agreement of several operators.
+    ///
+    /// Every operator must be authorized; while escalated, at least the
+    /// policy's `reset_quorum` distinct operators are required.
+    pub fn reset_with_quorum(&mut self, operators: &[String]) -> Result<ResetOutcome, KillSwitchError> {
+        if let Some(op) = operators.iter().find(|op| !self.authorized_operators.contains(op)) {
+            return Err(KillSwitchError::Unauthorized(op.clone()));
+        }
+
+        let mut distinct: Vec<&String> = operators.iter().collect();
+        distinct.sort();
+        distinct.dedup();
+
+        let required = match self.escalation {
+            Some(policy) if self.is_escalated() => policy.reset_quorum.max(1),
+            _ => 1,
+        };
+        if distinct.len() < required {
+            return Err(KillSwitchError::QuorumNotMet {
+                required,
+                got: distinct.len(),
+            });
+        }
+
+        let operator = distinct.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", ");
+        self.finish_reset(&operator)
+    }
+
+    fn finish_reset(&mut self, operator: &str) -> Result<ResetOutcome, KillSwitchError> {
+        self.collect_fired();
+        if !self.trigger.lower() {
             return Err(KillSwitchError::NotActive);
         }
 
-        KILL_SWITCH_ACTIVE.store(false, Ordering::SeqCst);
+        let reset_at = self.now();
+        let activated_at = self.last_enforced().map(|event| event.timestamp);
 
-        eprintln!("✅ Kill-switch reset by {} at {}", operator, Utc::now());
+        eprintln!("✅ Kill-switch reset by {} at {}", operator, reset_at);
 
-        Ok(())
+        let outcome = ResetOutcome {
+            reset_at,
+            activated_at,
+            downtime: activated_at.map(|at| reset_at - at),
+        };
+        self.trigger.notify(KillSwitchChange::Reset(outcome.clone()));
+
+        Ok(outcome)
+    }
+
+    /// Confirm that `adapter_id`, affected by the current activation, has
+    /// actually stopped.
+    ///
+    /// Subsystems call this once they have shut the adapter down. Returns
+    /// whether this was its first acknowledgement. Acknowledgements persist
+    /// with the event in [`KillState`].
+    pub fn acknowledge_stopped(&mut self, adapter_id: &str) -> Result<bool, KillSwitchError> {
+        self.collect_fired();
+        let now = self.now();
+        let event = self.current_event_mut()?;
+        if !event.affected_adapters.iter().any(|adapter| adapter == adapter_id) {
+            return Err(KillSwitchError::NotAffected(adapter_id.to_string()));
+        }
+        Ok(match event.acknowledged.entry(adapter_id.to_string()) {
+            Entry::Vacant(entry) => {
+                entry.insert(now);
+                true
+            }
+            Entry::Occupied(_) => false,
+        })
+    }
+
+    /// Adapters affected by the current activation that have not
+    /// acknowledged stopping; empty while the switch is not active.
+    pub fn unacknowledged(&self) -> Vec<String> {
+        if !self.is_active() {
+            return Vec::new();
+        }
+        let fired = lock(&self.trigger.fired);
+        fired
+            .last()
+            .or(self.last_enforced())
+            .map(KillEvent::unacknowledged)
+            .unwrap_or_default()
+    }
+
+    /// Stragglers of the current activation, once `timeout` has passed
+    /// since it without every affected adapter acknowledging.
+    ///
+    /// Reported at most once per activation, so it can be polled.
+    pub fn take_overdue(&mut self, timeout: Duration) -> Option<OverdueStop> {
+        self.collect_fired();
+        let now = self.now();
+        let event = self.current_event_mut().ok()?;
+        if event.overdue_reported || now - event.timestamp < timeout {
+            return None;
+        }
+        let unacknowledged = event.unacknowledged();
+        if unacknowledged.is_empty() {
+            return None;
+        }
+        event.overdue_reported = true;
+        Some(OverdueStop {
+            kill_id: event.id.clone(),
+            activated_at: event.timestamp,
+            unacknowledged,
+        })
+    }
+
+    fn current_event_mut(&mut self) -> Result<&mut KillEvent, KillSwitchError> {
+        if !self.is_active() {
+            return Err(KillSwitchError::NotActive);
+        }
+        self.events
+            .iter_mut()
+            .rev()
+            .find(|event| !event.shadow)
+            .ok_or(KillSwitchError::NotActive)
+    }
+
+    /// The activation in effect; `None` while the switch is not active.
+    ///
+    /// Like [`KillSwitch::get_events`], misses a dead-man's switch
+    /// activation until the switch is next activated or reset.
+    pub fn current_event(&self) -> Option<&KillEvent> {
+        self.is_active().then(|| self.last_enforced()).flatten()
+    }
+
+    /// The latest event that actually activated the switch.
+    fn last_enforced(&self) -> Option<&KillEvent> {
+        self.events.iter().rev().find(|event| !event.shadow)
     }
 
     /// Check if kill-switch is active.
     pub fn is_active(&self) -> bool {
-        self.active.load(Ordering::SeqCst)
+        self.trigger.is_active()
     }
 
     /// Get all kill events.
+    ///
+    /// An activation by the dead-man's switch is listed once the switch is
+    /// next activated or reset; [`KillSwitch::state`] includes it at once.
     pub fn get_events(&self) -> &[KillEvent] {
         &self.events
     }
+
+    /// Write kill events as CSV for spreadsheet review.
+    pub fn export_csv(&self, path: &Path) -> Result<(), KillSwitchError> {
+        let mut file = BufWriter::new(File::create(path)?);
+        write_events_csv(&self.events, &mut file)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Write kill events as a JSON array.
+    pub fn export_json(&self, path: &Path) -> Result<(), KillSwitchError> {
+        fs::write(path, serde_json::to_string_pretty(&self.events)?)?;
+        Ok(())
+    }
+}
+
+impl Drop for KillSwitch {
+    /// An instance that goes away stops counting towards [`is_killed`];
+    /// persist its [`KillState`] first to keep the kill across restarts.
+    fn drop(&mut self) {
+        // Stop the timer first so it cannot fire for a switch that is gone.
+        #[cfg(feature = "deadman")]
+        self.deadman.take();
+        let _transition = lock(&self.trigger.transition);
+        if self.trigger.is_active() {
+            ACTIVE_INSTANCES.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Columns written by [`write_events_csv`].
+pub const CSV_HEADER: [&str; 7] = [
+    "id",
+    "timestamp",
+    "operator",
+    "reason_code",
+    "reason_detail",
+    "affected_adapters",
+    "shadow",
+];
+
+/// Write `events` as RFC 4180 CSV, one row per event.
+///
+/// Affected adapters are joined with `;` in a single column.
+pub fn write_events_csv<W: Write>(events: &[KillEvent], out: &mut W) -> std::io::Result<()> {
+    write_csv_row(out, &CSV_HEADER.map(String::from))?;
+    for event in events {
+        write_csv_row(
+            out,
+            &[
+                event.id.clone(),
+                event.timestamp.to_rfc3339(),
+                event.triggered_by.clone(),
+                event.reason.code(),
+                event.reason.detail(),
+                event.affected_adapters.join(";"),
+                event.shadow.to_string(),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+fn write_csv_row<W: Write>(out: &mut W, fields: &[String]) -> std::io::Result<()> {
+    let row: Vec<Cow<str>> = fields.iter().map(|f| csv_field(f)).collect();
+    write!(out, "{}\r\n", row.join(","))
+}
+
+/// Quote a field if it contains a delimiter, quote or line break.
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
 }
 
-/// Check if global kill-switch is active.
+/// Check if any kill-switch in this process is active.
 /// Can be called from anywhere to check system state.
 pub fn is_killed() -> bool {
-    KILL_SWITCH_ACTIVE.load(Ordering::SeqCst)
+    ACTIVE_INSTANCES.load(Ordering::SeqCst) > 0
 }
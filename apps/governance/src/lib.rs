@@ -6,11 +6,48 @@
 //!
 //! HARD RULE: Rust can KILL, Python cannot.
 
+pub mod attest;
 pub mod audit;
+pub mod build_info;
+pub mod clock;
+pub mod config;
+pub mod critical;
+pub mod decision;
+#[cfg(feature = "deadman")]
+pub mod deadman;
+pub mod details;
+pub mod diff;
+pub mod display;
+pub mod drain;
+pub mod encryption;
+pub mod error;
+pub mod follow;
+pub mod governance;
+pub mod inclusion;
+pub mod keys;
 pub mod killswitch;
+pub mod lineage;
+pub mod migrate;
+pub mod monitor;
+pub mod notify;
+pub mod permissions;
+pub mod policy;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+pub mod quarantine;
+pub mod rechain;
+pub mod registry;
+pub mod replay;
+pub mod reverify;
+pub mod revocation;
+pub mod session;
 pub mod signatures;
+pub mod source;
+pub mod trust;
 pub mod cli;
 
-pub use audit::AuditLog;
+pub use audit::{AuditLog, AuditStreams, RetryPolicy};
+pub use error::GovernanceError;
 pub use killswitch::KillSwitch;
+pub use registry::Registry;
 pub use signatures::SignatureVerifier;
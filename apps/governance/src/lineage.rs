@@ -0,0 +1,365 @@
+//! Provenance Lineage
+//!
+//! Render an adapter's provenance chain as a GraphViz DOT graph, compare
+//! it with another, and move it between organizations as a signed
+//! `.prov` bundle.
+//!
+//! A bundle names its exporter and their key fingerprint, so an importer
+//! can tell which of its own trusted keys should have signed it. The key
+//! the sender ships is never used for verification.
+
+use crate::keys::fingerprint;
+use crate::signatures::{sign_ed25519, verify_ed25519, ProvenanceEntry, Signature, SignatureError, SignatureVerifier};
+use ed25519_dalek::SigningKey;
+use openlora_core::canonical::to_canonical_cbor;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::Path;
+use thiserror::Error;
+
+/// Bundle format written by [`ProvenanceChain::export_signed`].
+pub const BUNDLE_FORMAT: u32 = 1;
+
+/// Full provenance chain for an adapter, as stored on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceChain {
+    pub entries: Vec<ProvenanceEntry>,
+}
+
+/// Who exported a bundle, as claimed by the sender.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleSigner {
+    pub id: String,
+    /// Hex of the Ed25519 public key, for out-of-band onboarding.
+    pub public_key: String,
+    /// [`fingerprint`] of `public_key`.
+    pub fingerprint: String,
+}
+
+/// A provenance chain signed for transfer to another organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceBundle {
+    pub format: u32,
+    pub chain: ProvenanceChain,
+    pub exporter: BundleSigner,
+    /// Exporter's signature over [`ProvenanceBundle::canonical_bytes`].
+    pub signature: Signature,
+}
+
+/// How a chain differs from a reference chain.
+///
+/// Entries are matched by version; a version recorded twice in one chain
+/// is matched in order against the other's.
+#[derive(Debug, Clone, Default)]
+pub struct ProvenanceDiff {
+    /// Lowest version at which the chains disagree, or `None` if identical.
+    pub first_divergence: Option<u32>,
+    /// Entries only the compared chain has.
+    pub added: Vec<ProvenanceEntry>,
+    /// Entries only the reference chain has.
+    pub removed: Vec<ProvenanceEntry>,
+    /// Versions both chains have but record differently.
+    pub changed: Vec<EntryChange>,
+}
+
+impl ProvenanceDiff {
+    pub fn is_identical(&self) -> bool {
+        self.first_divergence.is_none()
+    }
+}
+
+/// One version recorded differently by the two chains.
+#[derive(Debug, Clone)]
+pub struct EntryChange {
+    pub version: u32,
+    /// Fields that differ, in entry order, compared as stored: an entry
+    /// edited without recomputing its hash shows no `hash` change.
+    pub fields: Vec<FieldChange>,
+}
+
+impl EntryChange {
+    /// The change to `field`, if it differs.
+    pub fn field(&self, field: EntryField) -> Option<&FieldChange> {
+        self.fields.iter().find(|change| change.field == field)
+    }
+}
+
+/// A field of a [`ProvenanceEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryField {
+    AdapterId,
+    Operation,
+    Actor,
+    Timestamp,
+    Signature,
+    ParentHash,
+    ContentHash,
+    Hash,
+}
+
+/// A field's value in the reference chain and in the compared one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: EntryField,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum BundleError {
+    #[error("Unsupported provenance bundle format {0} (expected {BUNDLE_FORMAT})")]
+    UnsupportedFormat(u32),
+    #[error("Bundle exporter {0} is not a trusted signer with a registered key")]
+    UntrustedExporter(String),
+    #[error("Bundle exporter {signer} claims key {claimed}, but the trusted key is {trusted}")]
+    ExporterKeyMismatch {
+        signer: String,
+        claimed: String,
+        trusted: String,
+    },
+    #[error("Bundle is signed by {signed_by}, not its exporter {exporter}")]
+    SignerMismatch { exporter: String, signed_by: String },
+    #[error("Bundle signature is invalid")]
+    InvalidSignature,
+    #[error("Signature error: {0}")]
+    Signature(#[from] SignatureError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl ProvenanceBundle {
+    /// Canonical CBOR of the format, entry hashes and exporter identity;
+    /// this is the signed message.
+    ///
+    /// Entry hashes cover everything but the entries' own signatures,
+    /// which are checked against the importer's keys separately.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        signed_message(self.format, &self.chain, &self.exporter)
+    }
+
+    /// Read a bundle from a JSON file.
+    pub fn load(path: &Path) -> Result<Self, BundleError> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Write the bundle as pretty JSON.
+    pub fn save(&self, path: &Path) -> Result<(), BundleError> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+impl ProvenanceChain {
+    /// Load a chain from a JSON file.
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Sign the chain into a bundle exported by `signer_id`.
+    ///
+    /// The chain is packaged as is; verifying it is the importer's job.
+    pub fn export_signed(&self, signer_id: &str, key: &SigningKey) -> ProvenanceBundle {
+        let public_key = key.verifying_key();
+        let exporter = BundleSigner {
+            id: signer_id.to_string(),
+            public_key: hex::encode(public_key.as_bytes()),
+            fingerprint: fingerprint(&public_key),
+        };
+        let signature = sign_ed25519(&signed_message(BUNDLE_FORMAT, self, &exporter), signer_id, key, None);
+        ProvenanceBundle {
+            format: BUNDLE_FORMAT,
+            chain: self.clone(),
+            exporter,
+            signature,
+        }
+    }
+
+    /// Check `bundle` end to end and return its chain.
+    ///
+    /// The exporter must be one of `verifier`'s signers, with the key the
+    /// bundle claims; the bundle signature must be Ed25519, and is checked
+    /// with the importer's key directly. The chain must then pass [`SignatureVerifier::verify_provenance`],
+    /// so each entry is linked and signed by a signer trusted here.
+    pub fn import_verified(bundle: &ProvenanceBundle, verifier: &SignatureVerifier) -> Result<Self, BundleError> {
+        if bundle.format != BUNDLE_FORMAT {
+            return Err(BundleError::UnsupportedFormat(bundle.format));
+        }
+
+        let exporter = &bundle.exporter;
+        let key = verifier
+            .signer_key(&exporter.id)
+            .ok_or_else(|| BundleError::UntrustedExporter(exporter.id.clone()))?;
+        let trusted = fingerprint(key);
+        if trusted != exporter.fingerprint {
+            return Err(BundleError::ExporterKeyMismatch {
+                signer: exporter.id.clone(),
+                claimed: exporter.fingerprint.clone(),
+                trusted,
+            });
+        }
+        if bundle.signature.signer_id != exporter.id {
+            return Err(BundleError::SignerMismatch {
+                exporter: exporter.id.clone(),
+                signed_by: bundle.signature.signer_id.clone(),
+            });
+        }
+        // A bundle edited after signing no longer matches the signed hash.
+        match verify_ed25519(&bundle.canonical_bytes(), &bundle.signature, key) {
+            Ok(true) => {}
+            Ok(false) | Err(SignatureError::ContentMismatch { .. }) => return Err(BundleError::InvalidSignature),
+            Err(e) => return Err(e.into()),
+        }
+
+        verifier.verify_provenance(&bundle.chain.entries)?;
+        Ok(bundle.chain.clone())
+    }
+
+    /// Compare `other` against this chain, taken as the known-good one.
+    pub fn diff(&self, other: &ProvenanceChain) -> ProvenanceDiff {
+        let mut versions: BTreeMap<u32, (Vec<&ProvenanceEntry>, Vec<&ProvenanceEntry>)> = BTreeMap::new();
+        for entry in &self.entries {
+            versions.entry(entry.version).or_default().0.push(entry);
+        }
+        for entry in &other.entries {
+            versions.entry(entry.version).or_default().1.push(entry);
+        }
+
+        let mut diff = ProvenanceDiff::default();
+        for (version, (expected, actual)) in versions {
+            let mut diverged = expected.len() != actual.len();
+            for (expected, actual) in expected.iter().zip(&actual) {
+                let fields = changed_fields(expected, actual);
+                if !fields.is_empty() {
+                    diff.changed.push(EntryChange { version, fields });
+                    diverged = true;
+                }
+            }
+            let paired = expected.len().min(actual.len());
+            diff.removed.extend(expected[paired..].iter().map(|e| (*e).clone()));
+            diff.added.extend(actual[paired..].iter().map(|e| (*e).clone()));
+            if diverged && diff.first_divergence.is_none() {
+                diff.first_divergence = Some(version);
+            }
+        }
+        diff
+    }
+
+    /// DOT graph with one node per entry and an edge from each parent.
+    ///
+    /// Nodes are labeled with version, operation, actor and timestamp.
+    /// Parents referenced by hash but absent from the chain are drawn as
+    /// dashed placeholders so a broken lineage stays visible.
+    pub fn to_dot(&self) -> String {
+        let nodes: HashMap<&str, usize> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (entry.hash.as_str(), i))
+            .collect();
+
+        let mut dot = String::from("digraph provenance {\n");
+        dot.push_str("    rankdir=TB;\n");
+        dot.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+        if let Some(first) = self.entries.first() {
+            let _ = writeln!(dot, "    label=\"{}\";", escape(&first.adapter_id));
+        }
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            let label = format!(
+                "v{} {}\\n{}\\n{}",
+                entry.version,
+                entry.operation,
+                escape(&entry.actor),
+                entry.timestamp.to_rfc3339()
+            );
+            let _ = writeln!(dot, "    n{} [label=\"{}\", tooltip=\"{}\"];", i, label, escape(&entry.hash));
+        }
+
+        let mut missing: Vec<&str> = Vec::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            for parent in entry.parents() {
+                match nodes.get(parent) {
+                    Some(p) => {
+                        let _ = writeln!(dot, "    n{} -> n{};", p, i);
+                    }
+                    None => {
+                        if !missing.contains(&parent) {
+                            let _ = writeln!(
+                                dot,
+                                "    \"missing:{0}\" [label=\"missing\\n{0}\", style=dashed];",
+                                escape(parent)
+                            );
+                            missing.push(parent);
+                        }
+                        let _ = writeln!(dot, "    \"missing:{}\" -> n{} [style=dashed];", escape(parent), i);
+                    }
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl ProvenanceEntry {
+    /// Hashes of the entries this one derives from.
+    ///
+    /// A single parent today; merges will fan in from several.
+    pub fn parents(&self) -> impl Iterator<Item = &str> {
+        self.parent_hash.as_deref().into_iter()
+    }
+}
+
+fn signed_message(format: u32, chain: &ProvenanceChain, exporter: &BundleSigner) -> Vec<u8> {
+    #[derive(Serialize)]
+    struct Signed<'a> {
+        format: u32,
+        entries: Vec<&'a str>,
+        exporter: &'a str,
+        fingerprint: &'a str,
+    }
+
+    to_canonical_cbor(&Signed {
+        format,
+        entries: chain.entries.iter().map(|e| e.hash.as_str()).collect(),
+        exporter: &exporter.id,
+        fingerprint: &exporter.fingerprint,
+    })
+    .expect("string and integer fields always encode")
+}
+
+/// Fields of `actual` that differ from `expected`.
+fn changed_fields(expected: &ProvenanceEntry, actual: &ProvenanceEntry) -> Vec<FieldChange> {
+    let signature = |entry: &ProvenanceEntry| {
+        entry
+            .signature
+            .as_ref()
+            .map(|s| format!("{} by {}", s.value, s.signer_id))
+    };
+    let timestamp = |entry: &ProvenanceEntry| Some(entry.timestamp.to_rfc3339());
+    let fields = [
+        (EntryField::AdapterId, Some(expected.adapter_id.clone()), Some(actual.adapter_id.clone())),
+        (EntryField::Operation, Some(expected.operation.to_string()), Some(actual.operation.to_string())),
+        (EntryField::Actor, Some(expected.actor.clone()), Some(actual.actor.clone())),
+        (EntryField::Timestamp, timestamp(expected), timestamp(actual)),
+        (EntryField::Signature, signature(expected), signature(actual)),
+        (EntryField::ParentHash, expected.parent_hash.clone(), actual.parent_hash.clone()),
+        (EntryField::ContentHash, expected.content_hash.clone(), actual.content_hash.clone()),
+        (EntryField::Hash, Some(expected.hash.clone()), Some(actual.hash.clone())),
+    ];
+    fields
+        .into_iter()
+        .filter(|(_, expected, actual)| expected != actual)
+        .map(|(field, expected, actual)| FieldChange { field, expected, actual })
+        .collect()
+}
+
+/// Escape a value for a double-quoted DOT string.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
@@ -1,44 +1,675 @@
 //! OpenLoRA Governance CLI Entry Point
 
-use clap::Parser;
-use openlora_governance::{cli::{Cli, Commands}, killswitch::{KillSwitch, KillReason, is_killed}, AuditLog};
+use clap::{CommandFactory, Parser};
+use openlora_governance::{attest, audit, build_info, governance::{AuditStatus, Governance}, cli::{self, Cli, Commands, ExportFormat, Unconfirmed}, config::{self, GovernanceConfig}, diff, display::{self, DisplayZone}, encryption::AuditKey, inclusion, keys, killswitch::{self, KillReason, KillState, KillSwitch, KillSwitchError}, follow::AuditFollower, lineage::{ProvenanceBundle, ProvenanceChain}, migrate, monitor::{TamperMonitor, TamperResponse}, permissions, quarantine::{self, QuarantineList}, rechain, registry::Registry, replay, revocation::{RevocationList, Revoked}, signatures::{self, Signature, SignatureVerifier, SignerReport, VerificationOutcome}, source::ContentSource, trust::{self, TrustRegistry, TrustSource}, AuditLog};
+#[cfg(feature = "protobuf")]
+use openlora_governance::protobuf;
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
 use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Kill-switch for the configured operators.
+///
+/// Without any configured operators, the invoking operators are authorized
+/// (the behaviour before governance.toml existed).
+fn kill_switch(config: &GovernanceConfig, operators: &[String], state: KillState) -> KillSwitch {
+    let operators = if config.authorized_operators.is_empty() {
+        operators.to_vec()
+    } else {
+        config.authorized_operators.clone()
+    };
+    let mut ks = KillSwitch::try_from_state(operators, state).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    ks.set_admins(config.admins.clone());
+    ks.set_escalation_policy(config.escalation_policy());
+    ks
+}
+
+/// Reject operators outside the configured list (any operator if none configured).
+fn require_authorized(config: &GovernanceConfig, operator: &str) {
+    if !config.authorized_operators.is_empty()
+        && !config.authorized_operators.iter().any(|o| o == operator)
+    {
+        eprintln!("Error: Unauthorized: {}", operator);
+        std::process::exit(1);
+    }
+}
+
+/// Print records the audit log could not take, so they can be replayed.
+fn report_unrecorded(gov: &Governance) {
+    let pending = gov.pending_audit();
+    if pending.is_empty() {
+        return;
+    }
+    eprintln!("⚠️  {} audit record(s) NOT written; replay them once the log is writable:", pending.len());
+    for record in pending {
+        eprintln!("{}", serde_json::to_string(record).unwrap());
+    }
+}
+
+/// Open the audit log at `path`, encrypting with `key` if configured.
+fn open_audit(path: &str, key: Option<&AuditKey>) -> Result<AuditLog, audit::AuditError> {
+    let mut log = AuditLog::open(PathBuf::from(path))?;
+    if let Some(key) = key {
+        log.set_key(key.clone())?;
+    }
+    Ok(log)
+}
+
+/// [`Governance::open`] encrypting with `key` if configured.
+fn open_governance(ks: KillSwitch, audit_log: &str, key: Option<&AuditKey>) -> Governance {
+    let mut gov = Governance::open(ks, PathBuf::from(audit_log));
+    if let Some(key) = key {
+        gov.set_audit_key(key.clone());
+    }
+    gov
+}
+
+/// Report a broken chain and exit with failure.
+fn chain_broken(path: &str, error: &audit::AuditError) -> ! {
+    eprintln!("🚨 AUDIT CHAIN BROKEN in {}: {}", path, error);
+    std::process::exit(1);
+}
+
+fn open_or_exit<T, E: std::fmt::Display>(what: &str, opened: Result<T, E>) -> T {
+    opened.unwrap_or_else(|e| {
+        eprintln!("Error opening {}: {}", what, e);
+        std::process::exit(2);
+    })
+}
+
+/// `value` followed by its short fingerprint.
+fn with_fingerprint(value: &str) -> String {
+    format!("{} ({})", value, display::fingerprint(value.as_bytes()))
+}
+
+/// Verifier trusting, by precedence, the `--trusted-signer` flags' signers,
+/// those in OPENLORA_TRUSTED_SIGNERS, or the config file's.
+fn trusted_verifier(config: &GovernanceConfig, signers_flagged: bool) -> SignatureVerifier {
+    if !signers_flagged {
+        if let Some(verifier) = open_or_exit("trusted signers", SignatureVerifier::from_env()) {
+            return config.apply_verification_policy(verifier);
+        }
+    }
+    open_or_exit("trusted signer keys", config.signature_verifier())
+}
+
+/// Warn about files any user could tamper with, or refuse when `strict`.
+fn check_permissions(paths: &[&Path], strict: bool) {
+    for path in paths {
+        for issue in open_or_exit(&path.display().to_string(), permissions::check_permissions(path)) {
+            if strict {
+                eprintln!("❌ Refusing: {}", issue);
+                std::process::exit(1);
+            }
+            eprintln!("⚠️  {}", issue);
+        }
+    }
+}
+
+fn read_signature(path: &str) -> Signature {
+    match std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|raw| serde_json::from_str(&raw).map_err(|e| e.to_string()))
+    {
+        Ok(signature) => signature,
+        Err(e) => {
+            eprintln!("Error reading signature {}: {}", path, e);
+            std::process::exit(2);
+        }
+    }
+}
+
+fn load_state(path: &Path) -> KillState {
+    match KillState::load(path) {
+        Ok(state) => state,
+        Err(e) => {
+            eprintln!("Error reading kill-switch state {}: {}", path.display(), e);
+            std::process::exit(2);
+        }
+    }
+}
+
+fn save_state(path: &Path, ks: &KillSwitch) {
+    if let Err(e) = ks.state().save(path) {
+        eprintln!("Error saving kill-switch state {}: {}", path.display(), e);
+        std::process::exit(2);
+    }
+}
+
+/// Print one audit entry for a human reader.
+fn print_entry(entry: &audit::AuditEntry, zone: &DisplayZone) {
+    let target = match (&entry.target_type, &entry.target_id) {
+        (Some(kind), Some(id)) => format!(" {}:{}", kind, id),
+        (Some(kind), None) => format!(" {}", kind),
+        _ => String::new(),
+    };
+    println!("{} {} by {}{}", zone.format(&entry.timestamp), entry.event_type.tag(), entry.actor, target);
+    if entry.details.as_object().is_some_and(|details| !details.is_empty()) {
+        let details = serde_json::to_string_pretty(&entry.details).unwrap_or_default();
+        for line in details.lines() {
+            println!("    {}", line);
+        }
+    }
+}
+
+/// Verifier trusting the signers served by the trust registry at `url`.
+///
+/// Reports whether they came from the registry or the local cache.
+fn remote_verifier(
+    config: &GovernanceConfig,
+    url: &str,
+    key: Option<&str>,
+    cache: &str,
+    ttl_secs: u64,
+    zone: &DisplayZone,
+) -> SignatureVerifier {
+    let key = key.expect("clap requires --trust-key with --trust-url");
+    let key = open_or_exit("trust registry key", signatures::load_public_key(Path::new(key)));
+    let ttl = chrono::Duration::seconds(ttl_secs.try_into().unwrap_or(i64::MAX));
+    let registry = TrustRegistry::new(url, key, PathBuf::from(cache), ttl);
+    let fetcher = open_or_exit("trust registry", trust::http_fetcher());
+    let (set, source) = open_or_exit("trust registry", registry.resolve(fetcher.as_ref()));
+    match source {
+        TrustSource::Remote => println!("🌐 Trusted signers from {} (issued {})", url, zone.format(&set.issued_at)),
+        TrustSource::Cached { fetched_at } => {
+            println!("🗄️  Trusted signers from cache {} (fetched {})", cache, zone.format(&fetched_at))
+        }
+        TrustSource::Fallback { fetched_at, error } => {
+            println!("⚠️  Trust registry unreachable ({}); using cache {} fetched {}", error, cache, zone.format(&fetched_at))
+        }
+    }
+
+    let mut verifier = open_or_exit("trust set", set.signature_verifier());
+    for (operation, role) in &config.provenance_roles {
+        verifier.require_role(*operation, role);
+    }
+    verifier
+}
+
+/// Operator a session token was issued to; exits if the token is unknown.
+fn session_holder(state: &KillState, token: &str) -> String {
+    match state.sessions.find(token) {
+        Some(record) => record.operator.clone(),
+        None => {
+            eprintln!("Error: {}", KillSwitchError::UnknownSession);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Ask the operator to type their id before a destructive action.
+///
+/// Refuses outright when stdin is not a terminal, so scripts must pass `--yes`.
+fn confirm(operator: &str, action: &str) -> bool {
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        eprint!("⚠️  About to {}. Type the operator id ({}) to confirm: ", action, operator);
+        let _ = io::stderr().flush();
+    }
+
+    match cli::confirm_operator(interactive, io::stdin().lock(), operator) {
+        Ok(()) => true,
+        Err(Unconfirmed::NotInteractive) => {
+            eprintln!("Refusing to {} without confirmation: stdin is not a TTY (pass --yes)", action);
+            false
+        }
+        Err(Unconfirmed::Mismatch) => {
+            eprintln!("Confirmation failed; aborting");
+            false
+        }
+    }
+}
 
 fn main() {
     let cli = Cli::parse();
 
+    // Needs neither config nor state, so it works before either exists
+    if let Commands::Completions { shell } = cli.command {
+        clap_complete::generate(shell, &mut Cli::command(), "openlora-gov", &mut io::stdout());
+        return;
+    }
+
+    let signers_flagged = !cli.trusted_signers.is_empty();
+    let config = match GovernanceConfig::load_or_default(cli.config.as_deref().map(Path::new))
+        .and_then(|c| c.with_overrides(cli.authorized_operators, cli.trusted_signers))
+    {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        }
+    };
+
+    let state_path = PathBuf::from(&cli.state);
+    let audit_key = open_or_exit("audit key", config.audit_key());
+    let zone = open_or_exit("time zone", DisplayZone::resolve(cli.timezone.as_deref()));
+
     match cli.command {
-        Commands::Kill { operator, reason, adapters } => {
-            let mut ks = KillSwitch::new(vec![operator.clone()]);
-            let reason = KillReason::ManualTrigger { operator: reason };
-            
-            match ks.activate(&operator, reason, adapters) {
-                Ok(event) => {
+        Commands::Kill { operator, session, co_signer, reason, adapters, audit_log, registry, idempotency_key, shadow, yes } => {
+            let state = load_state(&state_path);
+            let operator = match (operator, &session) {
+                (Some(operator), _) => operator,
+                (None, Some(token)) => session_holder(&state, token),
+                (None, None) => unreachable!("clap requires --operator or --session"),
+            };
+            if !yes && !confirm(&operator, "activate the kill-switch") {
+                std::process::exit(1);
+            }
+            if let Some(ref co_signer) = co_signer {
+                if !yes && !confirm(co_signer, "co-sign the kill-switch activation") {
+                    std::process::exit(1);
+                }
+            }
+            let signers: Vec<String> = std::iter::once(operator.clone()).chain(co_signer.clone()).collect();
+            let mut ks = kill_switch(&config, &signers, state);
+            ks.set_shadow_mode(shadow);
+            let mut gov = open_governance(ks, &audit_log, audit_key.as_ref());
+            gov.set_adapter_labels(open_or_exit("registry", Registry::load(Path::new(&registry))).labels());
+            match config.notifier() {
+                Ok(notifier) => gov.set_notifier(notifier),
+                Err(e) => eprintln!("⚠️  Notifications disabled: {}", e),
+            }
+            let reason = KillReason::ManualTrigger {
+                operator: operator.clone(),
+                reason: Some(reason),
+            };
+
+            let killed = match (session, co_signer) {
+                (Some(ref token), _) => gov.kill_with_session(token, reason, adapters, idempotency_key.as_deref()),
+                (None, Some(ref co_signer)) => gov.kill_cosigned(&operator, co_signer, reason, adapters),
+                (None, None) => gov.kill_idempotent(&operator, reason, adapters, idempotency_key.as_deref()),
+            };
+            match killed {
+                Ok(killed) if matches!(killed.audit, AuditStatus::Replayed) => {
+                    println!("🚨 Kill already processed for this idempotency key");
+                    println!("   Event ID: {}", with_fingerprint(&killed.outcome.id));
+                    println!("   Time: {}", zone.format(&killed.outcome.timestamp));
+                }
+                Ok(killed) if killed.outcome.shadow => {
+                    save_state(&state_path, gov.kill_switch());
+                    println!("🧪 Shadow kill recorded; the kill-switch was NOT activated");
+                    println!("   Event ID: {}", with_fingerprint(&killed.outcome.id));
+                    println!("   Time: {}", zone.format(&killed.outcome.timestamp));
+                    report_unrecorded(&gov);
+                }
+                Ok(killed) => {
+                    save_state(&state_path, gov.kill_switch());
                     println!("🚨 Kill-switch activated!");
-                    println!("   Event ID: {}", event.id);
-                    println!("   Time: {}", event.timestamp);
+                    println!("   Event ID: {}", with_fingerprint(&killed.outcome.id));
+                    println!("   Time: {}", zone.format(&killed.outcome.timestamp));
+                    if !killed.outcome.co_signers.is_empty() {
+                        println!("   Co-signed by: {}", killed.outcome.co_signers.join(", "));
+                    }
+                    report_unrecorded(&gov);
+                    let webhooks = config.notifications.webhooks.len();
+                    if webhooks > 0 {
+                        let delivered = gov.wait_for_notifications();
+                        println!("   Notified: {} of {} webhook(s)", delivered, webhooks);
+                    }
                 }
                 Err(e) => eprintln!("Error: {}", e),
             }
         }
-        Commands::Reset { operator } => {
-            let mut ks = KillSwitch::new(vec![operator.clone()]);
-            match ks.reset(&operator) {
-                Ok(()) => println!("✅ Kill-switch reset"),
+        Commands::Reset { operator, approvers, sessions, audit_log, yes } => {
+            let state = load_state(&state_path);
+            let operators = match operator {
+                Some(operator) => config.reset_operators(operator, approvers).unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }),
+                None => sessions.iter().map(|token| session_holder(&state, token)).collect(),
+            };
+            if !yes && !confirm(&operators[0], "reset the kill-switch") {
+                std::process::exit(1);
+            }
+            let ks = kill_switch(&config, &operators, state);
+            let mut gov = open_governance(ks, &audit_log, audit_key.as_ref());
+            let reset = if sessions.is_empty() {
+                gov.reset(&operators)
+            } else {
+                gov.reset_with_sessions(&sessions)
+            };
+            match reset {
+                Ok(reset) => {
+                    save_state(&state_path, gov.kill_switch());
+                    println!("✅ Kill-switch reset");
+                    match reset.outcome.downtime {
+                        Some(downtime) => println!("   Downtime: {}s", downtime.num_seconds()),
+                        None => println!("   Downtime: unknown (activation not recorded)"),
+                    }
+                    report_unrecorded(&gov);
+                }
                 Err(e) => eprintln!("Error: {}", e),
             }
         }
+        Commands::ForceReset { admin, justification, audit_log, yes } => {
+            if !yes && !confirm(&admin, "force a reset of the kill-switch, bypassing quorum") {
+                std::process::exit(1);
+            }
+            let ks = kill_switch(&config, &[], load_state(&state_path));
+            let mut gov = open_governance(ks, &audit_log, audit_key.as_ref());
+            match gov.force_reset(&admin, &justification) {
+                Ok(reset) => {
+                    save_state(&state_path, gov.kill_switch());
+                    println!("⚠️  Kill-switch FORCE reset by admin {}", admin);
+                    println!("   Justification: {}", justification);
+                    if let Some(downtime) = reset.outcome.downtime {
+                        println!("   Downtime: {}s", downtime.num_seconds());
+                    }
+                    report_unrecorded(&gov);
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::IssueSession { operator, ttl_secs, audit_log } => {
+            require_authorized(&config, &operator);
+            let ks = kill_switch(&config, std::slice::from_ref(&operator), load_state(&state_path));
+            let mut gov = open_governance(ks, &audit_log, audit_key.as_ref());
+            let ttl = chrono::Duration::seconds(ttl_secs.min(i64::MAX as u64) as i64);
+            match gov.issue_session(&operator, ttl) {
+                Ok(issued) => {
+                    save_state(&state_path, gov.kill_switch());
+                    println!("🔑 Session issued to {}", operator);
+                    println!("   Session ID: {}", issued.outcome.id);
+                    println!("   Token: {}", issued.outcome.token);
+                    println!("   Expires: {}", zone.format(&issued.outcome.expires_at));
+                    report_unrecorded(&gov);
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::EndSession { id, operator, audit_log } => {
+            require_authorized(&config, &operator);
+            let ks = kill_switch(&config, std::slice::from_ref(&operator), load_state(&state_path));
+            let mut gov = open_governance(ks, &audit_log, audit_key.as_ref());
+            match gov.end_session(&id, &operator) {
+                Some(ended) => {
+                    save_state(&state_path, gov.kill_switch());
+                    println!("✅ Session {} of {} ended", ended.outcome.id, ended.outcome.operator);
+                    report_unrecorded(&gov);
+                }
+                None => {
+                    eprintln!("Error: no session {}", id);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Info => {
+            let features = build_info::enabled_features();
+            println!("openlora-gov {} ({})", build_info::VERSION, build_info::GIT_HASH);
+            println!(
+                "   Features: {}",
+                if features.is_empty() { "none".to_string() } else { features.join(", ") }
+            );
+            println!("   Signature algorithms: {}", build_info::signature_algorithms().join(", "));
+            println!(
+                "   Audit hash: {} (hash version {})",
+                build_info::AUDIT_HASH_ALGORITHM,
+                build_info::AUDIT_HASH_VERSION
+            );
+        }
+        Commands::Completions { .. } => unreachable!("handled before loading config"),
         Commands::Status => {
-            if is_killed() {
+            if load_state(&state_path).active {
                 println!("🚨 Kill-switch is ACTIVE");
             } else {
                 println!("✅ Kill-switch is inactive");
             }
         }
-        Commands::VerifyAudit { path } => {
-            match AuditLog::open(PathBuf::from(&path)) {
+        Commands::WatchKillswitch { follow, timeout, poll_ms } => {
+            let poll = Duration::from_millis(poll_ms);
+            let mut active = load_state(&state_path).active;
+
+            if active && !follow {
+                println!("🚨 Kill-switch is ACTIVE");
+                return;
+            }
+
+            loop {
+                let deadline = timeout.map(|secs| Instant::now() + Duration::from_secs(secs));
+                let changed = KillState::wait_for_change(&state_path, active, poll, deadline).unwrap_or_else(|e| {
+                    eprintln!("Error reading kill-switch state {}: {}", state_path.display(), e);
+                    std::process::exit(2);
+                });
+                let Some(state) = changed else {
+                    eprintln!("No kill-switch change within {}s", timeout.unwrap_or_default());
+                    std::process::exit(3);
+                };
+                active = state.active;
+
+                if active {
+                    match state.events.last() {
+                        Some(event) => println!("🚨 Kill-switch ACTIVATED by {} at {}", event.triggered_by, zone.format(&event.timestamp)),
+                        None => println!("🚨 Kill-switch ACTIVATED"),
+                    }
+                    if !follow {
+                        return;
+                    }
+                } else {
+                    println!("✅ Kill-switch reset");
+                }
+            }
+        }
+        Commands::ExportKills { format, out } => {
+            let ks = KillSwitch::from_state(Vec::new(), load_state(&state_path));
+            let exported = match (&out, format) {
+                (Some(out), ExportFormat::Csv) => ks.export_csv(Path::new(out)),
+                (Some(out), ExportFormat::Json) => ks.export_json(Path::new(out)),
+                (None, ExportFormat::Csv) => {
+                    killswitch::write_events_csv(ks.get_events(), &mut io::stdout().lock()).map_err(Into::into)
+                }
+                (None, ExportFormat::Json) => serde_json::to_string_pretty(ks.get_events())
+                    .map(|json| println!("{}", json))
+                    .map_err(Into::into),
+            };
+            match exported {
+                Ok(()) => {
+                    if let Some(out) = out {
+                        println!("✅ Exported {} kill events to {}", ks.get_events().len(), out);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error exporting kill events: {}", e);
+                    std::process::exit(2);
+                }
+            }
+        }
+        Commands::Quarantine { adapter: None, from_file: Some(path), reason, operator, audit_log, list, registry } => {
+            require_authorized(&config, &operator);
+            let adapter_ids = open_or_exit(&path, quarantine::read_adapter_ids(Path::new(&path)));
+            let ks = kill_switch(&config, std::slice::from_ref(&operator), load_state(&state_path));
+            let mut gov = open_governance(ks, &audit_log, audit_key.as_ref());
+            gov.set_quarantine_limit(config.max_concurrent_quarantines);
+            gov.set_adapter_labels(open_or_exit("registry", Registry::load(Path::new(&registry))).labels());
+            let mut list = open_or_exit("quarantine list", QuarantineList::open(PathBuf::from(&list)));
+
+            let batch = gov.quarantine_batch(&mut list, &adapter_ids, &reason, &operator);
+            for adapter_id in &batch.quarantined {
+                println!("🔒 Quarantined {}", adapter_id);
+            }
+            for (adapter_id, e) in &batch.failed {
+                eprintln!("❌ {}: {}", adapter_id, e);
+            }
+            match &batch.escalation {
+                Some(Ok(killed)) => {
+                    save_state(&state_path, gov.kill_switch());
+                    println!("🚨 Kill-switch activated: {} adapters quarantined", list.len());
+                    println!("   Event ID: {}", with_fingerprint(&killed.outcome.id));
+                }
+                Some(Err(e)) => eprintln!("❌ Quarantine limit exceeded but kill failed: {}", e),
+                None => {}
+            }
+            report_unrecorded(&gov);
+            println!("{} quarantined, {} failed", batch.quarantined.len(), batch.failed.len());
+            if !batch.failed.is_empty() || matches!(batch.escalation, Some(Err(_))) {
+                std::process::exit(1);
+            }
+        }
+        Commands::Quarantine { adapter, reason, operator, audit_log, list, registry, .. } => {
+            let adapter = adapter.expect("clap requires --adapter or --from-file");
+            require_authorized(&config, &operator);
+            let ks = kill_switch(&config, std::slice::from_ref(&operator), load_state(&state_path));
+            let mut gov = open_governance(ks, &audit_log, audit_key.as_ref());
+            gov.set_quarantine_limit(config.max_concurrent_quarantines);
+            gov.set_adapter_labels(open_or_exit("registry", Registry::load(Path::new(&registry))).labels());
+            let mut list = open_or_exit("quarantine list", QuarantineList::open(PathBuf::from(&list)));
+            match gov.quarantine(&mut list, &adapter, &reason, &operator) {
+                Ok(quarantined) => {
+                    println!("🔒 Quarantined {} at {}", adapter, zone.format(&quarantined.record.quarantined_at));
+                    match quarantined.escalation {
+                        Some(Ok(killed)) => {
+                            save_state(&state_path, gov.kill_switch());
+                            println!("🚨 Kill-switch activated: {} adapters quarantined", quarantined.quarantined);
+                            println!("   Event ID: {}", with_fingerprint(&killed.outcome.id));
+                            report_unrecorded(&gov);
+                        }
+                        Some(Err(e)) => {
+                            eprintln!("❌ Quarantine limit exceeded but kill failed: {}", e);
+                            std::process::exit(1);
+                        }
+                        None => {}
+                    }
+                }
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        Commands::Release { adapter, operator, audit_log, list } => {
+            require_authorized(&config, &operator);
+            let mut audit = open_or_exit("audit log", open_audit(&audit_log, audit_key.as_ref()));
+            let mut list = open_or_exit("quarantine list", QuarantineList::open(PathBuf::from(&list)));
+            match list.release(&mut audit, &adapter, &operator) {
+                Ok(_) => println!("🔓 Released {}", adapter),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        Commands::Revoke { signature, content_hash, reason, operator, audit_log, list } => {
+            require_authorized(&config, &operator);
+            let revoked = match (signature, content_hash) {
+                (Some(path), _) => match Revoked::signature(&read_signature(&path)) {
+                    Ok(revoked) => revoked,
+                    Err(e) => {
+                        eprintln!("Error reading signature {}: {}", path, e);
+                        std::process::exit(2);
+                    }
+                },
+                (None, Some(hash)) => Revoked::Content(hash),
+                (None, None) => unreachable!("clap requires --signature or --content-hash"),
+            };
+            let mut audit = open_or_exit("audit log", open_audit(&audit_log, audit_key.as_ref()));
+            let mut list = open_or_exit("revocation list", RevocationList::open(PathBuf::from(&list)));
+            match list.revoke(&mut audit, revoked.clone(), &reason, &operator) {
+                Ok(record) => println!("⛔ Revoked {} at {}", revoked, zone.format(&record.revoked_at)),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::QuarantineList { list } => {
+            let list = open_or_exit("quarantine list", QuarantineList::open(PathBuf::from(&list)));
+            if list.is_empty() {
+                println!("No adapters quarantined");
+            }
+            for (adapter_id, record) in list.iter() {
+                println!(
+                    "🔒 {}  {}  by {}  — {}",
+                    adapter_id,
+                    zone.format(&record.quarantined_at),
+                    record.quarantined_by,
+                    record.reason
+                );
+            }
+        }
+        Commands::VerifyAudit { path, stream, since, until, typed_details, start_hash, report, overrides } => {
+            let overrides: HashMap<String, String> = overrides
+                .iter()
+                .map(|o| match o.split_once('=') {
+                    Some((id, hash)) => (id.to_string(), hash.to_string()),
+                    None => {
+                        eprintln!("Error: --override {:?} is not ID=HASH", o);
+                        std::process::exit(2);
+                    }
+                })
+                .collect();
+            let opened = match &stream {
+                Some(stream) => AuditLog::open_stream(Path::new(&path), stream).and_then(|mut log| {
+                    if let Some(ref key) = audit_key {
+                        log.set_key(key.clone())?;
+                    }
+                    Ok(log)
+                }),
+                None => open_audit(&path, audit_key.as_ref()),
+            };
+            let opened = opened.map(|mut log| {
+                log.set_details_validation(typed_details);
+                log
+            });
+            match opened {
+                Ok(log) if !overrides.is_empty() => match log.verify_integrity_with_overrides(&overrides) {
+                    Ok(verified) => {
+                        for applied in &verified.applied {
+                            println!(
+                                "⚠️  Override at line {}: entry {} not verified, chain continued from {} (stored {})",
+                                applied.line, applied.entry_id, applied.corrected_hash, applied.stored_hash
+                            );
+                        }
+                        for id in &verified.unused {
+                            eprintln!("❌ Override for {} matched no entry", id);
+                        }
+                        if !verified.unused.is_empty() {
+                            std::process::exit(1);
+                        }
+                        println!(
+                            "✅ Audit log verified with {} override(s): {} entries checked",
+                            verified.applied.len(),
+                            verified.verified
+                        );
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Audit log integrity check failed: {}", e);
+                        std::process::exit(1);
+                    }
+                },
+                Ok(log) if report => match log.verify_report_from(start_hash.as_deref().unwrap_or("genesis")) {
+                    Ok(issues) if issues.is_empty() => println!("✅ Audit log integrity verified"),
+                    Ok(issues) => {
+                        for issue in &issues {
+                            println!("❌ {}", issue);
+                        }
+                        println!("{} integrity issue(s) found", issues.len());
+                        std::process::exit(1);
+                    }
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(2);
+                    }
+                },
+                Ok(log) if since.is_some() || until.is_some() => {
+                    match log.verify_range(since, until) {
+                        Ok(report) => {
+                            println!("✅ Audit log window verified: {} entries", report.verified);
+                            println!(
+                                "   Scoped verification only: {} earlier entries used for linkage, later entries not checked",
+                                report.skipped_before
+                            );
+                        }
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
                 Ok(log) => {
-                    match log.verify_integrity() {
+                    match log.verify_integrity_from(start_hash.as_deref().unwrap_or("genesis")) {
                         Ok(true) => println!("✅ Audit log integrity verified"),
                         Ok(false) => println!("❌ Audit log integrity check failed"),
                         Err(e) => eprintln!("Error: {}", e),
@@ -47,13 +678,633 @@ fn main() {
                 Err(e) => eprintln!("Error opening log: {}", e),
             }
         }
-        Commands::Sign { adapter, signer } => {
-            println!("Signing adapter {} as {}", adapter, signer);
-            // TODO: Implement full signing
+        Commands::Query { path, tail, follow, poll_ms, labels } => {
+            let tail = tail.unwrap_or(usize::MAX);
+            let opened = match audit_key {
+                Some(key) => AuditFollower::open_with_key(PathBuf::from(&path), tail, key),
+                None => AuditFollower::open(PathBuf::from(&path), tail),
+            };
+            let (mut follower, entries) = match opened {
+                Ok(opened) => opened,
+                Err(e) => {
+                    eprintln!("Error reading {}: {}", path, e);
+                    std::process::exit(2);
+                }
+            };
+            let show = |entry: &audit::AuditEntry| {
+                if labels.iter().all(|(key, value)| entry.has_label(key, value)) {
+                    print_entry(entry, &zone);
+                }
+            };
+            entries.iter().for_each(show);
+            if !follow {
+                return;
+            }
+
+            // Read-only: the default Ctrl-C handling ends the process with
+            // nothing left half-done, and each entry is flushed as printed.
+            let poll = Duration::from_millis(poll_ms);
+            loop {
+                match follower.poll() {
+                    Ok(entries) => entries.iter().for_each(show),
+                    Err(e) => {
+                        eprintln!("Error following {}: {}", path, e);
+                        std::process::exit(2);
+                    }
+                }
+                std::thread::sleep(poll);
+            }
+        }
+        Commands::TailVerify { path, tail, poll_ms } => {
+            let opened = match audit_key {
+                Some(key) => AuditFollower::open_with_key(PathBuf::from(&path), usize::MAX, key),
+                None => AuditFollower::open(PathBuf::from(&path), usize::MAX),
+            };
+            let (mut follower, existing) = match opened {
+                Ok(opened) => opened,
+                Err(e) if e.is_integrity_failure() => chain_broken(&path, &e),
+                Err(e) => {
+                    eprintln!("Error reading {}: {}", path, e);
+                    std::process::exit(2);
+                }
+            };
+
+            let mut verifier = audit::ChainVerifier::new();
+            for entry in &existing {
+                if let Err(e) = verifier.verify(entry) {
+                    chain_broken(&path, &e);
+                }
+            }
+            existing[existing.len().saturating_sub(tail)..].iter().for_each(|entry| print_entry(entry, &zone));
+            println!("✅ {} existing entries verified, head {}", verifier.verified(), verifier.head());
+            println!("   Following {}; stops at the first broken link", path);
+
+            let poll = Duration::from_millis(poll_ms);
+            loop {
+                let entries = match follower.poll() {
+                    Ok(entries) => entries,
+                    Err(e) if e.is_integrity_failure() => chain_broken(&path, &e),
+                    Err(e) => {
+                        eprintln!("Error following {}: {}", path, e);
+                        std::process::exit(2);
+                    }
+                };
+                for entry in &entries {
+                    if let Err(e) = verifier.verify(entry) {
+                        chain_broken(&path, &e);
+                    }
+                    print_entry(entry, &zone);
+                }
+                std::thread::sleep(poll);
+            }
+        }
+        Commands::Monitor { path, incident_log, interval } => {
+            const MONITOR_OPERATOR: &str = "audit-monitor";
+
+            let incidents = match open_audit(&incident_log, audit_key.as_ref()) {
+                Ok(log) => log,
+                Err(e) => {
+                    eprintln!("Error opening incident log: {}", e);
+                    std::process::exit(2);
+                }
+            };
+            let mut monitor = TamperMonitor::new(incidents, MONITOR_OPERATOR);
+            // The monitor fires the switch itself, so it needs no operator entry.
+            let mut ks = kill_switch(&config, &[], load_state(&state_path));
+
+            loop {
+                let checked = open_audit(&path, audit_key.as_ref())
+                    .and_then(|log| monitor.check(&log, &mut ks));
+                match checked {
+                    Ok(None) => println!("✅ Audit log integrity verified"),
+                    Ok(Some(TamperResponse { message, incident, kill })) => {
+                        println!("❌ Audit log tampering detected: {}", message);
+                        match incident {
+                            Ok(entry) => println!("   Incident recorded: {}", entry.id),
+                            Err(e) => eprintln!("   Failed to record incident: {}", e),
+                        }
+                        match kill {
+                            Ok(event) => {
+                                save_state(&state_path, &ks);
+                                println!("🚨 Kill-switch activated: {}", with_fingerprint(&event.id));
+                            }
+                            Err(e) => eprintln!("   Failed to activate kill-switch: {}", e),
+                        }
+                        std::process::exit(1);
+                    }
+                    Err(e) => eprintln!("Error: {}", e),
+                }
+
+                match interval {
+                    Some(secs) => std::thread::sleep(std::time::Duration::from_secs(secs)),
+                    None => break,
+                }
+            }
+        }
+        Commands::Validate { path, schema_version } => {
+            match migrate::validate(Path::new(&path), schema_version) {
+                Ok(report) => {
+                    for issue in &report.issues {
+                        println!(
+                            "❌ line {} ({}): {}",
+                            issue.line,
+                            issue.id.as_deref().unwrap_or("?"),
+                            issue.message
+                        );
+                    }
+                    if report.issues.is_empty() {
+                        println!("✅ {} entries match schema v{}", report.total, schema_version);
+                    } else {
+                        println!(
+                            "❌ {} of {} entries do not match schema v{}",
+                            report.issues.len(),
+                            report.total,
+                            schema_version
+                        );
+                    }
+                }
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        Commands::Migrate { input, output } => {
+            match migrate::migrate(Path::new(&input), Path::new(&output)) {
+                Ok(report) => {
+                    println!("✅ Migrated {} entries to {}", report.migrated, output);
+                    println!("   Unknown event types preserved: {}", report.preserved_unknown);
+                    println!("   Head hash: {}", with_fingerprint(&report.head_hash));
+                }
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        #[cfg(feature = "protobuf")]
+        Commands::ExportAudit { path, out } => {
+            let log = open_or_exit(&path, open_audit(&path, audit_key.as_ref()));
+            let exported = match out {
+                Some(ref out) => std::fs::File::create(out)
+                    .map_err(Into::into)
+                    .and_then(|file| protobuf::export(&log, &mut io::BufWriter::new(file))),
+                None => protobuf::export(&log, &mut io::stdout().lock()),
+            };
+            match exported {
+                Ok(count) => {
+                    if let Some(out) = out {
+                        println!("✅ Exported {} audit entries to {}", count, out);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error exporting {}: {}", path, e);
+                    std::process::exit(2);
+                }
+            }
+        }
+        #[cfg(feature = "protobuf")]
+        Commands::ImportAudit { input, output } => {
+            let file = open_or_exit(&input, std::fs::File::open(&input));
+            match protobuf::import(&mut io::BufReader::new(file), Path::new(&output)) {
+                Ok(count) => println!("✅ Imported {} audit entries into {}", count, output),
+                Err(e) => {
+                    eprintln!("❌ Cannot import {}: {}", input, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Rechain { input, output, signer, key, reason, meta_log } => {
+            require_authorized(&config, &signer);
+            let key_path = key.unwrap_or_else(|| format!("{}.key", signer));
+            let key = open_or_exit("signing key", keys::load_signing_key(Path::new(&key_path)));
+            let mut meta = open_or_exit(&meta_log, open_audit(&meta_log, audit_key.as_ref()));
+
+            match rechain::rechain(Path::new(&input), Path::new(&output), &signer, &key, &reason, &mut meta) {
+                Ok(report) => {
+                    println!("✅ Re-chained {} entries into {}", report.entries, output);
+                    println!("   Old head: {}", report.old_head);
+                    println!("   New head: {}", report.new_head);
+                    println!("   Signed by: {}", signer);
+                    println!("   Recorded in {} (entry {})", meta_log, report.record.id);
+                }
+                Err(e) => {
+                    eprintln!("❌ Cannot rechain {}: {}", input, e);
+                    std::process::exit(if matches!(e, rechain::RechainError::Io(_)) { 2 } else { 1 });
+                }
+            }
+        }
+        Commands::Replay { path, list } => {
+            let log = open_or_exit(&path, open_audit(&path, audit_key.as_ref()));
+            let state = match replay::replay(&log) {
+                Ok(state) => state,
+                Err(e) => {
+                    eprintln!("Error replaying {}: {}", path, e);
+                    std::process::exit(2);
+                }
+            };
+            let quarantine = open_or_exit("quarantine list", QuarantineList::open(PathBuf::from(&list)));
+
+            println!("Replayed {} entries", state.entries);
+            println!("   Kill-switch: {}", if state.killed { "ACTIVE" } else { "inactive" });
+            println!("   Active adapters: {}", state.active.len());
+            println!("   Quarantined: {}", state.quarantined.len());
+            println!("   Destroyed: {}", state.destroyed.len());
+            if state.unknown_events > 0 {
+                println!("   Unknown events skipped: {}", state.unknown_events);
+            }
+
+            let discrepancies = state.compare(&load_state(&state_path), &quarantine);
+            if discrepancies.is_empty() {
+                println!("✅ Live state matches the audit log");
+            } else {
+                for d in &discrepancies {
+                    println!("❌ {}", d);
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Label { adapter, set, remove, registry } => {
+            let path = Path::new(&registry);
+            let mut registry = open_or_exit("registry", Registry::load(path));
+            let changes = set
+                .iter()
+                .map(|(key, value)| (key.as_str(), Some(value.as_str())))
+                .chain(remove.iter().map(|key| (key.as_str(), None)));
+            for (key, value) in changes {
+                if let Err(e) = registry.set_label(&adapter, key, value) {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            if let Err(e) = registry.save(path) {
+                eprintln!("Error saving registry {}: {}", path.display(), e);
+                std::process::exit(2);
+            }
+            let labels = &registry.get(&adapter).expect("labelled adapter is registered").labels;
+            let shown: Vec<String> = labels.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+            println!("🏷️  {}: {}", adapter, if shown.is_empty() { "no labels".to_string() } else { shown.join(", ") });
+        }
+        Commands::ConsistencyCheck { path, list, registry } => {
+            let log = open_or_exit(&path, open_audit(&path, audit_key.as_ref()));
+            let state = match replay::replay(&log) {
+                Ok(state) => state,
+                Err(e) => {
+                    eprintln!("Error replaying {}: {}", path, e);
+                    std::process::exit(2);
+                }
+            };
+            let quarantine = open_or_exit("quarantine list", QuarantineList::open(PathBuf::from(&list)));
+            let registry = open_or_exit("registry", Registry::load(Path::new(&registry)));
+
+            let mut discrepancies = state.compare(&load_state(&state_path), &quarantine);
+            discrepancies.extend(state.compare_registry(&registry));
+            println!(
+                "Checked {} audit entries against {} registered adapters, {} quarantined and the kill-switch state",
+                state.entries,
+                registry.iter().count(),
+                quarantine.iter().count()
+            );
+            if discrepancies.is_empty() {
+                println!("✅ Registry, quarantine list and kill-switch state agree with the audit log");
+            } else {
+                for d in &discrepancies {
+                    println!("❌ {}", d);
+                }
+                std::process::exit(1);
+            }
         }
-        Commands::Verify { adapter } => {
-            println!("Verifying adapter {}", adapter);
-            // TODO: Implement full verification
+        Commands::Diff { a, b } => {
+            let log_a = open_or_exit(&a, open_audit(&a, audit_key.as_ref()));
+            let log_b = open_or_exit(&b, open_audit(&b, audit_key.as_ref()));
+            let diff = match diff::diff_logs(&log_a, &log_b) {
+                Ok(diff) => diff,
+                Err(diff::DiffError::Unverified { log, source }) => {
+                    let path = if log == "a" { &a } else { &b };
+                    eprintln!("❌ Cannot diff: {} does not verify: {}", path, source);
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("Error reading logs: {}", e);
+                    std::process::exit(2);
+                }
+            };
+
+            let Some(fork) = &diff.fork else {
+                println!("✅ Logs identical ({} entries)", diff.common_prefix);
+                return;
+            };
+            println!("❌ Logs diverge after {} common entries", diff.common_prefix);
+            let describe = |entry: &Option<audit::AuditEntry>| match entry {
+                Some(e) => format!("{} ({})", e.id, e.hash),
+                None => "<end of log>".to_string(),
+            };
+            println!("   first difference at entry #{}:", fork.index);
+            println!("     a: {}", describe(&fork.a));
+            println!("     b: {}", describe(&fork.b));
+            for (label, entries) in [("a", &diff.only_in_a), ("b", &diff.only_in_b)] {
+                if !entries.is_empty() {
+                    println!("   only in {} ({}):", label, entries.len());
+                }
+                for e in entries {
+                    println!("     {} {} {:?} by {}", e.id, zone.format(&e.timestamp), e.event_type, e.actor);
+                }
+            }
+            std::process::exit(1);
+        }
+        Commands::ProveInclusion { path, entry } => {
+            let log = match open_audit(&path, audit_key.as_ref()) {
+                Ok(log) => log,
+                Err(e) => {
+                    eprintln!("Error opening log: {}", e);
+                    return;
+                }
+            };
+            match (log.prove_inclusion(&entry), log.merkle_head()) {
+                (Ok(proof), Ok(Some(head))) => {
+                    println!("{}", serde_json::to_string_pretty(&proof).unwrap());
+                    eprintln!("Merkle head: {}", head);
+                }
+                (Err(e), _) | (_, Err(e)) => eprintln!("Error: {}", e),
+                (Ok(_), Ok(None)) => eprintln!("Error: audit log is empty"),
+            }
+        }
+        Commands::VerifyInclusion { proof, head } => {
+            let parsed = std::fs::read_to_string(&proof)
+                .map_err(|e| e.to_string())
+                .and_then(|s| {
+                    serde_json::from_str::<inclusion::InclusionProof>(&s).map_err(|e| e.to_string())
+                });
+            match parsed {
+                Ok(proof) if inclusion::verify_inclusion(&proof, &head) => {
+                    println!("✅ Entry {} included at position {}", proof.entry.id, proof.index)
+                }
+                Ok(proof) => println!("❌ Inclusion proof for {} is invalid", proof.entry.id),
+                Err(e) => eprintln!("Error reading proof: {}", e),
+            }
+        }
+        Commands::ProvenanceGraph { chain, out } => {
+            let chain = match ProvenanceChain::load(Path::new(&chain)) {
+                Ok(chain) => chain,
+                Err(e) => {
+                    eprintln!("Error reading {}: {}", chain, e);
+                    std::process::exit(2);
+                }
+            };
+            let dot = chain.to_dot();
+            match out {
+                Some(out) => {
+                    if let Err(e) = std::fs::write(&out, dot) {
+                        eprintln!("Error writing {}: {}", out, e);
+                        std::process::exit(2);
+                    }
+                    println!("✅ Wrote lineage of {} entries to {}", chain.entries.len(), out);
+                }
+                None => print!("{}", dot),
+            }
+        }
+        Commands::ExportProvenance { chain: chain_path, signer, key, out } => {
+            let key_path = key.unwrap_or_else(|| format!("{}.key", signer));
+            let key = open_or_exit("signing key", keys::load_signing_key(Path::new(&key_path)));
+            let chain = open_or_exit(&chain_path, ProvenanceChain::load(Path::new(&chain_path)));
+
+            let bundle = chain.export_signed(&signer, &key);
+            if let Err(e) = bundle.save(Path::new(&out)) {
+                eprintln!("Error writing bundle {}: {}", out, e);
+                std::process::exit(2);
+            }
+            println!("✅ Exported {} provenance entries to {}", chain.entries.len(), out);
+            println!("   Signed by: {} ({})", signer, display::fingerprint(key.verifying_key().as_bytes()));
+        }
+        Commands::ImportProvenance { bundle: bundle_path, trusted, out } => {
+            let verifier = match trusted {
+                Some(trusted) => {
+                    let signers = open_or_exit(&trusted, attest::load_trusted_signers(Path::new(&trusted)));
+                    open_or_exit("trusted signer keys", config::signature_verifier(&signers))
+                }
+                None => trusted_verifier(&config, signers_flagged),
+            };
+            let bundle = open_or_exit(&bundle_path, ProvenanceBundle::load(Path::new(&bundle_path)));
+
+            let chain = match ProvenanceChain::import_verified(&bundle, &verifier) {
+                Ok(chain) => chain,
+                Err(e) => {
+                    eprintln!("❌ Rejected provenance bundle {}: {}", bundle_path, e);
+                    std::process::exit(1);
+                }
+            };
+            let written = serde_json::to_string_pretty(&chain)
+                .map_err(|e| e.to_string())
+                .and_then(|json| std::fs::write(&out, json).map_err(|e| e.to_string()));
+            if let Err(e) = written {
+                eprintln!("Error writing chain {}: {}", out, e);
+                std::process::exit(2);
+            }
+            println!("✅ Imported {} verified provenance entries to {}", chain.entries.len(), out);
+            println!("   Exported by: {}", bundle.exporter.id);
+        }
+        Commands::Keygen { signer, out } => {
+            match keys::generate_keypair(&signer, Path::new(&out)) {
+                Ok(key) => {
+                    println!("🔑 Generated keypair for {}", signer);
+                    println!("   Private key: {}", key.private_key_path.display());
+                    println!("   Public key:  {}", key.public_key_path.display());
+                    println!("   Fingerprint: {} ({})", display::fingerprint(key.public_key.as_bytes()), key.fingerprint);
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::AuditKeygen { out } => match keys::generate_audit_key(Path::new(&out)) {
+            Ok(_) => {
+                println!("🔑 Generated audit key {}", out);
+                println!("   Set audit_key_file in governance.toml to encrypt new entries");
+                println!("   Keep a backup: encrypted entries cannot be read or verified without it");
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::Attest { path, signer, key, out, clean } => {
+            let key_path = key.unwrap_or_else(|| format!("{}.key", signer));
+            let key = open_or_exit("signing key", keys::load_signing_key(Path::new(&key_path)));
+            let log = open_or_exit(&path, open_audit(&path, audit_key.as_ref()));
+
+            if let Some(adapter_id) = clean {
+                let attestation = match log.attest_clean(&adapter_id, &signer, &key) {
+                    Ok(attestation) => attestation,
+                    Err(e) => {
+                        eprintln!("❌ Cannot attest {} clean: {}", adapter_id, e);
+                        std::process::exit(1);
+                    }
+                };
+                let written = serde_json::to_string_pretty(&attestation)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| std::fs::write(&out, json).map_err(|e| e.to_string()));
+                if let Err(e) = written {
+                    eprintln!("Error writing attestation {}: {}", out, e);
+                    std::process::exit(2);
+                }
+                println!("✅ Attested {} clean through {} entries of {}", adapter_id, attestation.claims.count, path);
+                println!("   Head hash: {}", with_fingerprint(&attestation.claims.head_hash));
+                println!("   Signed by: {}", signer);
+                return;
+            }
+
+            let attestation = match attest::attest(&log, &signer, &key) {
+                Ok(attestation) => attestation,
+                Err(e) => {
+                    eprintln!("❌ Cannot attest {}: {}", path, e);
+                    std::process::exit(1);
+                }
+            };
+            let written = serde_json::to_string_pretty(&attestation)
+                .map_err(|e| e.to_string())
+                .and_then(|json| std::fs::write(&out, json).map_err(|e| e.to_string()));
+            if let Err(e) = written {
+                eprintln!("Error writing attestation {}: {}", out, e);
+                std::process::exit(2);
+            }
+            println!("✅ Attested {} entries of {}", attestation.claims.count, path);
+            println!("   Head hash: {}", with_fingerprint(&attestation.claims.head_hash));
+            println!("   Signed by: {}", signer);
+        }
+        Commands::CheckAttestation { path, attestation, trusted } => {
+            let verifier = match trusted {
+                Some(trusted) => {
+                    let signers = open_or_exit(&trusted, attest::load_trusted_signers(Path::new(&trusted)));
+                    open_or_exit("trusted signer keys", config::signature_verifier(&signers))
+                }
+                None => trusted_verifier(&config, signers_flagged),
+            };
+            let parsed = std::fs::read_to_string(&attestation)
+                .map_err(|e| e.to_string())
+                .and_then(|s| serde_json::from_str::<attest::Attestation>(&s).map_err(|e| e.to_string()));
+            let attestation = match parsed {
+                Ok(attestation) => attestation,
+                Err(e) => {
+                    eprintln!("Error reading attestation {}: {}", attestation, e);
+                    std::process::exit(2);
+                }
+            };
+            let log = open_or_exit(&path, open_audit(&path, audit_key.as_ref()));
+
+            match attest::check_attestation(&log, &attestation, &verifier) {
+                Ok(check) => {
+                    println!(
+                        "✅ Attestation valid: {} entries signed by {}",
+                        attestation.claims.count, attestation.signature.signer_id
+                    );
+                    if check.newer_entries > 0 {
+                        println!("   {} entries appended since", check.newer_entries);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Attestation check failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Sign { adapter, signer, key, legacy, audit_log, out, strict_permissions } => {
+            let key = (!legacy).then(|| {
+                let key_path = key.unwrap_or_else(|| format!("{}.key", signer));
+                open_or_exit("signing key", keys::load_signing_key(Path::new(&key_path)))
+            });
+            let source = open_or_exit("adapter", ContentSource::parse(&adapter));
+            check_permissions(source.local_path().as_slice(), strict_permissions);
+            let content = open_or_exit(&source.to_string(), source.open_content());
+            let mut audit = open_or_exit("audit log", open_audit(&audit_log, audit_key.as_ref()));
+
+            let signed = match key {
+                Some(key) => signatures::sign_ed25519_audited(&mut audit, content, &signer, &key, None),
+                None => trusted_verifier(&config, signers_flagged).sign_audited(&mut audit, content, &signer, None),
+            };
+            let mut signature = match signed {
+                Ok(signature) => signature,
+                Err(e) => {
+                    eprintln!("❌ Cannot sign {}: {}", source, e);
+                    std::process::exit(1);
+                }
+            };
+            signature.artifact_compression = source.compression();
+            let json = serde_json::to_string_pretty(&signature).expect("signatures always serialize");
+            match out {
+                Some(out) => {
+                    if let Err(e) = std::fs::write(&out, json) {
+                        eprintln!("Error writing signature {}: {}", out, e);
+                        std::process::exit(2);
+                    }
+                    println!("✅ Signed {} as {}", source, signer);
+                }
+                None => println!("{}", json),
+            }
+        }
+        Commands::Verify { adapter, signature: signature_path, creator, revocations, metrics, trust_url, trust_key, trust_cache, trust_ttl_secs, strict_permissions } => {
+            let mut verifier = match trust_url {
+                Some(url) => remote_verifier(&config, &url, trust_key.as_deref(), &trust_cache, trust_ttl_secs, &zone),
+                None => trusted_verifier(&config, signers_flagged),
+            };
+            let revocations = open_or_exit("revocation list", RevocationList::open(PathBuf::from(&revocations)));
+            verifier.set_revocation_list(Arc::new(RwLock::new(revocations)));
+            let source = open_or_exit("adapter", ContentSource::parse(&adapter));
+            let checked: Vec<&Path> = source.local_path().into_iter().chain([Path::new(&signature_path)]).collect();
+            check_permissions(&checked, strict_permissions);
+            let signature = read_signature(&signature_path);
+            let content = open_or_exit(&source.to_string(), source.open_content());
+            if signature.artifact_compression.is_some() && source.compression() != signature.artifact_compression {
+                println!("⚠️  Signature was made for a compressed artifact; verifying decompressed content");
+            }
+
+            let verified = verifier.verify_reader_detailed(content, &signature, creator.as_deref());
+            let mut report = open_or_exit("signer metrics", SignerReport::load(Path::new(&metrics)));
+            report.merge(&verifier.report());
+            if let Err(e) = report.save(Path::new(&metrics)) {
+                eprintln!("⚠️  Could not update signer metrics {}: {}", metrics, e);
+            }
+
+            match verified {
+                Ok(VerificationOutcome::TrustedExternal) => {
+                    println!("✅ Signature valid: {} signed by {}", source, signature.signer_id)
+                }
+                Ok(VerificationOutcome::SelfSigned) => {
+                    println!("✅ Signature valid: {} signed by {}", source, signature.signer_id);
+                    println!("⚠️  Self-signed by its creator; no external attestation");
+                }
+                Ok(VerificationOutcome::UnknownCreator) => {
+                    println!("✅ Signature valid: {} signed by {}", source, signature.signer_id);
+                    println!("⚠️  Creator unknown; pass --creator to check for external attestation");
+                }
+                Ok(VerificationOutcome::Failed) => {
+                    eprintln!("❌ Signature invalid for {}", source);
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("❌ Verification failed for {}: {}", source, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::SignerReport { metrics, json } => {
+            let report = open_or_exit("signer metrics", SignerReport::load(Path::new(&metrics)));
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report).expect("counts always serialize"));
+            } else if report.is_empty() {
+                println!("No verifications recorded in {}", metrics);
+            } else {
+                println!(
+                    "{:<24} {:>8} {:>8} {:>8} {:>8} {:>8} {:>9}",
+                    "SIGNER", "TOTAL", "OK", "FAILED", "UNKNOWN", "EXPIRED", "FAIL RATE"
+                );
+                for (signer, stats) in report.iter() {
+                    println!(
+                        "{:<24} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8.1}%",
+                        signer,
+                        stats.total(),
+                        stats.verified,
+                        stats.failed,
+                        stats.unknown_signer,
+                        stats.expired,
+                        stats.failure_rate() * 100.0
+                    );
+                }
+            }
         }
     }
 }
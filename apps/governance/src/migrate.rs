@@ -0,0 +1,239 @@
+//! Audit Log Migration
+//!
+//! Schema validation and re-chaining of audit logs written by older
+//! (or newer) builds. The original log is never modified.
+
+use crate::audit::{
+    hash_entry, legacy_hash_version, AuditEntry, AuditError, AuditEventType, ChainVerifier, HashedFields, HASH_VERSION,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// Audit entry schema version understood by this build.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Audit entry read with forward/backward-compatible deserialization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatEntry {
+    pub id: String,
+    pub timestamp: DateTime<Utc>,
+    pub event_type: AuditEventType,
+    pub actor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_seq: Option<u64>,
+    #[serde(default)]
+    pub target_type: Option<String>,
+    #[serde(default)]
+    pub target_id: Option<String>,
+    #[serde(default)]
+    pub details: serde_json::Value,
+    pub previous_hash: String,
+    pub hash: String,
+    #[serde(default = "legacy_hash_version")]
+    pub hash_version: u32,
+}
+
+impl CompatEntry {
+    fn to_entry(&self) -> AuditEntry {
+        AuditEntry {
+            id: self.id.clone(),
+            timestamp: self.timestamp,
+            event_type: self.event_type.clone(),
+            actor: self.actor.clone(),
+            actor_seq: self.actor_seq,
+            target_type: self.target_type.clone(),
+            target_id: self.target_id.clone(),
+            details: self.details.clone(),
+            previous_hash: self.previous_hash.clone(),
+            hash: self.hash.clone(),
+            hash_version: self.hash_version,
+        }
+    }
+}
+
+/// An entry that does not fit the current schema.
+#[derive(Debug, Clone)]
+pub struct SchemaIssue {
+    pub line: usize,
+    pub id: Option<String>,
+    pub message: String,
+}
+
+/// Result of validating a log against the current schema.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub total: usize,
+    pub valid: usize,
+    pub issues: Vec<SchemaIssue>,
+}
+
+/// Result of migrating a log into a new canonical, re-chained log.
+#[derive(Debug)]
+pub struct MigrationReport {
+    pub migrated: usize,
+    pub preserved_unknown: usize,
+    pub head_hash: String,
+}
+
+fn check_schema_version(schema_version: u32) -> Result<(), AuditError> {
+    if schema_version != CURRENT_SCHEMA_VERSION {
+        return Err(AuditError::UnsupportedSchema(schema_version));
+    }
+    Ok(())
+}
+
+/// Validate every entry of a log against the given schema version.
+///
+/// Entries are read leniently; anything that would not deserialize into
+/// the strict `AuditEntry` is reported rather than aborting the scan.
+pub fn validate(path: &Path, schema_version: u32) -> Result<ValidationReport, AuditError> {
+    check_schema_version(schema_version)?;
+
+    let reader = BufReader::new(File::open(path)?);
+    let mut report = ValidationReport::default();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        report.total += 1;
+
+        let compat: CompatEntry = match serde_json::from_str(&line) {
+            Ok(entry) => entry,
+            Err(e) => {
+                report.issues.push(SchemaIssue {
+                    line: index + 1,
+                    id: None,
+                    message: format!("unreadable entry: {}", e),
+                });
+                continue;
+            }
+        };
+
+        if let AuditEventType::Unknown(tag) = &compat.event_type {
+            report.issues.push(SchemaIssue {
+                line: index + 1,
+                id: Some(compat.id),
+                message: format!("unknown event type {:?}", tag),
+            });
+            continue;
+        }
+
+        match serde_json::from_str::<AuditEntry>(&line) {
+            Ok(_) => report.valid += 1,
+            Err(e) => report.issues.push(SchemaIssue {
+                line: index + 1,
+                id: Some(compat.id),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Rewrite `input` into canonical form at `output`, re-chaining hashes from genesis.
+///
+/// `input` must verify first: re-chaining a tampered log would give the
+/// tampering a fresh, valid chain. Every entry is re-hashed with the
+/// current hash version and renumbered in its actor's sequence. Unknown
+/// event types are preserved verbatim. `output` must not already exist;
+/// it is written beside itself and renamed into place, so a failed
+/// migration leaves no partial log behind.
+pub fn migrate(input: &Path, output: &Path) -> Result<MigrationReport, AuditError> {
+    verify_input(input)?;
+    migrate_unverified(input, output)
+}
+
+/// Check every entry of `input` the way [`AuditLog::verify_integrity`]
+/// would, reading entries leniently.
+///
+/// [`AuditLog::verify_integrity`]: crate::audit::AuditLog::verify_integrity
+fn verify_input(input: &Path) -> Result<(), AuditError> {
+    let mut verifier = ChainVerifier::new();
+    for line in BufReader::new(File::open(input)?).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        verifier.verify(&serde_json::from_str::<CompatEntry>(&line)?.to_entry())?;
+    }
+    Ok(())
+}
+
+/// [`migrate`] without verifying `input`, for an approved rechain of an
+/// edited log; entries removed from `input` leave no sequence gap.
+pub(crate) fn migrate_unverified(input: &Path, output: &Path) -> Result<MigrationReport, AuditError> {
+    let reader = BufReader::new(File::open(input)?);
+    if output.exists() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("{} already exists", output.display()),
+        )
+        .into());
+    }
+    let tmp = output.with_extension("tmp");
+    let out = OpenOptions::new().write(true).create_new(true).open(&tmp)?;
+    let written = write_rechained(reader, out).and_then(|report| {
+        std::fs::rename(&tmp, output)?;
+        Ok(report)
+    });
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    written
+}
+
+fn write_rechained(reader: BufReader<File>, mut out: File) -> Result<MigrationReport, AuditError> {
+    let mut previous_hash = "genesis".to_string();
+    let mut actor_seqs: HashMap<String, u64> = HashMap::new();
+    let mut migrated = 0;
+    let mut preserved_unknown = 0;
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut entry: CompatEntry = serde_json::from_str(&line)?;
+        if matches!(entry.event_type, AuditEventType::Unknown(_)) {
+            preserved_unknown += 1;
+        }
+
+        let seq = actor_seqs.entry(entry.actor.clone()).or_default();
+        *seq += 1;
+        entry.actor_seq = Some(*seq);
+        entry.hash_version = HASH_VERSION;
+        entry.hash = hash_entry(
+            entry.hash_version,
+            &HashedFields {
+                id: &entry.id,
+                timestamp: &entry.timestamp,
+                event_tag: entry.event_type.tag(),
+                actor: &entry.actor,
+                actor_seq: entry.actor_seq,
+                previous_hash: &previous_hash,
+            },
+            &entry.details,
+        );
+        entry.previous_hash = previous_hash;
+        previous_hash = entry.hash.clone();
+
+        writeln!(out, "{}", serde_json::to_string(&entry)?)?;
+        migrated += 1;
+    }
+
+    out.sync_all()?;
+
+    Ok(MigrationReport {
+        migrated,
+        preserved_unknown,
+        head_hash: previous_hash,
+    })
+}
@@ -0,0 +1,79 @@
+//! Tamper Monitor
+//!
+//! Verifies an audit log and, on tampering, activates the kill-switch.
+//! The integrity failure is recorded in a separate incident log before
+//! the kill, since the tampered log itself can no longer be trusted.
+//!
+//! The monitor fires the switch directly, as the dead-man's switch does,
+//! so it works however the switch's authorized operators are configured.
+
+use crate::audit::{AuditEntry, AuditError, AuditEventType, AuditLog, AuditTarget};
+use crate::killswitch::{KillEvent, KillReason, KillSwitch, KillSwitchError};
+use serde_json::json;
+
+/// Kill reason source for integrity failures.
+pub const AUDIT_INTEGRITY_SOURCE: &str = "audit-integrity";
+
+/// Response taken after detecting tampering.
+#[derive(Debug)]
+pub struct TamperResponse {
+    pub message: String,
+    /// Incident entry, if it could be recorded.
+    pub incident: Result<AuditEntry, AuditError>,
+    /// Kill event, if activation succeeded.
+    pub kill: Result<KillEvent, KillSwitchError>,
+}
+
+pub struct TamperMonitor {
+    incident_log: AuditLog,
+    operator: String,
+}
+
+impl TamperMonitor {
+    /// Create a monitor recording incidents, and kills, as `operator`.
+    pub fn new(incident_log: AuditLog, operator: &str) -> Self {
+        Self {
+            incident_log,
+            operator: operator.to_string(),
+        }
+    }
+
+    /// Verify `log` and kill on tampering; `None` means the log is intact.
+    ///
+    /// A broken chain or an unparseable entry both count as tampering;
+    /// IO errors are returned unchanged since they say nothing about integrity.
+    pub fn check(
+        &mut self,
+        log: &AuditLog,
+        kill_switch: &mut KillSwitch,
+    ) -> Result<Option<TamperResponse>, AuditError> {
+        let message = match log.verify_integrity() {
+            Ok(_) => return Ok(None),
+            Err(e) if e.is_integrity_failure() => format!("{}: {}", log.path().display(), e),
+            Err(e) => return Err(e),
+        };
+
+        // Record first (durably, append syncs), then kill regardless of
+        // whether recording succeeded: safety takes priority.
+        let incident = self.incident_log.append(
+            AuditEventType::AuditIntegrityFailed,
+            &self.operator,
+            Some(&AuditTarget::AuditLog(log.path().display().to_string())),
+            json!({ "message": message }),
+        );
+
+        let kill = kill_switch.activate_unattended(
+            &self.operator,
+            KillReason::ExternalSignal {
+                source: AUDIT_INTEGRITY_SOURCE.to_string(),
+                message: message.clone(),
+            },
+        );
+
+        Ok(Some(TamperResponse {
+            message,
+            incident,
+            kill,
+        }))
+    }
+}
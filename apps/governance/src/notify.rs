@@ -0,0 +1,238 @@
+//! Kill Notifications
+//!
+//! Tell on-call when the kill-switch fires. Notifiers run on background
+//! threads after the activation has been recorded; a failed notification
+//! is logged and retried with backoff, and never undoes the kill.
+//!
+//! The webhook notifier needs the `webhook` feature.
+
+use crate::audit::RetryPolicy;
+use crate::killswitch::KillEvent;
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, JoinHandle, Thread};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NotifyError {
+    #[error("Request failed: {0}")]
+    Request(String),
+    #[error("Endpoint returned HTTP {0}")]
+    Status(u16),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Webhook notifications need the `webhook` feature: {0}")]
+    Unsupported(String),
+}
+
+impl NotifyError {
+    /// Whether a later attempt may succeed; client errors are final.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            NotifyError::Request(_) => true,
+            NotifyError::Status(status) => *status == 429 || *status >= 500,
+            NotifyError::Serialization(_) | NotifyError::Unsupported(_) => false,
+        }
+    }
+}
+
+pub type NotifyFuture<'a> = Pin<Box<dyn Future<Output = Result<(), NotifyError>> + Send + 'a>>;
+
+/// Destination for kill-switch notifications.
+pub trait Notifier: Send + Sync {
+    /// Name used in log messages.
+    fn name(&self) -> &str;
+
+    fn notify<'a>(&'a self, event: &'a KillEvent) -> NotifyFuture<'a>;
+}
+
+/// Fans kill events out to every notifier, retrying failures.
+#[derive(Clone, Default)]
+pub struct NotificationDispatcher {
+    notifiers: Vec<Arc<dyn Notifier>>,
+    retry: RetryPolicy,
+}
+
+/// Notifications still being delivered.
+#[derive(Default)]
+pub struct PendingNotifications {
+    handles: Vec<JoinHandle<bool>>,
+}
+
+impl PendingNotifications {
+    /// Wait for delivery to finish; returns how many notifiers succeeded.
+    pub fn wait(self) -> usize {
+        self.handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or(false))
+            .filter(|delivered| *delivered)
+            .count()
+    }
+
+    /// Take over `other`'s deliveries.
+    pub fn extend(&mut self, other: PendingNotifications) {
+        self.handles.extend(other.handles);
+    }
+}
+
+impl NotificationDispatcher {
+    pub fn new(retry: RetryPolicy) -> Self {
+        Self {
+            notifiers: Vec::new(),
+            retry,
+        }
+    }
+
+    pub fn add(&mut self, notifier: impl Notifier + 'static) {
+        self.notifiers.push(Arc::new(notifier));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notifiers.is_empty()
+    }
+
+    /// Deliver `event` to every notifier, each on its own thread.
+    pub fn dispatch(&self, event: &KillEvent) -> PendingNotifications {
+        let handles = self
+            .notifiers
+            .iter()
+            .map(|notifier| {
+                let notifier = Arc::clone(notifier);
+                let event = event.clone();
+                let retry = self.retry;
+                thread::spawn(move || deliver(notifier.as_ref(), &event, retry))
+            })
+            .collect();
+        PendingNotifications { handles }
+    }
+}
+
+fn deliver(notifier: &dyn Notifier, event: &KillEvent, retry: RetryPolicy) -> bool {
+    let mut attempt = 1;
+    loop {
+        match block_on(notifier.notify(event)) {
+            Ok(()) => return true,
+            Err(e) if e.is_retryable() && attempt < retry.max_attempts => {
+                let delay = retry.backoff(attempt);
+                eprintln!(
+                    "⚠️  Notification to {} failed (attempt {}): {}; retrying in {:?}",
+                    notifier.name(),
+                    attempt,
+                    e,
+                    delay
+                );
+                thread::sleep(delay);
+                attempt += 1;
+            }
+            Err(e) => {
+                eprintln!(
+                    "❌ Notification to {} for kill event {} failed: {}",
+                    notifier.name(),
+                    event.id,
+                    e
+                );
+                return false;
+            }
+        }
+    }
+}
+
+/// Drive a future to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(feature = "webhook")]
+pub use webhook::WebhookNotifier;
+
+#[cfg(feature = "webhook")]
+mod webhook {
+    use super::{NotifyError, NotifyFuture, Notifier};
+    use crate::killswitch::KillEvent;
+    use serde_json::json;
+    use std::time::Duration;
+    use tokio::runtime::Runtime;
+
+    /// POST each kill event as JSON to a URL, e.g. a Slack incoming webhook.
+    pub struct WebhookNotifier {
+        url: String,
+        client: reqwest::Client,
+        runtime: Runtime,
+    }
+
+    impl WebhookNotifier {
+        pub fn new(url: &str) -> Result<Self, NotifyError> {
+            let client = reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .map_err(|e| NotifyError::Request(e.to_string()))?;
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .enable_all()
+                .build()
+                .map_err(|e| NotifyError::Request(e.to_string()))?;
+            Ok(Self {
+                url: url.to_string(),
+                client,
+                runtime,
+            })
+        }
+    }
+
+    impl Notifier for WebhookNotifier {
+        fn name(&self) -> &str {
+            &self.url
+        }
+
+        fn notify<'a>(&'a self, event: &'a KillEvent) -> NotifyFuture<'a> {
+            // `text` is what Slack displays; the full event rides along.
+            let body = json!({
+                "text": format!(
+                    "🚨 Kill-switch activated by {} at {} ({})",
+                    event.triggered_by,
+                    event.timestamp,
+                    event.reason.code()
+                ),
+                "event": event,
+            });
+            let body = match serde_json::to_vec(&body) {
+                Ok(body) => body,
+                Err(e) => return Box::pin(async move { Err(e.into()) }),
+            };
+            let request = self
+                .client
+                .post(&self.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+            // Runs on the notifier's own runtime, so any executor can await it.
+            let handle = self.runtime.spawn(async move {
+                let response = request.send().await.map_err(|e| NotifyError::Request(e.to_string()))?;
+                match response.status() {
+                    status if status.is_success() => Ok(()),
+                    status => Err(NotifyError::Status(status.as_u16())),
+                }
+            });
+            Box::pin(async move {
+                handle.await.map_err(|e| NotifyError::Request(e.to_string()))?
+            })
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! File Permissions
+//!
+//! Checks that an adapter and its signature sidecar cannot be modified by
+//! any local user before they are signed or trusted. A world-writable
+//! file, or one in a world-writable directory without the sticky bit, can
+//! be swapped after signing or before verifying.
+//!
+//! The check is Unix-only; elsewhere nothing is reported.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Why a path is too permissive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionProblem {
+    /// Any user may modify the file.
+    WorldWritable,
+    /// Any user may replace files in the directory.
+    WorldWritableDirectory,
+}
+
+/// A path whose mode lets any local user tamper with the file checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionIssue {
+    pub path: PathBuf,
+    /// Permission bits, e.g. `0o666`.
+    pub mode: u32,
+    pub problem: PermissionProblem,
+}
+
+impl fmt::Display for PermissionIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.problem {
+            PermissionProblem::WorldWritable => "is world-writable",
+            PermissionProblem::WorldWritableDirectory => "is a world-writable directory without the sticky bit",
+        };
+        write!(f, "{} {} (mode {:04o})", self.path.display(), what, self.mode)
+    }
+}
+
+/// Check the file at `path`, following symlinks, and its directory.
+pub fn check_permissions(path: &Path) -> io::Result<Vec<PermissionIssue>> {
+    let metadata = std::fs::metadata(path)?;
+    let mut issues = Vec::new();
+    if let Some(mode) = world_writable(&metadata) {
+        issues.push(PermissionIssue {
+            path: path.to_path_buf(),
+            mode,
+            problem: PermissionProblem::WorldWritable,
+        });
+    }
+
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    if let Some(mode) = world_writable(&std::fs::metadata(dir)?).filter(|mode| mode & 0o1000 == 0) {
+        issues.push(PermissionIssue {
+            path: dir.to_path_buf(),
+            mode,
+            problem: PermissionProblem::WorldWritableDirectory,
+        });
+    }
+    Ok(issues)
+}
+
+/// The permission bits, if writable by others.
+#[cfg(unix)]
+fn world_writable(metadata: &std::fs::Metadata) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    let mode = metadata.permissions().mode() & 0o7777;
+    (mode & 0o002 != 0).then_some(mode)
+}
+
+#[cfg(not(unix))]
+fn world_writable(_metadata: &std::fs::Metadata) -> Option<u32> {
+    None
+}
@@ -0,0 +1,151 @@
+//! Policy Engine
+//!
+//! Rules evaluated against the context of an operation, producing a
+//! [`GovernanceDecision`] and the trace of every rule considered. A
+//! context that no rule applies to gets the engine's default decision,
+//! `Deny` unless configured otherwise, so gaps in policy coverage fail
+//! safe instead of silently allowing.
+
+use openlora_core::{EvaluationTrace, GovernanceDecision, RuleOutcome};
+use std::collections::BTreeMap;
+
+/// What a rule is asked to judge.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyContext {
+    /// Operation being attempted, e.g. `activate`.
+    pub operation: String,
+    pub actor: String,
+    pub adapter_id: Option<String>,
+    /// Anything else rules look at, e.g. `risk-tier`.
+    pub attributes: BTreeMap<String, String>,
+}
+
+impl PolicyContext {
+    pub fn new(operation: &str, actor: &str) -> Self {
+        Self {
+            operation: operation.to_string(),
+            actor: actor.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_adapter(mut self, adapter_id: &str) -> Self {
+        self.adapter_id = Some(adapter_id.to_string());
+        self
+    }
+
+    pub fn with_attribute(mut self, key: &str, value: &str) -> Self {
+        self.attributes.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+/// A rule's judgement of one context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The rule does not cover this context.
+    NotApplicable,
+    Pass(Option<String>),
+    /// The rule covers this context and forbids it, saying why.
+    Fail(String),
+}
+
+/// One policy rule.
+pub trait PolicyRule: Send + Sync {
+    /// Name recorded in the evaluation trace.
+    fn name(&self) -> &str;
+
+    fn evaluate(&self, context: &PolicyContext) -> Verdict;
+}
+
+/// Decision for a context no rule applies to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DefaultDecision {
+    #[default]
+    Deny,
+    Allow,
+}
+
+/// A decision and how it was reached.
+#[derive(Debug, Clone)]
+pub struct PolicyEvaluation {
+    pub decision: GovernanceDecision,
+    /// Every rule considered, including those that did not apply.
+    pub trace: EvaluationTrace,
+    /// Whether no rule applied, so the decision is the engine's default.
+    pub from_default: bool,
+}
+
+/// Rules evaluated in order, with a default for uncovered contexts.
+pub struct PolicyEngine {
+    rules: Vec<Box<dyn PolicyRule>>,
+    default_decision: DefaultDecision,
+}
+
+impl Default for PolicyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PolicyEngine {
+    /// An engine with no rules, which denies everything.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            default_decision: DefaultDecision::Deny,
+        }
+    }
+
+    pub fn with_rule(mut self, rule: impl PolicyRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Decision for contexts no rule applies to.
+    pub fn with_default_decision(mut self, default_decision: DefaultDecision) -> Self {
+        self.default_decision = default_decision;
+        self
+    }
+
+    pub fn default_decision(&self) -> DefaultDecision {
+        self.default_decision
+    }
+
+    /// Evaluate every rule against `context`.
+    ///
+    /// Any failing rule denies, naming the first to fail. Otherwise the
+    /// operation is allowed if at least one rule applied, and gets the
+    /// default decision if none did.
+    pub fn evaluate(&self, context: &PolicyContext) -> PolicyEvaluation {
+        let mut trace = EvaluationTrace::new();
+        let mut failure = None;
+        let mut applied = false;
+
+        for rule in &self.rules {
+            let (outcome, detail) = match rule.evaluate(context) {
+                Verdict::NotApplicable => (RuleOutcome::Skipped, None),
+                Verdict::Pass(detail) => (RuleOutcome::Passed, detail),
+                Verdict::Fail(reason) => {
+                    failure.get_or_insert_with(|| format!("rule {} failed: {}", rule.name(), reason));
+                    (RuleOutcome::Failed, Some(reason))
+                }
+            };
+            applied |= outcome != RuleOutcome::Skipped;
+            trace.record(rule.name(), outcome, detail);
+        }
+
+        let decision = match (failure, applied, self.default_decision) {
+            (Some(reason), _, _) => GovernanceDecision::Deny { reason },
+            (None, true, _) | (None, false, DefaultDecision::Allow) => GovernanceDecision::Allow { trace: trace.clone() },
+            (None, false, DefaultDecision::Deny) => GovernanceDecision::Deny {
+                reason: format!("no policy rule covers operation {:?}", context.operation),
+            },
+        };
+        PolicyEvaluation {
+            decision,
+            trace,
+            from_default: !applied,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Protobuf Export
+//!
+//! Audit entries as a stream of length-delimited protobuf messages, for
+//! pipelines that standardize on protobuf. The schema is
+//! `proto/audit.proto`; [`ProtoEntry`] is derived to match it.
+//!
+//! This is for interop only: the log itself stays JSONL. Every field is
+//! carried unchanged, hashes included, so an imported log verifies
+//! exactly as the exported one did.
+
+use crate::audit::{AuditEntry, AuditError, AuditEventType, AuditLog};
+use chrono::{DateTime, SecondsFormat, Utc};
+use prost::Message;
+use std::fs::OpenOptions;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+use thiserror::Error;
+
+/// Largest message accepted on import, in bytes.
+pub const MAX_MESSAGE_LEN: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum ProtobufError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Audit(#[from] AuditError),
+    #[error("Invalid protobuf message: {0}")]
+    Decode(#[from] prost::DecodeError),
+    #[error("Message of {0} bytes exceeds the {MAX_MESSAGE_LEN} byte limit")]
+    TooLarge(u64),
+    #[error("Entry {id} has an invalid {field}: {reason}")]
+    InvalidField {
+        id: String,
+        field: &'static str,
+        reason: String,
+    },
+}
+
+/// `openlora.audit.v1.AuditEntry`.
+#[derive(Clone, PartialEq, Message)]
+pub struct ProtoEntry {
+    #[prost(string, tag = "1")]
+    pub id: String,
+    #[prost(string, tag = "2")]
+    pub timestamp: String,
+    #[prost(string, tag = "3")]
+    pub event_type: String,
+    #[prost(string, tag = "4")]
+    pub actor: String,
+    #[prost(uint64, optional, tag = "5")]
+    pub actor_seq: Option<u64>,
+    #[prost(string, optional, tag = "6")]
+    pub target_type: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub target_id: Option<String>,
+    #[prost(string, tag = "8")]
+    pub details_json: String,
+    #[prost(string, tag = "9")]
+    pub previous_hash: String,
+    #[prost(string, tag = "10")]
+    pub hash: String,
+    #[prost(uint32, tag = "11")]
+    pub hash_version: u32,
+}
+
+impl From<&AuditEntry> for ProtoEntry {
+    fn from(entry: &AuditEntry) -> Self {
+        Self {
+            id: entry.id.clone(),
+            timestamp: entry.timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            event_type: entry.event_type.tag().to_string(),
+            actor: entry.actor.clone(),
+            actor_seq: entry.actor_seq,
+            target_type: entry.target_type.clone(),
+            target_id: entry.target_id.clone(),
+            details_json: entry.details.to_string(),
+            previous_hash: entry.previous_hash.clone(),
+            hash: entry.hash.clone(),
+            hash_version: entry.hash_version,
+        }
+    }
+}
+
+impl TryFrom<ProtoEntry> for AuditEntry {
+    type Error = ProtobufError;
+
+    fn try_from(proto: ProtoEntry) -> Result<Self, ProtobufError> {
+        let invalid = |field, reason: String| ProtobufError::InvalidField {
+            id: proto.id.clone(),
+            field,
+            reason,
+        };
+        let timestamp = DateTime::parse_from_rfc3339(&proto.timestamp)
+            .map_err(|e| invalid("timestamp", e.to_string()))?
+            .with_timezone(&Utc);
+        let event_type: AuditEventType = serde_json::from_value(serde_json::Value::String(proto.event_type.clone()))
+            .map_err(|e| invalid("event_type", e.to_string()))?;
+        let details = serde_json::from_str(&proto.details_json).map_err(|e| invalid("details_json", e.to_string()))?;
+
+        Ok(AuditEntry {
+            id: proto.id,
+            timestamp,
+            event_type,
+            actor: proto.actor,
+            actor_seq: proto.actor_seq,
+            target_type: proto.target_type,
+            target_id: proto.target_id,
+            details,
+            previous_hash: proto.previous_hash,
+            hash: proto.hash,
+            hash_version: proto.hash_version,
+        })
+    }
+}
+
+/// Write one entry as a length-delimited message.
+pub fn write_entry(out: &mut impl Write, entry: &AuditEntry) -> Result<(), ProtobufError> {
+    out.write_all(&ProtoEntry::from(entry).encode_length_delimited_to_vec())?;
+    Ok(())
+}
+
+/// Read the next length-delimited entry; `None` at the end of the stream.
+pub fn read_entry(input: &mut impl Read) -> Result<Option<AuditEntry>, ProtobufError> {
+    let mut prefix = Vec::with_capacity(10);
+    let mut byte = [0u8];
+    loop {
+        if input.read(&mut byte)? == 0 {
+            if prefix.is_empty() {
+                return Ok(None);
+            }
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        prefix.push(byte[0]);
+        if byte[0] < 0x80 || prefix.len() == 10 {
+            break;
+        }
+    }
+    let len = prost::encoding::decode_varint(&mut prefix.as_slice())?;
+    if len > MAX_MESSAGE_LEN as u64 {
+        return Err(ProtobufError::TooLarge(len));
+    }
+
+    let mut message = vec![0; len as usize];
+    input.read_exact(&mut message)?;
+    ProtoEntry::decode(message.as_slice())?.try_into().map(Some)
+}
+
+/// Write every entry of `log` to `out`, in chain order.
+///
+/// Entries of an encrypted log are exported decrypted, so the log must
+/// be open with its key.
+pub fn export(log: &AuditLog, out: &mut impl Write) -> Result<usize, ProtobufError> {
+    let entries = log.entries()?;
+    for entry in &entries {
+        write_entry(out, entry)?;
+    }
+    out.flush()?;
+    Ok(entries.len())
+}
+
+/// Write the entries streamed from `input` as a JSONL log at `output`,
+/// which must not already exist. Entries are written as read, not
+/// re-chained.
+pub fn import(input: &mut impl Read, output: &Path) -> Result<usize, ProtobufError> {
+    let file = OpenOptions::new().write(true).create_new(true).open(output)?;
+    let mut out = BufWriter::new(file);
+    let mut imported = 0;
+    while let Some(entry) = read_entry(input)? {
+        serde_json::to_writer(&mut out, &entry).map_err(AuditError::from)?;
+        out.write_all(b"\n")?;
+        imported += 1;
+    }
+    out.into_inner().map_err(io::IntoInnerError::into_error)?.sync_all()?;
+    Ok(imported)
+}
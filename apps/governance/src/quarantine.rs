@@ -0,0 +1,162 @@
+//! Quarantine List
+//!
+//! Persisted index of quarantined adapters with who, when and why.
+//! Every change is appended to the audit log before the list is saved,
+//! so each record corresponds to an `AdapterQuarantined` entry.
+
+use crate::audit::{AuditError, AuditEventType, AuditLog, AuditTarget};
+use openlora_core::{AdapterId, IdError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Default location of the persisted quarantine list.
+pub const DEFAULT_QUARANTINE_PATH: &str = "quarantine.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantineRecord {
+    pub reason: String,
+    pub quarantined_at: DateTime<Utc>,
+    pub quarantined_by: String,
+    /// Audit entry that recorded the quarantine.
+    pub audit_entry_id: String,
+}
+
+#[derive(Debug, Error)]
+pub enum QuarantineError {
+    #[error("Adapter already quarantined: {0}")]
+    AlreadyQuarantined(String),
+    #[error("Adapter not quarantined: {0}")]
+    NotQuarantined(String),
+    #[error("Invalid adapter id: {0}")]
+    InvalidId(#[from] IdError),
+    #[error("Audit error: {0}")]
+    Audit(#[from] AuditError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Adapter ids listed one per line in the file at `path`, for
+/// quarantining in bulk.
+///
+/// Surrounding whitespace is ignored, as are blank lines and lines
+/// starting with `#`.
+pub fn read_adapter_ids(path: &Path) -> Result<Vec<String>, QuarantineError> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+pub struct QuarantineList {
+    path: PathBuf,
+    records: BTreeMap<String, QuarantineRecord>,
+}
+
+impl QuarantineList {
+    /// Open a persisted list; a missing file is an empty list.
+    pub fn open(path: PathBuf) -> Result<Self, QuarantineError> {
+        let records = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self { path, records })
+    }
+
+    fn save(&self) -> Result<(), QuarantineError> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&self.records)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Quarantine an adapter, recording the event in `audit`.
+    pub fn quarantine(
+        &mut self,
+        audit: &mut AuditLog,
+        adapter_id: &str,
+        reason: &str,
+        operator: &str,
+    ) -> Result<QuarantineRecord, QuarantineError> {
+        if self.records.contains_key(adapter_id) {
+            return Err(QuarantineError::AlreadyQuarantined(adapter_id.to_string()));
+        }
+
+        let target = AuditTarget::Adapter(AdapterId::new(adapter_id)?);
+        let entry = audit.append(
+            AuditEventType::AdapterQuarantined,
+            operator,
+            Some(&target),
+            json!({ "reason": reason }),
+        )?;
+
+        let record = QuarantineRecord {
+            reason: reason.to_string(),
+            quarantined_at: entry.timestamp,
+            quarantined_by: operator.to_string(),
+            audit_entry_id: entry.id,
+        };
+        self.records.insert(adapter_id.to_string(), record.clone());
+        self.save()?;
+        Ok(record)
+    }
+
+    /// Release an adapter from quarantine, recording the event in `audit`.
+    pub fn release(
+        &mut self,
+        audit: &mut AuditLog,
+        adapter_id: &str,
+        operator: &str,
+    ) -> Result<QuarantineRecord, QuarantineError> {
+        let Some(record) = self.records.get(adapter_id) else {
+            return Err(QuarantineError::NotQuarantined(adapter_id.to_string()));
+        };
+
+        // Lists written before ids were validated may hold ids that
+        // `AdapterId` rejects; those are recorded as-is.
+        audit.append(
+            AuditEventType::AdapterReleased,
+            operator,
+            Some(&AuditTarget::from_parts("adapter", Some(adapter_id))),
+            json!({ "quarantine_entry_id": record.audit_entry_id }),
+        )?;
+
+        let record = self.records.remove(adapter_id).expect("record checked above");
+        self.save()?;
+        Ok(record)
+    }
+
+    pub fn get(&self, adapter_id: &str) -> Option<&QuarantineRecord> {
+        self.records.get(adapter_id)
+    }
+
+    pub fn is_quarantined(&self, adapter_id: &str) -> bool {
+        self.records.contains_key(adapter_id)
+    }
+
+    /// Quarantined adapters, ordered by adapter id.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &QuarantineRecord)> {
+        self.records.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
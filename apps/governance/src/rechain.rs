@@ -0,0 +1,115 @@
+//! Audit Log Rechaining
+//!
+//! Approved corrections to history, such as removing a secret that was
+//! logged by mistake. The edited log is re-chained from genesis into a
+//! new file, the new head is attested by the approving signer, and the
+//! rewrite is recorded in a separate meta-log with both the old and the
+//! new head, so history is never changed silently. The edited input is
+//! left untouched.
+
+use crate::attest::{attest, AttestError, Attestation};
+use crate::audit::{AuditEntry, AuditError, AuditEventType, AuditLog, AuditTarget};
+use crate::migrate::{migrate_unverified, CompatEntry};
+use ed25519_dalek::SigningKey;
+use serde_json::json;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use thiserror::Error;
+
+/// Default location of the meta-log recording rechains.
+pub const DEFAULT_RECHAIN_LOG: &str = "rechain-meta.jsonl";
+
+#[derive(Debug, Error)]
+pub enum RechainError {
+    #[error("A reason is required to rechain a log")]
+    MissingReason,
+    #[error("Audit error: {0}")]
+    Audit(#[from] AuditError),
+    #[error("Attestation error: {0}")]
+    Attest(#[from] AttestError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Result of rechaining a log.
+#[derive(Debug)]
+pub struct RechainReport {
+    pub entries: usize,
+    /// Head recorded in the edited input, before rechaining.
+    pub old_head: String,
+    pub new_head: String,
+    /// The signer's attestation of the new log.
+    pub attestation: Attestation,
+    /// The meta-log entry recording the rechain.
+    pub record: AuditEntry,
+}
+
+/// Re-chain the edited log `input` into `output`, attest it as `signer_id`
+/// and record the rewrite in `meta_log`.
+///
+/// `output` must not exist. If the rewrite cannot be recorded, `output`
+/// is removed again.
+pub fn rechain(
+    input: &Path,
+    output: &Path,
+    signer_id: &str,
+    key: &SigningKey,
+    reason: &str,
+    meta_log: &mut AuditLog,
+) -> Result<RechainReport, RechainError> {
+    if reason.trim().is_empty() {
+        return Err(RechainError::MissingReason);
+    }
+
+    let old_head = recorded_head(input)?;
+    // The input was edited on purpose and no longer verifies.
+    let migrated = migrate_unverified(input, output)?;
+    let recorded = attest(&AuditLog::open(output.to_path_buf())?, signer_id, key)
+        .map_err(RechainError::from)
+        .and_then(|attestation| {
+            let record = meta_log.append(
+                AuditEventType::AuditLogRechained,
+                signer_id,
+                Some(&AuditTarget::AuditLog(output.display().to_string())),
+                json!({
+                    "reason": reason,
+                    "input": input.display().to_string(),
+                    "entries": migrated.migrated,
+                    "old_head": old_head,
+                    "new_head": migrated.head_hash,
+                    "attestation": attestation,
+                }),
+            )?;
+            Ok((attestation, record))
+        });
+
+    match recorded {
+        Ok((attestation, record)) => Ok(RechainReport {
+            entries: migrated.migrated,
+            old_head,
+            new_head: migrated.head_hash,
+            attestation,
+            record,
+        }),
+        Err(e) => {
+            // An unrecorded rewrite must not be left behind.
+            let _ = std::fs::remove_file(output);
+            Err(e)
+        }
+    }
+}
+
+/// Hash stored on the last entry of `path`, whether or not it still verifies.
+fn recorded_head(path: &Path) -> Result<String, RechainError> {
+    let mut head = "genesis".to_string();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            head = serde_json::from_str::<CompatEntry>(&line)?.hash;
+        }
+    }
+    Ok(head)
+}
@@ -0,0 +1,198 @@
+//! Adapter Registry
+//!
+//! Registered provenance chains, indexed by the content hashes they record,
+//! with each adapter's governance status and labels.
+
+use crate::signatures::ProvenanceEntry;
+use chrono::{DateTime, Utc};
+use openlora_core::AdapterGovernanceStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+
+/// Default location of the persisted registry.
+pub const DEFAULT_REGISTRY_PATH: &str = "registry.json";
+
+#[derive(Debug, Error)]
+pub enum RegistryError {
+    #[error("Empty provenance chain")]
+    EmptyChain,
+    #[error("Chain for {expected} contains entry for {actual}")]
+    MixedAdapters { expected: String, actual: String },
+    #[error("Adapter not registered: {0}")]
+    NotRegistered(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Operator-assigned labels of an adapter, such as `team` or `risk-tier`.
+pub type AdapterLabels = BTreeMap<String, String>;
+
+/// Registered state of a single adapter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegistryEntry {
+    pub chain: Vec<ProvenanceEntry>,
+    /// Governance status, once one has been assigned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AdapterGovernanceStatus>,
+    /// Labels for grouping adapters; copied into audit entries about them.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: AdapterLabels,
+}
+
+#[derive(Debug, Default)]
+pub struct Registry {
+    entries: HashMap<String, RegistryEntry>,
+    /// content_hash -> (timestamp, adapter_id, version) of the earliest
+    /// entry that produced it; ties go to the lower adapter id and version.
+    by_content_hash: HashMap<String, (DateTime<Utc>, String, u32)>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a persisted registry; a missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, RegistryError> {
+        let mut registry = Self::new();
+        if !path.exists() {
+            return Ok(registry);
+        }
+        let entries: BTreeMap<String, RegistryEntry> = serde_json::from_str(&fs::read_to_string(path)?)?;
+        for (adapter_id, entry) in entries {
+            for provenance in &entry.chain {
+                registry.index(provenance);
+            }
+            registry.entries.insert(adapter_id, entry);
+        }
+        Ok(registry)
+    }
+
+    /// Persist every entry, replacing the file at `path` atomically.
+    pub fn save(&self, path: &Path) -> Result<(), RegistryError> {
+        let entries: BTreeMap<&String, &RegistryEntry> = self.entries.iter().collect();
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&entries)?)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Register (or replace) an adapter's provenance chain.
+    pub fn register(&mut self, chain: Vec<ProvenanceEntry>) -> Result<(), RegistryError> {
+        let adapter_id = chain.first().ok_or(RegistryError::EmptyChain)?.adapter_id.clone();
+        if let Some(other) = chain.iter().find(|e| e.adapter_id != adapter_id) {
+            return Err(RegistryError::MixedAdapters {
+                expected: adapter_id,
+                actual: other.adapter_id.clone(),
+            });
+        }
+
+        let (mut status, mut labels) = (None, AdapterLabels::new());
+        if let Some(old) = self.entries.remove(&adapter_id) {
+            status = old.status;
+            labels = old.labels;
+            // Hashes owned by the old chain may also be recorded by other chains.
+            self.by_content_hash.retain(|_, (_, id, _)| *id != adapter_id);
+            let others: Vec<ProvenanceEntry> = self
+                .entries
+                .values()
+                .flat_map(|e| e.chain.iter().cloned())
+                .collect();
+            for entry in &others {
+                self.index(entry);
+            }
+        }
+
+        for entry in &chain {
+            self.index(entry);
+        }
+        self.entries.insert(adapter_id, RegistryEntry { chain, status, labels });
+        Ok(())
+    }
+
+    /// Assign a registered adapter's governance status.
+    pub fn set_status(&mut self, adapter_id: &str, status: AdapterGovernanceStatus) -> Result<(), RegistryError> {
+        let entry = self
+            .entries
+            .get_mut(adapter_id)
+            .ok_or_else(|| RegistryError::NotRegistered(adapter_id.to_string()))?;
+        entry.status = Some(status);
+        Ok(())
+    }
+
+    /// Set a registered adapter's label `key` to `value`, or remove it
+    /// when `value` is `None`.
+    pub fn set_label(&mut self, adapter_id: &str, key: &str, value: Option<&str>) -> Result<(), RegistryError> {
+        let entry = self
+            .entries
+            .get_mut(adapter_id)
+            .ok_or_else(|| RegistryError::NotRegistered(adapter_id.to_string()))?;
+        match value {
+            Some(value) => entry.labels.insert(key.to_string(), value.to_string()),
+            None => entry.labels.remove(key),
+        };
+        Ok(())
+    }
+
+    /// Labels of every adapter that has any, for
+    /// [`AuditLog::set_adapter_labels`](crate::audit::AuditLog::set_adapter_labels).
+    pub fn labels(&self) -> HashMap<String, AdapterLabels> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| !entry.labels.is_empty())
+            .map(|(id, entry)| (id.clone(), entry.labels.clone()))
+            .collect()
+    }
+
+    /// Append a single provenance entry to an adapter's chain.
+    pub fn record(&mut self, entry: ProvenanceEntry) {
+        self.index(&entry);
+        self.entries
+            .entry(entry.adapter_id.clone())
+            .or_default()
+            .chain
+            .push(entry);
+    }
+
+    /// Index `entry`'s content hash, keeping the earliest producer so
+    /// the result does not depend on the order entries are indexed in.
+    fn index(&mut self, entry: &ProvenanceEntry) {
+        let Some(ref content_hash) = entry.content_hash else {
+            return;
+        };
+        let producer = (entry.timestamp, entry.adapter_id.clone(), entry.version);
+        match self.by_content_hash.get_mut(content_hash) {
+            Some(earliest) if producer < *earliest => *earliest = producer,
+            Some(_) => {}
+            None => {
+                self.by_content_hash.insert(content_hash.clone(), producer);
+            }
+        }
+    }
+
+    /// Look up a registered adapter.
+    pub fn get(&self, adapter_id: &str) -> Option<&RegistryEntry> {
+        self.entries.get(adapter_id)
+    }
+
+    /// Registered adapters in id order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RegistryEntry)> {
+        let mut entries: Vec<_> = self.entries.iter().map(|(id, entry)| (id.as_str(), entry)).collect();
+        entries.sort_by_key(|(id, _)| *id);
+        entries.into_iter()
+    }
+
+    /// Find the adapter and version whose provenance recorded `content_hash`.
+    ///
+    /// If several entries recorded it, the earliest is returned.
+    pub fn find_by_content_hash(&self, content_hash: &str) -> Option<(String, u32)> {
+        self.by_content_hash
+            .get(content_hash)
+            .map(|(_, adapter_id, version)| (adapter_id.clone(), *version))
+    }
+}
@@ -0,0 +1,228 @@
+//! Audit Replay
+//!
+//! Rebuild governance state purely from the audit log, which is the
+//! source of truth, and compare it against live state.
+
+use crate::audit::{AuditEntry, AuditError, AuditEventType, AuditLog, AuditTarget};
+use crate::killswitch::KillState;
+use crate::quarantine::QuarantineList;
+use crate::registry::Registry;
+use openlora_core::AdapterGovernanceStatus;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Governance state derived from audit entries.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GovernanceState {
+    pub killed: bool,
+    pub kill_activations: usize,
+    /// Adapters seen in any adapter event.
+    pub known: BTreeSet<String>,
+    pub active: BTreeSet<String>,
+    /// Quarantined adapters and the recorded reason.
+    pub quarantined: BTreeMap<String, String>,
+    pub destroyed: BTreeSet<String>,
+    pub signature_failures: usize,
+    pub integrity_incidents: usize,
+    /// Entries whose event type this build does not know.
+    pub unknown_events: usize,
+    /// Entries replayed.
+    pub entries: usize,
+}
+
+/// A difference between replayed and live state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Discrepancy {
+    KillState { replayed: bool, live: bool },
+    QuarantinedOnlyInLog(String),
+    QuarantinedOnlyInList(String),
+    /// The registry's status for an adapter contradicts its audit history.
+    RegistryStatus {
+        adapter: String,
+        registry: AdapterGovernanceStatus,
+        /// Quarantined or destroyed per the log; `None` if neither.
+        replayed: Option<AdapterGovernanceStatus>,
+    },
+}
+
+impl std::fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Discrepancy::KillState { replayed, live } => write!(
+                f,
+                "kill-switch is {} per the audit log but {} in live state",
+                if *replayed { "active" } else { "inactive" },
+                if *live { "active" } else { "inactive" }
+            ),
+            Discrepancy::QuarantinedOnlyInLog(id) => {
+                write!(f, "{} is quarantined per the audit log but not in the quarantine list", id)
+            }
+            Discrepancy::QuarantinedOnlyInList(id) => {
+                write!(f, "{} is in the quarantine list but not quarantined per the audit log", id)
+            }
+            Discrepancy::RegistryStatus { adapter, registry, replayed } => match replayed {
+                Some(replayed) => write!(
+                    f,
+                    "{} is {:?} in the registry but {:?} per the audit log",
+                    adapter, registry, replayed
+                ),
+                None => write!(
+                    f,
+                    "{} is {:?} in the registry but neither quarantined nor destroyed per the audit log",
+                    adapter, registry
+                ),
+            },
+        }
+    }
+}
+
+impl GovernanceState {
+    /// Fold one audit entry into the state.
+    pub fn apply(&mut self, entry: &AuditEntry) {
+        self.entries += 1;
+        let adapter = match entry.target() {
+            Some(AuditTarget::Adapter(id)) => Some(id.to_string()),
+            // Ids logged before validation that `AdapterId` now rejects.
+            Some(AuditTarget::Other { kind, id }) if kind == "adapter" => id,
+            _ => None,
+        };
+        if let Some(ref id) = adapter {
+            self.known.insert(id.clone());
+        }
+
+        match (&entry.event_type, adapter) {
+            (AuditEventType::AdapterCreated, _) => {}
+            (AuditEventType::AdapterActivated, Some(id)) => {
+                self.active.insert(id);
+            }
+            (AuditEventType::AdapterDeactivated, Some(id)) => {
+                self.active.remove(&id);
+            }
+            (AuditEventType::AdapterQuarantined, Some(id)) => {
+                let reason = entry
+                    .details
+                    .get("reason")
+                    .and_then(|r| r.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                self.active.remove(&id);
+                self.quarantined.insert(id, reason);
+            }
+            (AuditEventType::AdapterReleased, Some(id)) => {
+                self.quarantined.remove(&id);
+            }
+            (AuditEventType::AdapterDestroyed, Some(id)) => {
+                self.active.remove(&id);
+                self.quarantined.remove(&id);
+                self.destroyed.insert(id);
+            }
+            // A shadow activation was only recorded, never enforced
+            (AuditEventType::KillSwitchActivated, _) if entry.details["shadow"] == true => {}
+            (AuditEventType::KillSwitchActivated, _) => {
+                self.killed = true;
+                self.kill_activations += 1;
+            }
+            (AuditEventType::KillSwitchReset | AuditEventType::KillSwitchForceReset, _) => self.killed = false,
+            (AuditEventType::SignatureFailed, _) => self.signature_failures += 1,
+            (AuditEventType::AuditIntegrityFailed, _) => self.integrity_incidents += 1,
+            (AuditEventType::Unknown(_), _) => self.unknown_events += 1,
+            // Adapter events without an adapter target carry no state
+            (
+                AuditEventType::AdapterActivated
+                | AuditEventType::AdapterDeactivated
+                | AuditEventType::AdapterQuarantined
+                | AuditEventType::AdapterReleased
+                | AuditEventType::AdapterDestroyed,
+                None,
+            ) => {}
+            (
+                AuditEventType::SignatureCreated
+                | AuditEventType::SignatureVerified
+                | AuditEventType::SignatureRevoked
+                | AuditEventType::PolicyEvaluated
+                | AuditEventType::AccessDenied
+                | AuditEventType::TrainingStarted
+                | AuditEventType::TrainingCompleted
+                | AuditEventType::TrainingFailed
+                | AuditEventType::AuditLogRechained
+                | AuditEventType::KillStopOverdue
+                | AuditEventType::QuarantineBatchCompleted
+                | AuditEventType::SessionIssued
+                | AuditEventType::SessionEnded
+                | AuditEventType::SessionExpired,
+                _,
+            ) => {}
+        }
+    }
+
+    /// Differences from the live kill-switch state and quarantine list.
+    pub fn compare(&self, kill: &KillState, quarantine: &QuarantineList) -> Vec<Discrepancy> {
+        let mut found = Vec::new();
+        if self.killed != kill.active {
+            found.push(Discrepancy::KillState {
+                replayed: self.killed,
+                live: kill.active,
+            });
+        }
+        for id in self.quarantined.keys() {
+            if !quarantine.is_quarantined(id) {
+                found.push(Discrepancy::QuarantinedOnlyInLog(id.clone()));
+            }
+        }
+        for (id, _) in quarantine.iter() {
+            if !self.quarantined.contains_key(id) {
+                found.push(Discrepancy::QuarantinedOnlyInList(id.clone()));
+            }
+        }
+        found
+    }
+
+    /// Status the audit log establishes for `adapter`.
+    ///
+    /// The log records only quarantine and destruction, so any other
+    /// registry status is consistent with `None`.
+    pub fn adapter_status(&self, adapter: &str) -> Option<AdapterGovernanceStatus> {
+        if self.destroyed.contains(adapter) {
+            Some(AdapterGovernanceStatus::Destroyed)
+        } else if self.quarantined.contains_key(adapter) {
+            Some(AdapterGovernanceStatus::Quarantined)
+        } else {
+            None
+        }
+    }
+
+    /// Registered adapters whose status contradicts the audit log.
+    ///
+    /// Adapters without an assigned status are not checked.
+    pub fn compare_registry(&self, registry: &Registry) -> Vec<Discrepancy> {
+        let mut found = Vec::new();
+        for (adapter, entry) in registry.iter() {
+            let Some(ref status) = entry.status else {
+                continue;
+            };
+            let replayed = self.adapter_status(adapter);
+            let consistent = match replayed {
+                Some(ref replayed) => replayed == status,
+                None => !matches!(
+                    status,
+                    AdapterGovernanceStatus::Quarantined | AdapterGovernanceStatus::Destroyed
+                ),
+            };
+            if !consistent {
+                found.push(Discrepancy::RegistryStatus {
+                    adapter: adapter.to_string(),
+                    registry: status.clone(),
+                    replayed,
+                });
+            }
+        }
+        found
+    }
+}
+
+/// Rebuild governance state by folding over every entry of `log`.
+pub fn replay(log: &AuditLog) -> Result<GovernanceState, AuditError> {
+    Ok(log.entries()?.iter().fold(GovernanceState::default(), |mut state, entry| {
+        state.apply(entry);
+        state
+    }))
+}
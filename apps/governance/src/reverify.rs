@@ -0,0 +1,85 @@
+//! Re-verification After Quarantine
+//!
+//! A signature check can fail for passing reasons: a signer key not yet
+//! distributed, a truncated download. An adapter quarantined over its
+//! signature is therefore checked again straight away by the
+//! [`Reverifier`] registered with
+//! [`Governance::set_reverifier`](crate::governance::Governance::set_reverifier).
+//! The outcome is recorded as `SignatureVerified` or `SignatureFailed`.
+//! With auto-release on, an adapter whose signature now verifies against
+//! a trusted signer is released; genuine failures stay quarantined.
+
+use crate::quarantine::{QuarantineError, QuarantineRecord};
+use crate::signatures::{Signature, SignatureError, SignatureVerifier};
+use std::io;
+
+/// Loads an adapter's content and signature by adapter id.
+pub type FetchSigned = Box<dyn Fn(&str) -> io::Result<(Vec<u8>, Signature)> + Send + Sync>;
+
+/// Whether a quarantine `reason` concerns the adapter's signature, and so
+/// triggers re-verification.
+pub fn is_signature_concern(reason: &str) -> bool {
+    reason.to_ascii_lowercase().contains("signature")
+}
+
+pub struct Reverifier {
+    verifier: SignatureVerifier,
+    fetch: FetchSigned,
+    auto_release: bool,
+}
+
+impl Reverifier {
+    /// Re-verify against `verifier`'s trusted signers, loading adapters
+    /// with `fetch`.
+    pub fn new(
+        verifier: SignatureVerifier,
+        fetch: impl Fn(&str) -> io::Result<(Vec<u8>, Signature)> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            verifier,
+            fetch: Box::new(fetch),
+            auto_release: false,
+        }
+    }
+
+    /// Release adapters whose signature verifies on re-verification.
+    pub fn with_auto_release(mut self, auto_release: bool) -> Self {
+        self.auto_release = auto_release;
+        self
+    }
+
+    pub fn auto_release(&self) -> bool {
+        self.auto_release
+    }
+
+    /// Fetch `adapter_id` and verify its signature, returning the
+    /// signature if it is valid and from a trusted signer.
+    pub fn verify(&self, adapter_id: &str) -> Result<Signature, SignatureError> {
+        let (content, signature) = (self.fetch)(adapter_id)?;
+        if self.verifier.verify(&content, &signature)? {
+            Ok(signature)
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+}
+
+/// Outcome of re-verifying a quarantined adapter.
+#[derive(Debug)]
+pub enum Reverification {
+    /// The signature now verifies.
+    Verified {
+        signer_id: String,
+        /// The release, when auto-release is on.
+        released: Option<Result<QuarantineRecord, QuarantineError>>,
+    },
+    /// The adapter stays quarantined.
+    Failed(SignatureError),
+}
+
+impl Reverification {
+    /// Whether the adapter was released.
+    pub fn is_released(&self) -> bool {
+        matches!(self, Self::Verified { released: Some(Ok(_)), .. })
+    }
+}
@@ -0,0 +1,217 @@
+//! Revocation List
+//!
+//! Signatures and content found to be malicious after signing. A revoked
+//! signature value or content hash is rejected by the verifier before any
+//! cryptographic check, however valid the signature is. Like the
+//! quarantine list, every revocation is appended to the audit log before
+//! the list is saved.
+//!
+//! Entries are kept in one form whatever form they arrive in: signatures
+//! as the lowercase hex of their decoded bytes, content hashes in
+//! lowercase, so neither re-encoding a signature as base64 nor changing
+//! the case of a hash slips past a revocation.
+
+use crate::audit::{AuditError, AuditEventType, AuditLog, AuditTarget};
+use crate::signatures::{Signature, SignatureEncoding, SignatureError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Default location of the persisted revocation list.
+pub const DEFAULT_REVOCATION_PATH: &str = "revocations.json";
+
+/// What a revocation matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Revoked {
+    /// A signature's raw bytes, hex encoded; see [`Revoked::signature`]
+    /// for signatures written in another encoding.
+    Signature(String),
+    /// SHA-256 of the signed content, hex encoded.
+    Content(String),
+}
+
+impl Revoked {
+    /// Revoke `signature` by its decoded bytes, however its value is encoded.
+    pub fn signature(signature: &Signature) -> Result<Self, SignatureError> {
+        Ok(Revoked::Signature(hex::encode(signature.decoded_value()?)))
+    }
+
+    /// The form the list stores and looks up.
+    fn normalized(&self) -> Self {
+        match self {
+            Revoked::Signature(value) => Revoked::Signature(value.to_ascii_lowercase()),
+            Revoked::Content(hash) => Revoked::Content(hash.to_ascii_lowercase()),
+        }
+    }
+}
+
+impl std::fmt::Display for Revoked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Revoked::Signature(value) => write!(f, "signature {}", value),
+            Revoked::Content(hash) => write!(f, "content {}", hash),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevocationRecord {
+    pub reason: String,
+    pub revoked_at: DateTime<Utc>,
+    pub revoked_by: String,
+    /// Audit entry that recorded the revocation.
+    pub audit_entry_id: String,
+}
+
+#[derive(Debug, Error)]
+pub enum RevocationError {
+    #[error("Already revoked: {0}")]
+    AlreadyRevoked(Revoked),
+    #[error("Audit error: {0}")]
+    Audit(#[from] AuditError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Records {
+    #[serde(default)]
+    signatures: BTreeMap<String, RevocationRecord>,
+    #[serde(default)]
+    content_hashes: BTreeMap<String, RevocationRecord>,
+}
+
+#[derive(Debug)]
+pub struct RevocationList {
+    path: PathBuf,
+    records: Records,
+}
+
+impl RevocationList {
+    /// Open a persisted list; a missing file is an empty list.
+    ///
+    /// Entries written before values were normalized, such as base64
+    /// signatures, are normalized as they are read.
+    pub fn open(path: PathBuf) -> Result<Self, RevocationError> {
+        let records: Records = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            Records::default()
+        };
+        let records = Records {
+            signatures: records
+                .signatures
+                .into_iter()
+                .map(|(value, record)| (normalize_stored_signature(value), record))
+                .collect(),
+            content_hashes: records
+                .content_hashes
+                .into_iter()
+                .map(|(hash, record)| (hash.to_ascii_lowercase(), record))
+                .collect(),
+        };
+        Ok(Self { path, records })
+    }
+
+    /// Re-read the file, picking up revocations made by other processes.
+    pub fn reload(&mut self) -> Result<(), RevocationError> {
+        *self = Self::open(self.path.clone())?;
+        Ok(())
+    }
+
+    fn save(&self) -> Result<(), RevocationError> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&self.records)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Revoke a signature or content hash, recording the event in `audit`.
+    pub fn revoke(
+        &mut self,
+        audit: &mut AuditLog,
+        revoked: Revoked,
+        reason: &str,
+        operator: &str,
+    ) -> Result<RevocationRecord, RevocationError> {
+        let revoked = revoked.normalized();
+        if self.get(&revoked).is_some() {
+            return Err(RevocationError::AlreadyRevoked(revoked));
+        }
+
+        let (target, records, value) = match &revoked {
+            Revoked::Signature(value) => (
+                AuditTarget::Signature(value.clone()),
+                &mut self.records.signatures,
+                value,
+            ),
+            Revoked::Content(hash) => (
+                AuditTarget::Content(hash.clone()),
+                &mut self.records.content_hashes,
+                hash,
+            ),
+        };
+        let entry = audit.append(
+            AuditEventType::SignatureRevoked,
+            operator,
+            Some(&target),
+            json!({ "reason": reason }),
+        )?;
+
+        let record = RevocationRecord {
+            reason: reason.to_string(),
+            revoked_at: entry.timestamp,
+            revoked_by: operator.to_string(),
+            audit_entry_id: entry.id,
+        };
+        records.insert(value.clone(), record.clone());
+        self.save()?;
+        Ok(record)
+    }
+
+    pub fn get(&self, revoked: &Revoked) -> Option<&RevocationRecord> {
+        match revoked.normalized() {
+            Revoked::Signature(value) => self.records.signatures.get(&value),
+            Revoked::Content(hash) => self.records.content_hashes.get(&hash),
+        }
+    }
+
+    /// Whether the signature with decoded bytes `value` is revoked.
+    pub fn is_signature_revoked(&self, value: &[u8]) -> bool {
+        self.records.signatures.contains_key(&hex::encode(value))
+    }
+
+    pub fn is_content_revoked(&self, content_hash: &str) -> bool {
+        self.records.content_hashes.contains_key(&content_hash.to_ascii_lowercase())
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.signatures.len() + self.records.content_hashes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// A stored signature value as lowercase hex: hex as written, or base64
+/// from lists that kept values in their original encoding.
+fn normalize_stored_signature(value: String) -> String {
+    if let Ok(bytes) = SignatureEncoding::Hex.decode(&value) {
+        return hex::encode(bytes);
+    }
+    match SignatureEncoding::Base64.decode(&value) {
+        Ok(bytes) => hex::encode(bytes),
+        Err(_) => value,
+    }
+}
@@ -0,0 +1,119 @@
+//! Operator Sessions
+//!
+//! Time-limited kill-switch authority. An operator who has authenticated
+//! out of band is issued an [`OperatorSession`]; actions presenting its
+//! token are attributed to that operator until the session expires or is
+//! ended. A leaked token is useful only until then.
+//!
+//! Sessions are an additional way to authorize, not a replacement: the
+//! operator-id entry points remain, and whoever can call them with an
+//! authorized id still has its authority. Deployments that want sessions
+//! only must expose just [`KillSwitch::activate_with_session`] and
+//! [`KillSwitch::reset_with_sessions`].
+//!
+//! [`KillSwitch::activate_with_session`]: crate::killswitch::KillSwitch::activate_with_session
+//! [`KillSwitch::reset_with_sessions`]: crate::killswitch::KillSwitch::reset_with_sessions
+//!
+//! Only a SHA-256 digest of each token is kept, so persisted state never
+//! holds a usable token.
+
+use chrono::{DateTime, Duration, Utc};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use openlora_core::hashing::content_hash;
+
+/// Session lifetime used when none is given, in seconds.
+pub const DEFAULT_SESSION_TTL_SECS: u64 = 3600;
+
+/// How long expired sessions are remembered, so a late presenter is told
+/// the session expired rather than that it is unknown.
+pub const EXPIRED_SESSION_RETENTION: Duration = Duration::days(1);
+
+/// A session as handed to the operator.
+#[derive(Debug, Clone)]
+pub struct OperatorSession {
+    /// Public identifier, safe to log.
+    pub id: String,
+    /// Secret presented to authorize actions.
+    pub token: String,
+    pub operator: String,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// What is persisted about a session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub id: String,
+    pub operator: String,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    token_sha256: String,
+}
+
+impl SessionRecord {
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Sessions issued by a kill-switch, persisted with its state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Sessions {
+    records: Vec<SessionRecord>,
+}
+
+impl Sessions {
+    /// Issue a session for `operator`, valid for `ttl` from `issued_at`.
+    pub(crate) fn issue(&mut self, operator: &str, ttl: Duration, issued_at: DateTime<Utc>) -> OperatorSession {
+        self.records
+            .retain(|record| record.expires_at + EXPIRED_SESSION_RETENTION > issued_at);
+
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        let session = OperatorSession {
+            id: uuid::Uuid::new_v4().to_string(),
+            token: hex::encode(secret),
+            operator: operator.to_string(),
+            issued_at,
+            expires_at: issued_at + ttl,
+        };
+        self.records.push(SessionRecord {
+            id: session.id.clone(),
+            operator: session.operator.clone(),
+            issued_at: session.issued_at,
+            expires_at: session.expires_at,
+            token_sha256: token_digest(&session.token),
+        });
+        session
+    }
+
+    /// The session `token` belongs to, expired or not.
+    pub fn find(&self, token: &str) -> Option<&SessionRecord> {
+        let digest = token_digest(token);
+        self.records.iter().find(|record| record.token_sha256 == digest)
+    }
+
+    /// End a session before it expires.
+    pub(crate) fn end(&mut self, id: &str) -> Option<SessionRecord> {
+        let index = self.records.iter().position(|record| record.id == id)?;
+        Some(self.records.remove(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SessionRecord> {
+        self.records.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+fn token_digest(token: &str) -> String {
+    content_hash(token.as_bytes())
+}
@@ -1,29 +1,134 @@
 //! Signature Verification
 //!
 //! Verify adapter signatures and provenance chains.
+//!
+//! For air-gapped signers, a verifier can also issue single-use
+//! [`Challenge`]s: an offline hardware token signs the bytes of
+//! [`Challenge::message`] as they are, and the verifier checks the
+//! response without the private key ever reaching the service.
 
-use chrono::{DateTime, Utc};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use chrono::{DateTime, Duration, Utc};
+use ed25519_dalek::pkcs8::spki::der::{pem, Decode};
+use ed25519_dalek::pkcs8::spki::SubjectPublicKeyInfoRef;
+use ed25519_dalek::pkcs8::DecodePublicKey;
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use openlora_core::canonical::{canonical_timestamp, to_canonical_cbor};
+use openlora_core::hashing::{Hasher, SHORT_HASH_LEN};
+use crate::audit::{AuditError, AuditEventType, AuditLog, AuditTarget};
+use crate::clock::{Clock, SystemClock};
+use crate::revocation::{RevocationList, Revoked};
+use crate::source::{Compression, ContentSource, SourceError};
+use openlora_core::{validate_principal_id, IdError, ProvenanceOperation};
+use rand_core::{OsRng, RngCore};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+/// Algorithm name for Ed25519 signatures.
+pub const ED25519_ALGORITHM: &str = "ed25519";
+
+/// Algorithm name for legacy signatures: a truncated SHA-256 over the
+/// signed fields, with no key. Accepted only when a verifier opts in with
+/// [`SignatureVerifier::set_allow_legacy_sha256`].
+pub const LEGACY_SHA256_ALGORITHM: &str = "sha256";
+
+/// Default location of persisted per-signer verification counts.
+pub const DEFAULT_SIGNER_METRICS_PATH: &str = "signer-metrics.json";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Signature {
     pub algorithm: String,
     pub value: String,
     pub signer_id: String,
     pub signed_at: DateTime<Utc>,
+    /// End of the signature's validity window, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Compression of the signed artifact on disk. Informational and not
+    /// covered by the signature, which is over the decompressed content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_compression: Option<Compression>,
+    /// SHA-256 of the signed content, hex encoded, so a detached
+    /// signature names what it covers. Checked before the signature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    /// How `value` is written; hex when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<SignatureEncoding>,
+}
+
+impl Signature {
+    /// The raw signature bytes, decoded per [`Signature::encoding`].
+    pub fn decoded_value(&self) -> Result<Vec<u8>, SignatureError> {
+        self.encoding.unwrap_or_default().decode(&self.value)
+    }
+
+    /// The same signature with its value re-encoded as `encoding`.
+    pub fn encoded_as(mut self, encoding: SignatureEncoding) -> Result<Self, SignatureError> {
+        self.value = encoding.encode(&self.decoded_value()?);
+        self.encoding = Some(encoding);
+        Ok(self)
+    }
+}
+
+/// Text encoding of a detached signature's value.
+///
+/// Hex is what this crate writes; base64 is what most external tools
+/// (openssl, age) emit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureEncoding {
+    #[default]
+    Hex,
+    /// Standard alphabet, padded.
+    Base64,
+}
+
+impl SignatureEncoding {
+    pub fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            SignatureEncoding::Hex => hex::encode(bytes),
+            SignatureEncoding::Base64 => BASE64_STANDARD.encode(bytes),
+        }
+    }
+
+    /// Decode `value`, rejecting anything malformed for this encoding.
+    pub fn decode(self, value: &str) -> Result<Vec<u8>, SignatureError> {
+        let decoded = match self {
+            SignatureEncoding::Hex => hex::decode(value).map_err(|e| e.to_string()),
+            SignatureEncoding::Base64 => BASE64_STANDARD.decode(value).map_err(|e| e.to_string()),
+        };
+        decoded.map_err(|e| SignatureError::InvalidEncoding(format!("{} value {:?}: {}", self, value, e)))
+    }
+}
+
+impl std::fmt::Display for SignatureEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SignatureEncoding::Hex => "hex",
+            SignatureEncoding::Base64 => "base64",
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProvenanceEntry {
     pub adapter_id: String,
     pub version: u32,
-    pub operation: String,
+    /// Stored as its variant name; unknown names fail to deserialize.
+    pub operation: ProvenanceOperation,
     pub actor: String,
     pub timestamp: DateTime<Utc>,
     pub signature: Option<Signature>,
     pub parent_hash: Option<String>,
+    /// Hash of the adapter content produced by this operation, if recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
     pub hash: String,
 }
 
@@ -35,17 +140,735 @@ pub enum SignatureError {
     Expired,
     #[error("Unknown signer: {0}")]
     UnknownSigner(String),
+    #[error("Revoked: {0}")]
+    Revoked(Revoked),
+    #[error("Content mismatch: signature covers {expected}, content hashes to {actual}")]
+    ContentMismatch { expected: String, actual: String },
+    #[error("Signer {signer} was not trusted at signing time {signed_at}")]
+    SignerNotTrustedAtTime { signer: String, signed_at: DateTime<Utc> },
     #[error("Provenance chain broken at {0}")]
     BrokenChain(String),
+    #[error("Provenance entry {index} ({operation}) signed by {signer}: {source}")]
+    EntrySignature {
+        index: usize,
+        operation: ProvenanceOperation,
+        signer: String,
+        source: Box<SignatureError>,
+    },
+    #[error("Provenance entry {index} ({operation}) must be signed by a signer with role {role}{}", signer.as_ref().map(|s| format!(", not {}", s)).unwrap_or_default())]
+    MissingRole {
+        index: usize,
+        operation: ProvenanceOperation,
+        signer: Option<String>,
+        role: String,
+    },
+    #[error("Invalid signature encoding: {0}")]
+    InvalidEncoding(String),
+    #[error("Invalid public key {path}: {reason}")]
+    InvalidKey { path: PathBuf, reason: String },
+    #[error("No public key registered for signer: {0}")]
+    MissingKey(String),
+    #[error("IO error reading signed content: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Audit log error: {0}")]
+    Audit(#[from] AuditError),
+    #[error("No signature verified{}", describe_failures(failures))]
+    NoneVerified {
+        /// Each signature's signer and why it failed, in the order given.
+        failures: Vec<(String, SignatureError)>,
+    },
+    #[error("Provenance chain too long: {len} entries (max {max})")]
+    ChainTooLong { len: usize, max: usize },
+    #[error("Provenance chain too large: over {max} bytes")]
+    ChainTooLarge { max: usize },
+    #[error(transparent)]
+    InvalidId(#[from] IdError),
+    #[error("Timestamp {at} is more than {max_skew} ahead of the verifier's clock ({now})")]
+    FutureTimestamp {
+        at: DateTime<Utc>,
+        now: DateTime<Utc>,
+        max_skew: Duration,
+    },
+    #[error("Invalid {TRUSTED_SIGNERS_ENV} entry {entry:?}: {reason}")]
+    InvalidSignerEntry { entry: String, reason: String },
+    #[error("Unknown or already used challenge: {0}")]
+    UnknownChallenge(String),
+    #[error("Challenge {0} does not match the one issued")]
+    ChallengeMismatch(String),
+    #[error("Challenge {nonce} expired at {expires_at}")]
+    ChallengeExpired { nonce: String, expires_at: DateTime<Utc> },
+    #[error("Signature algorithm {algorithm:?} not accepted for signer {signer}")]
+    UnsupportedAlgorithm { signer: String, algorithm: String },
+}
+
+impl From<openlora_core::Signature> for Signature {
+    fn from(signature: openlora_core::Signature) -> Self {
+        Self {
+            algorithm: signature.algorithm,
+            value: hex::encode(signature.value),
+            signer_id: signature.signer_id,
+            signed_at: signature.signed_at,
+            expires_at: signature.expires_at,
+            artifact_compression: None,
+            content_hash: None,
+            encoding: None,
+        }
+    }
+}
+
+impl TryFrom<Signature> for openlora_core::Signature {
+    type Error = SignatureError;
+
+    fn try_from(signature: Signature) -> Result<Self, Self::Error> {
+        let value = signature.decoded_value()?;
+        Ok(Self {
+            algorithm: signature.algorithm,
+            value,
+            signer_id: signature.signer_id,
+            signed_at: signature.signed_at,
+            expires_at: signature.expires_at,
+        })
+    }
+}
+
+/// Assurance level of a verified signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerificationOutcome {
+    /// Valid, and signed by a trusted signer other than the creator.
+    TrustedExternal,
+    /// Valid, but signed only by the content's own creator.
+    SelfSigned,
+    /// Valid, but the content's creator is unknown, so whether the signer
+    /// is independent of it cannot be judged.
+    UnknownCreator,
+    /// The signature does not match the content.
+    Failed,
+}
+
+impl VerificationOutcome {
+    /// Whether the signature is valid at all, whatever its assurance.
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, Self::Failed)
+    }
+}
+
+/// A period during which a signer is trusted; open ends are unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustInterval {
+    pub from: Option<DateTime<Utc>>,
+    /// Revocation time; signatures made at or after it are rejected.
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl TrustInterval {
+    pub fn contains(&self, at: &DateTime<Utc>) -> bool {
+        self.from.is_none_or(|from| *at >= from) && self.until.is_none_or(|until| *at < until)
+    }
+}
+
+/// Hit and miss counts of the verification cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub len: usize,
+}
+
+/// Verification outcomes for one signer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerStats {
+    pub verified: u64,
+    /// Invalid, revoked, untrusted at signing time, or over other content.
+    pub failed: u64,
+    /// Signatures claiming a signer that is not trusted at all.
+    pub unknown_signer: u64,
+    /// Signatures found to have been used past their expiry.
+    pub expired: u64,
+}
+
+impl SignerStats {
+    pub fn total(&self) -> u64 {
+        self.verified + self.failed + self.unknown_signer + self.expired
+    }
+
+    /// Share of verifications that did not succeed; 0 when there were none.
+    pub fn failure_rate(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => (total - self.verified) as f64 / total as f64,
+        }
+    }
+
+    fn add(&mut self, other: &SignerStats) {
+        self.verified += other.verified;
+        self.failed += other.failed;
+        self.unknown_signer += other.unknown_signer;
+        self.expired += other.expired;
+    }
+}
+
+/// Verification counts per signer id, persisted as a JSON object.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SignerReport {
+    signers: BTreeMap<String, SignerStats>,
+}
+
+impl SignerReport {
+    /// Load a persisted report; a missing file is an empty report.
+    pub fn load(path: &Path) -> Result<Self, SignatureError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = std::fs::read_to_string(path)?;
+        serde_json::from_str(&raw).map_err(|e| SignatureError::InvalidEncoding(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Persist atomically: sync a temporary file, then rename it into place.
+    pub fn save(&self, path: &Path) -> Result<(), SignatureError> {
+        let tmp = path.with_extension("tmp");
+        let json = serde_json::to_string_pretty(self).map_err(|e| SignatureError::InvalidEncoding(e.to_string()))?;
+        let mut file = std::fs::File::create(&tmp)?;
+        file.write_all(json.as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, path)?;
+        crate::killswitch::sync_parent(path)?;
+        Ok(())
+    }
+
+    /// Add `other`'s counts to this report.
+    pub fn merge(&mut self, other: &SignerReport) {
+        for (signer, stats) in &other.signers {
+            self.signers.entry(signer.clone()).or_default().add(stats);
+        }
+    }
+
+    pub fn get(&self, signer_id: &str) -> Option<&SignerStats> {
+        self.signers.get(signer_id)
+    }
+
+    /// Signers in id order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SignerStats)> {
+        self.signers.iter().map(|(signer, stats)| (signer.as_str(), stats))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.signers.is_empty()
+    }
+}
+
+/// Cached verdict for one signature over one message.
+///
+/// The message digest already covers the content hash, signer and
+/// validity window, so a changed timestamp never reuses a verdict. The
+/// value is keyed decoded: the same text read as hex and as base64 is two
+/// different signatures.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    message: String,
+    signer_id: String,
+    algorithm: String,
+    value: Vec<u8>,
+}
+
+/// Bounded least-recently-used map of verification results.
+struct VerifyCache {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<CacheKey, (bool, u64)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl VerifyCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<bool> {
+        self.tick += 1;
+        match self.entries.get_mut(key) {
+            Some((valid, last_used)) => {
+                *last_used = self.tick;
+                self.hits += 1;
+                Some(*valid)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, key: CacheKey, valid: bool) {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            // Linear scan; the cache is small and misses already pay for crypto
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, (valid, self.tick));
+    }
+}
+
+/// How far ahead of the verifier's clock a signature may be dated, by default.
+pub const DEFAULT_MAX_FUTURE_SKEW_SECS: i64 = 300;
+
+/// Environment variable listing trusted signers; see
+/// [`SignatureVerifier::from_env`].
+pub const TRUSTED_SIGNERS_ENV: &str = "OPENLORA_TRUSTED_SIGNERS";
+
+/// How long a [`Challenge`] may be answered, by default.
+pub const DEFAULT_CHALLENGE_TTL_SECS: i64 = 300;
+
+/// Domain separating signed challenges from signed content.
+const CHALLENGE_DOMAIN: &str = "openlora-challenge-v1";
+
+/// A single-use, nonce-bound message for an offline signer, from
+/// [`SignatureVerifier::issue_challenge`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Challenge {
+    /// Random 256-bit nonce, hex encoded.
+    pub nonce: String,
+    /// SHA-256 of the content the response vouches for.
+    pub content_hash: String,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Challenge {
+    /// The bytes the token signs with Ed25519, unhashed: canonical CBOR
+    /// of the challenge.
+    pub fn message(&self) -> Vec<u8> {
+        #[derive(Serialize)]
+        struct Signed<'a> {
+            domain: &'a str,
+            nonce: &'a str,
+            content_hash: &'a str,
+            issued_at: String,
+            expires_at: String,
+        }
+
+        to_canonical_cbor(&Signed {
+            domain: CHALLENGE_DOMAIN,
+            nonce: &self.nonce,
+            content_hash: &self.content_hash,
+            issued_at: canonical_timestamp(&self.issued_at),
+            expires_at: canonical_timestamp(&self.expires_at),
+        })
+        .expect("string fields always encode")
+    }
+
+    /// Answer the challenge as `signer_id` with `key`, as a token would.
+    ///
+    /// Only `value`, the signature over [`Challenge::message`], is checked
+    /// by [`SignatureVerifier::verify_challenge_response`].
+    pub fn sign(&self, signer_id: &str, key: &SigningKey) -> Signature {
+        Signature {
+            algorithm: ED25519_ALGORITHM.to_string(),
+            value: hex::encode(key.sign(&self.message()).to_bytes()),
+            signer_id: signer_id.to_string(),
+            signed_at: Utc::now(),
+            expires_at: None,
+            artifact_compression: None,
+            content_hash: None,
+            encoding: None,
+        }
+    }
 }
 
 pub struct SignatureVerifier {
     trusted_signers: Vec<String>,
+    keys: HashMap<String, VerifyingKey>,
+    /// Signers without a timeline are trusted at any time.
+    timelines: HashMap<String, Vec<TrustInterval>>,
+    roles: HashMap<String, HashSet<String>>,
+    /// Role a provenance entry's signer must hold, by operation.
+    required_roles: HashMap<ProvenanceOperation, String>,
+    max_chain_len: Option<usize>,
+    max_chain_bytes: Option<usize>,
+    cache: Option<Mutex<VerifyCache>>,
+    revocations: Option<Arc<RwLock<RevocationList>>>,
+    /// Behind a lock so verification can take `&self` on a shared verifier.
+    stats: Mutex<HashMap<String, SignerStats>>,
+    max_future_skew: Option<Duration>,
+    clock: Arc<dyn Clock>,
+    /// Challenges issued and not yet answered, by nonce.
+    challenges: Mutex<HashMap<String, Challenge>>,
+    challenge_ttl: Duration,
+    allow_legacy_sha256: bool,
 }
 
 impl SignatureVerifier {
     pub fn new(trusted_signers: Vec<String>) -> Self {
-        Self { trusted_signers }
+        Self {
+            trusted_signers,
+            keys: HashMap::new(),
+            timelines: HashMap::new(),
+            roles: HashMap::new(),
+            required_roles: HashMap::new(),
+            max_chain_len: None,
+            max_chain_bytes: None,
+            cache: None,
+            revocations: None,
+            stats: Mutex::new(HashMap::new()),
+            max_future_skew: Some(Duration::seconds(DEFAULT_MAX_FUTURE_SKEW_SECS)),
+            clock: Arc::new(SystemClock),
+            challenges: Mutex::new(HashMap::new()),
+            challenge_ttl: Duration::seconds(DEFAULT_CHALLENGE_TTL_SECS),
+            allow_legacy_sha256: false,
+        }
+    }
+
+    /// Like [`SignatureVerifier::new`], but refuses empty, padded or
+    /// control-character signer ids; see [`validate_principal_id`].
+    pub fn try_new(trusted_signers: Vec<String>) -> Result<Self, SignatureError> {
+        for signer in &trusted_signers {
+            validate_principal_id("signer id", signer)?;
+        }
+        Ok(Self::new(trusted_signers))
+    }
+
+    /// A verifier trusting the signers in [`TRUSTED_SIGNERS_ENV`], or
+    /// `None` if it is unset or empty; see
+    /// [`SignatureVerifier::parse_signer_list`] for the format.
+    pub fn from_env() -> Result<Option<Self>, SignatureError> {
+        match std::env::var(TRUSTED_SIGNERS_ENV) {
+            Ok(list) if !list.trim().is_empty() => Self::parse_signer_list(&list).map(Some),
+            Ok(_) | Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(e) => Err(SignatureError::InvalidSignerEntry {
+                entry: String::new(),
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    /// A verifier trusting a comma-separated list of signers, each either
+    /// `id` or `id=<key>` with the base64 of its raw 32-byte Ed25519
+    /// public key. Whitespace around entries and empty entries are ignored.
+    pub fn parse_signer_list(list: &str) -> Result<Self, SignatureError> {
+        let invalid = |entry: &str, reason: String| SignatureError::InvalidSignerEntry {
+            entry: entry.to_string(),
+            reason,
+        };
+
+        let mut verifier = Self::new(Vec::new());
+        for entry in list.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (id, key) = match entry.split_once('=') {
+                Some((id, key)) => (id.trim(), Some(key.trim())),
+                None => (entry, None),
+            };
+            validate_principal_id("signer id", id).map_err(|e| invalid(entry, e.to_string()))?;
+            if verifier.trusted_signers.iter().any(|s| s == id) {
+                return Err(invalid(entry, "signer listed more than once".to_string()));
+            }
+            verifier.trusted_signers.push(id.to_string());
+
+            if let Some(key) = key {
+                let bytes = BASE64_STANDARD
+                    .decode(key)
+                    .map_err(|e| invalid(entry, format!("public key is not base64: {}", e)))?;
+                let bytes: [u8; 32] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+                    invalid(entry, format!("public key is {} bytes, expected 32", bytes.len()))
+                })?;
+                let key = VerifyingKey::from_bytes(&bytes)
+                    .map_err(|_| invalid(entry, "not an Ed25519 public key".to_string()))?;
+                verifier.add_signer_key(id, key);
+            }
+        }
+        Ok(verifier)
+    }
+
+    /// Verification counts per signer since this verifier was created.
+    pub fn report(&self) -> SignerReport {
+        let stats = self.stats.lock().unwrap_or_else(PoisonError::into_inner);
+        SignerReport {
+            signers: stats.iter().map(|(signer, stats)| (signer.clone(), *stats)).collect(),
+        }
+    }
+
+    /// Count the outcome of verifying a signature by `signer_id`.
+    ///
+    /// IO errors say nothing about the signer and are not counted.
+    fn record(&self, signer_id: &str, result: &Result<bool, SignatureError>) {
+        if let Err(SignatureError::Io(_)) = result {
+            return;
+        }
+        let mut stats = self.stats.lock().unwrap_or_else(PoisonError::into_inner);
+        let stats = stats.entry(signer_id.to_string()).or_default();
+        match result {
+            Ok(true) => stats.verified += 1,
+            Err(SignatureError::UnknownSigner(_)) => stats.unknown_signer += 1,
+            Err(SignatureError::Expired) => stats.expired += 1,
+            Ok(false) | Err(_) => stats.failed += 1,
+        }
+    }
+
+    /// Reject signatures and content on `list` before any crypto.
+    ///
+    /// The list is shared, so revocations made through another handle
+    /// apply to the next verification.
+    pub fn set_revocation_list(&mut self, list: Arc<RwLock<RevocationList>>) {
+        self.revocations = Some(list);
+    }
+
+    /// Cache up to `capacity` verification results; 0 disables caching.
+    ///
+    /// Replaces any existing cache, resetting its counters.
+    pub fn set_cache_capacity(&mut self, capacity: usize) {
+        self.cache = (capacity > 0).then(|| Mutex::new(VerifyCache::new(capacity)));
+    }
+
+    /// Verification cache counters; all zero when caching is disabled.
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache.as_ref().map_or_else(CacheStats::default, |cache| {
+            let cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+            CacheStats {
+                hits: cache.hits,
+                misses: cache.misses,
+                len: cache.entries.len(),
+            }
+        })
+    }
+
+    /// Drop cached results, e.g. after the trusted signers change.
+    fn invalidate_cache(&mut self) {
+        if let Some(cache) = self.cache.as_mut() {
+            cache.get_mut().unwrap_or_else(PoisonError::into_inner).entries.clear();
+        }
+    }
+
+    /// Trust `signer_id` only for signatures made within `intervals`.
+    ///
+    /// `signed_at` is covered by the signature, so it cannot be moved by a
+    /// third party; it is still the signer's own claim, and a compromised
+    /// key can backdate. Revoke at the earliest suspected compromise.
+    pub fn set_trust_timeline(&mut self, signer_id: &str, intervals: Vec<TrustInterval>) {
+        self.timelines.insert(signer_id.to_string(), intervals);
+    }
+
+    /// Stop trusting signatures `signer_id` makes at or after `at`.
+    ///
+    /// Signatures from earlier trusted periods remain valid.
+    pub fn revoke_signer(&mut self, signer_id: &str, at: DateTime<Utc>) {
+        let timeline = self.timelines.entry(signer_id.to_string()).or_insert_with(|| {
+            vec![TrustInterval {
+                from: None,
+                until: None,
+            }]
+        });
+        timeline.retain(|interval| interval.from.is_none_or(|from| from < at));
+        for interval in timeline.iter_mut() {
+            if interval.until.is_none_or(|until| until > at) {
+                interval.until = Some(at);
+            }
+        }
+    }
+
+    /// Whether `signer_id` is trusted for a signature made at `at`.
+    pub fn is_trusted_at(&self, signer_id: &str, at: &DateTime<Utc>) -> bool {
+        if !self.trusted_signers.iter().any(|s| s == signer_id) {
+            return false;
+        }
+        self.timelines
+            .get(signer_id)
+            .is_none_or(|timeline| timeline.iter().any(|interval| interval.contains(at)))
+    }
+
+    /// Replace the roles held by `signer_id`, such as `trainer` or `reviewer`.
+    pub fn set_signer_roles(&mut self, signer_id: &str, roles: impl IntoIterator<Item = String>) {
+        self.roles.insert(signer_id.to_string(), roles.into_iter().collect());
+    }
+
+    pub fn has_role(&self, signer_id: &str, role: &str) -> bool {
+        self.roles.get(signer_id).is_some_and(|roles| roles.contains(role))
+    }
+
+    /// Require provenance entries recording `operation` to be signed by a
+    /// signer holding `role`.
+    pub fn require_role(&mut self, operation: ProvenanceOperation, role: &str) {
+        self.required_roles.insert(operation, role.to_string());
+    }
+
+    /// Bound the provenance chains this verifier will process.
+    ///
+    /// Chains from untrusted peers are rejected before any hashing when
+    /// they exceed `max_len` entries or `max_bytes` of variable-length
+    /// field data. `None` leaves a dimension unbounded.
+    pub fn set_chain_limits(&mut self, max_len: Option<usize>, max_bytes: Option<usize>) {
+        self.max_chain_len = max_len;
+        self.max_chain_bytes = max_bytes;
+    }
+
+    /// Reject signatures, and provenance entries, dated more than
+    /// `max_skew` ahead of the verifier's clock: either a clock is wrong or
+    /// the date is forged. `None` accepts any date.
+    ///
+    /// Defaults to [`DEFAULT_MAX_FUTURE_SKEW_SECS`].
+    pub fn set_max_future_skew(&mut self, max_skew: Option<Duration>) {
+        self.max_future_skew = max_skew;
+    }
+
+    /// Accept legacy [`LEGACY_SHA256_ALGORITHM`] signatures from signers
+    /// with no registered key. Off by default: anyone who knows the
+    /// content and a signer id can compute one. Signers with a key must
+    /// always sign with Ed25519.
+    pub fn set_allow_legacy_sha256(&mut self, allow: bool) {
+        self.allow_legacy_sha256 = allow;
+        self.invalidate_cache();
+    }
+
+    /// How long challenges issued from now on may be answered.
+    pub fn set_challenge_ttl(&mut self, ttl: Duration) {
+        self.challenge_ttl = ttl;
+    }
+
+    /// Issue a challenge binding a fresh nonce to `content`, for an
+    /// offline signer to sign with [`Challenge::message`].
+    ///
+    /// Expired challenges are forgotten as new ones are issued.
+    pub fn issue_challenge(&self, content: &[u8]) -> Challenge {
+        let mut nonce = [0u8; 32];
+        OsRng.fill_bytes(&mut nonce);
+        let issued_at = self.clock.now();
+        let challenge = Challenge {
+            nonce: hex::encode(nonce),
+            content_hash: Hasher::new().update(content).finish(),
+            issued_at,
+            expires_at: issued_at + self.challenge_ttl,
+        };
+
+        let mut challenges = self.challenges.lock().unwrap_or_else(PoisonError::into_inner);
+        challenges.retain(|_, issued| issued.expires_at > issued_at);
+        challenges.insert(challenge.nonce.clone(), challenge.clone());
+        challenge
+    }
+
+    /// Verify `signature` as the response to `challenge`.
+    ///
+    /// Each challenge is answerable once: it is used up by this call
+    /// whatever the outcome, so a replayed response fails with
+    /// [`SignatureError::UnknownChallenge`]. The challenge must be the
+    /// one issued and unexpired, and the response an Ed25519 signature
+    /// over [`Challenge::message`] by a signer trusted now and with a
+    /// registered key. The response's other fields are not covered by
+    /// the signature and are ignored.
+    pub fn verify_challenge_response(
+        &self,
+        challenge: &Challenge,
+        signature: &Signature,
+    ) -> Result<bool, SignatureError> {
+        let issued = self
+            .challenges
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&challenge.nonce)
+            .ok_or_else(|| SignatureError::UnknownChallenge(challenge.nonce.clone()))?;
+        if issued != *challenge {
+            return Err(SignatureError::ChallengeMismatch(issued.nonce));
+        }
+        if self.clock.now() >= issued.expires_at {
+            return Err(SignatureError::ChallengeExpired {
+                nonce: issued.nonce,
+                expires_at: issued.expires_at,
+            });
+        }
+        if signature.algorithm != ED25519_ALGORITHM {
+            return Err(SignatureError::UnsupportedAlgorithm {
+                signer: signature.signer_id.clone(),
+                algorithm: signature.algorithm.clone(),
+            });
+        }
+        let result = self.check_challenge_signature(&issued, signature);
+        self.record(&signature.signer_id, &result);
+        result
+    }
+
+    fn check_challenge_signature(&self, challenge: &Challenge, signature: &Signature) -> Result<bool, SignatureError> {
+        let signer = &signature.signer_id;
+        if !self.trusted_signers.contains(signer) {
+            return Err(SignatureError::UnknownSigner(signer.clone()));
+        }
+        let now = self.clock.now();
+        if !self.is_trusted_at(signer, &now) {
+            return Err(SignatureError::SignerNotTrustedAtTime {
+                signer: signer.clone(),
+                signed_at: now,
+            });
+        }
+        let key = self.keys.get(signer).ok_or_else(|| SignatureError::MissingKey(signer.clone()))?;
+        check_ed25519(key, &challenge.message(), signature.decoded_value()?)
+    }
+
+    /// Use `clock` instead of the system clock for the future-skew check
+    /// and challenge expiry.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    fn check_not_future(&self, at: &DateTime<Utc>) -> Result<(), SignatureError> {
+        let Some(max_skew) = self.max_future_skew else {
+            return Ok(());
+        };
+        let now = self.clock.now();
+        if *at - now > max_skew {
+            return Err(SignatureError::FutureTimestamp { at: *at, now, max_skew });
+        }
+        Ok(())
+    }
+
+    fn check_chain_limits(&self, chain: &[ProvenanceEntry]) -> Result<(), SignatureError> {
+        if let Some(max) = self.max_chain_len {
+            if chain.len() > max {
+                return Err(SignatureError::ChainTooLong { len: chain.len(), max });
+            }
+        }
+        if let Some(max) = self.max_chain_bytes {
+            let mut total = 0usize;
+            for entry in chain {
+                total = total.saturating_add(entry.approximate_size());
+                if total > max {
+                    return Err(SignatureError::ChainTooLarge { max });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Trust `signer_id` with an Ed25519 public key read from a PEM or DER file.
+    ///
+    /// The file must hold a SubjectPublicKeyInfo (`-----BEGIN PUBLIC KEY-----`);
+    /// keys for other algorithms are rejected.
+    pub fn add_signer_from_pem(&mut self, signer_id: &str, pem_path: &Path) -> Result<(), SignatureError> {
+        let key = load_public_key(pem_path)?;
+        self.add_signer_key(signer_id, key);
+        Ok(())
+    }
+
+    /// Trust `signer_id` with an Ed25519 public key.
+    pub fn add_signer_key(&mut self, signer_id: &str, key: VerifyingKey) {
+        if !self.trusted_signers.iter().any(|s| s == signer_id) {
+            self.trusted_signers.push(signer_id.to_string());
+        }
+        self.keys.insert(signer_id.to_string(), key);
+        self.invalidate_cache();
+    }
+
+    /// Ed25519 key registered for `signer_id`, if any.
+    pub fn signer_key(&self, signer_id: &str) -> Option<&VerifyingKey> {
+        self.keys.get(signer_id)
     }
 
     /// Verify a signature against content.
@@ -53,43 +876,293 @@ impl SignatureVerifier {
         &self,
         content: &[u8],
         signature: &Signature,
+    ) -> Result<bool, SignatureError> {
+        self.verify_reader(content, signature)
+    }
+
+    /// Verify a signature against content streamed from `reader`.
+    ///
+    /// The signer is checked before any content is read. The outcome is
+    /// counted in [`SignatureVerifier::report`].
+    pub fn verify_reader<R: Read>(
+        &self,
+        reader: R,
+        signature: &Signature,
+    ) -> Result<bool, SignatureError> {
+        let result = self.verify_reader_uncounted(reader, signature);
+        self.record(&signature.signer_id, &result);
+        result
+    }
+
+    fn verify_reader_uncounted<R: Read>(
+        &self,
+        mut reader: R,
+        signature: &Signature,
     ) -> Result<bool, SignatureError> {
         // Check signer is trusted
         if !self.trusted_signers.contains(&signature.signer_id) {
             return Err(SignatureError::UnknownSigner(signature.signer_id.clone()));
         }
+        if !self.is_trusted_at(&signature.signer_id, &signature.signed_at) {
+            return Err(SignatureError::SignerNotTrustedAtTime {
+                signer: signature.signer_id.clone(),
+                signed_at: signature.signed_at,
+            });
+        }
+        self.check_not_future(&signature.signed_at)?;
+
+        let revocations = self
+            .revocations
+            .as_ref()
+            .map(|list| list.read().unwrap_or_else(PoisonError::into_inner));
+        if let Some(ref list) = revocations {
+            let value = signature.decoded_value()?;
+            if list.is_signature_revoked(&value) {
+                return Err(SignatureError::Revoked(Revoked::Signature(hex::encode(value))));
+            }
+        }
+
+        let mut content_hasher = Hasher::new();
+        std::io::copy(&mut reader, &mut content_hasher)?;
+
+        if revocations.is_some() || signature.content_hash.is_some() {
+            let content_hash = content_hasher.clone().finish();
+            if revocations.as_ref().is_some_and(|list| list.is_content_revoked(&content_hash)) {
+                return Err(SignatureError::Revoked(Revoked::Content(content_hash)));
+            }
+            if let Some(ref expected) = signature.content_hash {
+                if !expected.eq_ignore_ascii_case(&content_hash) {
+                    return Err(SignatureError::ContentMismatch {
+                        expected: expected.clone(),
+                        actual: content_hash,
+                    });
+                }
+            }
+        }
+        drop(revocations);
 
         // Compute expected hash
-        let mut hasher = Sha256::new();
-        hasher.update(content);
-        hasher.update(signature.signer_id.as_bytes());
-        hasher.update(signature.signed_at.to_rfc3339().as_bytes());
-        let expected = format!("{:x}", hasher.finalize());
+        let expected = finish_signature_hash(
+            content_hasher,
+            &signature.signer_id,
+            &signature.signed_at,
+            signature.expires_at.as_ref(),
+        );
+
+        let Some(ref cache) = self.cache else {
+            return self.check_signature(&expected, signature);
+        };
+        let key = CacheKey {
+            message: expected,
+            signer_id: signature.signer_id.clone(),
+            algorithm: signature.algorithm.clone(),
+            value: signature.decoded_value()?,
+        };
+        if let Some(valid) = cache.lock().unwrap_or_else(PoisonError::into_inner).get(&key) {
+            return Ok(valid);
+        }
+        let valid = self.check_signature(&key.message, signature)?;
+        cache.lock().unwrap_or_else(PoisonError::into_inner).insert(key, valid);
+        Ok(valid)
+    }
 
-        // In production, this would use proper cryptographic verification
-        // For now, we verify the hash matches
-        Ok(signature.value == expected[..16])
+    /// Check `signature` over the message digest `expected`.
+    ///
+    /// The value is decoded first, so a malformed encoding is an error
+    /// rather than a failed verification. Only Ed25519 is accepted from a
+    /// signer with a key; see [`SignatureVerifier::set_allow_legacy_sha256`].
+    fn check_signature(&self, expected: &str, signature: &Signature) -> Result<bool, SignatureError> {
+        let value = signature.decoded_value()?;
+        let key = self.keys.get(&signature.signer_id);
+        let legacy_allowed =
+            self.allow_legacy_sha256 && key.is_none() && signature.algorithm == LEGACY_SHA256_ALGORITHM;
+        if signature.algorithm != ED25519_ALGORITHM && !legacy_allowed {
+            return Err(SignatureError::UnsupportedAlgorithm {
+                signer: signature.signer_id.clone(),
+                algorithm: signature.algorithm.clone(),
+            });
+        }
+        if signature.algorithm == ED25519_ALGORITHM {
+            let key = key.ok_or_else(|| SignatureError::MissingKey(signature.signer_id.clone()))?;
+            return check_ed25519(key, expected.as_bytes(), value);
+        }
+
+        // Legacy "sha256" signatures are a truncated hash, not cryptographic
+        Ok(hex::decode(&expected[..SHORT_HASH_LEN]).is_ok_and(|truncated| value == truncated))
+    }
+
+    /// Accept `content` if any one of `signatures` verifies, returning that
+    /// signature's signer.
+    ///
+    /// Signatures are tried in order. Unlike a threshold, one valid
+    /// signature suffices; if none verifies, every failure is reported.
+    pub fn verify_any(&self, content: &[u8], signatures: &[Signature]) -> Result<String, SignatureError> {
+        let mut failures = Vec::new();
+        for signature in signatures {
+            match self.verify(content, signature) {
+                Ok(true) => return Ok(signature.signer_id.clone()),
+                Ok(false) => failures.push((signature.signer_id.clone(), SignatureError::InvalidSignature)),
+                Err(e) => failures.push((signature.signer_id.clone(), e)),
+            }
+        }
+        Err(SignatureError::NoneVerified { failures })
+    }
+
+    /// Verify a signature and classify it against the content's creator.
+    ///
+    /// A valid signature whose signer is `creator` verifies but is only
+    /// `SelfSigned`; policy can require `TrustedExternal` for promotion.
+    /// Without a `creator` a valid signature is `UnknownCreator`.
+    pub fn verify_detailed(
+        &self,
+        content: &[u8],
+        signature: &Signature,
+        creator: Option<&str>,
+    ) -> Result<VerificationOutcome, SignatureError> {
+        self.verify_reader_detailed(content, signature, creator)
+    }
+
+    /// [`SignatureVerifier::verify_detailed`] over content streamed from `reader`.
+    pub fn verify_reader_detailed<R: Read>(
+        &self,
+        reader: R,
+        signature: &Signature,
+        creator: Option<&str>,
+    ) -> Result<VerificationOutcome, SignatureError> {
+        if !self.verify_reader(reader, signature)? {
+            return Ok(VerificationOutcome::Failed);
+        }
+        Ok(classify(signature, creator))
+    }
+
+    /// Verify every signed entry of a provenance chain and classify the chain.
+    ///
+    /// The creator is the actor of the first entry. The chain is
+    /// `TrustedExternal` if any valid signature comes from someone else,
+    /// `SelfSigned` if all come from the creator, and `Failed` if any
+    /// signature is invalid or no entry is signed.
+    pub fn verify_chain_signatures(
+        &self,
+        chain: &[ProvenanceEntry],
+    ) -> Result<VerificationOutcome, SignatureError> {
+        let Some(creator) = chain.first().map(|e| e.actor.as_str()) else {
+            return Ok(VerificationOutcome::Failed);
+        };
+
+        let mut outcome = VerificationOutcome::Failed;
+        for entry in chain {
+            let Some(ref signature) = entry.signature else {
+                continue;
+            };
+            match self.verify_detailed(&entry.canonical_bytes(), signature, Some(creator))? {
+                VerificationOutcome::Failed => return Ok(VerificationOutcome::Failed),
+                VerificationOutcome::TrustedExternal => outcome = VerificationOutcome::TrustedExternal,
+                VerificationOutcome::SelfSigned if outcome == VerificationOutcome::Failed => {
+                    outcome = VerificationOutcome::SelfSigned
+                }
+                // The creator is always given, so never `UnknownCreator`.
+                VerificationOutcome::SelfSigned | VerificationOutcome::UnknownCreator => {}
+            }
+        }
+        Ok(outcome)
     }
 
     /// Sign content (creates signature).
+    ///
+    /// The signature is a legacy [`LEGACY_SHA256_ALGORITHM`] one; verifiers
+    /// accept it only with [`SignatureVerifier::set_allow_legacy_sha256`].
+    /// Use [`sign_ed25519`] where a key is available.
     pub fn sign(&self, content: &[u8], signer_id: &str) -> Signature {
-        let now = Utc::now();
+        self.sign_with_expiry(content, signer_id, None)
+    }
+
+    /// Sign content with a validity window of `valid_for` from now.
+    pub fn sign_with_expiry(
+        &self,
+        content: &[u8],
+        signer_id: &str,
+        valid_for: Option<Duration>,
+    ) -> Signature {
+        self.sign_reader_with_expiry(content, signer_id, valid_for)
+            .expect("reading a byte slice cannot fail")
+    }
 
-        let mut hasher = Sha256::new();
-        hasher.update(content);
-        hasher.update(signer_id.as_bytes());
-        hasher.update(now.to_rfc3339().as_bytes());
-        let hash = format!("{:x}", hasher.finalize());
+    /// Sign content streamed from `reader`, hashing it in chunks.
+    pub fn sign_reader<R: Read>(&self, reader: R, signer_id: &str) -> std::io::Result<Signature> {
+        self.sign_reader_with_expiry(reader, signer_id, None)
+    }
 
-        Signature {
-            algorithm: "sha256".to_string(),
-            value: hash[..16].to_string(),
+    /// [`SignatureVerifier::sign_with_expiry`] over content streamed from `reader`.
+    pub fn sign_reader_with_expiry<R: Read>(
+        &self,
+        mut reader: R,
+        signer_id: &str,
+        valid_for: Option<Duration>,
+    ) -> std::io::Result<Signature> {
+        let mut content_hasher = Hasher::new();
+        std::io::copy(&mut reader, &mut content_hasher)?;
+
+        let now = Utc::now();
+        let expires_at = valid_for.map(|d| now + d);
+        let content_hash = content_hasher.clone().finish();
+        let hash = finish_signature_hash(content_hasher, signer_id, &now, expires_at.as_ref());
+
+        Ok(Signature {
+            algorithm: LEGACY_SHA256_ALGORITHM.to_string(),
+            value: hash[..SHORT_HASH_LEN].to_string(),
             signer_id: signer_id.to_string(),
             signed_at: now,
+            expires_at,
+            artifact_compression: None,
+            content_hash: Some(content_hash),
+            encoding: None,
+        })
+    }
+
+    /// Sign content streamed from `reader`, recording a `SignatureCreated`
+    /// entry in `audit`.
+    ///
+    /// The entry is about `target`, or the signed content's hash if none
+    /// is given. No signature is returned unless the entry was written.
+    pub fn sign_audited<R: Read>(
+        &self,
+        audit: &mut AuditLog,
+        reader: R,
+        signer_id: &str,
+        target: Option<&AuditTarget>,
+    ) -> Result<Signature, SignatureError> {
+        let signature = self.sign_reader(reader, signer_id)?;
+        record_created(audit, &signature, target)?;
+        Ok(signature)
+    }
+
+    /// Sign a provenance entry over its canonical bytes, recording the validity window.
+    pub fn sign_provenance(
+        &self,
+        entry: &mut ProvenanceEntry,
+        signer_id: &str,
+        valid_for: Option<Duration>,
+    ) {
+        let content = entry.canonical_bytes();
+        entry.signature = Some(self.sign_with_expiry(&content, signer_id, valid_for));
+    }
+
+    /// Verify a provenance entry's signature over its canonical bytes.
+    ///
+    /// An unsigned entry does not verify.
+    pub fn verify_provenance_signature(&self, entry: &ProvenanceEntry) -> Result<bool, SignatureError> {
+        match entry.signature {
+            Some(ref signature) => self.verify(&entry.canonical_bytes(), signature),
+            None => Ok(false),
         }
     }
 
     /// Verify a provenance chain.
+    ///
+    /// Each signed entry is verified against its own signer, so a chain can
+    /// pass through several trusted signers. Operations with a required
+    /// role must be signed by a signer holding it. Signature failures name
+    /// the entry's index and signer.
     pub fn verify_provenance(
         &self,
         chain: &[ProvenanceEntry],
@@ -97,6 +1170,7 @@ impl SignatureVerifier {
         if chain.is_empty() {
             return Ok(true);
         }
+        self.check_chain_limits(chain)?;
 
         for (i, entry) in chain.iter().enumerate() {
             // First entry should have no parent
@@ -104,35 +1178,329 @@ impl SignatureVerifier {
                 return Err(SignatureError::BrokenChain(entry.adapter_id.clone()));
             }
 
-            // Subsequent entries must reference previous hash
+            // Subsequent entries must reference previous hash, belong to
+            // the same adapter, and move the version forward
             if i > 0 {
-                let expected_parent = &chain[i - 1].hash;
+                let previous = &chain[i - 1];
                 match &entry.parent_hash {
-                    Some(parent) if parent == expected_parent => {}
+                    Some(parent) if *parent == previous.hash => {}
                     _ => return Err(SignatureError::BrokenChain(entry.adapter_id.clone())),
                 }
+                if entry.adapter_id != previous.adapter_id || entry.version <= previous.version {
+                    return Err(SignatureError::BrokenChain(entry.adapter_id.clone()));
+                }
             }
 
             // Verify hash is correct
-            let computed = self.compute_entry_hash(entry);
+            let computed = entry.compute_hash();
             if computed != entry.hash {
                 return Err(SignatureError::BrokenChain(entry.adapter_id.clone()));
             }
+            self.check_not_future(&entry.timestamp)?;
+
+            // A signature that expired before the next operation was
+            // recorded means a stale key was still in use.
+            if let (Some(signature), Some(next)) = (entry.signature.as_ref(), chain.get(i + 1)) {
+                if signature.expires_at.is_some_and(|expires_at| next.timestamp > expires_at) {
+                    let expired = Err(SignatureError::Expired);
+                    self.record(&signature.signer_id, &expired);
+                    return expired;
+                }
+            }
+
+            self.check_entry_signer(i, entry)?;
         }
 
         Ok(true)
     }
+}
+
+impl SignatureVerifier {
+    /// Verify the signature of the `index`th entry of a chain and the role
+    /// of its signer.
+    fn check_entry_signer(&self, index: usize, entry: &ProvenanceEntry) -> Result<(), SignatureError> {
+        let required_role = self.required_roles.get(&entry.operation);
+        let Some(ref signature) = entry.signature else {
+            return match required_role {
+                Some(role) => Err(SignatureError::MissingRole {
+                    index,
+                    operation: entry.operation,
+                    signer: None,
+                    role: role.clone(),
+                }),
+                None => Ok(()),
+            };
+        };
 
-    fn compute_entry_hash(&self, entry: &ProvenanceEntry) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(entry.adapter_id.as_bytes());
-        hasher.update(entry.version.to_le_bytes());
-        hasher.update(entry.operation.as_bytes());
-        hasher.update(entry.actor.as_bytes());
-        hasher.update(entry.timestamp.to_rfc3339().as_bytes());
-        if let Some(ref parent) = entry.parent_hash {
-            hasher.update(parent.as_bytes());
+        let failed = |source: SignatureError| SignatureError::EntrySignature {
+            index,
+            operation: entry.operation,
+            signer: signature.signer_id.clone(),
+            source: Box::new(source),
+        };
+        match self.verify(&entry.canonical_bytes(), signature) {
+            Ok(true) => {}
+            Ok(false) => return Err(failed(SignatureError::InvalidSignature)),
+            Err(e) => return Err(failed(e)),
         }
-        format!("{:x}", hasher.finalize())[..16].to_string()
+
+        match required_role {
+            Some(role) if !self.has_role(&signature.signer_id, role) => Err(SignatureError::MissingRole {
+                index,
+                operation: entry.operation,
+                signer: Some(signature.signer_id.clone()),
+                role: role.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ProvenanceEntry {
+    /// Canonical CBOR of the signed fields: everything but `signature` and
+    /// `hash`, with absent optional hashes omitted and the timestamp in
+    /// RFC 3339 UTC with nanoseconds.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        #[derive(Serialize)]
+        struct Signed<'a> {
+            adapter_id: &'a str,
+            version: u32,
+            operation: &'static str,
+            actor: &'a str,
+            timestamp: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            parent_hash: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            content_hash: Option<&'a str>,
+        }
+
+        to_canonical_cbor(&Signed {
+            adapter_id: &self.adapter_id,
+            version: self.version,
+            operation: self.operation.tag(),
+            actor: &self.actor,
+            timestamp: canonical_timestamp(&self.timestamp),
+            parent_hash: self.parent_hash.as_deref(),
+            content_hash: self.content_hash.as_deref(),
+        })
+        .expect("string and integer fields always encode")
+    }
+
+    /// Bytes of variable-length data held by the entry, for size limits.
+    fn approximate_size(&self) -> usize {
+        let optional = |s: &Option<String>| s.as_ref().map_or(0, String::len);
+        let signature = self.signature.as_ref().map_or(0, |sig| {
+            sig.algorithm.len() + sig.value.len() + sig.signer_id.len()
+        });
+        self.adapter_id.len()
+            + self.actor.len()
+            + self.hash.len()
+            + optional(&self.parent_hash)
+            + optional(&self.content_hash)
+            + signature
+    }
+
+    /// Compute the hash binding this entry to its parent.
+    pub fn compute_hash(&self) -> String {
+        let mut hasher = Hasher::new()
+            .update(&self.adapter_id)
+            .update(self.version.to_le_bytes())
+            .update(self.operation.tag())
+            .update(&self.actor)
+            .update(self.timestamp.to_rfc3339());
+        if let Some(ref parent) = self.parent_hash {
+            hasher = hasher.update(parent);
+        }
+        // Tagged so a content hash can never stand in for a parent hash.
+        if let Some(ref content_hash) = self.content_hash {
+            hasher = hasher.update(b"content:").update(content_hash);
+        }
+        hasher.finish_short()
+    }
+}
+
+fn describe_failures(failures: &[(String, SignatureError)]) -> String {
+    if failures.is_empty() {
+        return ": no signatures given".to_string();
+    }
+    let failures: Vec<String> = failures
+        .iter()
+        .map(|(signer, error)| format!("{}: {}", signer, error))
+        .collect();
+    format!(" ({})", failures.join("; "))
+}
+
+fn classify(signature: &Signature, creator: Option<&str>) -> VerificationOutcome {
+    match creator {
+        None => VerificationOutcome::UnknownCreator,
+        Some(creator) if creator == signature.signer_id => VerificationOutcome::SelfSigned,
+        Some(_) => VerificationOutcome::TrustedExternal,
+    }
+}
+
+/// Read an Ed25519 public key from a PEM or DER SubjectPublicKeyInfo file.
+pub fn load_public_key(path: &Path) -> Result<VerifyingKey, SignatureError> {
+    let invalid = |reason: String| SignatureError::InvalidKey {
+        path: path.to_path_buf(),
+        reason,
+    };
+
+    let bytes = std::fs::read(path).map_err(|e| invalid(e.to_string()))?;
+    let der = if bytes.starts_with(b"-----BEGIN") {
+        let (label, der) = pem::decode_vec(&bytes).map_err(|e| invalid(e.to_string()))?;
+        if label != "PUBLIC KEY" {
+            return Err(invalid(format!("expected a PEM \"PUBLIC KEY\" block, found {:?}", label)));
+        }
+        der
+    } else {
+        bytes
+    };
+
+    let spki = SubjectPublicKeyInfoRef::from_der(&der).map_err(|e| invalid(e.to_string()))?;
+    if spki.algorithm.oid != ed25519_dalek::pkcs8::ALGORITHM_OID {
+        return Err(invalid(format!("unsupported key algorithm {}", spki.algorithm.oid)));
+    }
+    VerifyingKey::from_public_key_der(&der).map_err(|e| invalid(e.to_string()))
+}
+
+/// Verify an Ed25519 `signature` over `content` with `key` alone,
+/// bypassing any verifier's signer list and legacy-algorithm policy.
+///
+/// For callers that have already chosen the key the content must be
+/// signed with; any other algorithm is refused.
+pub fn verify_ed25519(content: &[u8], signature: &Signature, key: &VerifyingKey) -> Result<bool, SignatureError> {
+    if signature.algorithm != ED25519_ALGORITHM {
+        return Err(SignatureError::UnsupportedAlgorithm {
+            signer: signature.signer_id.clone(),
+            algorithm: signature.algorithm.clone(),
+        });
+    }
+    let value = signature.decoded_value()?;
+    let content_hasher = Hasher::new().update(content);
+    if let Some(ref expected) = signature.content_hash {
+        let actual = content_hasher.clone().finish();
+        if !expected.eq_ignore_ascii_case(&actual) {
+            return Err(SignatureError::ContentMismatch {
+                expected: expected.clone(),
+                actual,
+            });
+        }
+    }
+    let expected = finish_signature_hash(
+        content_hasher,
+        &signature.signer_id,
+        &signature.signed_at,
+        signature.expires_at.as_ref(),
+    );
+    check_ed25519(key, expected.as_bytes(), value)
+}
+
+fn check_ed25519(key: &VerifyingKey, message: &[u8], value: Vec<u8>) -> Result<bool, SignatureError> {
+    let bytes: [u8; 64] = value.try_into().map_err(|value: Vec<u8>| {
+        SignatureError::InvalidEncoding(format!("ed25519 signature of {} bytes, expected 64", value.len()))
+    })?;
+    let sig = ed25519_dalek::Signature::from_bytes(&bytes);
+    Ok(key.verify_strict(message, &sig).is_ok())
+}
+
+/// Sign content with an Ed25519 key, valid for `valid_for` from now.
+pub fn sign_ed25519(
+    content: &[u8],
+    signer_id: &str,
+    key: &SigningKey,
+    valid_for: Option<Duration>,
+) -> Signature {
+    sign_ed25519_reader(content, signer_id, key, valid_for).expect("reading a byte slice cannot fail")
+}
+
+/// [`sign_ed25519`] over content streamed from `reader`.
+pub fn sign_ed25519_reader<R: Read>(
+    mut reader: R,
+    signer_id: &str,
+    key: &SigningKey,
+    valid_for: Option<Duration>,
+) -> std::io::Result<Signature> {
+    let mut content_hasher = Hasher::new();
+    std::io::copy(&mut reader, &mut content_hasher)?;
+
+    let now = Utc::now();
+    let expires_at = valid_for.map(|d| now + d);
+    let content_hash = content_hasher.clone().finish();
+    let hash = finish_signature_hash(content_hasher, signer_id, &now, expires_at.as_ref());
+
+    Ok(Signature {
+        algorithm: ED25519_ALGORITHM.to_string(),
+        value: hex::encode(key.sign(hash.as_bytes()).to_bytes()),
+        signer_id: signer_id.to_string(),
+        signed_at: now,
+        expires_at,
+        artifact_compression: None,
+        content_hash: Some(content_hash),
+        encoding: None,
+    })
+}
+
+/// Sign content streamed from `reader` with `key`, recording a
+/// `SignatureCreated` entry in `audit` as [`SignatureVerifier::sign_audited`]
+/// does.
+pub fn sign_ed25519_audited<R: Read>(
+    audit: &mut AuditLog,
+    reader: R,
+    signer_id: &str,
+    key: &SigningKey,
+    target: Option<&AuditTarget>,
+) -> Result<Signature, SignatureError> {
+    let signature = sign_ed25519_reader(reader, signer_id, key, None)?;
+    record_created(audit, &signature, target)?;
+    Ok(signature)
+}
+
+/// Append the `SignatureCreated` entry for `signature`, about `target` or
+/// else the signed content's hash.
+fn record_created(audit: &mut AuditLog, signature: &Signature, target: Option<&AuditTarget>) -> Result<(), AuditError> {
+    let target = target
+        .cloned()
+        .or_else(|| signature.content_hash.clone().map(AuditTarget::Content));
+    audit.append(
+        AuditEventType::SignatureCreated,
+        &signature.signer_id,
+        target.as_ref(),
+        json!({
+            "signer": signature.signer_id,
+            "target": target,
+            "signature": signature.value,
+            "content_hash": signature.content_hash,
+        }),
+    )?;
+    Ok(())
+}
+
+/// Sign the canonical content of `source`, recording its compression.
+pub fn sign_ed25519_source(
+    source: &ContentSource,
+    signer_id: &str,
+    key: &SigningKey,
+    valid_for: Option<Duration>,
+) -> Result<Signature, SourceError> {
+    let mut signature = sign_ed25519_reader(source.open_content()?, signer_id, key, valid_for)
+        .map_err(|e| SourceError::Io {
+            path: source.to_string().into(),
+            source: e,
+        })?;
+    signature.artifact_compression = source.compression();
+    Ok(signature)
+}
+
+/// Complete a signature hash over content already fed to `hasher`.
+fn finish_signature_hash(
+    hasher: Hasher,
+    signer_id: &str,
+    signed_at: &DateTime<Utc>,
+    expires_at: Option<&DateTime<Utc>>,
+) -> String {
+    let mut hasher = hasher.update(signer_id).update(signed_at.to_rfc3339());
+    if let Some(expires_at) = expires_at {
+        hasher = hasher.update(expires_at.to_rfc3339());
     }
+    hasher.finish()
 }
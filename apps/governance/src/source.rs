@@ -0,0 +1,178 @@
+//! Content Sources
+//!
+//! Where adapter bytes are read from: a local file, or an object-store
+//! URL (`s3://`, `gs://`) when built with the `object-store` feature.
+//! Sources are read as streams so large adapters are never held in memory.
+//!
+//! A `.gz` artifact is signed and verified over its decompressed content,
+//! so a signature holds whether or not the adapter is shipped compressed.
+
+use flate2::read::MultiGzDecoder;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SourceError {
+    #[error("IO error opening {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Unsupported content source {0:?} (object-store URLs need the `object-store` feature)")]
+    UnsupportedScheme(String),
+    #[error("Invalid content source URL {url:?}: {reason}")]
+    InvalidUrl { url: String, reason: String },
+    #[cfg(feature = "object-store")]
+    #[error("Object store error: {0}")]
+    ObjectStore(#[from] object_store::Error),
+}
+
+/// Compression of an on-disk artifact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    Gzip,
+}
+
+/// Location of adapter content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentSource {
+    File(PathBuf),
+    #[cfg(feature = "object-store")]
+    Object(url::Url),
+}
+
+impl ContentSource {
+    /// Parse a path or URL. Plain paths and `file://` URLs are local files.
+    pub fn parse(location: &str) -> Result<Self, SourceError> {
+        let Some((scheme, rest)) = location.split_once("://") else {
+            return Ok(Self::File(PathBuf::from(location)));
+        };
+        if scheme.eq_ignore_ascii_case("file") {
+            return Ok(Self::File(PathBuf::from(rest)));
+        }
+
+        #[cfg(feature = "object-store")]
+        {
+            let url = url::Url::parse(location).map_err(|e| SourceError::InvalidUrl {
+                url: location.to_string(),
+                reason: e.to_string(),
+            })?;
+            Ok(Self::Object(url))
+        }
+        #[cfg(not(feature = "object-store"))]
+        Err(SourceError::UnsupportedScheme(location.to_string()))
+    }
+
+    /// Compression of the artifact, detected from its extension.
+    pub fn compression(&self) -> Option<Compression> {
+        let name = match self {
+            Self::File(path) => path.to_string_lossy(),
+            #[cfg(feature = "object-store")]
+            Self::Object(url) => url.path().into(),
+        };
+        name.to_ascii_lowercase().ends_with(".gz").then_some(Compression::Gzip)
+    }
+
+    /// The file on local disk, if this is one.
+    pub fn local_path(&self) -> Option<&Path> {
+        match self {
+            Self::File(path) => Some(path),
+            #[cfg(feature = "object-store")]
+            Self::Object(_) => None,
+        }
+    }
+
+    /// Open the canonical content: the decompressed stream of a `.gz`
+    /// artifact, otherwise the raw bytes.
+    pub fn open_content(&self) -> Result<Box<dyn Read + Send>, SourceError> {
+        let raw = self.open()?;
+        Ok(match self.compression() {
+            Some(Compression::Gzip) => Box::new(MultiGzDecoder::new(raw)),
+            None => raw,
+        })
+    }
+
+    /// Open the artifact's raw bytes as a stream.
+    pub fn open(&self) -> Result<Box<dyn Read + Send>, SourceError> {
+        match self {
+            Self::File(path) => {
+                let file = File::open(path).map_err(|source| SourceError::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+                Ok(Box::new(file))
+            }
+            #[cfg(feature = "object-store")]
+            Self::Object(url) => Ok(Box::new(object::ObjectReader::open(url)?)),
+        }
+    }
+}
+
+impl std::fmt::Display for ContentSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            #[cfg(feature = "object-store")]
+            Self::Object(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+#[cfg(feature = "object-store")]
+mod object {
+    use super::SourceError;
+    use bytes::Bytes;
+    use futures::stream::{BoxStream, StreamExt};
+    use object_store::ObjectStore;
+    use std::io::{self, Read};
+    use tokio::runtime::Runtime;
+
+    /// Blocking reader over an object's byte stream, one chunk at a time.
+    pub(super) struct ObjectReader {
+        runtime: Runtime,
+        stream: BoxStream<'static, object_store::Result<Bytes>>,
+        chunk: Bytes,
+    }
+
+    impl ObjectReader {
+        pub(super) fn open(url: &url::Url) -> Result<Self, SourceError> {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|source| SourceError::Io {
+                    path: url.as_str().into(),
+                    source,
+                })?;
+
+            // Credentials come from the usual AWS_* / GOOGLE_* variables.
+            let options = std::env::vars().map(|(k, v)| (k.to_ascii_lowercase(), v));
+            let (store, path) = object_store::parse_url_opts(url, options)?;
+            let stream = runtime.block_on(store.get(&path))?.into_stream();
+
+            Ok(Self {
+                runtime,
+                stream,
+                chunk: Bytes::new(),
+            })
+        }
+    }
+
+    impl Read for ObjectReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            while self.chunk.is_empty() {
+                match self.runtime.block_on(self.stream.next()) {
+                    Some(Ok(chunk)) => self.chunk = chunk,
+                    Some(Err(e)) => return Err(io::Error::other(e)),
+                    None => return Ok(0),
+                }
+            }
+            let n = buf.len().min(self.chunk.len());
+            buf[..n].copy_from_slice(&self.chunk.split_to(n));
+            Ok(n)
+        }
+    }
+}
@@ -0,0 +1,378 @@
+//! Remote Trust Registry
+//!
+//! Trusted signers published by a central registry instead of listed in
+//! each governance.toml. The registry serves a [`TrustSet`] signed with
+//! its own Ed25519 key, which is pinned locally, so a tampered or
+//! intercepted set is refused rather than trusted.
+//!
+//! Fetched sets are cached with a TTL. If the registry cannot be reached
+//! the cached copy is used however old it is, and the caller is told
+//! which source the signers came from. A set issued before the cached one
+//! is refused, so an old set cannot be replayed to undo a revocation.
+//!
+//! Fetching over HTTP needs the `trust-registry` feature.
+
+use crate::clock::{Clock, SystemClock};
+use crate::config::TrustedSigner;
+use crate::signatures::SignatureVerifier;
+use chrono::{DateTime, Duration, Utc};
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use openlora_core::canonical::{canonical_timestamp, to_canonical_cbor};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Trust set format written by [`TrustSet::sign`].
+pub const TRUST_SET_FORMAT: u32 = 1;
+
+/// Default location of the cached trust set.
+pub const DEFAULT_TRUST_CACHE_PATH: &str = "trust-cache.json";
+
+/// Default time a fetched trust set is used before asking the registry again.
+pub const DEFAULT_TRUST_TTL_SECS: u64 = 3600;
+
+#[derive(Debug, Error)]
+pub enum TrustError {
+    #[error("Unsupported trust set format {0} (expected {TRUST_SET_FORMAT})")]
+    UnsupportedFormat(u32),
+    #[error("Trust set signature does not verify with the registry key")]
+    InvalidSignature,
+    #[error("Invalid public key for signer {signer}: {reason}")]
+    InvalidSignerKey { signer: String, reason: String },
+    #[error("Trust set issued at {fetched} is older than the cached set issued at {cached}")]
+    Rollback {
+        fetched: DateTime<Utc>,
+        cached: DateTime<Utc>,
+    },
+    #[error("Trust registry unreachable and no cached trust set: {0}")]
+    Unavailable(String),
+    #[error("Fetching a remote trust set requires the trust-registry feature")]
+    Unsupported,
+    #[error("Request failed: {0}")]
+    Request(String),
+    #[error("Registry responded with HTTP {0}")]
+    Status(u16),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl TrustError {
+    /// Whether the registry could not be reached, as opposed to answering
+    /// with something unacceptable. Only these fall back to the cache.
+    pub fn is_unreachable(&self) -> bool {
+        matches!(self, Self::Request(_) | Self::Status(_) | Self::Io(_))
+    }
+}
+
+/// A signer as published by the registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteSigner {
+    pub id: String,
+    /// Hex of the Ed25519 public key.
+    pub public_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trusted_from: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revoked_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+}
+
+impl RemoteSigner {
+    pub fn new(id: &str, key: &VerifyingKey) -> Self {
+        Self {
+            id: id.to_string(),
+            public_key: hex::encode(key.as_bytes()),
+            trusted_from: None,
+            revoked_at: None,
+            roles: Vec::new(),
+        }
+    }
+
+    fn verifying_key(&self) -> Result<VerifyingKey, TrustError> {
+        let invalid = |reason: &str| TrustError::InvalidSignerKey {
+            signer: self.id.clone(),
+            reason: reason.to_string(),
+        };
+        let bytes: [u8; 32] = hex::decode(&self.public_key)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| invalid("expected 32 hex-encoded bytes"))?;
+        VerifyingKey::from_bytes(&bytes).map_err(|e| invalid(&e.to_string()))
+    }
+}
+
+/// The registry's signed list of trusted signers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustSet {
+    pub format: u32,
+    pub issued_at: DateTime<Utc>,
+    pub signers: Vec<RemoteSigner>,
+    /// Hex Ed25519 signature by the registry over [`TrustSet::canonical_bytes`].
+    pub signature: String,
+}
+
+impl TrustSet {
+    /// Sign `signers` as the registry.
+    pub fn sign(signers: Vec<RemoteSigner>, issued_at: DateTime<Utc>, key: &SigningKey) -> Self {
+        let mut set = Self {
+            format: TRUST_SET_FORMAT,
+            issued_at,
+            signers,
+            signature: String::new(),
+        };
+        set.signature = hex::encode(key.sign(&set.canonical_bytes()).to_bytes());
+        set
+    }
+
+    /// The signed form: format, issue time and signers, as canonical CBOR.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        #[derive(Serialize)]
+        struct SignedSigner<'a> {
+            id: &'a str,
+            public_key: &'a str,
+            trusted_from: Option<String>,
+            revoked_at: Option<String>,
+            roles: &'a [String],
+        }
+        #[derive(Serialize)]
+        struct Signed<'a> {
+            format: u32,
+            issued_at: String,
+            signers: Vec<SignedSigner<'a>>,
+        }
+
+        to_canonical_cbor(&Signed {
+            format: self.format,
+            issued_at: canonical_timestamp(&self.issued_at),
+            signers: self
+                .signers
+                .iter()
+                .map(|s| SignedSigner {
+                    id: &s.id,
+                    public_key: &s.public_key,
+                    trusted_from: s.trusted_from.as_ref().map(canonical_timestamp),
+                    revoked_at: s.revoked_at.as_ref().map(canonical_timestamp),
+                    roles: &s.roles,
+                })
+                .collect(),
+        })
+        .expect("string and integer fields always encode")
+    }
+
+    /// Check the registry's signature with its pinned key.
+    pub fn verify(&self, registry_key: &VerifyingKey) -> Result<(), TrustError> {
+        if self.format != TRUST_SET_FORMAT {
+            return Err(TrustError::UnsupportedFormat(self.format));
+        }
+        let signature: [u8; 64] = hex::decode(&self.signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(TrustError::InvalidSignature)?;
+        let signature = ed25519_dalek::Signature::from_bytes(&signature);
+        registry_key
+            .verify_strict(&self.canonical_bytes(), &signature)
+            .map_err(|_| TrustError::InvalidSignature)
+    }
+
+    /// A verifier trusting exactly this set's signers, with their keys,
+    /// trust timelines and roles.
+    pub fn signature_verifier(&self) -> Result<SignatureVerifier, TrustError> {
+        let signers: Vec<TrustedSigner> = self
+            .signers
+            .iter()
+            .map(|s| TrustedSigner {
+                id: s.id.clone(),
+                public_key: None,
+                trusted_from: s.trusted_from,
+                revoked_at: s.revoked_at,
+                roles: s.roles.clone(),
+            })
+            .collect();
+        let mut verifier = crate::config::signature_verifier(&signers).expect("no key files to load");
+        for signer in &self.signers {
+            verifier.add_signer_key(&signer.id, signer.verifying_key()?);
+        }
+        Ok(verifier)
+    }
+}
+
+/// Where the trusted signers in use came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrustSource {
+    /// Fetched from the registry just now.
+    Remote,
+    /// The cached copy, still within its TTL; the registry was not asked.
+    Cached { fetched_at: DateTime<Utc> },
+    /// The registry was unreachable, so the cached copy was used past its TTL.
+    Fallback { fetched_at: DateTime<Utc>, error: String },
+}
+
+/// Retrieves a trust set document.
+pub trait TrustFetcher {
+    fn fetch(&self, url: &str) -> Result<Vec<u8>, TrustError>;
+}
+
+/// The HTTP fetcher, when built with the `trust-registry` feature.
+pub fn http_fetcher() -> Result<Box<dyn TrustFetcher>, TrustError> {
+    #[cfg(feature = "trust-registry")]
+    return Ok(Box::new(http::HttpFetcher::new()?));
+    #[cfg(not(feature = "trust-registry"))]
+    Err(TrustError::Unsupported)
+}
+
+/// A trust set as cached on disk.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedTrustSet {
+    url: String,
+    fetched_at: DateTime<Utc>,
+    set: TrustSet,
+}
+
+/// A remote registry, its pinned key and the local cache of its set.
+pub struct TrustRegistry {
+    url: String,
+    registry_key: VerifyingKey,
+    cache_path: PathBuf,
+    ttl: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl TrustRegistry {
+    pub fn new(url: &str, registry_key: VerifyingKey, cache_path: PathBuf, ttl: Duration) -> Self {
+        Self {
+            url: url.to_string(),
+            registry_key,
+            cache_path,
+            ttl,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Use `clock` to judge the cache's age.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// The current trust set and where it came from.
+    ///
+    /// A cached set within its TTL is used as is. Otherwise the registry
+    /// is asked, and a verified answer replaces the cache. Only when the
+    /// registry is unreachable is a stale cached set used; an answer that
+    /// fails verification is an error, not a reason to fall back.
+    pub fn resolve(&self, fetcher: &dyn TrustFetcher) -> Result<(TrustSet, TrustSource), TrustError> {
+        let now = self.clock.now();
+        let cached = self.load_cache();
+        if let Some(ref cached) = cached {
+            if now - cached.fetched_at < self.ttl {
+                let source = TrustSource::Cached {
+                    fetched_at: cached.fetched_at,
+                };
+                return Ok((cached.set.clone(), source));
+            }
+        }
+
+        match self.fetch(fetcher, cached.as_ref()) {
+            Ok(set) => {
+                self.save_cache(&CachedTrustSet {
+                    url: self.url.clone(),
+                    fetched_at: now,
+                    set: set.clone(),
+                })?;
+                Ok((set, TrustSource::Remote))
+            }
+            Err(e) if e.is_unreachable() => match cached {
+                Some(cached) => {
+                    let source = TrustSource::Fallback {
+                        fetched_at: cached.fetched_at,
+                        error: e.to_string(),
+                    };
+                    Ok((cached.set, source))
+                }
+                None => Err(TrustError::Unavailable(e.to_string())),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    fn fetch(&self, fetcher: &dyn TrustFetcher, cached: Option<&CachedTrustSet>) -> Result<TrustSet, TrustError> {
+        let set: TrustSet = serde_json::from_slice(&fetcher.fetch(&self.url)?)?;
+        set.verify(&self.registry_key)?;
+        if let Some(cached) = cached {
+            if set.issued_at < cached.set.issued_at {
+                return Err(TrustError::Rollback {
+                    fetched: set.issued_at,
+                    cached: cached.set.issued_at,
+                });
+            }
+        }
+        Ok(set)
+    }
+
+    /// The cached set for this registry, if any verifies.
+    ///
+    /// The cache is only a copy: one that is unreadable, from another
+    /// URL, or not signed by the registry is ignored.
+    fn load_cache(&self) -> Option<CachedTrustSet> {
+        let raw = fs::read_to_string(&self.cache_path).ok()?;
+        let cached: CachedTrustSet = serde_json::from_str(&raw).ok()?;
+        (cached.url == self.url && cached.set.verify(&self.registry_key).is_ok()).then_some(cached)
+    }
+
+    fn save_cache(&self, cached: &CachedTrustSet) -> Result<(), TrustError> {
+        let tmp = self.cache_path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(cached)?)?;
+        fs::rename(&tmp, &self.cache_path)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "trust-registry")]
+mod http {
+    use super::{TrustError, TrustFetcher};
+    use std::time::Duration;
+    use tokio::runtime::Runtime;
+
+    /// GET the trust set over HTTP(S).
+    pub struct HttpFetcher {
+        client: reqwest::Client,
+        runtime: Runtime,
+    }
+
+    impl HttpFetcher {
+        pub fn new() -> Result<Self, TrustError> {
+            let client = reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .map_err(|e| TrustError::Request(e.to_string()))?;
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| TrustError::Request(e.to_string()))?;
+            Ok(Self { client, runtime })
+        }
+    }
+
+    impl TrustFetcher for HttpFetcher {
+        fn fetch(&self, url: &str) -> Result<Vec<u8>, TrustError> {
+            self.runtime.block_on(async {
+                let response = self
+                    .client
+                    .get(url)
+                    .send()
+                    .await
+                    .map_err(|e| TrustError::Request(e.to_string()))?;
+                if !response.status().is_success() {
+                    return Err(TrustError::Status(response.status().as_u16()));
+                }
+                let body = response.bytes().await.map_err(|e| TrustError::Request(e.to_string()))?;
+                Ok(body.to_vec())
+            })
+        }
+    }
+}
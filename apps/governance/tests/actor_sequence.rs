@@ -0,0 +1,122 @@
+//! Per-actor sequence numbers, so an entry missing from one actor shows.
+
+use ed25519_dalek::SigningKey;
+use openlora_governance::audit::{AuditEntry, AuditError, AuditEventType, AuditLog, ChainVerifier};
+use openlora_governance::migrate::migrate;
+use openlora_governance::rechain::rechain;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+fn append(log: &mut AuditLog, actor: &str) -> AuditEntry {
+    log.append(AuditEventType::PolicyEvaluated, actor, None, json!({})).unwrap()
+}
+
+/// Rewrite the log at `path` without entry `index`, re-linking the rest
+/// so only the sequence numbers give the removal away.
+fn drop_and_relink(path: &Path, index: usize) {
+    let mut entries = AuditLog::open(path.to_path_buf()).unwrap().entries().unwrap();
+    entries.remove(index);
+    let mut previous_hash = "genesis".to_string();
+    let mut out = String::new();
+    for mut entry in entries {
+        entry.previous_hash = previous_hash;
+        entry.hash = entry.compute_hash();
+        previous_hash = entry.hash.clone();
+        out.push_str(&serde_json::to_string(&entry).unwrap());
+        out.push('\n');
+    }
+    fs::write(path, out).unwrap();
+}
+
+#[test]
+fn each_actor_counts_its_own_entries_across_reopens() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    assert_eq!(append(&mut log, "alice").actor_seq, Some(1));
+    assert_eq!(append(&mut log, "bob").actor_seq, Some(1));
+    assert_eq!(append(&mut log, "alice").actor_seq, Some(2));
+
+    let mut log = AuditLog::open(path).unwrap();
+    let batch = log
+        .append_batch(vec![
+            (AuditEventType::PolicyEvaluated, "bob", None, json!({})),
+            (AuditEventType::PolicyEvaluated, "bob", None, json!({})),
+            (AuditEventType::PolicyEvaluated, "carol", None, json!({})),
+        ])
+        .unwrap();
+    let seqs: Vec<_> = batch.iter().map(|entry| entry.actor_seq).collect();
+    assert_eq!(seqs, vec![Some(2), Some(3), Some(1)]);
+    assert_eq!(append(&mut log, "alice").actor_seq, Some(3));
+    assert!(log.verify_integrity().unwrap());
+}
+
+#[test]
+fn a_removed_entry_is_a_gap_in_its_actors_sequence() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    for actor in ["alice", "bob", "alice", "bob", "alice"] {
+        append(&mut log, actor);
+    }
+    drop_and_relink(&path, 2);
+
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    let gap = |e: &AuditError| {
+        matches!(e, AuditError::ActorSequenceGap { actor, expected: 2, found: 3, .. } if actor == "alice")
+    };
+    let err = log.verify_integrity().unwrap_err();
+    assert!(gap(&err) && err.is_integrity_failure());
+    assert!(gap(&log.verify_incremental().unwrap_err()));
+
+    let mut verifier = ChainVerifier::new();
+    let failure = log.entries().unwrap().iter().find_map(|entry| verifier.verify(entry).err());
+    assert!(failure.as_ref().is_some_and(gap));
+    assert_eq!(verifier.verified(), 3);
+}
+
+#[test]
+fn rechaining_renumbers_each_actor_from_one() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    for actor in ["alice", "bob", "alice", "alice"] {
+        append(&mut log, actor);
+    }
+    drop_and_relink(&path, 0);
+
+    // A plain migration refuses the gap; an approved rechain closes it.
+    let migrated = dir.path().join("migrated.jsonl");
+    assert!(matches!(migrate(&path, &migrated), Err(AuditError::ActorSequenceGap { .. })));
+    assert!(!migrated.exists());
+    let mut meta = AuditLog::open(dir.path().join("meta.jsonl")).unwrap();
+    let key = SigningKey::from_bytes(&[3; 32]);
+    rechain(&path, &migrated, "alice", &key, "drop entry", &mut meta).unwrap();
+    let log = AuditLog::open(migrated).unwrap();
+    assert!(log.verify_integrity().unwrap());
+    let seqs: Vec<_> = log.entries().unwrap().iter().map(|e| (e.actor.clone(), e.actor_seq)).collect();
+    assert_eq!(
+        seqs,
+        vec![
+            ("bob".to_string(), Some(1)),
+            ("alice".to_string(), Some(1)),
+            ("alice".to_string(), Some(2)),
+        ]
+    );
+}
+
+#[test]
+fn numbers_on_entries_from_before_sequencing_are_not_trusted() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut legacy = append(&mut AuditLog::open(dir.path().join("scratch.jsonl")).unwrap(), "alice");
+    legacy.hash_version = 2;
+    legacy.actor_seq = Some(7);
+    legacy.hash = legacy.compute_hash();
+    fs::write(&path, format!("{}\n", serde_json::to_string(&legacy).unwrap())).unwrap();
+
+    let mut log = AuditLog::open(path).unwrap();
+    assert_eq!(append(&mut log, "alice").actor_seq, Some(1));
+    assert!(log.verify_integrity().unwrap());
+}
@@ -0,0 +1,82 @@
+//! Adapter labels recorded in audit entries and used to filter them.
+
+mod common;
+
+use common::created_chain;
+use openlora_core::AdapterId;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+use openlora_governance::registry::{Registry, RegistryError};
+use serde_json::json;
+
+fn labelled_registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register(created_chain("fraud-v2")).unwrap();
+    registry.register(created_chain("chat-v1")).unwrap();
+    registry.set_label("fraud-v2", "risk-tier", Some("high")).unwrap();
+    registry.set_label("fraud-v2", "team", Some("payments")).unwrap();
+    registry.set_label("chat-v1", "risk-tier", Some("low")).unwrap();
+    registry
+}
+
+#[test]
+fn labels_persist_and_survive_re_registration() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("registry.json");
+    let mut registry = labelled_registry();
+    registry.register(created_chain("fraud-v2")).unwrap();
+    registry.set_label("fraud-v2", "team", None).unwrap();
+    registry.save(&path).unwrap();
+
+    let loaded = Registry::load(&path).unwrap();
+    let labels = &loaded.get("fraud-v2").unwrap().labels;
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels["risk-tier"], "high");
+    assert_eq!(loaded.labels().len(), 2);
+    assert!(matches!(
+        registry.set_label("unknown", "team", Some("x")),
+        Err(RegistryError::NotRegistered(_))
+    ));
+}
+
+#[test]
+fn entries_about_labelled_adapters_carry_their_labels() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    log.set_adapter_labels(labelled_registry().labels());
+
+    let fraud = AuditTarget::Adapter(AdapterId::new("fraud-v2").unwrap());
+    let quarantined = log
+        .append(AuditEventType::AdapterQuarantined, "ops", Some(&fraud), json!({ "reason": "drift" }))
+        .unwrap();
+    assert_eq!(quarantined.details["labels"], json!({ "risk-tier": "high", "team": "payments" }));
+
+    let chosen = log
+        .append(AuditEventType::AdapterActivated, "ops", Some(&fraud), json!({ "labels": { "risk-tier": "set-by-caller" } }))
+        .unwrap();
+    assert_eq!(chosen.details["labels"], json!({ "risk-tier": "set-by-caller" }));
+
+    let unlabelled = AuditTarget::Adapter(AdapterId::new("other").unwrap());
+    let plain = log.append(AuditEventType::AdapterActivated, "ops", Some(&unlabelled), json!({})).unwrap();
+    assert_eq!(plain.details, json!({}));
+    assert!(log.verify_integrity().unwrap());
+}
+
+#[test]
+fn kills_can_be_found_by_the_labels_of_affected_adapters() {
+    let dir = tempfile::tempdir().unwrap();
+    let ks = KillSwitch::new(vec!["alice".to_string()]);
+    let mut gov = Governance::open(ks, dir.path().join("audit.jsonl"));
+    gov.set_adapter_labels(labelled_registry().labels());
+
+    let reason = KillReason::ManualTrigger { operator: "alice".to_string(), reason: None };
+    gov.kill("alice", reason, vec!["fraud-v2".to_string(), "other".to_string()]).unwrap();
+
+    let log = gov.audit_log().unwrap();
+    let high = log.entries_with_label("risk-tier", "high").unwrap();
+    assert_eq!(high.len(), 1);
+    assert!(matches!(high[0].event_type, AuditEventType::KillSwitchActivated));
+    assert_eq!(high[0].details["adapter_labels"], json!({ "fraud-v2": { "risk-tier": "high", "team": "payments" } }));
+    assert!(log.entries_with_label("risk-tier", "low").unwrap().is_empty());
+}
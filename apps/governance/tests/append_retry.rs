@@ -0,0 +1,135 @@
+//! Retried audit writes land exactly once, even after failing mid-line.
+
+use openlora_governance::audit::{append_exactly_once, AppendTarget, RetryPolicy};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+use std::rc::Rc;
+use std::time::Duration;
+
+/// What the in-memory target does on its next few operations.
+#[derive(Default)]
+struct Faults {
+    /// Bytes each failing write gets out before timing out, one per attempt.
+    partial_writes: VecDeque<usize>,
+    /// Syncs that time out after the write went through.
+    failed_syncs: usize,
+}
+
+/// An in-memory file shared between opens, failing as `Faults` says.
+#[derive(Clone, Default)]
+struct Flaky {
+    data: Rc<RefCell<Vec<u8>>>,
+    faults: Rc<RefCell<Faults>>,
+    pos: u64,
+    /// This handle's write was cut off; the rest of the line times out.
+    cut_off: bool,
+}
+
+fn timed_out() -> io::Error {
+    io::Error::new(io::ErrorKind::TimedOut, "remote filesystem timed out")
+}
+
+impl Read for Flaky {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let data = self.data.borrow();
+        let mut cursor = Cursor::new(&data[..]);
+        cursor.set_position(self.pos);
+        let n = cursor.read(buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for Flaky {
+    fn seek(&mut self, from: SeekFrom) -> io::Result<u64> {
+        let SeekFrom::Start(pos) = from else {
+            unimplemented!("only absolute seeks are used");
+        };
+        self.pos = pos;
+        Ok(pos)
+    }
+}
+
+impl Write for Flaky {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.cut_off {
+            return Err(timed_out());
+        }
+        let n = match self.faults.borrow_mut().partial_writes.pop_front() {
+            Some(n) => {
+                self.cut_off = true;
+                n.min(buf.len())
+            }
+            None => buf.len(),
+        };
+        self.data.borrow_mut().extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl AppendTarget for Flaky {
+    fn size(&self) -> io::Result<u64> {
+        Ok(self.data.borrow().len() as u64)
+    }
+
+    fn truncate(&self, len: u64) -> io::Result<()> {
+        self.data.borrow_mut().truncate(len as usize);
+        Ok(())
+    }
+
+    fn sync(&self) -> io::Result<()> {
+        let mut faults = self.faults.borrow_mut();
+        if faults.failed_syncs > 0 {
+            faults.failed_syncs -= 1;
+            return Err(timed_out());
+        }
+        Ok(())
+    }
+}
+
+fn retry(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    }
+}
+
+fn target(existing: &[u8], faults: Faults) -> Flaky {
+    Flaky {
+        data: Rc::new(RefCell::new(existing.to_vec())),
+        faults: Rc::new(RefCell::new(faults)),
+        pos: 0,
+        cut_off: false,
+    }
+}
+
+const LINE: &[u8] = b"{\"id\":\"entry-2\"}\n";
+
+#[test]
+fn a_line_cut_off_mid_write_is_rewritten_once() {
+    let file = target(b"{\"id\":\"entry-1\"}\n", Faults { partial_writes: VecDeque::from([5]), ..Faults::default() });
+    append_exactly_once(|| Ok(file.clone()), &retry(3), LINE).unwrap();
+    assert_eq!(*file.data.borrow(), [&b"{\"id\":\"entry-1\"}\n"[..], LINE].concat());
+}
+
+#[test]
+fn a_complete_line_whose_sync_failed_is_not_written_again() {
+    let file = target(b"", Faults { failed_syncs: 1, ..Faults::default() });
+    append_exactly_once(|| Ok(file.clone()), &retry(3), LINE).unwrap();
+    assert_eq!(*file.data.borrow(), LINE);
+}
+
+#[test]
+fn giving_up_leaves_no_partial_line_behind() {
+    let existing = b"{\"id\":\"entry-1\"}\n";
+    let file = target(existing, Faults { partial_writes: VecDeque::from([5, 9]), ..Faults::default() });
+    let err = append_exactly_once(|| Ok(file.clone()), &retry(2), LINE).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    assert_eq!(*file.data.borrow(), existing);
+}
@@ -0,0 +1,105 @@
+//! Decisions carried out against governance state, every effect audited.
+
+use chrono::{TimeZone, Utc};
+use openlora_core::{AdapterGovernanceStatus, AdapterId, EvaluationTrace, GovernanceDecision, KillReason, ProvenanceOperation};
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::clock::ManualClock;
+use openlora_governance::decision::{ApplyDecision, GovernanceContext};
+use openlora_governance::error::GovernanceError;
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{KillSwitch, KillSwitchError};
+use openlora_governance::quarantine::QuarantineList;
+use openlora_governance::registry::Registry;
+use openlora_governance::replay::replay;
+use openlora_governance::signatures::ProvenanceEntry;
+use serde_json::json;
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    for adapter_id in ["fraud-v2", "chat-v1"] {
+        let mut entry = ProvenanceEntry {
+            adapter_id: adapter_id.to_string(),
+            version: 1,
+            operation: ProvenanceOperation::Created,
+            actor: "alice".to_string(),
+            timestamp: Utc::now(),
+            signature: None,
+            parent_hash: None,
+            content_hash: None,
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        registry.register(vec![entry]).unwrap();
+    }
+    registry
+}
+
+fn adapter(id: &str) -> AdapterId {
+    AdapterId::new(id).unwrap()
+}
+
+#[test]
+fn each_decision_takes_effect_and_is_audited() {
+    let dir = tempfile::tempdir().unwrap();
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut gov = Governance::new(
+        KillSwitch::new(vec!["policy".to_string()]).with_clock(ManualClock::new(start)),
+        AuditLog::open(dir.path().join("audit.jsonl")).unwrap(),
+    );
+    let mut quarantine = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+    let mut registry = registry();
+    let mut ctx = GovernanceContext::new(&mut gov, &mut quarantine, &mut registry, "policy").with_adapter("chat-v1");
+
+    GovernanceDecision::Allow { trace: EvaluationTrace::new() }.apply(&mut ctx).unwrap();
+    GovernanceDecision::Deny { reason: "outside change window".to_string() }.apply(&mut ctx).unwrap();
+    GovernanceDecision::Quarantine { adapter_id: adapter("fraud-v2"), reason: "drift".to_string() }
+        .apply(&mut ctx)
+        .unwrap();
+    GovernanceDecision::Destroy { adapter_id: adapter("chat-v1"), reason: "exfiltration".to_string() }
+        .apply(&mut ctx)
+        .unwrap();
+    let kill = GovernanceDecision::Kill { reason: KillReason::UnauthorizedEscalation { actor: "mallory".to_string() } };
+    kill.apply(&mut ctx).unwrap();
+    assert!(matches!(
+        kill.apply(&mut ctx),
+        Err(GovernanceError::KillSwitch(KillSwitchError::AlreadyActive))
+    ));
+
+    assert!(quarantine.is_quarantined("fraud-v2"));
+    assert_eq!(registry.get("fraud-v2").unwrap().status, Some(AdapterGovernanceStatus::Quarantined));
+    assert_eq!(registry.get("chat-v1").unwrap().status, Some(AdapterGovernanceStatus::Destroyed));
+    let event = &gov.kill_switch().get_events()[0];
+    assert_eq!(event.affected_adapters, vec!["chat-v1".to_string()]);
+    assert_eq!(event.reason.code(), "UnauthorizedEscalation");
+
+    let log = gov.audit_log().unwrap();
+    let entries = log.entries().unwrap();
+    let tags: Vec<_> = entries.iter().map(|entry| entry.event_type.tag()).collect();
+    assert_eq!(tags, vec!["AccessDenied", "AdapterQuarantined", "AdapterDestroyed", "KillSwitchActivated"]);
+    assert_eq!(entries[0].target_id.as_deref(), Some("chat-v1"));
+    assert_eq!(entries[0].details["reason"], "outside change window");
+    assert_eq!(entries[0].details["occurred_at"], json!(start));
+    assert!(matches!(entries[2].event_type, AuditEventType::AdapterDestroyed));
+
+    let state = replay(log).unwrap();
+    assert!(state.killed && state.destroyed.contains("chat-v1"));
+    assert!(state.quarantined.contains_key("fraud-v2"));
+}
+
+#[test]
+fn destroying_an_unregistered_adapter_is_still_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gov = Governance::new(
+        KillSwitch::new(vec!["policy".to_string()]),
+        AuditLog::open(dir.path().join("audit.jsonl")).unwrap(),
+    );
+    let mut quarantine = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+    let mut registry = Registry::new();
+    let mut ctx = GovernanceContext::new(&mut gov, &mut quarantine, &mut registry, "policy");
+
+    GovernanceDecision::Destroy { adapter_id: adapter("stray-v9"), reason: "unknown origin".to_string() }
+        .apply(&mut ctx)
+        .unwrap();
+    assert!(registry.get("stray-v9").is_none());
+    assert!(replay(gov.audit_log().unwrap()).unwrap().destroyed.contains("stray-v9"));
+}
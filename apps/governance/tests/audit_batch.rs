@@ -0,0 +1,69 @@
+//! Batches of audit entries are chained and written all-or-nothing.
+
+use openlora_core::AdapterId;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget, RetryPolicy};
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn batch_entries_are_ordinary_chained_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open_with_recent(path.clone(), 8).unwrap();
+    let adapter = AuditTarget::Adapter(AdapterId::new("adapter-1").unwrap());
+
+    let first = log
+        .append(AuditEventType::AdapterActivated, "ops", Some(&adapter), json!({}))
+        .unwrap();
+    let batch = log
+        .append_batch(vec![
+            (AuditEventType::AdapterQuarantined, "ops", Some(&adapter), json!({ "reason": "drift" })),
+            (AuditEventType::SignatureFailed, "ops", Some(&adapter), json!({})),
+            (AuditEventType::PolicyEvaluated, "policy", None, json!({ "decision": "deny" })),
+        ])
+        .unwrap();
+
+    assert_eq!(batch.len(), 3);
+    assert_eq!(batch[0].previous_hash, first.hash);
+    assert_eq!(batch[1].previous_hash, batch[0].hash);
+    assert_eq!(batch[2].previous_hash, batch[1].hash);
+    assert_eq!(log.head_hash(), batch[2].hash);
+    assert_eq!(log.recent(8).len(), 4);
+
+    let reopened = AuditLog::open(path).unwrap();
+    assert!(reopened.verify_integrity().unwrap());
+    assert_eq!(reopened.head_hash(), batch[2].hash);
+    assert_eq!(reopened.entries().unwrap().len(), 4);
+    assert!(log.append_batch(Vec::new()).unwrap().is_empty());
+}
+
+#[test]
+fn failed_batch_appends_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    log.set_retry_policy(RetryPolicy::none());
+    let head = log
+        .append(AuditEventType::PolicyEvaluated, "ops", None, json!({}))
+        .unwrap()
+        .hash;
+
+    // A directory where the log file should be makes every write fail.
+    let saved = fs::read(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    fs::create_dir(&path).unwrap();
+    let result = log.append_batch(vec![
+        (AuditEventType::AdapterQuarantined, "ops", None, json!({})),
+        (AuditEventType::PolicyEvaluated, "ops", None, json!({})),
+    ]);
+    assert!(result.is_err());
+    assert_eq!(log.head_hash(), head);
+
+    fs::remove_dir(&path).unwrap();
+    fs::write(&path, saved).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({}))
+        .unwrap();
+    let reopened = AuditLog::open(path).unwrap();
+    assert!(reopened.verify_integrity().unwrap());
+    assert_eq!(reopened.entries().unwrap().len(), 2);
+}
@@ -0,0 +1,115 @@
+//! Adversarial rearrangements of a valid audit log are rejected with
+//! errors that say how the chain was broken.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog};
+use proptest::prelude::*;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+/// Write a valid log of `count` entries and return its lines.
+fn valid_lines(path: &Path, count: usize) -> Vec<String> {
+    let mut log = AuditLog::open(path.to_path_buf()).unwrap();
+    for i in 0..count {
+        log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({ "i": i }))
+            .unwrap();
+    }
+    fs::read_to_string(path).unwrap().lines().map(String::from).collect()
+}
+
+fn entry_id(line: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(line).unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+fn verify(path: &Path, lines: &[String]) -> Result<bool, AuditError> {
+    fs::write(path, lines.join("\n") + "\n").unwrap();
+    AuditLog::open(path.to_path_buf()).unwrap().verify_integrity()
+}
+
+#[test]
+fn link_to_a_later_entry_is_a_forward_link() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let lines = valid_lines(&path, 3);
+
+    let reordered = [lines[0].clone(), lines[2].clone(), lines[1].clone()];
+    match verify(&path, &reordered) {
+        Err(AuditError::ForwardLink { id, .. }) => assert_eq!(id, entry_id(&lines[2])),
+        other => panic!("expected ForwardLink, got {:?}", other),
+    }
+}
+
+#[test]
+fn replayed_entry_is_a_back_link() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let lines = valid_lines(&path, 3);
+
+    // Entry 1 appended again after entry 2 closes a loop back to entry 0.
+    let mut cycled = lines.clone();
+    cycled.push(lines[1].clone());
+    match verify(&path, &cycled) {
+        Err(AuditError::BackLink { id, target }) => {
+            assert_eq!(id, entry_id(&lines[1]));
+            assert_eq!(target, entry_id(&lines[0]));
+        }
+        other => panic!("expected BackLink, got {:?}", other),
+    }
+}
+
+#[test]
+fn second_genesis_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let first = valid_lines(&path, 2);
+    fs::remove_file(&path).unwrap();
+    let second = valid_lines(&path, 2);
+
+    let spliced: Vec<String> = first.iter().chain(&second).cloned().collect();
+    match verify(&path, &spliced) {
+        Err(AuditError::GenesisMidChain { id }) => assert_eq!(id, entry_id(&second[0])),
+        other => panic!("expected GenesisMidChain, got {:?}", other),
+    }
+}
+
+#[test]
+fn link_to_an_unknown_hash_is_an_integrity_violation() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let lines = valid_lines(&path, 3);
+
+    let mut dangling = lines.clone();
+    dangling.remove(1);
+    assert!(matches!(
+        verify(&path, &dangling),
+        Err(AuditError::IntegrityViolation { .. })
+    ));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn any_reordering_is_a_chain_error(order in Just((0..6).collect::<Vec<usize>>()).prop_shuffle()) {
+        prop_assume!(order.windows(2).any(|w| w[0] > w[1]));
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let lines = valid_lines(&path, 6);
+
+        let shuffled: Vec<String> = order.iter().map(|&i| lines[i].clone()).collect();
+        let result = verify(&path, &shuffled);
+        prop_assert!(
+            matches!(
+                result,
+                Err(AuditError::ForwardLink { .. })
+                    | Err(AuditError::BackLink { .. })
+                    | Err(AuditError::GenesisMidChain { .. })
+            ),
+            "expected a chain-order error, got {:?}",
+            result
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Audit entries encrypted at rest, with the chain computed over plaintext.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog};
+use openlora_governance::encryption::AuditKey;
+use openlora_governance::follow::AuditFollower;
+use openlora_governance::keys::{generate_audit_key, load_audit_key};
+use serde_json::{json, Value};
+use std::fs;
+
+fn key(byte: u8) -> AuditKey {
+    AuditKey::from_bytes([byte; 32])
+}
+
+#[test]
+fn sealed_entries_hide_details_but_keep_the_chain_visible() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open_with_key(path.clone(), key(1)).unwrap();
+    assert!(log.is_encrypted());
+    let first = log
+        .append(AuditEventType::PolicyEvaluated, "policy", None, json!({ "patient": "jane-doe" }))
+        .unwrap();
+    let second = log.append(AuditEventType::AccessDenied, "policy", None, json!({})).unwrap();
+
+    let raw = fs::read_to_string(&path).unwrap();
+    assert!(!raw.contains("jane-doe") && !raw.contains("PolicyEvaluated"));
+    let lines: Vec<Value> = raw.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(lines[0]["id"], json!(first.id));
+    assert_eq!(lines[1]["previous_hash"], json!(first.hash));
+    assert_eq!(lines[1]["hash"], json!(second.hash));
+
+    let reopened = AuditLog::open_with_key(path.clone(), key(1)).unwrap();
+    assert!(reopened.verify_integrity().unwrap());
+    let entries = reopened.entries().unwrap();
+    assert_eq!(entries[0].details, json!({ "patient": "jane-doe" }));
+    assert_eq!(reopened.head_hash(), second.hash);
+
+    // Without the key only the chain structure is available.
+    let mut locked = AuditLog::open(path).unwrap();
+    assert_eq!(locked.head_hash(), second.hash);
+    assert!(matches!(locked.entries(), Err(AuditError::KeyRequired)));
+    assert!(matches!(locked.verify_integrity(), Err(AuditError::KeyRequired)));
+    assert!(matches!(
+        locked.append(AuditEventType::AccessDenied, "policy", None, json!({})),
+        Err(AuditError::KeyRequired)
+    ));
+    assert!(matches!(locked.set_recent_capacity(4), Err(AuditError::KeyRequired)));
+}
+
+#[test]
+fn wrong_keys_and_tampering_fail_decryption() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open_with_key(path.clone(), key(1)).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({ "n": 1 })).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({ "n": 2 })).unwrap();
+
+    let err = AuditLog::open_with_key(path.clone(), key(2))
+        .unwrap()
+        .verify_integrity()
+        .unwrap_err();
+    assert!(matches!(err, AuditError::Decryption { .. }));
+    assert!(err.is_integrity_failure());
+
+    // Swapping ciphertexts between lines is caught by the bound chain fields.
+    let raw = fs::read_to_string(&path).unwrap();
+    let sealed: Vec<String> = raw
+        .lines()
+        .map(|l| serde_json::from_str::<Value>(l).unwrap()["sealed"].as_str().unwrap().to_string())
+        .collect();
+    let swapped = raw
+        .replace(&sealed[0], "FIRST")
+        .replace(&sealed[1], &sealed[0])
+        .replace("FIRST", &sealed[1]);
+    fs::write(&path, swapped).unwrap();
+    let log = AuditLog::open_with_key(path, key(1)).unwrap();
+    assert!(matches!(log.verify_integrity(), Err(AuditError::Decryption { .. })));
+}
+
+#[test]
+fn a_plaintext_log_can_switch_to_encryption() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({})).unwrap();
+
+    let mut log = AuditLog::open_with_recent(path.clone(), 8).unwrap();
+    log.set_key(key(3)).unwrap();
+    log.append(AuditEventType::AccessDenied, "policy", None, json!({ "secret": true })).unwrap();
+    assert_eq!(log.recent(8).len(), 2);
+    assert!(log.verify_integrity().unwrap());
+
+    let (_, tail) = AuditFollower::open_with_key(path.clone(), 8, key(3)).unwrap();
+    assert_eq!(tail.len(), 2);
+    assert_eq!(tail[1].details, json!({ "secret": true }));
+    assert!(matches!(AuditFollower::open(path, 8), Err(AuditError::KeyRequired)));
+}
+
+#[test]
+fn generated_keys_load_and_are_never_overwritten() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.key");
+    generate_audit_key(&path).unwrap();
+    assert!(generate_audit_key(&path).is_err());
+
+    let mut log = AuditLog::open_with_key(dir.path().join("audit.jsonl"), load_audit_key(&path).unwrap()).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({})).unwrap();
+    let reloaded = load_audit_key(&path).unwrap();
+    assert!(AuditLog::open_with_key(dir.path().join("audit.jsonl"), reloaded)
+        .unwrap()
+        .verify_integrity()
+        .unwrap());
+
+    fs::write(dir.path().join("short.key"), "abcd").unwrap();
+    assert!(load_audit_key(&dir.path().join("short.key")).is_err());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+}
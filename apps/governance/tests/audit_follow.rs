@@ -0,0 +1,65 @@
+//! Following an audit log across appends, partial writes and rotation.
+
+use openlora_governance::audit::{AuditEntry, AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::follow::AuditFollower;
+use serde_json::json;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+fn append(log: &mut AuditLog, name: &str) {
+    log.append(
+        AuditEventType::PolicyEvaluated,
+        "ops",
+        Some(&AuditTarget::Operator(name.to_string())),
+        json!({}),
+    )
+    .unwrap();
+}
+
+fn names(entries: &[AuditEntry]) -> Vec<String> {
+    entries.iter().map(|e| e.target_id.clone().unwrap()).collect()
+}
+
+#[test]
+fn tail_then_follow_without_gaps_or_repeats() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    for name in ["a", "b", "c"] {
+        append(&mut log, name);
+    }
+
+    let (mut follower, tail) = AuditFollower::open(path.clone(), 2).unwrap();
+    assert_eq!(names(&tail), ["b", "c"]);
+    assert!(follower.poll().unwrap().is_empty());
+
+    append(&mut log, "d");
+    // Half an entry is held back until its line is complete.
+    let line = serde_json::to_string(&log.entries().unwrap()[0]).unwrap();
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(&line.as_bytes()[..10]).unwrap();
+    assert_eq!(names(&follower.poll().unwrap()), ["d"]);
+    file.write_all(&line.as_bytes()[10..]).unwrap();
+    file.write_all(b"\n").unwrap();
+    assert_eq!(names(&follower.poll().unwrap()), ["a"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn follow_reopens_a_rotated_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    append(&mut log, "a");
+
+    let (mut follower, _) = AuditFollower::open(path.clone(), 0).unwrap();
+    append(&mut log, "b");
+    fs::rename(&path, dir.path().join("audit.1.jsonl")).unwrap();
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    append(&mut log, "c");
+
+    // The rotated file is drained before the new one is read.
+    assert_eq!(names(&follower.poll().unwrap()), ["b", "c"]);
+    append(&mut log, "d");
+    assert_eq!(names(&follower.poll().unwrap()), ["d"]);
+}
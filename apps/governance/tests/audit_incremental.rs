@@ -0,0 +1,108 @@
+//! Verifying only what was appended since the last verification.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog, AuditTarget};
+use serde_json::json;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+fn append(log: &mut AuditLog, name: &str) {
+    log.append(
+        AuditEventType::PolicyEvaluated,
+        "ops",
+        Some(&AuditTarget::Operator(name.to_string())),
+        json!({ "name": name }),
+    )
+    .unwrap();
+}
+
+#[test]
+fn only_new_entries_are_verified_after_the_first_pass() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    append(&mut log, "a");
+    append(&mut log, "b");
+
+    let first = log.verify_incremental().unwrap();
+    assert_eq!((first.verified, first.appended, first.rescanned), (2, 2, true));
+    assert_eq!(first.head, log.head_hash());
+
+    append(&mut log, "c");
+    let second = log.verify_incremental().unwrap();
+    assert_eq!((second.verified, second.appended, second.rescanned), (3, 1, false));
+    assert_eq!(second.head, log.head_hash());
+
+    let idle = log.verify_incremental().unwrap();
+    assert_eq!((idle.verified, idle.appended, idle.rescanned), (3, 0, false));
+}
+
+#[test]
+fn a_torn_last_line_waits_for_the_next_call() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    append(&mut log, "a");
+    log.verify_incremental().unwrap();
+
+    let mut writer = AuditLog::open(path.clone()).unwrap();
+    append(&mut writer, "b");
+    let contents = fs::read_to_string(&path).unwrap();
+    let (complete, last) = contents.trim_end().rsplit_once('\n').unwrap();
+    let half = last.len() / 2;
+    fs::write(&path, format!("{}\n{}", complete, &last[..half])).unwrap();
+
+    let partial = log.verify_incremental().unwrap();
+    assert_eq!((partial.verified, partial.appended), (1, 0));
+
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    writeln!(file, "{}", &last[half..]).unwrap();
+    let complete = log.verify_incremental().unwrap();
+    assert_eq!((complete.verified, complete.appended, complete.rescanned), (2, 1, false));
+}
+
+#[test]
+fn a_shrunk_or_rewritten_log_is_verified_again_from_genesis() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    append(&mut log, "a");
+    append(&mut log, "b");
+    log.verify_incremental().unwrap();
+
+    let contents = fs::read_to_string(&path).unwrap();
+    let first_line = contents.lines().next().unwrap();
+    fs::write(&path, format!("{}\n", first_line)).unwrap();
+    let shrunk = log.verify_incremental().unwrap();
+    assert_eq!((shrunk.verified, shrunk.rescanned), (1, true));
+
+    fs::write(&path, &contents).unwrap();
+    let restored = log.verify_incremental().unwrap();
+    assert_eq!((restored.verified, restored.appended, restored.rescanned), (2, 1, false));
+
+    // Same length, but the cached head line now carries another hash.
+    let head = log.head_hash().to_string();
+    fs::write(&path, contents.replace(&head, &"0".repeat(head.len()))).unwrap();
+    assert!(matches!(log.verify_incremental(), Err(AuditError::IntegrityViolation { .. })));
+}
+
+#[test]
+fn errors_drop_the_cache_and_full_verification_still_sees_the_prefix() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    append(&mut log, "a");
+    append(&mut log, "b");
+    append(&mut log, "c");
+    log.verify_incremental().unwrap();
+
+    // An edit inside the cached prefix is only caught by a full pass.
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::write(&path, contents.replacen("\"name\":\"a\"", "\"name\":\"z\"", 1)).unwrap();
+    assert!(!log.verify_incremental().unwrap().rescanned);
+    assert!(log.verify_integrity().is_err());
+
+    log.invalidate_verification();
+    assert!(log.verify_incremental().is_err());
+    fs::write(&path, contents).unwrap();
+    let again = log.verify_incremental().unwrap();
+    assert_eq!((again.verified, again.rescanned), (3, true));
+}
@@ -0,0 +1,56 @@
+//! Non-JSON lines are reported by line number; a leading BOM is accepted.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog};
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+fn write_log(path: &Path) -> String {
+    let mut log = AuditLog::open(path.to_path_buf()).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({})).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({})).unwrap();
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn a_leading_bom_is_stripped() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let raw = write_log(&path);
+    fs::write(&path, format!("\u{feff}{}", raw)).unwrap();
+
+    let mut log = AuditLog::open_with_recent(path.clone(), 8).unwrap();
+    assert!(log.verify_integrity().unwrap());
+    assert_eq!(log.entries().unwrap().len(), 2);
+    assert_eq!(log.recent(8).len(), 2);
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({})).unwrap();
+    assert!(AuditLog::open(path).unwrap().verify_integrity().unwrap());
+}
+
+#[test]
+fn garbage_lines_are_reported_with_their_line_number() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let raw = write_log(&path);
+    let (first, rest) = raw.split_once('\n').unwrap();
+
+    for (corrupted, line) in [
+        (format!("{}\n\n# reviewed\n{}", first, rest), 3),
+        (format!("{}trailing\n", raw), 3),
+        (format!("{} junk\n{}", first, rest), 1),
+        (format!("\n{}\u{feff}\n", raw), 4),
+    ] {
+        fs::write(&path, corrupted).unwrap();
+        match AuditLog::open(path.clone()) {
+            Err(AuditError::MalformedLine { line: got }) => assert_eq!(got, line),
+            Err(other) => panic!("expected MalformedLine, got {}", other),
+            Ok(_) => panic!("opened a log with a malformed line {}", line),
+        }
+    }
+
+    // JSON that is simply not an entry stays a serialization error.
+    fs::write(&path, format!("{}{{\"not\": \"an entry\"}}\n", raw)).unwrap();
+    let err = AuditLog::open(path).err().unwrap();
+    assert!(matches!(err, AuditError::Serialization(_)), "{}", err);
+    assert!(err.is_integrity_failure());
+}
@@ -0,0 +1,97 @@
+//! Scoped verification of the entries within a time window.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use openlora_governance::audit::{AuditEntry, AuditError, AuditEventType, AuditLog, RangeVerification, HASH_VERSION};
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+fn minute(n: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::minutes(n)
+}
+
+/// Five entries by alice, one a minute from minute 0.
+fn chain() -> Vec<AuditEntry> {
+    let mut previous_hash = "genesis".to_string();
+    (0..5)
+        .map(|i| {
+            let mut entry = AuditEntry {
+                id: format!("entry-{}", i),
+                timestamp: minute(i),
+                event_type: AuditEventType::PolicyEvaluated,
+                actor: "alice".to_string(),
+                actor_seq: Some(i as u64 + 1),
+                target_type: None,
+                target_id: None,
+                details: json!({ "n": i }),
+                previous_hash: previous_hash.clone(),
+                hash: String::new(),
+                hash_version: HASH_VERSION,
+            };
+            entry.hash = entry.compute_hash();
+            previous_hash.clone_from(&entry.hash);
+            entry
+        })
+        .collect()
+}
+
+fn open(path: &Path, entries: &[AuditEntry]) -> AuditLog {
+    let lines: Vec<String> = entries.iter().map(|e| serde_json::to_string(e).unwrap()).collect();
+    fs::write(path, lines.join("\n") + "\n").unwrap();
+    AuditLog::open(path.to_path_buf()).unwrap()
+}
+
+fn range(verified: usize, skipped_before: usize) -> RangeVerification {
+    RangeVerification { verified, skipped_before }
+}
+
+#[test]
+fn both_bounds_are_inclusive() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = open(&dir.path().join("audit.jsonl"), &chain());
+
+    assert_eq!(log.verify_range(Some(minute(1)), Some(minute(3))).unwrap(), range(3, 1));
+    let between = minute(1) + Duration::seconds(30);
+    assert_eq!(log.verify_range(Some(between), Some(between + Duration::minutes(2))).unwrap(), range(2, 2));
+    assert_eq!(log.verify_range(None, Some(minute(0))).unwrap(), range(1, 0));
+    assert_eq!(log.verify_range(Some(minute(4)), None).unwrap(), range(1, 4));
+    assert_eq!(log.verify_range(Some(minute(5)), None).unwrap(), range(0, 5));
+}
+
+#[test]
+fn tampering_outside_the_window_goes_unnoticed() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut entries = chain();
+    entries[4].details = json!({ "n": 40 });
+    entries[0].details = json!({ "n": 0, "edited": true });
+    let log = open(&dir.path().join("audit.jsonl"), &entries);
+
+    assert_eq!(log.verify_range(Some(minute(2)), Some(minute(3))).unwrap(), range(2, 2));
+    assert!(log.verify_range(None, Some(minute(4))).is_err());
+}
+
+#[test]
+fn the_entry_the_window_links_to_must_be_intact() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut entries = chain();
+    entries[1].details = json!({ "n": 10 });
+    let log = open(&dir.path().join("audit.jsonl"), &entries);
+
+    assert!(matches!(
+        log.verify_range(Some(minute(2)), Some(minute(3))),
+        Err(AuditError::IntegrityViolation { .. })
+    ));
+    assert_eq!(log.verify_range(Some(minute(3)), None).unwrap(), range(2, 3));
+}
+
+#[test]
+fn unverified_entries_before_the_window_do_not_fail_it() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut entries = chain();
+    // Entry 0 no longer matches its hash, but only entry 1's is checked.
+    entries[0].hash_version = HASH_VERSION + 1;
+    entries[0].actor_seq = Some(50);
+    let log = open(&dir.path().join("audit.jsonl"), &entries);
+
+    assert_eq!(log.verify_range(Some(minute(2)), None).unwrap(), range(3, 2));
+}
@@ -0,0 +1,58 @@
+//! The in-memory buffer of recent audit entries.
+
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use serde_json::json;
+
+fn append(log: &mut AuditLog, i: usize) {
+    // Large details so the tail spans several read chunks.
+    log.append(
+        AuditEventType::PolicyEvaluated,
+        "monitor",
+        Some(&AuditTarget::Operator(i.to_string())),
+        json!({ "padding": "x".repeat(10_000) }),
+    )
+    .unwrap();
+}
+
+fn ids(entries: &[openlora_governance::audit::AuditEntry]) -> Vec<String> {
+    entries.iter().map(|e| e.target_id.clone().unwrap()).collect()
+}
+
+#[test]
+fn recent_is_filled_from_the_tail_and_follows_appends() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    for i in 0..50 {
+        append(&mut log, i);
+    }
+    // Disabled by default.
+    assert!(log.recent(10).is_empty());
+
+    let mut log = AuditLog::open_with_recent(path, 20).unwrap();
+    assert_eq!(ids(log.recent(3)), ["47", "48", "49"]);
+    assert_eq!(log.recent(100).len(), 20);
+    assert_eq!(ids(&log.recent(100)[..1]), ["30"]);
+
+    for i in 50..55 {
+        append(&mut log, i);
+    }
+    let recent = log.recent(20);
+    assert_eq!(recent.len(), 20);
+    assert_eq!(ids(&recent[..1]), ["35"]);
+    assert_eq!(ids(&recent[19..]), ["54"]);
+    assert_eq!(recent.last().unwrap().hash, log.head_hash());
+}
+
+#[test]
+fn short_logs_fill_the_buffer_partially() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open_with_recent(path.clone(), 5).unwrap();
+    assert!(log.recent(5).is_empty());
+    append(&mut log, 0);
+    append(&mut log, 1);
+
+    let reopened = AuditLog::open_with_recent(path, 5).unwrap();
+    assert_eq!(ids(reopened.recent(5)), ["0", "1"]);
+}
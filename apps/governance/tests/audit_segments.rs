@@ -0,0 +1,45 @@
+//! Verifying a log segment that continues another chain.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog, AuditTarget};
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn a_continuation_segment_verifies_from_the_previous_head() {
+    let dir = tempfile::tempdir().unwrap();
+    let full = dir.path().join("full.jsonl");
+    let mut log = AuditLog::open(full.clone()).unwrap();
+    let mut hashes = Vec::new();
+    for name in ["a", "b", "c", "d"] {
+        let entry = log
+            .append(
+                AuditEventType::PolicyEvaluated,
+                "ops",
+                Some(&AuditTarget::Operator(name.to_string())),
+                json!({ "name": name }),
+            )
+            .unwrap();
+        hashes.push(entry.hash);
+    }
+
+    let contents = fs::read_to_string(&full).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    let segment = dir.path().join("segment.jsonl");
+    fs::write(&segment, format!("{}\n{}\n", lines[2], lines[3])).unwrap();
+    let segment = AuditLog::open(segment).unwrap();
+
+    assert!(segment.verify_integrity_from(&hashes[1]).unwrap());
+    assert!(matches!(
+        segment.verify_integrity(),
+        Err(AuditError::IntegrityViolation { ref expected, ref actual }) if expected == "genesis" && *actual == hashes[1]
+    ));
+    assert!(matches!(
+        segment.verify_integrity_from(&hashes[0]),
+        Err(AuditError::IntegrityViolation { .. })
+    ));
+
+    // The first segment still verifies from genesis, and a genesis entry
+    // is not a valid continuation.
+    assert!(log.verify_integrity_from("genesis").unwrap());
+    assert!(matches!(log.verify_integrity_from(&hashes[3]), Err(AuditError::IntegrityViolation { .. })));
+}
@@ -0,0 +1,52 @@
+//! Independent audit chains sharing one directory, selected by stream key.
+
+use openlora_governance::audit::{stream_path, AuditError, AuditEventType, AuditLog, AuditStreams};
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn each_stream_is_its_own_chain() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut streams = AuditStreams::new(dir.path().to_path_buf());
+
+    let training = streams.append("training", AuditEventType::PolicyEvaluated, "trainer", None, json!({})).unwrap();
+    let serving = streams.append("serving", AuditEventType::PolicyEvaluated, "server", None, json!({})).unwrap();
+    streams.append("training", AuditEventType::PolicyEvaluated, "trainer", None, json!({})).unwrap();
+
+    // Both streams start from genesis rather than one following the other.
+    assert_eq!(training.previous_hash, "genesis");
+    assert_eq!(serving.previous_hash, "genesis");
+    assert_eq!(streams.stream("training").unwrap().entries().unwrap().len(), 2);
+    assert_eq!(streams.stream("serving").unwrap().head_hash(), serving.hash);
+    assert_eq!(streams.stream("serving").unwrap().stream(), Some("serving"));
+    assert_eq!(streams.list().unwrap(), ["serving", "training"]);
+}
+
+#[test]
+fn streams_are_verified_by_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut streams = AuditStreams::new(dir.path().to_path_buf());
+    for stream in ["training", "serving"] {
+        for n in 0..3 {
+            streams.append(stream, AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": n })).unwrap();
+        }
+    }
+
+    let path = stream_path(dir.path(), "serving").unwrap();
+    fs::write(&path, fs::read_to_string(&path).unwrap().replace("\"n\":1", "\"n\":10")).unwrap();
+
+    assert!(streams.verify_integrity("training").unwrap());
+    assert!(streams.verify_integrity("serving").unwrap_err().is_integrity_failure());
+    assert!(AuditLog::open_stream(dir.path(), "training").unwrap().verify_integrity().unwrap());
+}
+
+#[test]
+fn stream_names_cannot_escape_the_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in ["", "../outside", "a/b", "with space", "x.jsonl"] {
+        assert!(matches!(stream_path(dir.path(), name), Err(AuditError::InvalidStream(_))), "{:?}", name);
+    }
+    let mut streams = AuditStreams::new(dir.path().to_path_buf());
+    assert!(streams.stream("../outside").is_err());
+    assert!(streams.list().unwrap().is_empty());
+}
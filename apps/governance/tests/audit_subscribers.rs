@@ -0,0 +1,83 @@
+//! In-process subscribers to audit appends.
+
+use openlora_governance::audit::{AuditEventType, AuditLog, BatchEntry, SUBSCRIBER_QUEUE_CAPACITY};
+use serde_json::json;
+use std::sync::mpsc::{channel, sync_channel};
+use std::time::{Duration, Instant};
+
+const WAIT: Duration = Duration::from_secs(10);
+
+fn append(log: &mut AuditLog, n: usize) -> String {
+    log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": n })).unwrap().id
+}
+
+#[test]
+fn every_subscriber_sees_each_durable_entry_in_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+
+    let receivers: Vec<_> = (0..2)
+        .map(|_| {
+            let (tx, rx) = channel();
+            let path = path.clone();
+            log.subscribe(Box::new(move |entry| {
+                // Already on disk by the time it is delivered.
+                let on_disk = std::fs::read_to_string(&path).unwrap().contains(&entry.id);
+                tx.send((entry.id.clone(), on_disk)).unwrap();
+            }));
+            rx
+        })
+        .collect();
+
+    let ids: Vec<String> = (0..3).map(|n| append(&mut log, n)).collect();
+    for rx in receivers {
+        let seen: Vec<(String, bool)> = (0..3).map(|_| rx.recv_timeout(WAIT).unwrap()).collect();
+        assert_eq!(seen, ids.iter().map(|id| (id.clone(), true)).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn a_panicking_subscriber_is_dropped_without_harming_others() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let panicky = log.subscribe(Box::new(|_| panic!("subscriber bug")));
+    let (tx, rx) = channel();
+    log.subscribe(Box::new(move |entry| tx.send(entry.id.clone()).unwrap()));
+
+    // Its thread has died once an append notices it is gone.
+    let deadline = Instant::now() + WAIT;
+    let mut appended = 0;
+    while log.dropped_for(panicky).is_some() {
+        assert!(Instant::now() < deadline, "panicking subscriber never removed");
+        append(&mut log, appended);
+        appended += 1;
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    assert_eq!(rx.iter().take(appended).count(), appended);
+    assert!(log.verify_integrity().unwrap());
+}
+
+#[test]
+fn a_stuck_subscriber_drops_entries_instead_of_blocking_appends() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let (release, gate) = sync_channel::<()>(0);
+    let stuck = log.subscribe(Box::new(move |_| {
+        let _ = gate.recv();
+    }));
+
+    let overflow = 10;
+    let batch: Vec<BatchEntry> = (0..SUBSCRIBER_QUEUE_CAPACITY + 1 + overflow)
+        .map(|n| (AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": n })))
+        .collect();
+    log.append_batch(batch).unwrap();
+
+    // One entry may still have been queued when the callback took the first.
+    let dropped = log.dropped_for(stuck).unwrap();
+    assert!((overflow as u64..=overflow as u64 + 1).contains(&dropped), "dropped {}", dropped);
+    assert!(log.verify_integrity().unwrap());
+    drop(release);
+    assert!(log.unsubscribe(stuck));
+}
@@ -0,0 +1,50 @@
+//! Typed audit targets round-trip through the stored strings.
+
+use openlora_core::{AdapterId, ModelId};
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use serde_json::json;
+
+#[test]
+fn typed_targets_are_stored_as_strings_and_read_back() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let adapter = AuditTarget::Adapter(AdapterId::new("adapter-1").unwrap());
+    let model = AuditTarget::Model(ModelId::new("llama-3").unwrap());
+
+    let entry = log
+        .append(AuditEventType::AdapterActivated, "ops", Some(&adapter), json!({}))
+        .unwrap();
+    assert_eq!(entry.target_type.as_deref(), Some("adapter"));
+    assert_eq!(entry.target_id.as_deref(), Some("adapter-1"));
+
+    log.append(AuditEventType::PolicyEvaluated, "ops", Some(&model), json!({}))
+        .unwrap();
+    log.append(AuditEventType::AdapterDeactivated, "ops", Some(&adapter), json!({}))
+        .unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({}))
+        .unwrap();
+
+    let reopened = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    reopened.verify_integrity().unwrap();
+    let entries = reopened.entries_for(&adapter).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e.target() == Some(adapter.clone())));
+    assert_eq!(reopened.entries_for(&model).unwrap().len(), 1);
+}
+
+#[test]
+fn unrecognised_targets_are_kept_verbatim() {
+    let target = AuditTarget::from_parts("dataset", Some("wiki-2024"));
+    assert_eq!(
+        target,
+        AuditTarget::Other {
+            kind: "dataset".into(),
+            id: Some("wiki-2024".into()),
+        }
+    );
+
+    // An adapter id that no longer validates is not silently dropped.
+    let legacy = AuditTarget::from_parts("adapter", Some("bad id"));
+    assert_eq!(legacy.kind(), "adapter");
+    assert_eq!(legacy.id(), Some("bad id"));
+}
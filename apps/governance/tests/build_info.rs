@@ -0,0 +1,40 @@
+//! Build information reported by `openlora-gov info`.
+
+use openlora_governance::audit::HASH_VERSION;
+use openlora_governance::build_info::{enabled_features, AUDIT_HASH_VERSION, GIT_HASH, VERSION};
+
+#[test]
+fn every_optional_feature_compiled_in_is_listed() {
+    let compiled: Vec<&str> = [
+        ("object-store", cfg!(feature = "object-store")),
+        ("webhook", cfg!(feature = "webhook")),
+        ("deadman", cfg!(feature = "deadman")),
+        ("timezone", cfg!(feature = "timezone")),
+        ("trust-registry", cfg!(feature = "trust-registry")),
+        ("protobuf", cfg!(feature = "protobuf")),
+    ]
+    .into_iter()
+    .filter_map(|(name, on)| on.then_some(name))
+    .collect();
+    assert_eq!(enabled_features(), compiled);
+}
+
+#[test]
+#[cfg(not(any(
+    feature = "object-store",
+    feature = "webhook",
+    feature = "deadman",
+    feature = "timezone",
+    feature = "trust-registry",
+    feature = "protobuf"
+)))]
+fn the_default_build_has_no_optional_features() {
+    assert!(enabled_features().is_empty());
+}
+
+#[test]
+fn version_and_hash_scheme_match_the_crate() {
+    assert_eq!(VERSION, env!("CARGO_PKG_VERSION"));
+    assert!(!GIT_HASH.is_empty());
+    assert_eq!(AUDIT_HASH_VERSION, HASH_VERSION);
+}
@@ -0,0 +1,72 @@
+//! Quarantining many adapters at once, continuing past failures.
+
+use chrono::{TimeZone, Utc};
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::clock::ManualClock;
+use openlora_governance::details::check;
+use openlora_governance::governance::{AuditStatus, Governance};
+use openlora_governance::killswitch::KillSwitch;
+use openlora_governance::quarantine::{read_adapter_ids, QuarantineError, QuarantineList};
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn every_listed_adapter_is_tried_and_the_batch_summarized() {
+    let dir = tempfile::tempdir().unwrap();
+    let ids = dir.path().join("ids.txt");
+    fs::write(&ids, "# incident-123\nfraud-v2\n\n  chat-v1  \nbad id\nfraud-v2\nsearch-v3\n").unwrap();
+    let adapter_ids = read_adapter_ids(&ids).unwrap();
+    assert_eq!(adapter_ids, vec!["fraud-v2", "chat-v1", "bad id", "fraud-v2", "search-v3"]);
+
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut gov = Governance::new(
+        KillSwitch::new(vec!["ops".to_string()]).with_clock(ManualClock::new(start)),
+        AuditLog::open(dir.path().join("audit.jsonl")).unwrap(),
+    );
+    let mut list = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+    let batch = gov.quarantine_batch(&mut list, &adapter_ids, "incident-123", "ops");
+
+    assert_eq!(batch.quarantined, vec!["fraud-v2", "chat-v1", "search-v3"]);
+    assert_eq!(batch.failed.len(), 2);
+    assert!(matches!(batch.failed[0], (ref id, QuarantineError::InvalidId(_)) if id == "bad id"));
+    assert!(matches!(batch.failed[1], (ref id, QuarantineError::AlreadyQuarantined(_)) if id == "fraud-v2"));
+    assert!(batch.escalation.is_none());
+    assert_eq!(list.len(), 3);
+
+    let AuditStatus::Recorded(ref summary) = batch.summary else {
+        panic!("summary not recorded: {:?}", batch.summary);
+    };
+    assert!(matches!(summary.event_type, AuditEventType::QuarantineBatchCompleted));
+    assert_eq!(summary.details["quarantined"].as_array().unwrap().len(), 3);
+    assert_eq!(summary.details["failed"][1]["adapter_id"], "fraud-v2");
+    assert_eq!(summary.details["occurred_at"], json!(start));
+    check(&summary.event_type, &summary.details.to_string()).unwrap();
+
+    let log = gov.audit_log().unwrap();
+    let per_adapter = log
+        .entries()
+        .unwrap()
+        .iter()
+        .filter(|entry| matches!(entry.event_type, AuditEventType::AdapterQuarantined))
+        .count();
+    assert_eq!(per_adapter, 3);
+    assert!(log.verify_integrity().unwrap());
+}
+
+#[test]
+fn a_batch_crossing_the_limit_escalates_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gov = Governance::new(
+        KillSwitch::new(vec!["ops".to_string()]),
+        AuditLog::open(dir.path().join("audit.jsonl")).unwrap(),
+    );
+    gov.set_quarantine_limit(Some(1));
+    let mut list = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+    let adapter_ids: Vec<String> = ["a-1", "a-2", "a-3"].map(String::from).to_vec();
+    let batch = gov.quarantine_batch(&mut list, &adapter_ids, "incident-123", "ops");
+
+    assert_eq!(batch.quarantined.len(), 3);
+    let killed = batch.escalation.unwrap().unwrap();
+    assert_eq!(killed.outcome.affected_adapters, vec!["a-1", "a-2"]);
+    assert_eq!(gov.kill_switch().get_events().len(), 1);
+}
@@ -0,0 +1,82 @@
+//! Provenance entries are signed over canonical CBOR other languages can rebuild.
+
+use chrono::{TimeZone, Utc};
+use openlora_core::ProvenanceOperation;
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, ProvenanceEntry, SignatureVerifier};
+
+fn entry() -> ProvenanceEntry {
+    let mut entry = ProvenanceEntry {
+        adapter_id: "adapter-1".to_string(),
+        version: 1,
+        operation: ProvenanceOperation::Created,
+        actor: "alice".to_string(),
+        timestamp: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        signature: None,
+        parent_hash: None,
+        content_hash: None,
+        hash: String::new(),
+    };
+    entry.hash = entry.compute_hash();
+    entry
+}
+
+/// `entry` signed by alice, and a verifier holding her key.
+fn signed(mut entry: ProvenanceEntry) -> (ProvenanceEntry, SignatureVerifier) {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    entry.signature = Some(sign_ed25519(&entry.canonical_bytes(), "alice", &key, None));
+    (entry, verifier)
+}
+
+/// A CBOR text string shorter than 24 bytes, or one byte-length prefixed.
+fn text(s: &str) -> Vec<u8> {
+    let mut bytes = if s.len() < 24 { vec![0x60 + s.len() as u8] } else { vec![0x78, s.len() as u8] };
+    bytes.extend_from_slice(s.as_bytes());
+    bytes
+}
+
+#[test]
+fn the_bytes_are_rfc_8949_deterministic_cbor() {
+    // Keys sorted by their encoding: shorter first, then bytewise.
+    let mut expected = vec![0xa5];
+    for (key, value) in [
+        ("actor", text("alice")),
+        ("version", vec![0x01]),
+        ("operation", text("Created")),
+        ("timestamp", text("2024-01-01T00:00:00.000000000Z")),
+        ("adapter_id", text("adapter-1")),
+    ] {
+        expected.extend(text(key));
+        expected.extend(value);
+    }
+    assert_eq!(entry().canonical_bytes(), expected);
+}
+
+#[test]
+fn the_signature_and_hash_are_not_part_of_the_message() {
+    let unsigned = entry();
+    let (mut signed, verifier) = signed(entry());
+    signed.hash = "rewritten".into();
+    assert_eq!(signed.canonical_bytes(), unsigned.canonical_bytes());
+    assert!(verifier.verify_provenance_signature(&signed).unwrap());
+
+    // Recorded hashes are signed once present.
+    let mut with_content = entry();
+    with_content.content_hash = Some("sha256:abc".into());
+    assert_ne!(with_content.canonical_bytes(), unsigned.canonical_bytes());
+}
+
+#[test]
+fn changing_a_signed_field_breaks_the_signature() {
+    let (signed, verifier) = signed(entry());
+
+    let mut tampered = signed.clone();
+    tampered.version = 2;
+    assert!(!matches!(verifier.verify_provenance_signature(&tampered), Ok(true)));
+    let mut tampered = signed.clone();
+    tampered.timestamp += chrono::Duration::nanoseconds(1);
+    assert!(!matches!(verifier.verify_provenance_signature(&tampered), Ok(true)));
+    assert!(!verifier.verify_provenance_signature(&entry()).unwrap());
+}
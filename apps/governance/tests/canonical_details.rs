@@ -0,0 +1,61 @@
+//! Stored `details` verify only in canonical form, as when parsed whole.
+
+use chrono::{TimeZone, Utc};
+use openlora_core::hashing::Hasher;
+use openlora_governance::audit::{AuditEntry, AuditError, AuditEventType, AuditLog};
+use serde_json::json;
+use std::fs;
+
+/// A v2 entry line storing `raw` as its details, hashed over those bytes.
+fn line_with_raw_details(raw: &str) -> String {
+    let mut entry = AuditEntry {
+        id: "entry-0".to_string(),
+        timestamp: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        event_type: AuditEventType::PolicyEvaluated,
+        actor: "alice".to_string(),
+        actor_seq: None,
+        target_type: None,
+        target_id: None,
+        details: json!({ "a": 2, "b": 1 }),
+        previous_hash: "genesis".to_string(),
+        hash: String::new(),
+        hash_version: 2,
+    };
+    entry.hash = [
+        entry.id.as_str(),
+        &entry.timestamp.to_rfc3339(),
+        "PolicyEvaluated",
+        "alice",
+        raw,
+        "genesis",
+    ]
+    .into_iter()
+    .fold(Hasher::domain("openlora.audit.v2"), Hasher::field)
+    .finish();
+    serde_json::to_string(&entry).unwrap().replace(r#""details":{"a":2,"b":1}"#, &format!(r#""details":{}"#, raw))
+}
+
+fn verify(line: String) -> Result<bool, AuditError> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    fs::write(&path, line + "\n").unwrap();
+    AuditLog::open(path).unwrap().verify_integrity()
+}
+
+#[test]
+fn canonical_details_verify() {
+    assert!(verify(line_with_raw_details(r#"{"a":2,"b":1}"#)).unwrap());
+}
+
+#[test]
+fn a_hash_over_non_canonical_details_is_rejected() {
+    for raw in [r#"{"b":1,"a":2}"#, r#"{"a": 2, "b": 1}"#] {
+        let line = line_with_raw_details(raw);
+        assert!(line.contains(raw));
+        assert!(
+            matches!(verify(line), Err(AuditError::IntegrityViolation { .. })),
+            "{}",
+            raw
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Verifying entries one at a time as a log grows.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog, AuditTarget, ChainVerifier};
+use openlora_governance::follow::AuditFollower;
+use serde_json::json;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+fn append(log: &mut AuditLog, name: &str) {
+    log.append(
+        AuditEventType::PolicyEvaluated,
+        "ops",
+        Some(&AuditTarget::Operator(name.to_string())),
+        json!({ "name": name }),
+    )
+    .unwrap();
+}
+
+#[test]
+fn followed_entries_verify_against_the_running_head() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    append(&mut log, "a");
+    append(&mut log, "b");
+
+    let (mut follower, existing) = AuditFollower::open(path.clone(), usize::MAX).unwrap();
+    let mut verifier = ChainVerifier::new();
+    for entry in &existing {
+        verifier.verify(entry).unwrap();
+    }
+
+    append(&mut log, "c");
+    append(&mut log, "d");
+    for entry in follower.poll().unwrap() {
+        verifier.verify(&entry).unwrap();
+    }
+    assert_eq!(verifier.verified(), 4);
+    assert_eq!(verifier.head(), log.head_hash());
+}
+
+#[test]
+fn the_first_bad_entry_stops_verification() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    for name in ["a", "b", "c"] {
+        append(&mut log, name);
+    }
+    let entries = log.entries().unwrap();
+
+    let mut verifier = ChainVerifier::new();
+    verifier.verify(&entries[0]).unwrap();
+
+    let mut edited = entries[1].clone();
+    edited.details = json!({ "name": "x" });
+    assert!(matches!(verifier.verify(&edited), Err(AuditError::IntegrityViolation { .. })));
+    // The head did not move, so skipping ahead fails as well.
+    assert!(verifier.verify(&entries[2]).is_err());
+    assert_eq!(verifier.head(), entries[0].hash);
+    verifier.verify(&entries[1]).unwrap();
+}
+
+#[test]
+fn an_entry_restarting_from_genesis_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    append(&mut log, "a");
+
+    let (mut follower, existing) = AuditFollower::open(path.clone(), usize::MAX).unwrap();
+    let mut verifier = ChainVerifier::new();
+    verifier.verify(&existing[0]).unwrap();
+
+    // Splice in the first entry of an unrelated log.
+    let other_dir = tempfile::tempdir().unwrap();
+    let mut other = AuditLog::open(other_dir.path().join("audit.jsonl")).unwrap();
+    append(&mut other, "z");
+    let line = serde_json::to_string(&other.entries().unwrap()[0]).unwrap();
+    writeln!(OpenOptions::new().append(true).open(&path).unwrap(), "{}", line).unwrap();
+
+    let spliced = follower.poll().unwrap();
+    assert!(matches!(verifier.verify(&spliced[0]), Err(AuditError::GenesisMidChain { .. })));
+}
@@ -0,0 +1,138 @@
+//! Single-use challenges answered by an offline signer.
+
+use chrono::{Duration, Utc};
+use ed25519_dalek::{Signer, SigningKey};
+use openlora_governance::clock::ManualClock;
+use openlora_governance::signatures::{sign_ed25519, SignatureError, SignatureVerifier};
+
+fn token() -> SigningKey {
+    SigningKey::from_bytes(&[7; 32])
+}
+
+fn verifier(clock: &ManualClock) -> SignatureVerifier {
+    let mut verifier = SignatureVerifier::new(Vec::new()).with_clock(clock.clone());
+    verifier.add_signer_key("hsm-1", token().verifying_key());
+    verifier
+}
+
+#[test]
+fn a_signed_challenge_verifies_once() {
+    let clock = ManualClock::new(Utc::now());
+    let verifier = verifier(&clock);
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    assert_eq!(challenge.nonce.len(), 64);
+    assert_eq!(challenge.expires_at - challenge.issued_at, Duration::minutes(5));
+    assert_ne!(verifier.issue_challenge(b"adapter weights").nonce, challenge.nonce);
+
+    // Signed offline; only the challenge and the response cross the gap.
+    let response = challenge.sign("hsm-1", &token());
+    assert!(verifier.verify_challenge_response(&challenge, &response).unwrap());
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &response),
+        Err(SignatureError::UnknownChallenge(ref nonce)) if *nonce == challenge.nonce
+    ));
+}
+
+#[test]
+fn bad_responses_use_the_challenge_up() {
+    let clock = ManualClock::new(Utc::now());
+    let verifier = verifier(&clock);
+
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let forged = challenge.sign("hsm-1", &SigningKey::from_bytes(&[8; 32]));
+    assert!(!verifier.verify_challenge_response(&challenge, &forged).unwrap());
+    let response = challenge.sign("hsm-1", &token());
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &response),
+        Err(SignatureError::UnknownChallenge(_))
+    ));
+
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let untrusted = challenge.sign("mallory", &token());
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &untrusted),
+        Err(SignatureError::UnknownSigner(_))
+    ));
+}
+
+#[test]
+fn altered_or_expired_challenges_are_refused() {
+    let clock = ManualClock::new(Utc::now());
+    let mut verifier = verifier(&clock);
+
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let mut altered = challenge.clone();
+    altered.content_hash = "00".repeat(32);
+    let response = altered.sign("hsm-1", &token());
+    assert!(matches!(
+        verifier.verify_challenge_response(&altered, &response),
+        Err(SignatureError::ChallengeMismatch(_))
+    ));
+
+    verifier.set_challenge_ttl(Duration::seconds(30));
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let response = challenge.sign("hsm-1", &token());
+    clock.advance(Duration::seconds(30));
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &response),
+        Err(SignatureError::ChallengeExpired { expires_at, .. }) if expires_at == challenge.expires_at
+    ));
+}
+
+#[test]
+fn responses_must_be_ed25519_from_a_keyed_signer() {
+    let clock = ManualClock::new(Utc::now());
+    let mut verifier = verifier(&clock);
+    verifier.set_allow_legacy_sha256(true);
+
+    // A sha256 "signature" needs no key: anyone could compute it.
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let sha256 = verifier.sign(&challenge.message(), "hsm-1");
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &sha256),
+        Err(SignatureError::UnsupportedAlgorithm { .. })
+    ));
+    let response = challenge.sign("hsm-1", &token());
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &response),
+        Err(SignatureError::UnknownChallenge(_))
+    ));
+
+    let mut verifier = SignatureVerifier::new(vec!["keyless".to_string()]).with_clock(clock.clone());
+    verifier.set_allow_legacy_sha256(true);
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let sha256 = verifier.sign(&challenge.message(), "keyless");
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &sha256),
+        Err(SignatureError::UnsupportedAlgorithm { .. })
+    ));
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let response = challenge.sign("keyless", &token());
+    assert!(matches!(
+        verifier.verify_challenge_response(&challenge, &response),
+        Err(SignatureError::MissingKey(ref signer)) if signer == "keyless"
+    ));
+}
+
+#[test]
+fn the_token_signs_the_message_bytes_themselves() {
+    let clock = ManualClock::new(Utc::now());
+    let verifier = verifier(&clock);
+
+    // What a token outside this crate returns: a bare signature over `message()`.
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let value = hex::encode(token().sign(&challenge.message()).to_bytes());
+    let response = serde_json::from_value(serde_json::json!({
+        "algorithm": "ed25519",
+        "value": value,
+        "signer_id": "hsm-1",
+        "signed_at": Utc::now(),
+    }))
+    .unwrap();
+    assert!(verifier.verify_challenge_response(&challenge, &response).unwrap());
+
+    // A content signature over the message is not a response to it.
+    let challenge = verifier.issue_challenge(b"adapter weights");
+    let content_signature = sign_ed25519(&challenge.message(), "hsm-1", &token(), None);
+    assert!(!verifier.verify_challenge_response(&challenge, &content_signature).unwrap());
+}
@@ -0,0 +1,66 @@
+//! Attesting that an adapter's audit history holds no negative events.
+
+use ed25519_dalek::SigningKey;
+use openlora_governance::attest::{check_clean_attestation, AttestError};
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::signatures::{SignatureError, SignatureVerifier};
+use serde_json::json;
+
+fn adapter(id: &str) -> AuditTarget {
+    AuditTarget::from_parts("adapter", Some(id))
+}
+
+#[test]
+fn a_clean_adapter_is_attested_and_later_incidents_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let key = SigningKey::from_bytes(&[9; 32]);
+    log.append(AuditEventType::AdapterActivated, "alice", Some(&adapter("fraud-v2")), json!({})).unwrap();
+    log.append(AuditEventType::AdapterQuarantined, "ops", Some(&adapter("chat-v1")), json!({ "reason": "drift" }))
+        .unwrap();
+
+    let attestation = log.attest_clean("fraud-v2", "reviewer", &key).unwrap();
+    assert_eq!(attestation.claims.adapter_id, "fraud-v2");
+    assert_eq!(attestation.claims.count, 2);
+    assert_eq!(attestation.claims.head_hash, log.entries().unwrap()[1].hash);
+
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("reviewer", key.verifying_key());
+    let check = check_clean_attestation(&log, &attestation, &verifier).unwrap();
+    assert_eq!(check.newer_entries, 0);
+    assert!(check.later_incidents.is_empty());
+
+    let destroyed = log
+        .append(AuditEventType::AdapterDestroyed, "ops", Some(&adapter("fraud-v2")), json!({ "reason": "leak" }))
+        .unwrap();
+    let check = check_clean_attestation(&log, &attestation, &verifier).unwrap();
+    assert_eq!(check.newer_entries, 1);
+    assert_eq!(check.later_incidents, vec![format!("AdapterDestroyed {}", destroyed.id)]);
+
+    let mut forged = attestation.clone();
+    forged.claims.adapter_id = "chat-v1".to_string();
+    assert!(matches!(
+        check_clean_attestation(&log, &forged, &verifier),
+        Err(AttestError::Signature(SignatureError::ContentMismatch { .. }))
+    ));
+}
+
+#[test]
+fn an_adapter_with_negative_history_is_refused() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let key = SigningKey::from_bytes(&[9; 32]);
+    let failed = log
+        .append(AuditEventType::SignatureFailed, "ci", Some(&adapter("fraud-v2")), json!({}))
+        .unwrap();
+    log.append(AuditEventType::AdapterActivated, "alice", Some(&adapter("fraud-v2")), json!({})).unwrap();
+
+    match log.attest_clean("fraud-v2", "reviewer", &key) {
+        Err(AttestError::NotClean { adapter_id, incidents }) => {
+            assert_eq!(adapter_id, "fraud-v2");
+            assert_eq!(incidents, vec![format!("SignatureFailed {}", failed.id)]);
+        }
+        other => panic!("expected NotClean, got {:?}", other.map(|a| a.claims)),
+    }
+    assert!(log.attest_clean("chat-v1", "reviewer", &key).is_ok());
+}
@@ -0,0 +1,67 @@
+//! Time-dependent kill-switch behaviour driven by a manual clock.
+
+use chrono::{Duration, TimeZone, Utc};
+use openlora_governance::clock::{Clock, ManualClock};
+use openlora_governance::killswitch::{EscalationPolicy, KillReason, KillSwitch, KillSwitchError};
+
+fn reason() -> KillReason {
+    KillReason::ManualTrigger { operator: "ops".to_string(), reason: None }
+}
+
+fn clocked() -> (KillSwitch, ManualClock) {
+    let clock = ManualClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap());
+    let ks = KillSwitch::new(vec!["ops".to_string(), "peer".to_string()]).with_clock(clock.clone());
+    (ks, clock)
+}
+
+#[test]
+fn sessions_expire_when_the_clock_passes_their_ttl() {
+    let (mut ks, clock) = clocked();
+    let session = ks.issue_session("ops", Duration::minutes(15)).unwrap();
+    assert_eq!(session.issued_at, clock.now());
+
+    clock.advance(Duration::minutes(14));
+    assert_eq!(ks.session_operator(&session.token).unwrap(), "ops");
+
+    clock.advance(Duration::minutes(1));
+    assert!(matches!(
+        ks.session_operator(&session.token),
+        Err(KillSwitchError::SessionExpired { expired_at, .. }) if expired_at == session.expires_at
+    ));
+}
+
+#[test]
+fn escalation_follows_the_clock_window() {
+    let (mut ks, clock) = clocked();
+    ks.set_escalation_policy(Some(EscalationPolicy {
+        max_activations: 1,
+        window: Duration::hours(1),
+        reset_quorum: 2,
+    }));
+
+    for _ in 0..2 {
+        ks.activate("ops", reason(), Vec::new()).unwrap();
+        clock.advance(Duration::minutes(10));
+        if ks.is_active() && !ks.is_escalated() {
+            ks.reset("ops").unwrap();
+        }
+    }
+    assert_eq!(ks.activation_rate(Duration::hours(1)), 2);
+    assert!(matches!(ks.reset("ops"), Err(KillSwitchError::Escalated { .. })));
+
+    // Once the activations age out of the window, one operator suffices.
+    clock.advance(Duration::hours(1));
+    assert!(!ks.is_escalated());
+    let outcome = ks.reset("ops").unwrap();
+    assert_eq!(outcome.reset_at, clock.now());
+    assert_eq!(outcome.downtime, Some(Duration::minutes(70)));
+}
+
+#[test]
+fn events_are_stamped_with_the_clock() {
+    let (mut ks, clock) = clocked();
+    clock.set(Utc.with_ymd_and_hms(2030, 6, 1, 12, 0, 0).unwrap());
+    let event = ks.activate("ops", reason(), Vec::new()).unwrap();
+    assert_eq!(event.timestamp, clock.now());
+    assert_eq!(ks.now(), clock.now());
+}
@@ -0,0 +1,63 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use chrono::{Duration, TimeZone, Utc};
+use ed25519_dalek::SigningKey;
+use openlora_core::ProvenanceOperation;
+use openlora_governance::signatures::{sign_ed25519, ProvenanceEntry, SignatureVerifier};
+
+/// The Ed25519 key derived from `seed`.
+pub fn key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+/// A verifier trusting each `(signer, seed)` with [`key`]`(seed)`.
+pub fn verifier(signers: &[(&str, u8)]) -> SignatureVerifier {
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    for (signer, seed) in signers {
+        verifier.add_signer_key(signer, key(*seed).verifying_key());
+    }
+    verifier
+}
+
+/// A chain for `adapter-1` of `(operation, signer, seed)` steps an hour
+/// apart, each signed by its own signer.
+pub fn signed_chain(steps: &[(ProvenanceOperation, &str, u8)]) -> Vec<ProvenanceEntry> {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut chain: Vec<ProvenanceEntry> = Vec::new();
+    for (i, (operation, signer, seed)) in steps.iter().enumerate() {
+        let mut entry = ProvenanceEntry {
+            adapter_id: "adapter-1".to_string(),
+            version: i as u32 + 1,
+            operation: *operation,
+            actor: signer.to_string(),
+            timestamp: start + Duration::hours(i as i64),
+            signature: None,
+            parent_hash: chain.last().map(|e| e.hash.clone()),
+            content_hash: None,
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        entry.signature = Some(sign_ed25519(&entry.canonical_bytes(), signer, &key(*seed), None));
+        chain.push(entry);
+    }
+    chain
+}
+
+/// An unsigned, single-entry chain creating `adapter_id` with content
+/// `<adapter_id>-weights`.
+pub fn created_chain(adapter_id: &str) -> Vec<ProvenanceEntry> {
+    let mut entry = ProvenanceEntry {
+        adapter_id: adapter_id.to_string(),
+        version: 1,
+        operation: ProvenanceOperation::Created,
+        actor: "alice".to_string(),
+        timestamp: Utc::now(),
+        signature: None,
+        parent_hash: None,
+        content_hash: Some(format!("{}-weights", adapter_id)),
+        hash: String::new(),
+    };
+    entry.hash = entry.compute_hash();
+    vec![entry]
+}
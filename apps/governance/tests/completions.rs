@@ -0,0 +1,21 @@
+//! Shell completion scripts generated from the CLI definition.
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+use openlora_governance::cli::Cli;
+
+fn script(shell: Shell) -> String {
+    let mut out = Vec::new();
+    clap_complete::generate(shell, &mut Cli::command(), "openlora-gov", &mut out);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn scripts_cover_subcommands_and_global_options() {
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+        let script = script(shell);
+        for word in ["verify-audit", "tail-verify", "export-provenance", "completions", "timezone"] {
+            assert!(script.contains(word), "{:?} completions lack {}", shell, word);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Loading `governance.toml` and building the kill-switch and verifier from it.
+
+use ed25519_dalek::pkcs8::{spki::der::pem::LineEnding, EncodePublicKey};
+use ed25519_dalek::SigningKey;
+use openlora_governance::config::{ConfigError, GovernanceConfig};
+use openlora_governance::killswitch::{KillReason, KillSwitchError};
+use openlora_governance::signatures::sign_ed25519;
+use std::fs;
+use std::path::Path;
+
+fn write_config(dir: &Path, body: &str) -> std::path::PathBuf {
+    let path = dir.join("governance.toml");
+    fs::write(&path, body).unwrap();
+    path
+}
+
+fn invalid(body: &str) -> String {
+    match toml::from_str::<GovernanceConfig>(body).unwrap().validate() {
+        Err(ConfigError::Invalid(message)) => message,
+        other => panic!("expected Invalid, got {:?}", other),
+    }
+}
+
+#[test]
+fn a_config_file_builds_the_switch_and_verifier() {
+    let dir = tempfile::tempdir().unwrap();
+    let key = SigningKey::from_bytes(&[3; 32]);
+    let pem = dir.path().join("release.pub");
+    fs::write(&pem, key.verifying_key().to_public_key_pem(LineEnding::LF).unwrap()).unwrap();
+    let path = write_config(
+        dir.path(),
+        &format!(
+            r#"
+authorized_operators = ["alice", "bob"]
+
+[[trusted_signers]]
+id = "release"
+public_key = {:?}
+
+[quorum]
+reset = 2
+
+[anomaly]
+quarantine_threshold = 0.5
+"#,
+            pem
+        ),
+    );
+
+    let config = GovernanceConfig::load(&path).unwrap();
+    assert_eq!(config.quorum.reset, 2);
+    assert_eq!((config.anomaly.quarantine_threshold, config.anomaly.kill_threshold), (0.5, 0.9));
+
+    let mut ks = config.kill_switch();
+    let reason = KillReason::ManualTrigger { operator: "mallory".into(), reason: None };
+    assert!(matches!(ks.activate("mallory", reason, Vec::new()), Err(KillSwitchError::Unauthorized(_))));
+
+    let verifier = config.signature_verifier().unwrap();
+    let signature = sign_ed25519(b"adapter", "release", &key, None);
+    assert!(verifier.verify(b"adapter", &signature).unwrap());
+}
+
+#[test]
+fn command_line_lists_replace_the_files() {
+    let config: GovernanceConfig = toml::from_str(r#"authorized_operators = ["alice"]"#).unwrap();
+
+    let kept = config.clone().with_overrides(Vec::new(), Vec::new()).unwrap();
+    assert_eq!(kept.authorized_operators, ["alice"]);
+
+    let replaced = config.with_overrides(vec!["carol".into()], vec!["release".into()]).unwrap();
+    assert_eq!(replaced.authorized_operators, ["carol"]);
+    assert_eq!(replaced.trusted_signers.len(), 1);
+    assert!(replaced.trusted_signers[0].public_key.is_none());
+
+    // Overrides are validated like the file.
+    let config = GovernanceConfig::default();
+    assert!(config.with_overrides(vec!["alice".into(), "alice".into()], Vec::new()).is_err());
+}
+
+#[test]
+fn inconsistent_configs_are_explained() {
+    assert!(invalid("authorized_operators = [\"alice\"]\n[quorum]\nreset = 2").contains("quorum.reset is 2"));
+    assert!(invalid("[quorum]\nactivate = 0").contains("quorum.activate must be at least 1"));
+    assert!(invalid("[anomaly]\nkill_threshold = 1.5").contains("anomaly.kill_threshold"));
+    assert!(invalid("[anomaly]\nquarantine_threshold = 0.95").contains("exceeds anomaly.kill_threshold"));
+    assert!(invalid("authorized_operators = [\"alice\", \"alice\"]").contains("more than once"));
+}
+
+#[test]
+fn unreadable_and_malformed_files_name_the_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing.toml");
+    assert!(matches!(GovernanceConfig::load(&missing), Err(ConfigError::Io { ref path, .. }) if *path == missing));
+    assert!(GovernanceConfig::load_or_default(Some(&missing)).is_err());
+
+    let path = write_config(dir.path(), "authorised_operators = [\"alice\"]");
+    match GovernanceConfig::load(&path) {
+        Err(ConfigError::Parse { path: reported, source }) => {
+            assert_eq!(reported, path);
+            assert!(source.to_string().contains("authorised_operators"));
+        }
+        other => panic!("expected Parse, got {:?}", other),
+    }
+}
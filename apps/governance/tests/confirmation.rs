@@ -0,0 +1,33 @@
+//! Destructive commands need a typed confirmation or `--yes`.
+
+use clap::Parser;
+use openlora_governance::cli::{confirm_operator, Cli, Commands, Unconfirmed};
+
+#[test]
+fn the_operator_must_type_their_own_id() {
+    assert_eq!(confirm_operator(true, "alice\n".as_bytes(), "alice"), Ok(()));
+    assert_eq!(confirm_operator(true, "  alice  \r\n".as_bytes(), "alice"), Ok(()));
+    assert_eq!(confirm_operator(true, "bob\n".as_bytes(), "alice"), Err(Unconfirmed::Mismatch));
+    assert_eq!(confirm_operator(true, "y\n".as_bytes(), "alice"), Err(Unconfirmed::Mismatch));
+    // End of input is not a confirmation.
+    assert_eq!(confirm_operator(true, "".as_bytes(), "alice"), Err(Unconfirmed::Mismatch));
+}
+
+#[test]
+fn without_a_terminal_nothing_is_read_and_the_action_refused() {
+    // Even the right answer piped in does not count.
+    assert_eq!(confirm_operator(false, "alice\n".as_bytes(), "alice"), Err(Unconfirmed::NotInteractive));
+}
+
+#[test]
+fn yes_skips_the_prompt_for_each_destructive_command() {
+    let parse = |args: &[&str]| Cli::try_parse_from([&["openlora-gov"], args].concat()).unwrap().command;
+
+    assert!(matches!(parse(&["kill", "-o", "alice", "-r", "drill", "--yes"]), Commands::Kill { yes: true, .. }));
+    assert!(matches!(parse(&["kill", "-o", "alice", "-r", "drill"]), Commands::Kill { yes: false, .. }));
+    assert!(matches!(parse(&["reset", "-o", "alice", "-y"]), Commands::Reset { yes: true, .. }));
+    assert!(matches!(
+        parse(&["force-reset", "--admin", "root", "--justification", "outage", "--yes"]),
+        Commands::ForceReset { yes: true, .. }
+    ));
+}
@@ -0,0 +1,72 @@
+//! The registry's statuses are reconciled against the audit log.
+
+mod common;
+
+use common::created_chain;
+use openlora_core::{AdapterGovernanceStatus, AdapterId};
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::registry::Registry;
+use openlora_governance::replay::{replay, Discrepancy};
+use serde_json::json;
+
+fn adapter(id: &str) -> AuditTarget {
+    AuditTarget::Adapter(AdapterId::new(id).unwrap())
+}
+
+#[test]
+fn statuses_that_contradict_the_log_are_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    log.append(AuditEventType::AdapterActivated, "ops", Some(&adapter("live")), json!({})).unwrap();
+    log.append(AuditEventType::AdapterDestroyed, "ops", Some(&adapter("gone")), json!({})).unwrap();
+    log.append(AuditEventType::AdapterQuarantined, "ops", Some(&adapter("held")), json!({ "reason": "drift" }))
+        .unwrap();
+
+    let mut registry = Registry::new();
+    for id in ["live", "gone", "held", "fresh", "unassessed"] {
+        registry.register(created_chain(id)).unwrap();
+    }
+    registry.set_status("live", AdapterGovernanceStatus::Verified).unwrap();
+    registry.set_status("gone", AdapterGovernanceStatus::Verified).unwrap();
+    registry.set_status("held", AdapterGovernanceStatus::Quarantined).unwrap();
+    registry.set_status("fresh", AdapterGovernanceStatus::Destroyed).unwrap();
+    assert!(registry.set_status("missing", AdapterGovernanceStatus::Verified).is_err());
+
+    // Statuses survive a save and reload, as does the content index.
+    let path = dir.path().join("registry.json");
+    registry.save(&path).unwrap();
+    let registry = Registry::load(&path).unwrap();
+    assert_eq!(registry.get("held").unwrap().status, Some(AdapterGovernanceStatus::Quarantined));
+    assert_eq!(registry.find_by_content_hash("gone-weights"), Some(("gone".to_string(), 1)));
+
+    let state = replay(&log).unwrap();
+    let found = state.compare_registry(&registry);
+    assert_eq!(
+        found,
+        vec![
+            Discrepancy::RegistryStatus {
+                adapter: "fresh".to_string(),
+                registry: AdapterGovernanceStatus::Destroyed,
+                replayed: None,
+            },
+            Discrepancy::RegistryStatus {
+                adapter: "gone".to_string(),
+                registry: AdapterGovernanceStatus::Verified,
+                replayed: Some(AdapterGovernanceStatus::Destroyed),
+            },
+        ]
+    );
+    assert_eq!(found[1].to_string(), "gone is Verified in the registry but Destroyed per the audit log");
+}
+
+#[test]
+fn re_registering_keeps_the_status() {
+    let mut registry = Registry::new();
+    registry.register(created_chain("adapter-1")).unwrap();
+    registry.set_status("adapter-1", AdapterGovernanceStatus::Verified).unwrap();
+    registry.register(created_chain("adapter-1")).unwrap();
+    assert_eq!(registry.get("adapter-1").unwrap().status, Some(AdapterGovernanceStatus::Verified));
+
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(Registry::load(&dir.path().join("registry.json")).unwrap().iter().count(), 0);
+}
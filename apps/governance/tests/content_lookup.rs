@@ -0,0 +1,83 @@
+//! Finding the provenance entry that produced a piece of adapter content.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use openlora_core::ProvenanceOperation;
+use openlora_governance::registry::Registry;
+use openlora_governance::signatures::ProvenanceEntry;
+
+fn at(hours: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::hours(hours)
+}
+
+/// A chain for `adapter_id` whose versions record `(content_hash, hour)`.
+fn chain(adapter_id: &str, versions: &[(Option<&str>, i64)]) -> Vec<ProvenanceEntry> {
+    let mut chain: Vec<ProvenanceEntry> = Vec::new();
+    for (i, (content_hash, hour)) in versions.iter().enumerate() {
+        let mut entry = ProvenanceEntry {
+            adapter_id: adapter_id.to_string(),
+            version: i as u32 + 1,
+            operation: if i == 0 { ProvenanceOperation::Created } else { ProvenanceOperation::Trained },
+            actor: "trainer".to_string(),
+            timestamp: at(*hour),
+            signature: None,
+            parent_hash: chain.last().map(|e| e.hash.clone()),
+            content_hash: content_hash.map(String::from),
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        chain.push(entry);
+    }
+    chain
+}
+
+fn found(adapter_id: &str, version: u32) -> Option<(String, u32)> {
+    Some((adapter_id.to_string(), version))
+}
+
+#[test]
+fn each_recorded_hash_leads_to_its_version() {
+    let mut registry = Registry::new();
+    registry.register(chain("adapter-1", &[(Some("w1"), 0), (None, 1), (Some("w3"), 2)])).unwrap();
+
+    assert_eq!(registry.find_by_content_hash("w1"), found("adapter-1", 1));
+    assert_eq!(registry.find_by_content_hash("w3"), found("adapter-1", 3));
+    assert_eq!(registry.find_by_content_hash("never-seen"), None);
+
+    let mut next = chain("adapter-1", &[(Some("w4"), 3)]).remove(0);
+    next.version = 4;
+    registry.record(next);
+    assert_eq!(registry.find_by_content_hash("w4"), found("adapter-1", 4));
+}
+
+#[test]
+fn a_shared_hash_leads_to_its_earliest_producer_whatever_the_order() {
+    let copy = chain("copy", &[(Some("shared"), 5)]);
+    let original = chain("original", &[(None, 0), (Some("shared"), 2)]);
+    let twin = chain("twin", &[(Some("shared"), 5)]);
+
+    for order in [[&copy, &original, &twin], [&twin, &original, &copy], [&original, &twin, &copy]] {
+        let mut registry = Registry::new();
+        for chain in order {
+            registry.register(chain.clone()).unwrap();
+        }
+        assert_eq!(registry.find_by_content_hash("shared"), found("original", 2));
+
+        // Without the original, equally early producers go by adapter id.
+        registry.register(chain("original", &[(None, 0)])).unwrap();
+        assert_eq!(registry.find_by_content_hash("shared"), found("copy", 1));
+    }
+}
+
+#[test]
+fn the_index_is_rebuilt_on_load() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("registry.json");
+    let mut registry = Registry::new();
+    registry.register(chain("twin", &[(Some("shared"), 1)])).unwrap();
+    registry.register(chain("original", &[(Some("shared"), 0), (Some("w2"), 1)])).unwrap();
+    registry.save(&path).unwrap();
+
+    let loaded = Registry::load(&path).unwrap();
+    assert_eq!(loaded.find_by_content_hash("shared"), found("original", 1));
+    assert_eq!(loaded.find_by_content_hash("w2"), found("original", 2));
+}
@@ -0,0 +1,67 @@
+//! Adapter locations: local paths, `file://` URLs and object-store URLs.
+
+use openlora_governance::signatures::{sign_ed25519_source, SignatureVerifier};
+use openlora_governance::source::{Compression, ContentSource, SourceError};
+use std::io::Read;
+use std::path::PathBuf;
+
+#[test]
+fn paths_and_file_urls_are_local_files() {
+    let plain = ContentSource::parse("adapters/a.safetensors").unwrap();
+    assert_eq!(plain, ContentSource::File(PathBuf::from("adapters/a.safetensors")));
+    let url = ContentSource::parse("FILE:///srv/adapters/a.safetensors.GZ").unwrap();
+    assert_eq!(url.local_path(), Some(PathBuf::from("/srv/adapters/a.safetensors.GZ").as_path()));
+    assert_eq!(url.compression(), Some(Compression::Gzip));
+}
+
+#[test]
+fn a_local_file_is_streamed_and_verified() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("adapter.safetensors");
+    std::fs::write(&path, b"adapter weights").unwrap();
+    let source = ContentSource::parse(&format!("file://{}", path.display())).unwrap();
+
+    let key = ed25519_dalek::SigningKey::from_bytes(&[5; 32]);
+    let signature = sign_ed25519_source(&source, "alice", &key, None).unwrap();
+    assert_eq!(signature.artifact_compression, None);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    assert!(verifier.verify_reader(source.open_content().unwrap(), &signature).unwrap());
+
+    let mut content = Vec::new();
+    source.open().unwrap().read_to_end(&mut content).unwrap();
+    assert_eq!(content, b"adapter weights");
+}
+
+#[test]
+fn a_missing_file_names_its_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing.safetensors");
+    match ContentSource::File(path.clone()).open() {
+        Err(SourceError::Io { path: reported, source }) => {
+            assert_eq!(reported, path);
+            assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+        }
+        Err(other) => panic!("expected an IO error, got {:?}", other),
+        Ok(_) => panic!("opened a missing file"),
+    }
+}
+
+#[cfg(not(feature = "object-store"))]
+#[test]
+fn object_store_urls_need_the_feature() {
+    for url in ["s3://bucket/adapter.safetensors", "gs://bucket/adapter.safetensors"] {
+        assert!(matches!(ContentSource::parse(url), Err(SourceError::UnsupportedScheme(ref u)) if u == url));
+    }
+}
+
+#[cfg(feature = "object-store")]
+#[test]
+fn object_store_urls_are_objects() {
+    let source = ContentSource::parse("s3://bucket/adapters/a.safetensors.gz").unwrap();
+    assert!(matches!(source, ContentSource::Object(ref url) if url.host_str() == Some("bucket")));
+    assert_eq!(source.local_path(), None);
+    assert_eq!(source.compression(), Some(Compression::Gzip));
+    assert_eq!(source.to_string(), "s3://bucket/adapters/a.safetensors.gz");
+    assert!(matches!(ContentSource::parse("s3://bucket:port/a"), Err(SourceError::InvalidUrl { .. })));
+}
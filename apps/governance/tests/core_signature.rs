@@ -0,0 +1,58 @@
+//! Converting between the core signature (bytes) and this crate's (text).
+
+use chrono::{TimeZone, Utc};
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, Signature, SignatureEncoding, SignatureError, SignatureVerifier};
+
+fn core_signature(value: Vec<u8>) -> openlora_core::Signature {
+    openlora_core::Signature {
+        algorithm: "ed25519".to_string(),
+        value,
+        signer_id: "alice".to_string(),
+        signed_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        expires_at: None,
+    }
+}
+
+#[test]
+fn core_bytes_become_hex_and_back() {
+    let signature = Signature::from(core_signature(vec![0x00, 0xab, 0xff]));
+    assert_eq!(signature.value, "00abff");
+    assert_eq!(signature.signer_id, "alice");
+
+    let back = openlora_core::Signature::try_from(signature).unwrap();
+    assert_eq!(back.value, [0x00, 0xab, 0xff]);
+}
+
+#[test]
+fn a_converted_signature_still_verifies() {
+    let key = SigningKey::from_bytes(&[4; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    let signature = sign_ed25519(b"adapter", "alice", &key, None);
+
+    let core = openlora_core::Signature::try_from(signature.clone()).unwrap();
+    assert_eq!(core.value.len(), 64);
+    let round_tripped = Signature::from(core);
+    assert_eq!(round_tripped.value, signature.value);
+    assert!(verifier.verify(b"adapter", &round_tripped).unwrap());
+
+    // Base64 text decodes to the same bytes.
+    let base64 = signature.clone().encoded_as(SignatureEncoding::Base64).unwrap();
+    let core_from_base64 = openlora_core::Signature::try_from(base64).unwrap();
+    assert_eq!(hex::encode(core_from_base64.value), signature.value);
+}
+
+#[test]
+fn malformed_text_is_an_encoding_error() {
+    for value in ["not hex", "abc", "zz"] {
+        let signature = Signature {
+            value: value.to_string(),
+            ..Signature::from(core_signature(Vec::new()))
+        };
+        assert!(matches!(
+            openlora_core::Signature::try_from(signature),
+            Err(SignatureError::InvalidEncoding(_))
+        ));
+    }
+}
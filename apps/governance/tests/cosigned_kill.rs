@@ -0,0 +1,59 @@
+//! Severe kills can require two operators at activation time.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{KillReason, KillState, KillSwitch, KillSwitchError};
+use serde_json::json;
+
+fn reason() -> KillReason {
+    KillReason::ManualTrigger { operator: "alice".to_string(), reason: None }
+}
+
+#[test]
+fn both_operators_must_be_authorized_and_distinct() {
+    let mut ks = KillSwitch::new(vec!["alice".to_string(), "bob".to_string()]);
+
+    assert!(matches!(
+        ks.activate_cosigned("alice", "alice", reason(), Vec::new()),
+        Err(KillSwitchError::CoSignerNotDistinct(ref who)) if who == "alice"
+    ));
+    assert!(matches!(
+        ks.activate_cosigned("alice", "mallory", reason(), Vec::new()),
+        Err(KillSwitchError::Unauthorized(ref who)) if who == "mallory"
+    ));
+    assert!(!ks.is_active());
+
+    let event = ks.activate_cosigned("alice", "bob", reason(), vec!["adapter-1".to_string()]).unwrap();
+    assert_eq!(event.triggered_by, "alice");
+    assert_eq!(event.co_signers, ["bob"]);
+    assert!(ks.is_active());
+    assert!(matches!(
+        ks.activate_cosigned("alice", "bob", reason(), Vec::new()),
+        Err(KillSwitchError::AlreadyActive)
+    ));
+
+    // Single-operator activation is unchanged and has no co-signers.
+    ks.reset("alice").unwrap();
+    assert!(ks.activate("bob", reason(), Vec::new()).unwrap().co_signers.is_empty());
+}
+
+#[test]
+fn co_signers_are_recorded_and_persisted() {
+    let dir = tempfile::tempdir().unwrap();
+    let audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut gov = Governance::new(KillSwitch::new(vec!["alice".to_string(), "bob".to_string()]), audit);
+
+    let killed = gov.kill_cosigned("alice", "bob", reason(), Vec::new()).unwrap();
+    assert!(killed.audit.is_recorded());
+
+    let entries = gov.audit_log().unwrap().entries().unwrap();
+    let last = entries.last().unwrap();
+    assert!(matches!(last.event_type, AuditEventType::KillSwitchActivated));
+    assert_eq!(last.actor, "alice");
+    assert_eq!(last.details["co_signers"], json!(["bob"]));
+
+    let state = dir.path().join("killswitch.json");
+    gov.kill_switch().state().save(&state).unwrap();
+    let restored = KillState::load(&state).unwrap();
+    assert_eq!(restored.events.last().unwrap().co_signers, ["bob"]);
+}
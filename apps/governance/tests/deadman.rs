@@ -0,0 +1,68 @@
+//! The dead-man's switch fires when heartbeats stop.
+#![cfg(feature = "deadman")]
+
+use openlora_governance::killswitch::{KillReason, KillSwitch, KillSwitchChange};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+#[test]
+fn fires_once_heartbeats_stop() {
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+    let changes = ks.subscribe();
+    ks.arm_deadman(Duration::from_millis(200)).unwrap();
+
+    let until = Instant::now() + Duration::from_millis(600);
+    while Instant::now() < until {
+        ks.heartbeat();
+        sleep(Duration::from_millis(20));
+    }
+    assert!(!ks.is_active());
+    assert!(ks.is_deadman_armed());
+
+    let event = match changes.recv_timeout(Duration::from_secs(5)).unwrap() {
+        KillSwitchChange::Activated(event) => event,
+        other => panic!("expected activation, got {:?}", other),
+    };
+    assert!(ks.is_active());
+    assert_eq!(event.triggered_by, "deadman");
+    assert!(matches!(event.reason, KillReason::ExternalSignal { ref source, .. } if source == "deadman"));
+    assert_eq!(ks.state().events.len(), 1);
+
+    // One-shot: nothing more fires, and the reset sees the activation.
+    while ks.is_deadman_armed() {
+        sleep(Duration::from_millis(5));
+    }
+    let outcome = ks.reset("ops").unwrap();
+    assert_eq!(outcome.activated_at, Some(event.timestamp));
+    assert_eq!(ks.get_events().len(), 1);
+}
+
+#[test]
+fn disarmed_or_dropped_switches_do_not_fire() {
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+    ks.arm_deadman(Duration::from_millis(50)).unwrap();
+    ks.disarm_deadman();
+    assert!(!ks.is_deadman_armed());
+    sleep(Duration::from_millis(150));
+    assert!(!ks.is_active());
+
+    // Dropping an armed switch returns at once rather than waiting out the timer.
+    let started = Instant::now();
+    let mut armed = KillSwitch::new(vec!["ops".to_string()]);
+    armed.arm_deadman(Duration::from_secs(3600)).unwrap();
+    drop(armed);
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn an_active_switch_gets_no_extra_event() {
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+    ks.activate("ops", KillReason::ManualTrigger { operator: "ops".to_string(), reason: None }, Vec::new())
+        .unwrap();
+    ks.arm_deadman(Duration::from_millis(20)).unwrap();
+    while ks.is_deadman_armed() {
+        sleep(Duration::from_millis(5));
+    }
+    assert_eq!(ks.state().events.len(), 1);
+    ks.reset("ops").unwrap();
+}
@@ -0,0 +1,75 @@
+//! Allow decisions carry the rules that let them through into the audit log.
+
+use chrono::{TimeZone, Utc};
+use openlora_core::{AdapterId, EvaluationTrace, GovernanceDecision, RuleOutcome};
+use openlora_governance::audit::{AuditEventType, AuditTarget};
+use openlora_governance::clock::ManualClock;
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::KillSwitch;
+use serde_json::json;
+
+#[test]
+fn an_allow_records_its_evaluation_trace() {
+    let dir = tempfile::tempdir().unwrap();
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let ks = KillSwitch::new(vec!["alice".to_string()]).with_clock(ManualClock::new(start));
+    let mut gov = Governance::open(ks, dir.path().join("audit.jsonl"));
+
+    let mut trace = EvaluationTrace::new();
+    trace.record("signature-valid", RuleOutcome::Passed, Some("signer release".to_string()));
+    trace.record("risk-tier-review", RuleOutcome::Skipped, None);
+    trace.record("anomaly-score", RuleOutcome::Passed, Some("0.12 < 0.5".to_string()));
+    assert!(trace.all_passed());
+    assert_eq!(trace.with_outcome(RuleOutcome::Skipped).count(), 1);
+
+    let target = AuditTarget::Adapter(AdapterId::new("fraud-v2").unwrap());
+    let status = gov.record_decision("policy", Some(target.clone()), &GovernanceDecision::Allow { trace: trace.clone() });
+    assert!(status.is_recorded());
+
+    let log = gov.audit_log().unwrap();
+    let entries = log.entries_for(&target).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert!(matches!(entries[0].event_type, AuditEventType::PolicyEvaluated));
+    let rules = &entries[0].details["decision"]["Allow"]["trace"]["rules"];
+    assert_eq!(rules[0], json!({ "rule": "signature-valid", "outcome": "Passed", "detail": "signer release" }));
+    assert_eq!(rules[1], json!({ "rule": "risk-tier-review", "outcome": "Skipped" }));
+    assert_eq!(entries[0].details["occurred_at"], json!(start));
+
+    let recorded: GovernanceDecision = serde_json::from_value(entries[0].details["decision"].clone()).unwrap();
+    assert!(matches!(recorded, GovernanceDecision::Allow { trace: ref t } if *t == trace));
+    assert!(log.verify_integrity().unwrap());
+}
+
+#[test]
+fn a_failed_rule_is_visible_in_the_trace() {
+    let mut trace = EvaluationTrace::new();
+    trace.record("signature-valid", RuleOutcome::Passed, None);
+    trace.record("provenance-complete", RuleOutcome::Failed, Some("missing version 2".to_string()));
+    assert!(!trace.all_passed());
+    let failed: Vec<_> = trace.with_outcome(RuleOutcome::Failed).map(|r| r.rule.as_str()).collect();
+    assert_eq!(failed, vec!["provenance-complete"]);
+
+    let decision: GovernanceDecision = serde_json::from_value(json!({ "Allow": {} })).unwrap();
+    assert!(matches!(decision, GovernanceDecision::Allow { trace } if trace.rules.is_empty()));
+}
+
+#[test]
+fn the_legacy_unit_allow_still_reads() {
+    let legacy: GovernanceDecision = serde_json::from_value(json!("Allow")).unwrap();
+    match legacy {
+        GovernanceDecision::Allow { ref trace } => assert!(trace.rules.is_empty()),
+        ref other => panic!("expected Allow, got {:?}", other),
+    }
+
+    // Written back in the current form, which reads as the same decision.
+    let written = serde_json::to_value(&legacy).unwrap();
+    assert_eq!(written, json!({ "Allow": { "trace": { "rules": [] } } }));
+    let reread: GovernanceDecision = serde_json::from_value(written).unwrap();
+    assert!(matches!(reread, GovernanceDecision::Allow { ref trace } if trace.rules.is_empty()));
+
+    let no_trace: GovernanceDecision = serde_json::from_value(json!({ "Allow": {} })).unwrap();
+    assert!(matches!(no_trace, GovernanceDecision::Allow { .. }));
+    let deny: GovernanceDecision = serde_json::from_value(json!({ "Deny": { "reason": "unsigned" } })).unwrap();
+    assert!(matches!(deny, GovernanceDecision::Deny { ref reason } if reason == "unsigned"));
+    assert!(serde_json::from_value::<GovernanceDecision>(json!("Deny")).is_err());
+}
@@ -0,0 +1,81 @@
+//! Degraded mode: kill-switch actions when the audit log is unwritable.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::governance::{AuditStatus, Governance};
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+use std::fs;
+
+fn manual(operator: &str) -> KillReason {
+    KillReason::ManualTrigger {
+        operator: operator.to_string(),
+        reason: None,
+    }
+}
+
+#[test]
+fn kill_proceeds_and_buffers_when_audit_is_unwritable() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let log = AuditLog::open(path.clone()).unwrap();
+    let mut gov = Governance::new(KillSwitch::new(vec!["alice".into()]), log);
+
+    // A directory where the log file should be makes every write fail.
+    fs::create_dir(&path).unwrap();
+
+    let killed = gov.kill("alice", manual("alice"), vec!["adapter-1".into()]).unwrap();
+    assert!(gov.kill_switch().is_active(), "kill must take effect without audit");
+    match killed.audit {
+        AuditStatus::Deferred(warning) => assert_eq!(warning.pending, 1),
+        AuditStatus::Recorded(_) | AuditStatus::Replayed => panic!("write to a directory cannot succeed"),
+    }
+
+    let reset = gov.reset(&["alice".to_string()]).unwrap();
+    assert!(!reset.audit.is_recorded());
+    assert!(!gov.kill_switch().is_active());
+    assert_eq!(gov.pending_audit().len(), 2);
+
+    // Once writable again, buffered records are written in action order.
+    fs::remove_dir(&path).unwrap();
+    assert_eq!(gov.flush_pending().unwrap(), 2);
+    assert!(gov.pending_audit().is_empty());
+
+    let log = AuditLog::open(path).unwrap();
+    assert!(log.verify_integrity().unwrap());
+    let entries = log.entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(matches!(entries[0].event_type, AuditEventType::KillSwitchActivated));
+    assert!(matches!(entries[1].event_type, AuditEventType::KillSwitchReset));
+    assert_eq!(entries[0].target_id.as_deref(), Some(killed.outcome.id.as_str()));
+    assert!(entries[0].details.get("occurred_at").is_some());
+}
+
+#[test]
+fn buffered_records_are_written_before_the_next_action() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    fs::create_dir(&path).unwrap();
+
+    // The log cannot even be opened; the facade still works.
+    let mut gov = Governance::open(KillSwitch::new(vec!["alice".into()]), path.clone());
+    assert!(!gov.kill("alice", manual("alice"), Vec::new()).unwrap().audit.is_recorded());
+
+    fs::remove_dir(&path).unwrap();
+    let reset = gov.reset(&["alice".to_string()]).unwrap();
+    assert!(reset.audit.is_recorded());
+    assert!(gov.pending_audit().is_empty());
+
+    let entries = AuditLog::open(path).unwrap().entries().unwrap();
+    assert!(matches!(entries[0].event_type, AuditEventType::KillSwitchActivated));
+    assert!(matches!(entries[1].event_type, AuditEventType::KillSwitchReset));
+}
+
+#[test]
+fn refused_actions_are_not_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut gov = Governance::open(KillSwitch::new(vec!["alice".into()]), path.clone());
+
+    assert!(gov.kill("mallory", manual("mallory"), Vec::new()).is_err());
+    assert!(gov.pending_audit().is_empty());
+    assert!(AuditLog::open(path).unwrap().entries().unwrap().is_empty());
+}
@@ -0,0 +1,30 @@
+//! Rendering stored UTC timestamps in an operator's time zone.
+
+use chrono::{TimeZone, Utc};
+use openlora_governance::display::{DisplayZone, ZoneError};
+
+#[test]
+fn utc_display_is_unchanged() {
+    let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 30, 0).unwrap();
+    for name in ["UTC", "utc", "Z"] {
+        let zone = DisplayZone::parse(name).unwrap();
+        assert_eq!(zone.format(&at), at.to_string());
+    }
+    assert_eq!(DisplayZone::resolve(Some("UTC")).unwrap(), DisplayZone::Utc);
+    assert_eq!(DisplayZone::parse("local").unwrap(), DisplayZone::Local);
+}
+
+#[cfg(not(feature = "timezone"))]
+#[test]
+fn named_zones_need_the_feature() {
+    assert!(matches!(DisplayZone::parse("Europe/Berlin"), Err(ZoneError::Unsupported(_))));
+}
+
+#[cfg(feature = "timezone")]
+#[test]
+fn named_zones_are_rendered_with_their_offset() {
+    let at = Utc.with_ymd_and_hms(2024, 7, 1, 12, 30, 0).unwrap();
+    let berlin = DisplayZone::parse("Europe/Berlin").unwrap();
+    assert_eq!(berlin.format(&at), "2024-07-01 14:30:00 CEST");
+    assert!(matches!(DisplayZone::parse("Mars/Olympus"), Err(ZoneError::Unknown(_))));
+}
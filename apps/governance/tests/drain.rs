@@ -0,0 +1,57 @@
+//! Activation drains in-flight operations within the grace period.
+
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn reason() -> KillReason {
+    KillReason::ManualTrigger {
+        operator: "alice".into(),
+        reason: None,
+    }
+}
+
+#[test]
+fn activation_waits_for_running_operations() {
+    let mut ks = KillSwitch::new(vec!["alice".into()]);
+    ks.set_drain_grace(Duration::from_secs(10));
+    let gate = ks.operations();
+
+    let (started, wait_started) = channel();
+    let worker = thread::spawn(move || {
+        let guard = gate.begin().expect("gate open before activation");
+        started.send(()).unwrap();
+        thread::sleep(Duration::from_millis(200));
+        assert!(!guard.is_cancelled());
+    });
+    wait_started.recv().unwrap();
+    assert_eq!(ks.operations().in_flight(), 1);
+
+    let begun = Instant::now();
+    ks.activate("alice", reason(), Vec::new()).unwrap();
+    assert!(begun.elapsed() < Duration::from_secs(10));
+    assert_eq!(ks.operations().in_flight(), 0);
+    worker.join().unwrap();
+
+    assert!(ks.begin_operation().is_none(), "gate closed while active");
+    ks.reset("alice").unwrap();
+    assert!(ks.begin_operation().is_some(), "gate reopened after reset");
+}
+
+#[test]
+fn operations_outliving_the_grace_period_are_cancelled() {
+    let mut ks = KillSwitch::new(vec!["alice".into()]);
+    ks.set_drain_grace(Duration::from_millis(50));
+    let stuck = ks.begin_operation().unwrap();
+
+    ks.activate("alice", reason(), Vec::new()).unwrap();
+    assert!(stuck.is_cancelled());
+    assert_eq!(ks.operations().in_flight(), 1);
+    drop(stuck);
+    assert_eq!(ks.operations().in_flight(), 0);
+
+    ks.reset("alice").unwrap();
+    let fresh = ks.begin_operation().unwrap();
+    assert!(!fresh.is_cancelled());
+}
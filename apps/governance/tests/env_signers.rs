@@ -0,0 +1,54 @@
+//! Trusted signers injected through OPENLORA_TRUSTED_SIGNERS.
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, SignatureError, SignatureVerifier, TRUSTED_SIGNERS_ENV};
+
+fn encoded_key(seed: u8) -> String {
+    BASE64_STANDARD.encode(SigningKey::from_bytes(&[seed; 32]).verifying_key().to_bytes())
+}
+
+#[test]
+fn ids_and_keys_are_parsed_from_the_list() {
+    let list = format!(" release={} , ci,, ", encoded_key(7));
+    let verifier = SignatureVerifier::parse_signer_list(&list).unwrap();
+    assert!(verifier.signer_key("release").is_some());
+    assert!(verifier.signer_key("ci").is_none());
+
+    let signature = sign_ed25519(b"adapter", "release", &SigningKey::from_bytes(&[7; 32]), None);
+    assert!(verifier.verify(b"adapter", &signature).unwrap());
+    let forged = sign_ed25519(b"adapter", "release", &SigningKey::from_bytes(&[8; 32]), None);
+    assert!(!verifier.verify(b"adapter", &forged).unwrap());
+    let unknown = sign_ed25519(b"adapter", "mallory", &SigningKey::from_bytes(&[7; 32]), None);
+    assert!(matches!(verifier.verify(b"adapter", &unknown), Err(SignatureError::UnknownSigner(_))));
+}
+
+#[test]
+fn malformed_entries_are_named_in_the_error() {
+    for (list, reason) in [
+        ("release=not base64!", "public key is not base64"),
+        ("release=AAAA", "public key is 3 bytes, expected 32"),
+        ("release,ci,release", "signer listed more than once"),
+        (" =AAAA", "signer id"),
+    ] {
+        let err = SignatureVerifier::parse_signer_list(list).err().unwrap();
+        assert!(matches!(err, SignatureError::InvalidSignerEntry { .. }), "{}: {:?}", list, err);
+        let message = err.to_string();
+        assert!(message.starts_with(&format!("Invalid {} entry", TRUSTED_SIGNERS_ENV)), "{}", message);
+        assert!(message.contains(reason), "{}: {}", list, message);
+    }
+}
+
+#[test]
+fn the_verifier_is_read_from_the_environment() {
+    std::env::remove_var(TRUSTED_SIGNERS_ENV);
+    assert!(SignatureVerifier::from_env().unwrap().is_none());
+
+    std::env::set_var(TRUSTED_SIGNERS_ENV, format!("release={}", encoded_key(7)));
+    let verifier = SignatureVerifier::from_env().unwrap().unwrap();
+    assert!(verifier.signer_key("release").is_some());
+
+    std::env::set_var(TRUSTED_SIGNERS_ENV, "  ");
+    assert!(SignatureVerifier::from_env().unwrap().is_none());
+    std::env::remove_var(TRUSTED_SIGNERS_ENV);
+}
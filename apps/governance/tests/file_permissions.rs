@@ -0,0 +1,47 @@
+//! Adapters and signatures any local user could tamper with are reported.
+#![cfg(unix)]
+
+use openlora_governance::permissions::{check_permissions, PermissionProblem};
+use std::fs::{self, Permissions};
+use std::os::unix::fs::PermissionsExt;
+
+#[test]
+fn world_writable_files_are_reported_with_their_mode() {
+    let dir = tempfile::tempdir().unwrap();
+    let adapter = dir.path().join("adapter.safetensors");
+    fs::write(&adapter, b"weights").unwrap();
+
+    fs::set_permissions(&adapter, Permissions::from_mode(0o644)).unwrap();
+    assert!(check_permissions(&adapter).unwrap().is_empty());
+
+    fs::set_permissions(&adapter, Permissions::from_mode(0o666)).unwrap();
+    let issues = check_permissions(&adapter).unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].path, adapter);
+    assert_eq!(issues[0].mode, 0o666);
+    assert_eq!(issues[0].problem, PermissionProblem::WorldWritable);
+    assert!(issues[0].to_string().ends_with("is world-writable (mode 0666)"));
+}
+
+#[test]
+fn a_world_writable_directory_needs_the_sticky_bit() {
+    let dir = tempfile::tempdir().unwrap();
+    let signature = dir.path().join("adapter.sig.json");
+    fs::write(&signature, b"{}").unwrap();
+    fs::set_permissions(&signature, Permissions::from_mode(0o600)).unwrap();
+
+    fs::set_permissions(dir.path(), Permissions::from_mode(0o777)).unwrap();
+    let issues = check_permissions(&signature).unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].path, dir.path());
+    assert_eq!(issues[0].problem, PermissionProblem::WorldWritableDirectory);
+
+    fs::set_permissions(dir.path(), Permissions::from_mode(0o1777)).unwrap();
+    assert!(check_permissions(&signature).unwrap().is_empty());
+}
+
+#[test]
+fn a_missing_file_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(check_permissions(&dir.path().join("missing")).is_err());
+}
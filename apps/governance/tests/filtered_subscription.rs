@@ -0,0 +1,40 @@
+//! Subscribers can ask for only the kills they care about.
+
+use openlora_governance::killswitch::{KillReason, KillSwitch, KillSwitchChange};
+
+#[test]
+fn only_matching_kills_and_their_resets_are_delivered() {
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+    let everything = ks.subscribe();
+    let reward_hacking = ks.subscribe_filtered(|reason| matches!(reason, KillReason::RewardHacking { .. }));
+
+    let manual = KillReason::ManualTrigger { operator: "ops".to_string(), reason: None };
+    ks.activate("ops", manual, Vec::new()).unwrap();
+    ks.reset("ops").unwrap();
+    assert!(reward_hacking.try_recv().is_err());
+
+    let hacking = KillReason::RewardHacking { adapter_id: "adapter-1".to_string() };
+    let event = ks.activate("ops", hacking, vec!["adapter-1".to_string()]).unwrap();
+    ks.reset("ops").unwrap();
+
+    match reward_hacking.try_recv().unwrap() {
+        KillSwitchChange::Activated(got) => assert_eq!(got.id, event.id),
+        other => panic!("expected activation, got {:?}", other),
+    }
+    assert!(matches!(reward_hacking.try_recv().unwrap(), KillSwitchChange::Reset(_)));
+    assert!(reward_hacking.try_recv().is_err());
+
+    assert_eq!(everything.try_iter().count(), 4);
+}
+
+#[test]
+fn dropped_filtered_receivers_are_removed() {
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+    drop(ks.subscribe_filtered(|_| true));
+    let kept = ks.subscribe_filtered(|_| false);
+
+    let manual = KillReason::ManualTrigger { operator: "ops".to_string(), reason: None };
+    ks.activate("ops", manual, Vec::new()).unwrap();
+    ks.reset("ops").unwrap();
+    assert!(kept.try_recv().is_err());
+}
@@ -0,0 +1,24 @@
+//! Short fingerprints for comparing hashes, keys and ids by eye.
+
+use ed25519_dalek::SigningKey;
+use openlora_governance::display::fingerprint;
+use openlora_governance::keys::{self, generate_keypair};
+
+#[test]
+fn fingerprints_are_short_grouped_hex_of_the_digest() {
+    // SHA-256 of the empty input starts e3b0c44298fc1c14.
+    assert_eq!(fingerprint(b""), "e3b0:c442:98fc:1c14");
+    assert_eq!(fingerprint(b"event-1"), fingerprint(b"event-1"));
+    assert_ne!(fingerprint(b"event-1"), fingerprint(b"event-2"));
+}
+
+#[test]
+fn a_key_fingerprint_starts_its_full_fingerprint() {
+    let key = SigningKey::from_bytes(&[7; 32]).verifying_key();
+    let short = fingerprint(key.as_bytes()).replace(':', "");
+    assert!(keys::fingerprint(&key).starts_with(&format!("SHA256:{}", short)));
+
+    let dir = tempfile::tempdir().unwrap();
+    let generated = generate_keypair("release", dir.path()).unwrap();
+    assert_eq!(generated.fingerprint, keys::fingerprint(&generated.public_key));
+}
@@ -0,0 +1,56 @@
+//! Admins can force a reset past quorum, and it is recorded distinctly.
+
+use chrono::Duration;
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{EscalationPolicy, KillReason, KillSwitch, KillSwitchError};
+use openlora_governance::replay::replay;
+use serde_json::json;
+
+fn escalated_governance(dir: &tempfile::TempDir) -> Governance {
+    let mut ks = KillSwitch::new(vec!["alice".to_string(), "bob".to_string()]);
+    ks.set_admins(vec!["root".to_string()]);
+    ks.set_escalation_policy(Some(EscalationPolicy {
+        max_activations: 0,
+        window: Duration::hours(1),
+        reset_quorum: 2,
+    }));
+    let audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut gov = Governance::new(ks, audit);
+    let reason = KillReason::ManualTrigger { operator: "alice".to_string(), reason: None };
+    gov.kill("alice", reason, Vec::new()).unwrap();
+    gov
+}
+
+#[test]
+fn force_reset_requires_the_admin_role_and_a_justification() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gov = escalated_governance(&dir);
+
+    assert!(matches!(
+        gov.force_reset("alice", "outage"),
+        Err(KillSwitchError::NotAdmin(ref who)) if who == "alice"
+    ));
+    assert!(matches!(gov.force_reset("root", "  "), Err(KillSwitchError::MissingJustification)));
+    assert!(matches!(gov.reset(&["alice".to_string()]), Err(KillSwitchError::Escalated { .. })));
+    assert!(gov.kill_switch().is_active());
+}
+
+#[test]
+fn force_reset_bypasses_quorum_and_is_flagged_in_the_audit_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gov = escalated_governance(&dir);
+
+    let reset = gov.force_reset("root", "quorum unreachable during outage").unwrap();
+    assert!(reset.audit.is_recorded());
+    assert!(!gov.kill_switch().is_active());
+
+    let entries = gov.audit_log().unwrap().entries().unwrap();
+    let last = entries.last().unwrap();
+    assert!(matches!(last.event_type, AuditEventType::KillSwitchForceReset));
+    assert_eq!(last.actor, "root");
+    assert_eq!(last.details["justification"], json!("quorum unreachable during outage"));
+    assert_eq!(last.details["bypassed_escalation"], json!(true));
+
+    assert!(!replay(gov.audit_log().unwrap()).unwrap().killed);
+}
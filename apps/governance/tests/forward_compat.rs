@@ -0,0 +1,58 @@
+//! Event types and kill reasons from newer builds survive a round trip.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::killswitch::{KillEvent, KillReason};
+use serde_json::json;
+
+#[test]
+fn unknown_event_types_are_kept_and_hashed_by_tag() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({})).unwrap();
+    let newer = log.append(AuditEventType::Unknown("ModelRetired".into()), "bob", None, json!({ "n": 1 })).unwrap();
+    assert!(std::fs::read_to_string(&path).unwrap().contains("\"event_type\":\"ModelRetired\""));
+
+    let reopened = AuditLog::open(path).unwrap();
+    assert!(reopened.verify_integrity().unwrap());
+    let entries = reopened.entries().unwrap();
+    assert!(matches!(entries[1].event_type, AuditEventType::Unknown(ref tag) if tag == "ModelRetired"));
+    assert_eq!(entries[1].hash, newer.hash);
+
+    // A build that knows the variant hashes it the same as one that does not.
+    let mut known = entries[0].clone();
+    assert!(matches!(known.event_type, AuditEventType::PolicyEvaluated));
+    known.event_type = AuditEventType::Unknown("PolicyEvaluated".into());
+    assert_eq!(known.compute_hash(), entries[0].hash);
+}
+
+#[test]
+fn unknown_kill_reasons_round_trip_verbatim() {
+    let raw = json!({ "QuotaExceeded": { "tenant": "t1", "limit": 3 } });
+    let reason: KillReason = serde_json::from_value(raw.clone()).unwrap();
+    assert!(matches!(reason, KillReason::Other(_)));
+    assert_eq!(reason.code(), "QuotaExceeded");
+    assert_eq!(serde_json::to_value(&reason).unwrap(), raw);
+
+    let core: openlora_core::KillReason = serde_json::from_value(raw.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&core).unwrap(), raw);
+    assert_eq!(serde_json::to_value(KillReason::from(core)).unwrap(), raw);
+}
+
+#[test]
+fn events_with_unknown_reasons_still_load() {
+    let event: KillEvent = serde_json::from_value(json!({
+        "id": "kill-1",
+        "reason": "ThermalShutdown",
+        "timestamp": "2024-01-01T00:00:00Z",
+        "triggered_by": "sensor",
+        "affected_adapters": [],
+    }))
+    .unwrap();
+    assert_eq!(event.reason.code(), "Other");
+    assert_eq!(serde_json::to_value(&event.reason).unwrap(), json!("ThermalShutdown"));
+
+    // Known reasons still parse as themselves.
+    let known: KillReason = serde_json::from_value(json!({ "RewardHacking": { "adapter_id": "a" } })).unwrap();
+    assert!(matches!(known, KillReason::RewardHacking { ref adapter_id } if adapter_id == "a"));
+}
@@ -0,0 +1,62 @@
+//! Signatures and provenance dated ahead of the verifier's clock.
+
+use chrono::{Duration, Utc};
+use ed25519_dalek::SigningKey;
+use openlora_core::ProvenanceOperation;
+use openlora_governance::clock::{Clock, ManualClock};
+use openlora_governance::signatures::{sign_ed25519, ProvenanceEntry, SignatureError, SignatureVerifier};
+
+fn verifier(clock: &ManualClock) -> SignatureVerifier {
+    let mut verifier = SignatureVerifier::new(Vec::new()).with_clock(clock.clone());
+    verifier.add_signer_key("release", SigningKey::from_bytes(&[7; 32]).verifying_key());
+    verifier
+}
+
+#[test]
+fn signatures_too_far_ahead_are_rejected() {
+    let clock = ManualClock::new(Utc::now() - Duration::minutes(10));
+    let mut verifier = verifier(&clock);
+    let signature = sign_ed25519(b"adapter", "release", &SigningKey::from_bytes(&[7; 32]), None);
+
+    let err = verifier.verify(b"adapter", &signature).unwrap_err();
+    assert!(matches!(
+        err,
+        SignatureError::FutureTimestamp { at, max_skew, .. } if at == signature.signed_at && max_skew == Duration::minutes(5)
+    ));
+    assert_eq!(verifier.report().get("release").unwrap().failed, 1);
+
+    // Within the default five minutes once the clock catches up.
+    clock.advance(Duration::minutes(6));
+    assert!(verifier.verify(b"adapter", &signature).unwrap());
+
+    verifier.set_max_future_skew(Some(Duration::zero()));
+    assert!(matches!(verifier.verify(b"adapter", &signature), Err(SignatureError::FutureTimestamp { .. })));
+    verifier.set_max_future_skew(None);
+    clock.advance(Duration::days(-1));
+    assert!(verifier.verify(b"adapter", &signature).unwrap());
+}
+
+#[test]
+fn provenance_entries_from_the_future_are_rejected() {
+    let clock = ManualClock::new(Utc::now());
+    let verifier = verifier(&clock);
+    let mut entry = ProvenanceEntry {
+        adapter_id: "fraud-v2".to_string(),
+        version: 1,
+        operation: ProvenanceOperation::Created,
+        actor: "alice".to_string(),
+        timestamp: clock.now() + Duration::hours(2),
+        signature: None,
+        parent_hash: None,
+        content_hash: None,
+        hash: String::new(),
+    };
+    entry.hash = entry.compute_hash();
+    assert!(matches!(
+        verifier.verify_provenance(&[entry.clone()]),
+        Err(SignatureError::FutureTimestamp { .. })
+    ));
+
+    clock.advance(Duration::hours(2));
+    assert!(verifier.verify_provenance(&[entry]).unwrap());
+}
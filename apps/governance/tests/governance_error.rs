@@ -0,0 +1,50 @@
+//! One error type across modules, composed with `?`.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog};
+use openlora_governance::config::GovernanceConfig;
+use openlora_governance::killswitch::{KillReason, KillSwitch, KillSwitchError};
+use openlora_governance::GovernanceError;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+fn kill_and_record(dir: &Path, operator: &str) -> Result<usize, GovernanceError> {
+    let config = GovernanceConfig::load_or_default(Some(&dir.join("governance.toml")))?;
+    let mut ks = KillSwitch::new(config.authorized_operators);
+    let reason = KillReason::ManualTrigger { operator: operator.to_string(), reason: None };
+    let event = ks.activate(operator, reason, Vec::new())?;
+
+    let mut log = AuditLog::open(dir.join("audit.jsonl"))?;
+    log.append(
+        AuditEventType::KillSwitchActivated,
+        operator,
+        None,
+        json!({ "event_id": event.id }),
+    )?;
+    Ok(log.entries()?.len())
+}
+
+#[test]
+fn module_errors_convert_and_keep_their_variants() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("governance.toml"), "authorized_operators = [\"alice\"]\n").unwrap();
+
+    assert_eq!(kill_and_record(dir.path(), "alice").unwrap(), 1);
+    let refused = kill_and_record(dir.path(), "mallory").unwrap_err();
+    assert!(matches!(refused, GovernanceError::KillSwitch(KillSwitchError::Unauthorized(_))));
+    assert_eq!(refused.to_string(), KillSwitchError::Unauthorized("mallory".to_string()).to_string());
+    assert!(!refused.is_integrity_failure());
+
+    fs::write(dir.path().join("governance.toml"), "authorized_operators = [").unwrap();
+    assert!(matches!(kill_and_record(dir.path(), "alice"), Err(GovernanceError::Config(_))));
+}
+
+#[test]
+fn integrity_failures_are_recognised_through_the_wrapper() {
+    let broken: GovernanceError = AuditError::IntegrityViolation {
+        expected: "a".to_string(),
+        actual: "b".to_string(),
+    }
+    .into();
+    assert!(broken.is_integrity_failure());
+}
@@ -0,0 +1,41 @@
+//! Gzip artifacts are signed and verified over their decompressed content.
+
+use ed25519_dalek::SigningKey;
+use flate2::write::GzEncoder;
+use openlora_governance::signatures::{sign_ed25519_source, SignatureError, SignatureVerifier};
+use openlora_governance::source::{Compression, ContentSource};
+use std::io::Write;
+
+#[test]
+fn signature_is_stable_across_compression() {
+    let dir = tempfile::tempdir().unwrap();
+    let content: Vec<u8> = (0..200_000u32).flat_map(|i| i.to_le_bytes()).collect();
+
+    let plain = dir.path().join("adapter.safetensors");
+    std::fs::write(&plain, &content).unwrap();
+    let packed = dir.path().join("adapter.safetensors.gz");
+    let mut gz = GzEncoder::new(std::fs::File::create(&packed).unwrap(), flate2::Compression::default());
+    gz.write_all(&content).unwrap();
+    gz.finish().unwrap();
+
+    let key = SigningKey::from_bytes(&[3; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+
+    let plain = ContentSource::File(plain);
+    let packed = ContentSource::File(packed);
+    assert_eq!(plain.compression(), None);
+    assert_eq!(packed.compression(), Some(Compression::Gzip));
+
+    let signature = sign_ed25519_source(&packed, "alice", &key, None).unwrap();
+    assert_eq!(signature.artifact_compression, Some(Compression::Gzip));
+
+    // The same signature verifies the compressed and uncompressed artifact.
+    assert!(verifier.verify_reader(packed.open_content().unwrap(), &signature).unwrap());
+    assert!(verifier.verify_reader(plain.open_content().unwrap(), &signature).unwrap());
+    // The raw compressed bytes are not the signed content.
+    assert!(matches!(
+        verifier.verify_reader(packed.open().unwrap(), &signature),
+        Err(SignatureError::ContentMismatch { .. })
+    ));
+}
@@ -0,0 +1,79 @@
+//! Logs mixing hash versions verify; a return to an older version does not.
+
+use chrono::{Duration, TimeZone, Utc};
+use openlora_governance::audit::{AuditEntry, AuditError, AuditEventType, AuditLog, HASH_VERSION, LEGACY_HASH_VERSION};
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+/// Entries hashed with `versions` in turn, chained from genesis.
+fn chain(versions: &[u32]) -> Vec<AuditEntry> {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut previous_hash = "genesis".to_string();
+    let mut seq = 0;
+    versions
+        .iter()
+        .enumerate()
+        .map(|(i, &hash_version)| {
+            seq += 1;
+            let mut entry = AuditEntry {
+                id: format!("entry-{}", i),
+                timestamp: start + Duration::minutes(i as i64),
+                event_type: AuditEventType::PolicyEvaluated,
+                actor: "alice".to_string(),
+                actor_seq: (hash_version >= 3).then_some(seq),
+                target_type: None,
+                target_id: None,
+                details: json!({ "n": i }),
+                previous_hash: previous_hash.clone(),
+                hash: String::new(),
+                hash_version,
+            };
+            entry.hash = entry.compute_hash();
+            previous_hash.clone_from(&entry.hash);
+            entry
+        })
+        .collect()
+}
+
+fn write(path: &Path, entries: &[AuditEntry]) {
+    let lines: Vec<String> = entries.iter().map(|e| serde_json::to_string(e).unwrap()).collect();
+    fs::write(path, lines.join("\n") + "\n").unwrap();
+}
+
+#[test]
+fn v1_entries_are_short_and_later_versions_full_length() {
+    let entries = chain(&[LEGACY_HASH_VERSION, 2, HASH_VERSION]);
+    let lengths: Vec<usize> = entries.iter().map(|e| e.hash.len()).collect();
+    assert_eq!(lengths, [16, 64, 64]);
+}
+
+#[test]
+fn a_log_upgraded_mid_chain_verifies_and_keeps_growing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    write(&path, &chain(&[LEGACY_HASH_VERSION, LEGACY_HASH_VERSION, 2, 2]));
+
+    let mut log = AuditLog::open(path).unwrap();
+    assert!(log.verify_integrity().unwrap());
+    let appended = log.append(AuditEventType::PolicyEvaluated, "bob", None, json!({})).unwrap();
+    assert_eq!(appended.hash_version, HASH_VERSION);
+    assert!(log.verify_integrity().unwrap());
+    assert_eq!(log.entries().unwrap().len(), 5);
+}
+
+#[test]
+fn an_older_version_after_a_newer_one_is_a_downgrade() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let entries = chain(&[LEGACY_HASH_VERSION, 2, LEGACY_HASH_VERSION]);
+    write(&path, &entries);
+
+    match AuditLog::open(path).unwrap().verify_integrity() {
+        Err(AuditError::HashVersionDowngrade { id, version, previous }) => {
+            assert_eq!(id, entries[2].id);
+            assert_eq!((version, previous), (LEGACY_HASH_VERSION, 2));
+        }
+        other => panic!("expected HashVersionDowngrade, got {:?}", other),
+    }
+}
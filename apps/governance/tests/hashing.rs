@@ -0,0 +1,30 @@
+//! The shared hashing helpers behind signatures, provenance and the audit chain.
+
+use openlora_core::hashing::{content_hash, Hasher, SHORT_HASH_LEN};
+use std::io::Write;
+
+#[test]
+fn content_hash_is_hex_sha256() {
+    assert_eq!(
+        content_hash(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    let mut streamed = Hasher::new();
+    streamed.write_all(b"a").unwrap();
+    streamed.write_all(b"bc").unwrap();
+    assert_eq!(streamed.finish(), content_hash(b"abc"));
+}
+
+#[test]
+fn framed_fields_do_not_collide_across_splits() {
+    let raw = |a: &str, b: &str| Hasher::new().update(a).update(b).finish();
+    let framed = |a: &str, b: &str| Hasher::domain("test").field(a).field(b).finish();
+    assert_eq!(raw("ab", "c"), raw("a", "bc"));
+    assert_ne!(framed("ab", "c"), framed("a", "bc"));
+    assert_ne!(framed("a", "b"), Hasher::domain("other").field("a").field("b").finish());
+
+    let short = Hasher::new().update("abc").finish_short();
+    assert_eq!(short.len(), SHORT_HASH_LEN);
+    assert!(content_hash(b"abc").starts_with(&short));
+}
@@ -0,0 +1,44 @@
+//! Retried kill activations with the same idempotency key.
+
+use openlora_governance::audit::AuditLog;
+use openlora_governance::governance::{AuditStatus, Governance};
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+
+fn manual() -> KillReason {
+    KillReason::ManualTrigger {
+        operator: "alice".to_string(),
+        reason: None,
+    }
+}
+
+#[test]
+fn repeated_key_returns_the_original_event() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut gov = Governance::new(KillSwitch::new(vec!["alice".into()]), AuditLog::open(path.clone()).unwrap());
+
+    let first = gov.kill_idempotent("alice", manual(), Vec::new(), Some("req-1")).unwrap();
+    assert!(first.audit.is_recorded());
+
+    // A retry neither fails with AlreadyActive nor creates a second event.
+    let retry = gov.kill_idempotent("alice", manual(), Vec::new(), Some("req-1")).unwrap();
+    assert!(matches!(retry.audit, AuditStatus::Replayed));
+    assert_eq!(retry.outcome.id, first.outcome.id);
+    assert_eq!(gov.kill_switch().get_events().len(), 1);
+
+    // Still replayed after a reset, and after a restart from persisted state.
+    gov.reset(&["alice".to_string()]).unwrap();
+    let state = gov.kill_switch().state();
+    let mut restored = KillSwitch::from_state(vec!["alice".into()], state);
+    let replayed = restored
+        .activate_idempotent("alice", manual(), Vec::new(), Some("req-1"))
+        .unwrap();
+    assert!(replayed.is_replayed());
+    assert!(!restored.is_active());
+
+    // Unauthorized callers learn nothing from a known key.
+    assert!(restored.activate_idempotent("mallory", manual(), Vec::new(), Some("req-1")).is_err());
+
+    // Only the first activation and the reset were audited.
+    assert_eq!(AuditLog::open(path).unwrap().entries().unwrap().len(), 2);
+}
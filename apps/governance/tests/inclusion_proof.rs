@@ -0,0 +1,83 @@
+//! Merkle inclusion proofs for single audit entries.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::inclusion::{verify_inclusion, ProofStep, Side};
+use serde_json::json;
+
+fn log_with(dir: &tempfile::TempDir, n: usize) -> AuditLog {
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    for i in 0..n {
+        log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": i })).unwrap();
+    }
+    log
+}
+
+#[test]
+fn every_entry_proves_against_the_head() {
+    for n in 1..=9 {
+        let dir = tempfile::tempdir().unwrap();
+        let log = log_with(&dir, n);
+        let head = log.merkle_head().unwrap().unwrap();
+        for entry in log.entries().unwrap() {
+            let proof = log.prove_inclusion(&entry.id).unwrap();
+            assert!(verify_inclusion(&proof, &head), "entry {} of {}", proof.index, n);
+        }
+    }
+}
+
+#[test]
+fn proofs_must_have_the_shape_their_index_implies() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = log_with(&dir, 5);
+    let head = log.merkle_head().unwrap().unwrap();
+    let id = log.entries().unwrap()[2].id.clone();
+    let proof = log.prove_inclusion(&id).unwrap();
+    assert!(verify_inclusion(&proof, &head));
+
+    let mut flipped = proof.clone();
+    flipped.path[0].side = match flipped.path[0].side {
+        Side::Left => Side::Right,
+        Side::Right => Side::Left,
+    };
+    assert!(!verify_inclusion(&flipped, &head));
+
+    let mut short = proof.clone();
+    short.path.pop();
+    assert!(!verify_inclusion(&short, &head));
+
+    let mut long = proof.clone();
+    long.path.push(ProofStep {
+        side: Side::Right,
+        hash: head.clone(),
+    });
+    assert!(!verify_inclusion(&long, &head));
+
+    // The same path claimed for another position or log size.
+    let mut moved = proof.clone();
+    moved.index = 3;
+    assert!(!verify_inclusion(&moved, &head));
+    let mut resized = proof.clone();
+    resized.leaf_count = 3;
+    assert!(!verify_inclusion(&resized, &head));
+    let mut out_of_range = proof;
+    out_of_range.index = 5;
+    assert!(!verify_inclusion(&out_of_range, &head));
+}
+
+#[test]
+fn an_altered_entry_or_another_head_does_not_verify() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = log_with(&dir, 4);
+    let head = log.merkle_head().unwrap().unwrap();
+    let id = log.entries().unwrap()[1].id.clone();
+    let proof = log.prove_inclusion(&id).unwrap();
+
+    let mut altered = proof.clone();
+    altered.entry.actor = "mallory".to_string();
+    assert!(!verify_inclusion(&altered, &head));
+
+    log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": 4 })).unwrap();
+    let newer = log.merkle_head().unwrap().unwrap();
+    assert!(!verify_inclusion(&proof, &newer));
+    assert!(verify_inclusion(&log.prove_inclusion(&id).unwrap(), &newer));
+}
@@ -0,0 +1,46 @@
+//! Report-only verification lists every violation instead of the first.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog};
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn every_violation_is_reported_with_its_line_and_entry() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    for i in 0..6 {
+        log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": i })).unwrap();
+    }
+    assert!(log.verify_report().unwrap().is_empty());
+
+    let mut lines: Vec<String> = fs::read_to_string(&path).unwrap().lines().map(String::from).collect();
+    let entries = log.entries().unwrap();
+    lines[1] = lines[1].replace("\"n\":1", "\"n\":100");
+    lines.remove(3);
+    lines.push(lines[0].clone());
+    fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+    let log = AuditLog::open(path).unwrap();
+    assert!(log.verify_integrity().is_err());
+    let issues = log.verify_report().unwrap();
+    let found: Vec<_> = issues.iter().map(|issue| (issue.line, issue.entry_id.clone())).collect();
+    assert_eq!(
+        found,
+        vec![
+            (2, Some(entries[1].id.clone())),
+            (4, Some(entries[4].id.clone())),
+            (6, Some(entries[0].id.clone())),
+        ]
+    );
+    assert!(issues.iter().all(|issue| issue.error.is_integrity_failure()));
+    assert!(matches!(issues[2].error, AuditError::GenesisMidChain { .. }));
+    assert!(issues[0].to_string().starts_with(&format!("line 2 (entry {}): ", entries[1].id)));
+}
+
+#[test]
+fn a_missing_log_has_nothing_to_report() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    assert!(log.verify_report().unwrap().is_empty());
+}
@@ -0,0 +1,88 @@
+//! Kill events exported as CSV and JSON for compliance review.
+
+use chrono::{TimeZone, Utc};
+use openlora_governance::killswitch::{write_events_csv, KillEvent, KillReason, KillSwitch, CSV_HEADER};
+use std::collections::BTreeMap;
+use std::fs;
+
+fn event(id: &str, reason: KillReason, affected: &[&str]) -> KillEvent {
+    KillEvent {
+        id: id.to_string(),
+        reason,
+        timestamp: Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap(),
+        triggered_by: "alice".to_string(),
+        affected_adapters: affected.iter().map(|a| a.to_string()).collect(),
+        idempotency_key: None,
+        co_signers: Vec::new(),
+        acknowledged: BTreeMap::new(),
+        overdue_reported: false,
+        shadow: false,
+    }
+}
+
+fn csv(events: &[KillEvent]) -> String {
+    let mut out = Vec::new();
+    write_events_csv(events, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn each_event_is_one_row_under_the_header() {
+    let events = [event(
+        "kill-1",
+        KillReason::RewardHacking { adapter_id: "adapter-1".into() },
+        &["adapter-1", "adapter-2"],
+    )];
+    assert_eq!(
+        csv(&events),
+        format!(
+            "{}\r\nkill-1,2024-03-01T12:00:00+00:00,alice,RewardHacking,adapter_id=adapter-1,adapter-1;adapter-2,false\r\n",
+            CSV_HEADER.join(",")
+        )
+    );
+}
+
+#[test]
+fn details_with_commas_quotes_and_newlines_are_quoted() {
+    let reason = |reason: &str| KillReason::ManualTrigger {
+        operator: "alice".into(),
+        reason: Some(reason.into()),
+    };
+    let events = [
+        event("kill-1", reason("drift, then \"loss\" spiked"), &[]),
+        event("kill-2", reason("line one\nline two"), &[]),
+    ];
+    let written = csv(&events);
+    let rows: Vec<&str> = written.split("\r\n").skip(1).collect();
+    assert_eq!(
+        rows[0],
+        "kill-1,2024-03-01T12:00:00+00:00,alice,ManualTrigger,\"operator=alice reason=drift, then \"\"loss\"\" spiked\",,false"
+    );
+    assert_eq!(
+        rows[1],
+        "kill-2,2024-03-01T12:00:00+00:00,alice,ManualTrigger,\"operator=alice reason=line one\nline two\",,false"
+    );
+}
+
+#[test]
+fn both_formats_are_written_from_a_switch() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut ks = KillSwitch::new(vec!["alice".to_string()]);
+    ks.set_shadow_mode(true);
+    let reason = KillReason::ExternalSignal { source: "pager".into(), message: "a, b".into() };
+    let fired = ks.activate("alice", reason, vec!["adapter-1".into()]).unwrap();
+
+    let csv_path = dir.path().join("kills.csv");
+    ks.export_csv(&csv_path).unwrap();
+    let written = fs::read_to_string(&csv_path).unwrap();
+    assert_eq!(written.lines().count(), 2);
+    assert!(written.contains(&format!("{},", fired.id)));
+    assert!(written.contains(",\"source=pager message=a, b\",adapter-1,true\r\n"));
+
+    let json_path = dir.path().join("kills.json");
+    ks.export_json(&json_path).unwrap();
+    let exported: Vec<KillEvent> = serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert_eq!(exported.len(), 1);
+    assert_eq!(exported[0].id, fired.id);
+    assert_eq!(exported[0].affected_adapters, ["adapter-1"]);
+}
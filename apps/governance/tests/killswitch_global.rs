@@ -0,0 +1,51 @@
+//! The process-wide `is_killed` flag stays consistent with instances.
+//!
+//! A single test: the flag is shared by everything in this binary.
+
+use openlora_governance::killswitch::{is_killed, KillReason, KillState, KillSwitch};
+
+fn manual() -> KillReason {
+    KillReason::ManualTrigger {
+        operator: "alice".to_string(),
+        reason: None,
+    }
+}
+
+#[test]
+fn global_flag_follows_every_instance() {
+    let mut a = KillSwitch::new(vec!["alice".into()]);
+    let mut b = KillSwitch::new(vec!["alice".into()]);
+    assert!(!is_killed());
+
+    a.activate("alice", manual(), Vec::new()).unwrap();
+    b.activate("alice", manual(), Vec::new()).unwrap();
+    assert!(is_killed());
+
+    // Resetting one instance leaves the other's kill in force.
+    a.reset("alice").unwrap();
+    assert!(!a.is_active());
+    assert!(b.is_active());
+    assert!(is_killed());
+
+    b.reset("alice").unwrap();
+    assert!(!is_killed());
+
+    // A restored active state counts, and resetting it clears the flag.
+    let mut restored = KillSwitch::from_state(
+        vec!["alice".into()],
+        KillState {
+            active: true,
+            ..KillState::default()
+        },
+    );
+    assert!(is_killed());
+    restored.reset("alice").unwrap();
+    assert!(!is_killed());
+
+    // Dropping an active instance withdraws it from the global flag.
+    let mut dropped = KillSwitch::new(vec!["alice".into()]);
+    dropped.activate("alice", manual(), Vec::new()).unwrap();
+    assert!(is_killed());
+    drop(dropped);
+    assert!(!is_killed());
+}
@@ -0,0 +1,81 @@
+//! Concurrent kill-switch activations and resets stay consistent.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::governance::{Governance, SharedGovernance};
+use openlora_governance::killswitch::{KillReason, KillSwitch, KillSwitchError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+const THREADS: usize = 8;
+const ITERATIONS: usize = 50;
+
+#[test]
+fn concurrent_activate_and_reset_agree_with_audit_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let operators: Vec<String> = (0..THREADS).map(|i| format!("op-{}", i)).collect();
+    let gov = SharedGovernance::new(Governance::open(KillSwitch::new(operators.clone()), path.clone()));
+
+    let activations = Arc::new(AtomicUsize::new(0));
+    let resets = Arc::new(AtomicUsize::new(0));
+
+    let handles: Vec<_> = operators
+        .into_iter()
+        .enumerate()
+        .map(|(i, operator)| {
+            let gov = gov.clone();
+            let (activations, resets) = (activations.clone(), resets.clone());
+            thread::spawn(move || {
+                for n in 0..ITERATIONS {
+                    if (i + n) % 2 == 0 {
+                        let reason = KillReason::ManualTrigger {
+                            operator: operator.clone(),
+                            reason: None,
+                        };
+                        match gov.kill(&operator, reason, Vec::new()) {
+                            Ok(killed) => {
+                                assert!(killed.audit.is_recorded());
+                                activations.fetch_add(1, Ordering::SeqCst);
+                            }
+                            Err(KillSwitchError::AlreadyActive) => {}
+                            Err(e) => panic!("unexpected activate error: {}", e),
+                        }
+                    } else {
+                        match gov.reset(std::slice::from_ref(&operator)) {
+                            Ok(reset) => {
+                                assert!(reset.audit.is_recorded());
+                                resets.fetch_add(1, Ordering::SeqCst);
+                            }
+                            Err(KillSwitchError::NotActive) => {}
+                            Err(e) => panic!("unexpected reset error: {}", e),
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let activations = activations.load(Ordering::SeqCst);
+    let resets = resets.load(Ordering::SeqCst);
+    assert!(activations > 0);
+    assert_eq!(gov.is_active(), activations == resets + 1);
+    assert!(activations == resets || activations == resets + 1);
+    gov.with(|g| assert_eq!(g.kill_switch().get_events().len(), activations));
+
+    let log = AuditLog::open(path).unwrap();
+    assert!(log.verify_integrity().unwrap());
+    let entries = log.entries().unwrap();
+    assert_eq!(entries.len(), activations + resets);
+    for (i, entry) in entries.iter().enumerate() {
+        // The log must strictly alternate, starting with an activation.
+        if i % 2 == 0 {
+            assert!(matches!(entry.event_type, AuditEventType::KillSwitchActivated), "entry {}", i);
+        } else {
+            assert!(matches!(entry.event_type, AuditEventType::KillSwitchReset), "entry {}", i);
+        }
+    }
+}
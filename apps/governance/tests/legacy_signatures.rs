@@ -0,0 +1,72 @@
+//! Legacy sha256 signatures: refused by default, never accepted for keyed signers.
+
+use ed25519_dalek::SigningKey;
+use openlora_governance::config::GovernanceConfig;
+use openlora_governance::signatures::{sign_ed25519, SignatureError, SignatureVerifier, LEGACY_SHA256_ALGORITHM};
+
+#[test]
+fn legacy_signatures_are_refused_by_default() {
+    let verifier = SignatureVerifier::new(vec!["alice".to_string()]);
+    let signature = verifier.sign(b"weights", "alice");
+    assert_eq!(signature.algorithm, LEGACY_SHA256_ALGORITHM);
+
+    match verifier.verify(b"weights", &signature) {
+        Err(SignatureError::UnsupportedAlgorithm { signer, algorithm }) => {
+            assert_eq!(signer, "alice");
+            assert_eq!(algorithm, LEGACY_SHA256_ALGORITHM);
+        }
+        other => panic!("expected UnsupportedAlgorithm, got {:?}", other),
+    }
+}
+
+#[test]
+fn opting_in_accepts_legacy_signatures_from_unkeyed_signers() {
+    let mut verifier = SignatureVerifier::new(vec!["alice".to_string()]);
+    verifier.set_allow_legacy_sha256(true);
+    let signature = verifier.sign(b"weights", "alice");
+
+    assert!(verifier.verify(b"weights", &signature).unwrap());
+}
+
+#[test]
+fn sha256_signature_for_a_keyed_signer_is_rejected() {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    verifier.set_allow_legacy_sha256(true);
+
+    // Anyone can compute this without alice's private key.
+    let forged = verifier.sign(b"weights", "alice");
+    assert!(matches!(
+        verifier.verify(b"weights", &forged),
+        Err(SignatureError::UnsupportedAlgorithm { .. })
+    ));
+
+    let genuine = sign_ed25519(b"weights", "alice", &key, None);
+    assert!(verifier.verify(b"weights", &genuine).unwrap());
+}
+
+#[test]
+fn unknown_algorithms_are_rejected() {
+    let mut verifier = SignatureVerifier::new(vec!["alice".to_string()]);
+    verifier.set_allow_legacy_sha256(true);
+    let mut signature = verifier.sign(b"weights", "alice");
+    signature.algorithm = "md5".to_string();
+
+    assert!(matches!(
+        verifier.verify(b"weights", &signature),
+        Err(SignatureError::UnsupportedAlgorithm { .. })
+    ));
+}
+
+#[test]
+fn config_opts_in_to_legacy_signatures() {
+    let config: GovernanceConfig = toml::from_str("allow_legacy_signatures = true").unwrap();
+    let verifier = config.apply_verification_policy(SignatureVerifier::new(vec!["alice".to_string()]));
+    let signature = verifier.sign(b"weights", "alice");
+    assert!(verifier.verify(b"weights", &signature).unwrap());
+
+    let config: GovernanceConfig = toml::from_str("").unwrap();
+    let verifier = config.apply_verification_policy(SignatureVerifier::new(vec!["alice".to_string()]));
+    assert!(verifier.verify(b"weights", &signature).is_err());
+}
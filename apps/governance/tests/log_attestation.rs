@@ -0,0 +1,112 @@
+//! Attesting to a verified audit log and checking the attestation later.
+
+use ed25519_dalek::pkcs8::{spki::der::pem::LineEnding, EncodePublicKey};
+use ed25519_dalek::SigningKey;
+use openlora_governance::attest::{attest, check_attestation, load_trusted_signers, AttestError};
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::config::signature_verifier;
+use openlora_governance::signatures::{SignatureError, SignatureVerifier};
+use serde_json::json;
+use std::fs;
+
+fn log_with_entries(dir: &tempfile::TempDir, n: usize) -> AuditLog {
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    for i in 0..n {
+        log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": i })).unwrap();
+    }
+    log
+}
+
+/// A verifier built from a signers file listing `reviewer`'s key by relative path.
+fn trusted_verifier(dir: &tempfile::TempDir, key: &SigningKey) -> SignatureVerifier {
+    let keys = dir.path().join("keys");
+    fs::create_dir(&keys).unwrap();
+    fs::write(keys.join("reviewer.pub"), key.verifying_key().to_public_key_pem(LineEnding::LF).unwrap()).unwrap();
+    fs::write(keys.join("signers.json"), r#"[{ "id": "reviewer", "public_key": "reviewer.pub" }]"#).unwrap();
+    let signers = load_trusted_signers(&keys.join("signers.json")).unwrap();
+    assert_eq!(signers[0].public_key.as_deref(), Some(keys.join("reviewer.pub").as_path()));
+    signature_verifier(&signers).unwrap()
+}
+
+#[test]
+fn an_attestation_holds_as_the_log_grows() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = log_with_entries(&dir, 3);
+    let key = SigningKey::from_bytes(&[4; 32]);
+    let attestation = attest(&log, "reviewer", &key).unwrap();
+    let entries = log.entries().unwrap();
+    assert_eq!(attestation.claims.count, 3);
+    assert_eq!(attestation.claims.head_hash, log.head_hash());
+    assert_eq!(attestation.claims.first_ts, Some(entries[0].timestamp));
+    assert_eq!(attestation.claims.last_ts, Some(entries[2].timestamp));
+
+    // It survives a round trip through the file the CLI writes.
+    let attestation = serde_json::from_str(&serde_json::to_string(&attestation).unwrap()).unwrap();
+    let verifier = trusted_verifier(&dir, &key);
+    assert_eq!(check_attestation(&log, &attestation, &verifier).unwrap().newer_entries, 0);
+
+    log.append(AuditEventType::PolicyEvaluated, "bob", None, json!({})).unwrap();
+    assert_eq!(check_attestation(&log, &attestation, &verifier).unwrap().newer_entries, 1);
+}
+
+#[test]
+fn an_empty_log_is_attested_at_genesis() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = log_with_entries(&dir, 0);
+    let key = SigningKey::from_bytes(&[4; 32]);
+    let attestation = attest(&log, "reviewer", &key).unwrap();
+    assert_eq!((attestation.claims.count, attestation.claims.first_ts), (0, None));
+    check_attestation(&log, &attestation, &trusted_verifier(&dir, &key)).unwrap();
+}
+
+#[test]
+fn a_rewritten_or_tampered_log_fails_the_check() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = log_with_entries(&dir, 3);
+    let key = SigningKey::from_bytes(&[4; 32]);
+    let attestation = attest(&log, "reviewer", &key).unwrap();
+    let verifier = trusted_verifier(&dir, &key);
+    let path = dir.path().join("audit.jsonl");
+    let original = fs::read_to_string(&path).unwrap();
+
+    // Edited in place: the chain no longer verifies.
+    fs::write(&path, original.replace("\"n\":1", "\"n\":10")).unwrap();
+    let result = check_attestation(&AuditLog::open(path.clone()).unwrap(), &attestation, &verifier);
+    assert!(matches!(result, Err(AttestError::Audit(ref e)) if e.is_integrity_failure()), "{:?}", result);
+
+    // Rebuilt from genesis: a valid chain, but not the attested one.
+    fs::remove_file(&path).unwrap();
+    let rebuilt = log_with_entries(&dir, 3);
+    assert!(matches!(check_attestation(&rebuilt, &attestation, &verifier), Err(AttestError::Mismatch(_))));
+
+    // Truncated below the attested count.
+    let lines: Vec<&str> = original.lines().take(2).collect();
+    fs::write(&path, lines.join("\n") + "\n").unwrap();
+    assert!(matches!(
+        check_attestation(&AuditLog::open(path).unwrap(), &attestation, &verifier),
+        Err(AttestError::Mismatch(_))
+    ));
+}
+
+#[test]
+fn an_attestation_by_an_untrusted_key_is_refused() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = log_with_entries(&dir, 2);
+    let verifier = trusted_verifier(&dir, &SigningKey::from_bytes(&[4; 32]));
+
+    // Claiming to be the trusted reviewer with another key.
+    let forged = attest(&log, "reviewer", &SigningKey::from_bytes(&[5; 32])).unwrap();
+    assert!(matches!(check_attestation(&log, &forged, &verifier), Err(AttestError::InvalidSignature)));
+
+    // Signing as someone the verifier does not know.
+    let unknown = attest(&log, "mallory", &SigningKey::from_bytes(&[5; 32])).unwrap();
+    assert!(matches!(check_attestation(&log, &unknown, &verifier), Err(AttestError::Signature(_))));
+
+    // Edited claims no longer match what was signed.
+    let mut edited = attest(&log, "reviewer", &SigningKey::from_bytes(&[4; 32])).unwrap();
+    edited.claims.count = 1;
+    assert!(matches!(
+        check_attestation(&log, &edited, &verifier),
+        Err(AttestError::Signature(SignatureError::ContentMismatch { .. }))
+    ));
+}
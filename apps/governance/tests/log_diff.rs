@@ -0,0 +1,70 @@
+//! Comparing two replicas of an audit log.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::diff::{diff_entries, diff_logs, DiffError};
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+
+fn append(log: &mut AuditLog, n: usize) {
+    log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": n })).unwrap();
+}
+
+/// Two replicas sharing `shared` entries, then `a_only` and `b_only` of their own.
+fn replicas(dir: &tempfile::TempDir, shared: usize, a_only: usize, b_only: usize) -> (PathBuf, PathBuf) {
+    let (a, b) = (dir.path().join("a.jsonl"), dir.path().join("b.jsonl"));
+    let mut log = AuditLog::open(a.clone()).unwrap();
+    (0..shared).for_each(|n| append(&mut log, n));
+    fs::copy(&a, &b).unwrap();
+    (0..a_only).for_each(|n| append(&mut log, 100 + n));
+    let mut log = AuditLog::open(b.clone()).unwrap();
+    (0..b_only).for_each(|n| append(&mut log, 200 + n));
+    (a, b)
+}
+
+#[test]
+fn identical_replicas_have_no_fork() {
+    let dir = tempfile::tempdir().unwrap();
+    let (a, b) = replicas(&dir, 5, 0, 0);
+    let diff = diff_logs(&AuditLog::open(a).unwrap(), &AuditLog::open(b).unwrap()).unwrap();
+    assert!(diff.is_identical());
+    assert_eq!(diff.common_prefix, 5);
+}
+
+#[test]
+fn the_fork_and_each_side_are_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let (a, b) = replicas(&dir, 4, 2, 3);
+    let (a, b) = (AuditLog::open(a).unwrap(), AuditLog::open(b).unwrap());
+    let diff = diff_logs(&a, &b).unwrap();
+
+    assert_eq!(diff.common_prefix, 4);
+    let fork = diff.fork.unwrap();
+    assert_eq!(fork.index, 4);
+    assert_eq!(fork.a.unwrap().id, a.entries().unwrap()[4].id);
+    assert_eq!(fork.b.unwrap().id, b.entries().unwrap()[4].id);
+    assert_eq!((diff.only_in_a.len(), diff.only_in_b.len()), (2, 3));
+
+    // A replica that is merely behind forks at its end.
+    let entries = a.entries().unwrap();
+    let diff = diff_entries(&entries[..3], &entries).unwrap();
+    assert_eq!(diff.common_prefix, 3);
+    assert!(diff.fork.unwrap().a.is_none());
+    assert_eq!(diff.only_in_b.len(), 3);
+}
+
+#[test]
+fn a_tampered_replica_is_refused_rather_than_compared() {
+    let dir = tempfile::tempdir().unwrap();
+    let (a, b) = replicas(&dir, 5, 0, 0);
+
+    // Edited in place, keeping the stored hashes that binary search compares.
+    fs::write(&b, fs::read_to_string(&b).unwrap().replace("\"n\":2", "\"n\":20")).unwrap();
+    match diff_logs(&AuditLog::open(a).unwrap(), &AuditLog::open(b).unwrap()) {
+        Err(DiffError::Unverified { log, source }) => {
+            assert_eq!(log, "b");
+            assert!(source.is_integrity_failure());
+        }
+        other => panic!("expected an unverified log, got {:?}", other),
+    }
+}
@@ -0,0 +1,36 @@
+//! Manual kills carry the operator and their stated reason separately.
+
+use openlora_governance::audit::AuditLog;
+use openlora_governance::details::check;
+use openlora_governance::governance::{AuditStatus, Governance};
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+
+#[test]
+fn the_reason_is_kept_apart_from_the_operator_and_audited() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gov = Governance::new(
+        KillSwitch::new(vec!["alice".to_string()]),
+        AuditLog::open(dir.path().join("audit.jsonl")).unwrap(),
+    );
+    let reason = KillReason::ManualTrigger {
+        operator: "alice".to_string(),
+        reason: Some("runaway spend".to_string()),
+    };
+    assert_eq!(reason.detail(), "operator=alice reason=runaway spend");
+
+    let killed = gov.kill("alice", reason, Vec::new()).unwrap();
+    let AuditStatus::Recorded(ref entry) = killed.audit else {
+        panic!("activation not recorded: {:?}", killed.audit);
+    };
+    assert_eq!(entry.details["reason"]["ManualTrigger"]["operator"], "alice");
+    assert_eq!(entry.details["reason"]["ManualTrigger"]["reason"], "runaway spend");
+    check(&entry.event_type, &entry.details.to_string()).unwrap();
+}
+
+#[test]
+fn reasons_recorded_before_the_field_still_load() {
+    let reason: KillReason = serde_json::from_str(r#"{"ManualTrigger":{"operator":"alice"}}"#).unwrap();
+    assert!(matches!(reason, KillReason::ManualTrigger { ref operator, reason: None } if operator == "alice"));
+    assert_eq!(reason.detail(), "operator=alice");
+    assert_eq!(serde_json::to_string(&reason).unwrap(), r#"{"ManualTrigger":{"operator":"alice"}}"#);
+}
@@ -0,0 +1,73 @@
+//! Too many concurrent quarantines escalate to a full kill.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::governance::{Governance, MASS_QUARANTINE_SOURCE};
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+use openlora_governance::quarantine::QuarantineList;
+
+fn setup(dir: &tempfile::TempDir, limit: Option<usize>) -> (Governance, QuarantineList) {
+    let audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut gov = Governance::new(KillSwitch::new(vec!["ops".to_string()]), audit);
+    gov.set_quarantine_limit(limit);
+    let list = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+    (gov, list)
+}
+
+#[test]
+fn exceeding_the_limit_activates_the_kill_switch() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut gov, mut list) = setup(&dir, Some(2));
+
+    for adapter in ["adapter-a", "adapter-b"] {
+        let quarantined = gov.quarantine(&mut list, adapter, "drift", "ops").unwrap();
+        assert!(quarantined.escalation.is_none());
+    }
+    assert!(!gov.kill_switch().is_active());
+
+    let quarantined = gov.quarantine(&mut list, "adapter-c", "drift", "ops").unwrap();
+    assert_eq!(quarantined.quarantined, 3);
+    let killed = quarantined.escalation.unwrap().unwrap();
+    assert!(killed.audit.is_recorded());
+    assert!(gov.kill_switch().is_active());
+    match killed.outcome.reason {
+        KillReason::ExternalSignal { ref source, .. } => assert_eq!(source, MASS_QUARANTINE_SOURCE),
+        ref other => panic!("expected ExternalSignal, got {:?}", other),
+    }
+    assert_eq!(killed.outcome.affected_adapters, ["adapter-a", "adapter-b", "adapter-c"]);
+
+    // Already killed: further quarantines do not activate again.
+    let quarantined = gov.quarantine(&mut list, "adapter-d", "drift", "ops").unwrap();
+    assert!(quarantined.escalation.is_none());
+
+    let entries = gov.audit_log().unwrap().entries().unwrap();
+    let kills = entries
+        .iter()
+        .filter(|e| matches!(e.event_type, AuditEventType::KillSwitchActivated))
+        .count();
+    assert_eq!(kills, 1);
+    assert!(matches!(entries.last().unwrap().event_type, AuditEventType::AdapterQuarantined));
+}
+
+#[test]
+fn without_a_limit_quarantines_never_escalate() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut gov, mut list) = setup(&dir, None);
+
+    for i in 0..5 {
+        let quarantined = gov.quarantine(&mut list, &format!("adapter-{}", i), "drift", "ops").unwrap();
+        assert!(quarantined.escalation.is_none());
+    }
+    assert!(!gov.kill_switch().is_active());
+}
+
+#[test]
+fn a_refused_escalation_keeps_the_quarantine() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut gov, mut list) = setup(&dir, Some(1));
+    gov.quarantine(&mut list, "adapter-a", "drift", "ops").unwrap();
+
+    let quarantined = gov.quarantine(&mut list, "adapter-b", "drift", "mallory").unwrap();
+    assert!(quarantined.escalation.unwrap().is_err());
+    assert!(list.is_quarantined("adapter-b"));
+    assert!(!gov.kill_switch().is_active());
+}
@@ -0,0 +1,64 @@
+//! Migrating a verified audit log into a new, re-chained log.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog, HASH_VERSION};
+use openlora_governance::migrate::migrate;
+use serde_json::json;
+use std::fs;
+
+fn log_with_entries(path: &std::path::Path) {
+    let mut log = AuditLog::open(path.to_path_buf()).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": 1 })).unwrap();
+    log.append(AuditEventType::Unknown("FromTheFuture".into()), "bob", None, json!({ "n": 2 }))
+        .unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": 3 })).unwrap();
+}
+
+#[test]
+fn a_verified_log_is_rechained_into_a_new_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("audit.jsonl");
+    let output = dir.path().join("migrated.jsonl");
+    log_with_entries(&input);
+    let original = fs::read_to_string(&input).unwrap();
+
+    let report = migrate(&input, &output).unwrap();
+    assert_eq!((report.migrated, report.preserved_unknown), (3, 1));
+    assert_eq!(fs::read_to_string(&input).unwrap(), original);
+    assert!(!output.with_extension("tmp").exists());
+
+    let migrated = AuditLog::open(output).unwrap();
+    assert!(migrated.verify_integrity().unwrap());
+    assert_eq!(migrated.head_hash(), report.head_hash);
+    let entries = migrated.entries().unwrap();
+    assert!(entries.iter().all(|e| e.hash_version == HASH_VERSION));
+    assert!(matches!(entries[1].event_type, AuditEventType::Unknown(ref tag) if tag == "FromTheFuture"));
+}
+
+#[test]
+fn a_tampered_log_is_refused_and_nothing_is_written() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("audit.jsonl");
+    let output = dir.path().join("migrated.jsonl");
+    log_with_entries(&input);
+    fs::write(&input, fs::read_to_string(&input).unwrap().replace("\"n\":3", "\"n\":30")).unwrap();
+
+    let err = migrate(&input, &output).unwrap_err();
+    assert!(err.is_integrity_failure(), "{}", err);
+    assert!(!output.exists());
+    assert!(!output.with_extension("tmp").exists());
+}
+
+#[test]
+fn an_existing_output_is_never_replaced() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("audit.jsonl");
+    let output = dir.path().join("migrated.jsonl");
+    log_with_entries(&input);
+    fs::write(&output, "keep me\n").unwrap();
+
+    match migrate(&input, &output) {
+        Err(AuditError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::AlreadyExists),
+        other => panic!("expected AlreadyExists, got {:?}", other),
+    }
+    assert_eq!(fs::read_to_string(&output).unwrap(), "keep me\n");
+}
@@ -0,0 +1,94 @@
+//! Kill notifications are delivered after recording and retried on failure.
+
+use openlora_governance::audit::{AuditLog, RetryPolicy};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{KillEvent, KillReason, KillSwitch};
+use openlora_governance::notify::{NotificationDispatcher, Notifier, NotifyError, NotifyFuture};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Fails with HTTP 503 until `failures` attempts have been made.
+struct Flaky {
+    failures: u32,
+    attempts: Arc<AtomicU32>,
+    delivered: Arc<Mutex<Vec<String>>>,
+}
+
+impl Notifier for Flaky {
+    fn name(&self) -> &str {
+        "flaky"
+    }
+
+    fn notify<'a>(&'a self, event: &'a KillEvent) -> NotifyFuture<'a> {
+        Box::pin(async move {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(NotifyError::Status(503));
+            }
+            self.delivered.lock().unwrap().push(event.id.clone());
+            Ok(())
+        })
+    }
+}
+
+fn fast_retry(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+    }
+}
+
+fn manual() -> KillReason {
+    KillReason::ManualTrigger {
+        operator: "alice".to_string(),
+        reason: None,
+    }
+}
+
+#[test]
+fn notification_is_retried_until_delivered() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut gov = Governance::new(KillSwitch::new(vec!["alice".into()]), log);
+
+    let attempts = Arc::new(AtomicU32::new(0));
+    let delivered = Arc::new(Mutex::new(Vec::new()));
+    let mut notifier = NotificationDispatcher::new(fast_retry(5));
+    notifier.add(Flaky {
+        failures: 2,
+        attempts: Arc::clone(&attempts),
+        delivered: Arc::clone(&delivered),
+    });
+    gov.set_notifier(notifier);
+
+    let killed = gov.kill_idempotent("alice", manual(), Vec::new(), Some("req-1")).unwrap();
+    assert!(killed.audit.is_recorded());
+    // A replayed key announces nothing new.
+    gov.kill_idempotent("alice", manual(), Vec::new(), Some("req-1")).unwrap();
+
+    assert_eq!(gov.wait_for_notifications(), 1);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert_eq!(*delivered.lock().unwrap(), vec![killed.outcome.id]);
+}
+
+#[test]
+fn failed_notification_does_not_undo_the_kill() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut gov = Governance::new(KillSwitch::new(vec!["alice".into()]), log);
+
+    let attempts = Arc::new(AtomicU32::new(0));
+    let mut notifier = NotificationDispatcher::new(fast_retry(3));
+    notifier.add(Flaky {
+        failures: u32::MAX,
+        attempts: Arc::clone(&attempts),
+        delivered: Arc::default(),
+    });
+    gov.set_notifier(notifier);
+
+    gov.kill("alice", manual(), Vec::new()).unwrap();
+    assert_eq!(gov.wait_for_notifications(), 0);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert!(gov.kill_switch().is_active());
+}
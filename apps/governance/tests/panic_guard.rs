@@ -0,0 +1,80 @@
+//! A panic inside a critical section activates the kill-switch.
+
+use openlora_governance::critical::PANIC_GUARD_SOURCE;
+use chrono::Duration;
+use openlora_governance::killswitch::{EscalationPolicy, KillReason, KillSwitch};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::thread;
+
+#[test]
+fn a_normal_drop_does_nothing() {
+    let ks = KillSwitch::new(vec!["ops".to_string()]);
+    {
+        let section = ks.critical_section("adapter swap");
+        assert_eq!(section.operation(), "adapter swap");
+    }
+    assert!(!ks.is_active());
+    assert!(ks.state().events.is_empty());
+}
+
+#[test]
+fn a_panic_in_the_guarded_scope_sets_the_kill_state() {
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+    let panicked = catch_unwind(AssertUnwindSafe(|| {
+        let _section = ks.critical_section("adapter swap");
+        panic!("weights half written");
+    }));
+    assert!(panicked.is_err());
+    assert!(ks.is_active());
+    assert!(ks.begin_operation().is_none());
+
+    let events = ks.state().events;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].triggered_by, PANIC_GUARD_SOURCE);
+    assert!(matches!(
+        events[0].reason,
+        KillReason::ExternalSignal { ref source, ref message }
+            if source == PANIC_GUARD_SOURCE && message == "panic during adapter swap"
+    ));
+    ks.reset("ops").unwrap();
+    assert!(!ks.is_active());
+}
+
+#[test]
+fn a_panic_on_another_thread_is_caught_too() {
+    let ks = KillSwitch::new(vec!["ops".to_string()]);
+    let section = ks.critical_section("policy reload");
+    let worker = thread::spawn(move || {
+        let _section = section;
+        panic!("policy file truncated");
+    });
+    assert!(worker.join().is_err());
+    assert!(ks.is_active());
+}
+
+#[test]
+fn a_panic_counts_towards_escalation_at_once() {
+    let mut ks = KillSwitch::new(vec!["ops".to_string(), "lead".to_string()]);
+    ks.set_escalation_policy(Some(EscalationPolicy {
+        max_activations: 1,
+        window: Duration::hours(1),
+        reset_quorum: 2,
+    }));
+    let manual = KillReason::ManualTrigger { operator: "ops".to_string(), reason: None };
+    ks.activate("ops", manual, Vec::new()).unwrap();
+    ks.reset("ops").unwrap();
+    assert_eq!(ks.activation_rate(Duration::hours(1)), 1);
+
+    let panicked = catch_unwind(AssertUnwindSafe(|| {
+        let _section = ks.critical_section("adapter swap");
+        panic!("weights half written");
+    }));
+    assert!(panicked.is_err());
+
+    // Nothing has moved the panic's event into the switch's own list yet.
+    assert_eq!(ks.get_events().len(), 1);
+    assert_eq!(ks.activation_rate(Duration::hours(1)), 2);
+    assert!(ks.is_escalated());
+    assert!(!ks.can_reset("ops"));
+    assert!(ks.reset("ops").is_err());
+}
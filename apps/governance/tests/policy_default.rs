@@ -0,0 +1,72 @@
+//! Contexts no policy rule covers get the engine's default, `Deny` unless set.
+
+use openlora_core::{GovernanceDecision, RuleOutcome};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::KillSwitch;
+use openlora_governance::policy::{DefaultDecision, PolicyContext, PolicyEngine, PolicyRule, Verdict};
+
+/// Allows activations of adapters below a risk tier.
+struct RiskTier;
+
+impl PolicyRule for RiskTier {
+    fn name(&self) -> &str {
+        "risk-tier"
+    }
+
+    fn evaluate(&self, context: &PolicyContext) -> Verdict {
+        if context.operation != "activate" {
+            return Verdict::NotApplicable;
+        }
+        match context.attributes.get("risk-tier").map(String::as_str) {
+            Some("high") => Verdict::Fail("high-risk adapters need review".to_string()),
+            tier => Verdict::Pass(tier.map(|t| format!("tier {}", t))),
+        }
+    }
+}
+
+#[test]
+fn uncovered_operations_are_denied_by_default() {
+    let engine = PolicyEngine::new().with_rule(RiskTier);
+    assert_eq!(engine.default_decision(), DefaultDecision::Deny);
+
+    let evaluation = engine.evaluate(&PolicyContext::new("export", "ops").with_adapter("fraud-v2"));
+    assert!(evaluation.from_default);
+    assert!(matches!(evaluation.decision, GovernanceDecision::Deny { ref reason } if reason.contains("export")));
+    assert_eq!(evaluation.trace.rules[0].outcome, RuleOutcome::Skipped);
+
+    let allowing = PolicyEngine::new().with_rule(RiskTier).with_default_decision(DefaultDecision::Allow);
+    let evaluation = allowing.evaluate(&PolicyContext::new("export", "ops"));
+    assert!(evaluation.from_default);
+    assert!(matches!(evaluation.decision, GovernanceDecision::Allow { .. }));
+}
+
+#[test]
+fn covered_operations_follow_their_rules() {
+    let engine = PolicyEngine::new().with_rule(RiskTier);
+
+    let low = engine.evaluate(&PolicyContext::new("activate", "ops").with_attribute("risk-tier", "low"));
+    assert!(!low.from_default);
+    assert!(matches!(low.decision, GovernanceDecision::Allow { ref trace } if trace.rules[0].detail.as_deref() == Some("tier low")));
+
+    let high = engine.evaluate(&PolicyContext::new("activate", "ops").with_attribute("risk-tier", "high"));
+    assert!(!high.from_default);
+    assert!(matches!(high.decision, GovernanceDecision::Deny { ref reason } if reason.contains("risk-tier")));
+}
+
+#[test]
+fn default_decisions_are_marked_in_the_audit_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gov = Governance::open(KillSwitch::new(vec!["alice".to_string()]), dir.path().join("audit.jsonl"));
+    let engine = PolicyEngine::new().with_rule(RiskTier);
+
+    let covered = engine.evaluate(&PolicyContext::new("activate", "ops").with_attribute("risk-tier", "high"));
+    let uncovered = engine.evaluate(&PolicyContext::new("export", "ops"));
+    assert!(gov.record_evaluation("policy", None, &covered).is_recorded());
+    assert!(gov.record_evaluation("policy", None, &uncovered).is_recorded());
+
+    let entries = gov.audit_log().unwrap().entries().unwrap();
+    assert!(entries[0].details.get("default_decision").is_none());
+    assert_eq!(entries[0].details["trace"]["rules"][0]["outcome"], "Failed");
+    assert_eq!(entries[1].details["default_decision"], true);
+    assert!(entries[1].details["decision"]["Deny"]["reason"].is_string());
+}
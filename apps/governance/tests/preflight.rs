@@ -0,0 +1,71 @@
+//! Pre-flight checks agree with what activation and reset enforce.
+
+use chrono::Duration;
+use openlora_governance::killswitch::{EscalationPolicy, KillReason, KillSwitch};
+
+fn manual(operator: &str) -> KillReason {
+    KillReason::ManualTrigger { operator: operator.to_string(), reason: None }
+}
+
+#[test]
+fn checks_follow_authorization_and_state() {
+    let mut ks = KillSwitch::new(vec!["alice".to_string()]);
+    assert!(ks.can_activate("alice"));
+    assert!(!ks.can_activate("mallory"));
+    assert!(!ks.can_reset("alice"), "nothing to reset");
+    assert!(!ks.is_active(), "checks have no side effects");
+
+    ks.activate("alice", manual("alice"), Vec::new()).unwrap();
+    assert!(!ks.can_activate("alice"));
+    assert!(ks.can_reset("alice"));
+    assert!(!ks.can_reset("mallory"));
+    assert!(ks.reset("mallory").is_err());
+    ks.reset("alice").unwrap();
+}
+
+#[test]
+fn escalation_withholds_single_operator_reset() {
+    let mut ks = KillSwitch::new(vec!["alice".to_string(), "bob".to_string()]);
+    ks.set_escalation_policy(Some(EscalationPolicy {
+        max_activations: 0,
+        window: Duration::hours(1),
+        reset_quorum: 2,
+    }));
+    ks.activate("alice", manual("alice"), Vec::new()).unwrap();
+
+    assert!(!ks.can_reset("alice"));
+    assert!(ks.reset("alice").is_err());
+    ks.reset_with_quorum(&["alice".to_string(), "bob".to_string()]).unwrap();
+}
+
+#[test]
+fn shadow_mode_admits_activations_while_the_switch_is_active() {
+    let mut ks = KillSwitch::new(vec!["alice".to_string()]);
+    ks.activate("alice", manual("alice"), Vec::new()).unwrap();
+    ks.set_shadow_mode(true);
+
+    assert!(ks.can_activate("alice"));
+    assert!(ks.activate("alice", manual("alice"), Vec::new()).unwrap().shadow);
+    assert!(!ks.can_activate("mallory"));
+    assert!(ks.activate("mallory", manual("mallory"), Vec::new()).is_err());
+
+    ks.set_shadow_mode(false);
+    assert!(!ks.can_activate("alice"));
+    assert!(ks.activate("alice", manual("alice"), Vec::new()).is_err());
+}
+
+#[test]
+fn session_checks_need_a_live_session() {
+    let mut ks = KillSwitch::new(vec!["alice".to_string()]);
+    let live = ks.issue_session("alice", Duration::minutes(5)).unwrap();
+    let expired = ks.issue_session("alice", Duration::seconds(-1)).unwrap();
+
+    assert!(ks.can_activate_with_session(&live.token));
+    assert!(!ks.can_activate_with_session(&expired.token));
+    assert!(!ks.can_activate_with_session("not-a-token"));
+
+    ks.activate_with_session(&live.token, manual("alice"), Vec::new()).unwrap();
+    assert!(ks.can_reset_with_session(&live.token));
+    assert!(!ks.can_reset_with_session(&expired.token));
+    ks.reset_with_sessions(std::slice::from_ref(&live.token)).unwrap();
+}
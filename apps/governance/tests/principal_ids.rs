@@ -0,0 +1,54 @@
+//! Degenerate operator and signer ids are refused at construction.
+
+use openlora_core::{validate_principal_id, IdError};
+use openlora_governance::config::GovernanceConfig;
+use openlora_governance::killswitch::{KillSwitch, KillSwitchError};
+use openlora_governance::signatures::{SignatureError, SignatureVerifier};
+
+fn ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[test]
+fn principal_ids_must_be_printable_and_unpadded() {
+    for id in ["alice", "alice@example.com", "Release Bot", "ci:main"] {
+        assert!(validate_principal_id("operator id", id).is_ok(), "{:?}", id);
+    }
+    assert!(matches!(validate_principal_id("operator id", ""), Err(IdError::Empty { .. })));
+    assert!(matches!(validate_principal_id("operator id", " \t"), Err(IdError::Empty { .. })));
+    assert!(matches!(
+        validate_principal_id("operator id", "alice "),
+        Err(IdError::SurroundingWhitespace { .. })
+    ));
+    assert!(matches!(
+        validate_principal_id("operator id", "ali\u{0}ce"),
+        Err(IdError::InvalidChar { ch: '\u{0}', .. })
+    ));
+    assert!(matches!(
+        validate_principal_id("operator id", &"a".repeat(129)),
+        Err(IdError::TooLong { .. })
+    ));
+}
+
+#[test]
+fn allowlists_cannot_hold_degenerate_entries() {
+    assert!(KillSwitch::try_new(ids(&["alice", "bob"])).is_ok());
+    assert!(matches!(
+        KillSwitch::try_new(ids(&["alice", ""])),
+        Err(KillSwitchError::InvalidId(IdError::Empty { .. }))
+    ));
+    assert!(SignatureVerifier::try_new(ids(&["release"])).is_ok());
+    assert!(matches!(
+        SignatureVerifier::try_new(ids(&["release\n"])),
+        Err(SignatureError::InvalidId(_))
+    ));
+}
+
+#[test]
+fn config_rejects_padded_ids() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("governance.toml");
+    std::fs::write(&path, "authorized_operators = [\"alice\", \" bob\"]\n").unwrap();
+    let err = GovernanceConfig::load(&path).unwrap_err();
+    assert!(err.to_string().contains("whitespace"), "{}", err);
+}
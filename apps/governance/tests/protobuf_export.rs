@@ -0,0 +1,62 @@
+//! Audit logs round-trip through length-delimited protobuf.
+#![cfg(feature = "protobuf")]
+
+use openlora_core::AdapterId;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::protobuf::{self, ProtobufError, ProtoEntry};
+use prost::Message;
+use serde_json::json;
+use std::io::Cursor;
+
+#[test]
+fn export_and_import_round_trip_every_field() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let adapter = AuditTarget::Adapter(AdapterId::new("fraud-v2").unwrap());
+    log.append(AuditEventType::AdapterQuarantined, "ops", Some(&adapter), json!({ "reason": "drift", "score": 0.125 }))
+        .unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "policy", None, json!({ "nested": { "list": [1, null, "x"] } }))
+        .unwrap();
+    log.append(AuditEventType::Unknown("FromTheFuture".to_string()), "ops", None, json!({})).unwrap();
+
+    let mut stream = Vec::new();
+    assert_eq!(protobuf::export(&log, &mut stream).unwrap(), 3);
+
+    let imported_path = dir.path().join("imported.jsonl");
+    assert_eq!(protobuf::import(&mut Cursor::new(&stream), &imported_path).unwrap(), 3);
+    let imported = AuditLog::open(imported_path.clone()).unwrap();
+    assert!(imported.verify_integrity().unwrap());
+    assert_eq!(imported.head_hash(), log.head_hash());
+
+    let original = serde_json::to_value(log.entries().unwrap()).unwrap();
+    assert_eq!(serde_json::to_value(imported.entries().unwrap()).unwrap(), original);
+    assert!(protobuf::import(&mut Cursor::new(&stream), &imported_path).is_err(), "never overwrites");
+}
+
+#[test]
+fn truncated_or_oversized_streams_are_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({})).unwrap();
+    let mut stream = Vec::new();
+    protobuf::export(&log, &mut stream).unwrap();
+
+    let mut truncated = Cursor::new(&stream[..stream.len() - 3]);
+    assert!(matches!(protobuf::read_entry(&mut truncated), Err(ProtobufError::Io(_))));
+
+    let mut huge = Vec::new();
+    prost::encoding::encode_varint(1 << 40, &mut huge);
+    assert!(matches!(protobuf::read_entry(&mut Cursor::new(huge)), Err(ProtobufError::TooLarge(_))));
+
+    let bad_time = ProtoEntry {
+        id: "e1".to_string(),
+        timestamp: "yesterday".to_string(),
+        details_json: "{}".to_string(),
+        ..ProtoEntry::default()
+    };
+    let mut cursor = Cursor::new(bad_time.encode_length_delimited_to_vec());
+    assert!(matches!(
+        protobuf::read_entry(&mut cursor),
+        Err(ProtobufError::InvalidField { field: "timestamp", .. })
+    ));
+}
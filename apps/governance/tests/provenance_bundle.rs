@@ -0,0 +1,103 @@
+//! Signed provenance bundles for moving lineage between organizations.
+
+mod common;
+
+use common::{key, signed_chain, verifier};
+use openlora_core::ProvenanceOperation;
+use openlora_governance::lineage::{BundleError, ProvenanceBundle, ProvenanceChain};
+use openlora_governance::signatures::SignatureError;
+
+/// A chain created by `trainer` (seed 1) and transferred by `reviewer` (seed 2).
+fn chain() -> ProvenanceChain {
+    ProvenanceChain {
+        entries: signed_chain(&[
+            (ProvenanceOperation::Created, "trainer", 1),
+            (ProvenanceOperation::Transferred, "reviewer", 2),
+        ]),
+    }
+}
+
+#[test]
+fn bundles_round_trip_through_a_file_and_verify() {
+    let dir = tempfile::tempdir().unwrap();
+    let verifier = verifier(&[("trainer", 1), ("reviewer", 2), ("exporter", 9)]);
+    let bundle = chain().export_signed("exporter", &key(9));
+
+    let path = dir.path().join("adapter-1.prov");
+    bundle.save(&path).unwrap();
+    let loaded = ProvenanceBundle::load(&path).unwrap();
+
+    let imported = ProvenanceChain::import_verified(&loaded, &verifier).unwrap();
+    assert_eq!(imported.entries.len(), 2);
+    assert_eq!(imported.entries[1].hash, chain().entries[1].hash);
+}
+
+#[test]
+fn the_exporter_must_be_trusted_with_the_key_it_claims() {
+    let verifier = verifier(&[("trainer", 1), ("reviewer", 2), ("exporter", 9)]);
+
+    let unknown = chain().export_signed("stranger", &key(7));
+    assert!(matches!(
+        ProvenanceChain::import_verified(&unknown, &verifier),
+        Err(BundleError::UntrustedExporter(ref id)) if id == "stranger"
+    ));
+
+    // Impersonating a trusted exporter with another key is caught before
+    // the signature is even checked.
+    let impostor = chain().export_signed("exporter", &key(7));
+    assert!(matches!(
+        ProvenanceChain::import_verified(&impostor, &verifier),
+        Err(BundleError::ExporterKeyMismatch { .. })
+    ));
+
+    // Copying the genuine identity into the bundle does not help.
+    let mut forged = impostor.clone();
+    forged.exporter = chain().export_signed("exporter", &key(9)).exporter;
+    assert!(matches!(
+        ProvenanceChain::import_verified(&forged, &verifier),
+        Err(BundleError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn tampered_or_untrusted_chains_are_rejected() {
+    let verifier = verifier(&[("trainer", 1), ("reviewer", 2), ("exporter", 9)]);
+
+    let mut tampered = chain().export_signed("exporter", &key(9));
+    tampered.chain.entries.pop();
+    assert!(matches!(
+        ProvenanceChain::import_verified(&tampered, &verifier),
+        Err(BundleError::InvalidSignature)
+    ));
+
+    // The exporter is trusted here, but one of the chain's signers is not.
+    let partial = common::verifier(&[("trainer", 1), ("exporter", 9)]);
+    let bundle = chain().export_signed("exporter", &key(9));
+    match ProvenanceChain::import_verified(&bundle, &partial) {
+        Err(BundleError::Signature(SignatureError::EntrySignature { index, signer, .. })) => {
+            assert_eq!((index, signer.as_str()), (1, "reviewer"));
+        }
+        other => panic!("expected EntrySignature, got {:?}", other),
+    }
+
+    let mut future = bundle.clone();
+    future.format = 2;
+    assert!(matches!(
+        ProvenanceChain::import_verified(&future, &verifier),
+        Err(BundleError::UnsupportedFormat(2))
+    ));
+}
+
+#[test]
+fn a_forged_sha256_bundle_signature_is_rejected() {
+    let mut verifier = verifier(&[("trainer", 1), ("reviewer", 2), ("exporter", 9)]);
+    verifier.set_allow_legacy_sha256(true);
+
+    // A sha256 "signature" needs only the bundle, not the exporter's key.
+    let mut forged = chain().export_signed("exporter", &key(9));
+    forged.signature = verifier.sign(&forged.canonical_bytes(), "exporter");
+    assert!(matches!(
+        ProvenanceChain::import_verified(&forged, &verifier),
+        Err(BundleError::Signature(SignatureError::UnsupportedAlgorithm { ref algorithm, .. })) if algorithm == "sha256"
+    ));
+}
@@ -0,0 +1,87 @@
+//! Comparing a claimed provenance chain against a known-good one.
+
+use chrono::{Duration, TimeZone, Utc};
+use openlora_core::ProvenanceOperation;
+use openlora_governance::lineage::{EntryField, FieldChange, ProvenanceChain};
+use openlora_governance::signatures::ProvenanceEntry;
+
+fn chain(steps: &[(ProvenanceOperation, &str)]) -> ProvenanceChain {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut entries: Vec<ProvenanceEntry> = Vec::new();
+    for (i, (operation, actor)) in steps.iter().enumerate() {
+        let mut entry = ProvenanceEntry {
+            adapter_id: "adapter-1".to_string(),
+            version: i as u32 + 1,
+            operation: *operation,
+            actor: actor.to_string(),
+            timestamp: start + Duration::hours(i as i64),
+            signature: None,
+            parent_hash: entries.last().map(|e| e.hash.clone()),
+            content_hash: None,
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        entries.push(entry);
+    }
+    ProvenanceChain { entries }
+}
+
+#[test]
+fn identical_chains_do_not_diverge() {
+    let known = chain(&[(ProvenanceOperation::Created, "alice"), (ProvenanceOperation::Trained, "bob")]);
+    let diff = known.diff(&known.clone());
+    assert!(diff.is_identical());
+    assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty());
+}
+
+#[test]
+fn a_rewritten_entry_reports_its_changed_fields() {
+    let known = chain(&[
+        (ProvenanceOperation::Created, "alice"),
+        (ProvenanceOperation::Trained, "bob"),
+        (ProvenanceOperation::Transferred, "carol"),
+    ]);
+    let mut claimed = known.clone();
+    claimed.entries[1].actor = "mallory".to_string();
+    claimed.entries[1].operation = ProvenanceOperation::Merged;
+
+    let diff = known.diff(&claimed);
+    assert_eq!(diff.first_divergence, Some(2));
+    assert_eq!(diff.changed.len(), 1);
+    let change = &diff.changed[0];
+    assert_eq!(change.version, 2);
+    assert_eq!(
+        change.field(EntryField::Actor),
+        Some(&FieldChange {
+            field: EntryField::Actor,
+            expected: Some("bob".to_string()),
+            actual: Some("mallory".to_string()),
+        })
+    );
+    assert!(change.field(EntryField::Operation).is_some());
+    assert!(change.field(EntryField::Timestamp).is_none());
+    // The stored hash was left alone, so it no longer matches: not reported.
+    assert!(change.field(EntryField::Hash).is_none());
+}
+
+#[test]
+fn missing_and_extra_versions_are_listed() {
+    let known = chain(&[
+        (ProvenanceOperation::Created, "alice"),
+        (ProvenanceOperation::Trained, "bob"),
+        (ProvenanceOperation::Transferred, "carol"),
+    ]);
+    let mut claimed = known.clone();
+    let dropped = claimed.entries.remove(2);
+    let mut extra = claimed.entries[1].clone();
+    extra.actor = "mallory".to_string();
+    extra.hash = extra.compute_hash();
+    claimed.entries.push(extra);
+
+    let diff = known.diff(&claimed);
+    assert_eq!(diff.first_divergence, Some(2));
+    assert_eq!(diff.removed.iter().map(|e| e.version).collect::<Vec<_>>(), vec![dropped.version]);
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].actor, "mallory");
+    assert!(diff.changed.is_empty());
+}
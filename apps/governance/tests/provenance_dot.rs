@@ -0,0 +1,58 @@
+//! DOT export of a provenance chain, including dangling parents.
+
+use chrono::{Duration, TimeZone, Utc};
+use openlora_core::ProvenanceOperation;
+use openlora_governance::lineage::ProvenanceChain;
+use openlora_governance::signatures::ProvenanceEntry;
+
+fn entry(version: u32, operation: ProvenanceOperation, actor: &str, parent: Option<&str>, hash: &str) -> ProvenanceEntry {
+    ProvenanceEntry {
+        adapter_id: "adapter-1".to_string(),
+        version,
+        operation,
+        actor: actor.to_string(),
+        timestamp: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::hours(version as i64),
+        signature: None,
+        parent_hash: parent.map(String::from),
+        content_hash: None,
+        hash: hash.to_string(),
+    }
+}
+
+#[test]
+fn the_graph_matches_the_golden_output() {
+    let chain = ProvenanceChain {
+        entries: vec![
+            entry(1, ProvenanceOperation::Created, "alice", None, "h1"),
+            entry(2, ProvenanceOperation::Trained, "bob \"the trainer\"", Some("h1"), "h2"),
+            // Both derive from v3, which is absent; its placeholder is drawn once.
+            entry(4, ProvenanceOperation::Trained, "alice", Some("h3"), "h4"),
+            entry(5, ProvenanceOperation::Trained, "alice", Some("h3"), "h5"),
+        ],
+    };
+
+    let expected = r#"digraph provenance {
+    rankdir=TB;
+    node [shape=box, fontname="monospace"];
+    label="adapter-1";
+    n0 [label="v1 Created\nalice\n2024-01-01T01:00:00+00:00", tooltip="h1"];
+    n1 [label="v2 Trained\nbob \"the trainer\"\n2024-01-01T02:00:00+00:00", tooltip="h2"];
+    n2 [label="v4 Trained\nalice\n2024-01-01T04:00:00+00:00", tooltip="h4"];
+    n3 [label="v5 Trained\nalice\n2024-01-01T05:00:00+00:00", tooltip="h5"];
+    n0 -> n1;
+    "missing:h3" [label="missing\nh3", style=dashed];
+    "missing:h3" -> n2 [style=dashed];
+    "missing:h3" -> n3 [style=dashed];
+}
+"#;
+    assert_eq!(chain.to_dot(), expected);
+}
+
+#[test]
+fn an_empty_chain_is_an_empty_graph() {
+    let chain = ProvenanceChain { entries: Vec::new() };
+    assert_eq!(
+        chain.to_dot(),
+        "digraph provenance {\n    rankdir=TB;\n    node [shape=box, fontname=\"monospace\"];\n}\n"
+    );
+}
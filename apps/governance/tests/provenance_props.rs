@@ -0,0 +1,230 @@
+//! Property tests for provenance chain verification.
+
+use chrono::{Duration, TimeZone, Utc};
+use openlora_core::ProvenanceOperation;
+use openlora_governance::signatures::{ProvenanceEntry, SignatureError, SignatureVerifier};
+use proptest::prelude::*;
+
+#[derive(Debug, Clone)]
+struct Step {
+    operation: ProvenanceOperation,
+    actor: String,
+    version_gap: u32,
+    seconds_gap: i64,
+    content_hash: Option<String>,
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    (
+        prop_oneof![
+            Just(ProvenanceOperation::Created),
+            Just(ProvenanceOperation::Trained),
+            Just(ProvenanceOperation::Merged),
+            Just(ProvenanceOperation::Cloned),
+            Just(ProvenanceOperation::Transferred),
+        ],
+        "[a-z]{1,8}",
+        1u32..4,
+        0i64..3600,
+        proptest::option::of("[0-9a-f]{16}"),
+    )
+        .prop_map(|(operation, actor, version_gap, seconds_gap, content_hash)| Step {
+            operation,
+            actor,
+            version_gap,
+            seconds_gap,
+            content_hash,
+        })
+}
+
+/// Build a chain that is valid by construction.
+fn build_chain(adapter_id: &str, steps: &[Step]) -> Vec<ProvenanceEntry> {
+    let mut chain: Vec<ProvenanceEntry> = Vec::with_capacity(steps.len());
+    let mut version = 0;
+    let mut timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+    for step in steps {
+        version += step.version_gap;
+        timestamp += Duration::seconds(step.seconds_gap);
+        let mut entry = ProvenanceEntry {
+            adapter_id: adapter_id.to_string(),
+            version,
+            operation: step.operation,
+            actor: step.actor.clone(),
+            timestamp,
+            signature: None,
+            parent_hash: chain.last().map(|e| e.hash.clone()),
+            content_hash: step.content_hash.clone(),
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        chain.push(entry);
+    }
+    chain
+}
+
+fn chain(min: usize) -> impl Strategy<Value = Vec<ProvenanceEntry>> {
+    ("[a-z]{1,8}", prop::collection::vec(step(), min..8))
+        .prop_map(|(adapter_id, steps)| build_chain(&adapter_id, &steps))
+}
+
+fn verifier() -> SignatureVerifier {
+    SignatureVerifier::new(Vec::new())
+}
+
+fn assert_broken(result: Result<bool, SignatureError>) -> Result<(), TestCaseError> {
+    prop_assert!(
+        matches!(result, Err(SignatureError::BrokenChain(_))),
+        "expected BrokenChain, got {:?}",
+        result
+    );
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn valid_chains_verify(chain in chain(1)) {
+        prop_assert!(verifier().verify_provenance(&chain).unwrap());
+    }
+
+    #[test]
+    fn swapped_entries_are_rejected(chain in chain(2), a in any::<prop::sample::Index>(), b in any::<prop::sample::Index>()) {
+        let (a, b) = (a.index(chain.len()), b.index(chain.len()));
+        prop_assume!(a != b);
+        let mut chain = chain;
+        chain.swap(a, b);
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn reversed_chains_are_rejected(chain in chain(2)) {
+        let mut chain = chain;
+        chain.reverse();
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn dropped_entries_are_rejected(chain in chain(3), i in any::<prop::sample::Index>()) {
+        // Dropping the head leaves a chain whose first entry has a parent.
+        let i = i.index(chain.len() - 1);
+        let mut chain = chain;
+        chain.remove(i);
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn altered_parent_hash_is_rejected(chain in chain(1), i in any::<prop::sample::Index>(), parent in "[0-9a-f]{16}") {
+        let i = i.index(chain.len());
+        let mut chain = chain;
+        prop_assume!(chain[i].parent_hash.as_ref() != Some(&parent));
+        chain[i].parent_hash = Some(parent);
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn missing_parent_hash_is_rejected(chain in chain(2), i in any::<prop::sample::Index>()) {
+        let i = 1 + i.index(chain.len() - 1);
+        let mut chain = chain;
+        chain[i].parent_hash = None;
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn tampered_hash_is_rejected(chain in chain(1), i in any::<prop::sample::Index>(), hash in "[0-9a-f]{16}") {
+        let i = i.index(chain.len());
+        let mut chain = chain;
+        prop_assume!(chain[i].hash != hash);
+        chain[i].hash = hash;
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn tampered_fields_are_rejected(chain in chain(1), i in any::<prop::sample::Index>(), actor in "[A-Z]{1,8}") {
+        let i = i.index(chain.len());
+        let mut chain = chain;
+        chain[i].actor = actor;
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn content_hash_cannot_replace_parent(chain in chain(2), i in any::<prop::sample::Index>()) {
+        // Moving the parent hash into content_hash must change the entry hash.
+        let i = 1 + i.index(chain.len() - 1);
+        let mut chain = chain;
+        let entry = &mut chain[i];
+        prop_assume!(entry.content_hash.is_none());
+        entry.content_hash = entry.parent_hash.take();
+        entry.hash = entry.compute_hash();
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn spliced_adapters_are_rejected(chain in chain(2), i in any::<prop::sample::Index>()) {
+        // Rehash every entry after relabelling one so only the adapter differs.
+        let i = 1 + i.index(chain.len() - 1);
+        let mut chain = chain;
+        chain[i].adapter_id.push_str("-other");
+        for j in i..chain.len() {
+            chain[j].parent_hash = Some(chain[j - 1].hash.clone());
+            chain[j].hash = chain[j].compute_hash();
+        }
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+
+    #[test]
+    fn non_increasing_versions_are_rejected(chain in chain(2), i in any::<prop::sample::Index>()) {
+        let i = 1 + i.index(chain.len() - 1);
+        let mut chain = chain;
+        chain[i].version = chain[i - 1].version;
+        for j in i..chain.len() {
+            chain[j].parent_hash = Some(chain[j - 1].hash.clone());
+            chain[j].hash = chain[j].compute_hash();
+        }
+        assert_broken(verifier().verify_provenance(&chain))?;
+    }
+}
+
+#[test]
+fn unknown_operations_fail_to_load() {
+    let entry = serde_json::json!({
+        "adapter_id": "a",
+        "version": 1,
+        "operation": "Deleted",
+        "actor": "alice",
+        "timestamp": "2024-01-01T00:00:00Z",
+        "signature": null,
+        "parent_hash": null,
+        "hash": "0000000000000000",
+    });
+    let err = serde_json::from_value::<ProvenanceEntry>(entry).unwrap_err();
+    assert!(err.to_string().contains("unknown variant `Deleted`"), "{}", err);
+}
+
+proptest! {
+    #[test]
+    fn chains_over_the_length_limit_are_rejected(chain in chain(2)) {
+        let mut verifier = verifier();
+        verifier.set_chain_limits(Some(chain.len() - 1), None);
+        let result = verifier.verify_provenance(&chain);
+        prop_assert!(
+            matches!(result, Err(SignatureError::ChainTooLong { len, max }) if len == chain.len() && max == chain.len() - 1),
+            "expected ChainTooLong, got {:?}",
+            result
+        );
+
+        verifier.set_chain_limits(Some(chain.len()), None);
+        prop_assert!(verifier.verify_provenance(&chain).unwrap());
+    }
+
+    #[test]
+    fn chains_over_the_size_limit_are_rejected(chain in chain(1)) {
+        let mut verifier = verifier();
+        verifier.set_chain_limits(None, Some(chain[0].adapter_id.len()));
+        let result = verifier.verify_provenance(&chain);
+        prop_assert!(
+            matches!(result, Err(SignatureError::ChainTooLarge { .. })),
+            "expected ChainTooLarge, got {:?}",
+            result
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Provenance chains signed by several signers, with role requirements.
+
+mod common;
+
+use common::{signed_chain, verifier};
+use openlora_core::ProvenanceOperation;
+use openlora_governance::config::GovernanceConfig;
+use openlora_governance::signatures::SignatureError;
+
+#[test]
+fn each_entry_is_verified_against_its_own_signer() {
+    let verifier = verifier(&[("trainer", 1), ("reviewer", 2)]);
+    let handoff = signed_chain(&[
+        (ProvenanceOperation::Created, "trainer", 1),
+        (ProvenanceOperation::Trained, "trainer", 1),
+        (ProvenanceOperation::Transferred, "reviewer", 2),
+    ]);
+    assert!(verifier.verify_provenance(&handoff).unwrap());
+
+    // A signer outside the trusted set fails at its own entry.
+    let rogue = signed_chain(&[
+        (ProvenanceOperation::Created, "trainer", 1),
+        (ProvenanceOperation::Transferred, "mallory", 3),
+    ]);
+    match verifier.verify_provenance(&rogue) {
+        Err(SignatureError::EntrySignature { index, operation, signer, source }) => {
+            assert_eq!((index, operation, signer.as_str()), (1, ProvenanceOperation::Transferred, "mallory"));
+            assert!(matches!(*source, SignatureError::UnknownSigner(_)));
+        }
+        other => panic!("expected EntrySignature, got {:?}", other),
+    }
+
+    // A signature made with another signer's key names the claimed signer.
+    let forged = signed_chain(&[
+        (ProvenanceOperation::Created, "trainer", 1),
+        (ProvenanceOperation::Transferred, "reviewer", 1),
+    ]);
+    match verifier.verify_provenance(&forged) {
+        Err(SignatureError::EntrySignature { index, signer, source, .. }) => {
+            assert_eq!((index, signer.as_str()), (1, "reviewer"));
+            assert!(matches!(*source, SignatureError::InvalidSignature));
+        }
+        other => panic!("expected EntrySignature, got {:?}", other),
+    }
+}
+
+#[test]
+fn required_roles_are_enforced_per_operation() {
+    let mut verifier = verifier(&[("trainer", 1), ("reviewer", 2)]);
+    verifier.set_signer_roles("trainer", ["trainer".to_string()]);
+    verifier.set_signer_roles("reviewer", ["reviewer".to_string()]);
+    verifier.require_role(ProvenanceOperation::Transferred, "reviewer");
+
+    let handoff = signed_chain(&[
+        (ProvenanceOperation::Created, "trainer", 1),
+        (ProvenanceOperation::Transferred, "reviewer", 2),
+    ]);
+    assert!(verifier.verify_provenance(&handoff).unwrap());
+
+    let self_transfer = signed_chain(&[
+        (ProvenanceOperation::Created, "trainer", 1),
+        (ProvenanceOperation::Transferred, "trainer", 1),
+    ]);
+    match verifier.verify_provenance(&self_transfer) {
+        Err(SignatureError::MissingRole { index, signer, role, .. }) => {
+            assert_eq!((index, signer.as_deref(), role.as_str()), (1, Some("trainer"), "reviewer"));
+        }
+        other => panic!("expected MissingRole, got {:?}", other),
+    }
+
+    let mut unsigned = handoff.clone();
+    unsigned[1].signature = None;
+    assert!(matches!(
+        verifier.verify_provenance(&unsigned),
+        Err(SignatureError::MissingRole { index: 1, signer: None, .. })
+    ));
+}
+
+#[test]
+fn roles_load_from_config() {
+    let config: GovernanceConfig = toml::from_str(
+        r#"
+        [[trusted_signers]]
+        id = "reviewer"
+        roles = ["reviewer"]
+
+        [provenance_roles]
+        Transferred = "reviewer"
+        "#,
+    )
+    .unwrap();
+    config.validate().unwrap();
+    let verifier = config.signature_verifier().unwrap();
+    assert!(verifier.has_role("reviewer", "reviewer"));
+
+    let unheld: GovernanceConfig = toml::from_str(
+        r#"
+        [[trusted_signers]]
+        id = "trainer"
+
+        [provenance_roles]
+        Transferred = "reviewer"
+        "#,
+    )
+    .unwrap();
+    assert!(unheld.validate().is_err());
+}
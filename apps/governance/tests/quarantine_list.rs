@@ -0,0 +1,66 @@
+//! The persisted quarantine list and the audit entries behind it.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::quarantine::{QuarantineError, QuarantineList};
+
+fn setup(dir: &tempfile::TempDir) -> (AuditLog, QuarantineList) {
+    (
+        AuditLog::open(dir.path().join("audit.jsonl")).unwrap(),
+        QuarantineList::open(dir.path().join("quarantine.json")).unwrap(),
+    )
+}
+
+#[test]
+fn each_record_matches_its_audit_entry_and_survives_a_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut audit, mut list) = setup(&dir);
+    let record = list.quarantine(&mut audit, "adapter-b", "reward hacking", "alice").unwrap();
+    list.quarantine(&mut audit, "adapter-a", "drift", "bob").unwrap();
+
+    let entries = audit.entries().unwrap();
+    assert_eq!(record.audit_entry_id, entries[0].id);
+    assert_eq!(record.quarantined_at, entries[0].timestamp);
+    assert!(matches!(entries[0].event_type, AuditEventType::AdapterQuarantined));
+    assert_eq!(entries[0].target_id.as_deref(), Some("adapter-b"));
+    assert_eq!(entries[0].details["reason"], "reward hacking");
+
+    let reopened = QuarantineList::open(list.path().to_path_buf()).unwrap();
+    let ids: Vec<&String> = reopened.iter().map(|(id, _)| id).collect();
+    assert_eq!(ids, ["adapter-a", "adapter-b"]);
+    let reloaded = reopened.get("adapter-b").unwrap();
+    assert_eq!((reloaded.reason.as_str(), reloaded.quarantined_by.as_str()), ("reward hacking", "alice"));
+    assert_eq!(reloaded.audit_entry_id, record.audit_entry_id);
+}
+
+#[test]
+fn a_release_is_audited_against_the_quarantine_it_ends() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut audit, mut list) = setup(&dir);
+    let record = list.quarantine(&mut audit, "adapter-1", "drift", "alice").unwrap();
+
+    let released = list.release(&mut audit, "adapter-1", "bob").unwrap();
+    assert_eq!(released.audit_entry_id, record.audit_entry_id);
+    assert!(!list.is_quarantined("adapter-1"));
+    assert!(QuarantineList::open(list.path().to_path_buf()).unwrap().is_empty());
+
+    let entries = audit.entries().unwrap();
+    assert!(matches!(entries[1].event_type, AuditEventType::AdapterReleased));
+    assert_eq!(entries[1].actor, "bob");
+    assert_eq!(entries[1].details["quarantine_entry_id"], record.audit_entry_id.as_str());
+}
+
+#[test]
+fn refused_changes_write_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut audit, mut list) = setup(&dir);
+    list.quarantine(&mut audit, "adapter-1", "drift", "alice").unwrap();
+
+    assert!(matches!(
+        list.quarantine(&mut audit, "adapter-1", "again", "alice"),
+        Err(QuarantineError::AlreadyQuarantined(_))
+    ));
+    assert!(matches!(list.release(&mut audit, "adapter-2", "alice"), Err(QuarantineError::NotQuarantined(_))));
+    assert!(matches!(list.quarantine(&mut audit, "../etc", "drift", "alice"), Err(QuarantineError::InvalidId(_))));
+    assert_eq!(audit.entries().unwrap().len(), 1);
+    assert_eq!(list.len(), 1);
+}
@@ -0,0 +1,74 @@
+//! Re-chaining an edited audit log records the rewrite with both heads.
+
+use ed25519_dalek::SigningKey;
+use openlora_governance::attest::check_attestation;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::rechain::{rechain, RechainError};
+use openlora_governance::signatures::SignatureVerifier;
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn edited_log_is_rechained_signed_and_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("audit.jsonl");
+    let output = dir.path().join("audit.rechained.jsonl");
+    let key = SigningKey::from_bytes(&[3; 32]);
+
+    let mut log = AuditLog::open(input.clone()).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({ "token": "hunter2" }))
+        .unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({})).unwrap();
+    let old_head = log.head_hash().to_string();
+
+    // The approved edit: scrub a secret, which breaks the chain.
+    let edited = fs::read_to_string(&input).unwrap().replace("hunter2", "[redacted]");
+    fs::write(&input, edited).unwrap();
+    assert!(AuditLog::open(input.clone()).unwrap().verify_integrity().is_err());
+
+    let mut meta = AuditLog::open(dir.path().join("meta.jsonl")).unwrap();
+    let report = rechain(&input, &output, "alice", &key, "scrub leaked token", &mut meta).unwrap();
+    assert_eq!(report.entries, 2);
+    assert_eq!(report.old_head, old_head);
+    assert_ne!(report.new_head, old_head);
+
+    let rechained = AuditLog::open(output.clone()).unwrap();
+    assert!(rechained.verify_integrity().unwrap());
+    assert_eq!(rechained.head_hash(), report.new_head);
+    assert!(!fs::read_to_string(&output).unwrap().contains("hunter2"));
+
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    check_attestation(&rechained, &report.attestation, &verifier).unwrap();
+
+    let records = meta
+        .entries_for(&AuditTarget::AuditLog(output.display().to_string()))
+        .unwrap();
+    assert_eq!(records.len(), 1);
+    assert!(matches!(records[0].event_type, AuditEventType::AuditLogRechained));
+    assert_eq!(records[0].actor, "alice");
+    assert_eq!(records[0].details["old_head"], json!(old_head));
+    assert_eq!(records[0].details["new_head"], json!(report.new_head));
+    assert_eq!(records[0].details["reason"], json!("scrub leaked token"));
+}
+
+#[test]
+fn rechain_requires_a_reason_and_a_fresh_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("audit.jsonl");
+    let output = dir.path().join("out.jsonl");
+    let key = SigningKey::from_bytes(&[3; 32]);
+    let mut log = AuditLog::open(input.clone()).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({})).unwrap();
+    let mut meta = AuditLog::open(dir.path().join("meta.jsonl")).unwrap();
+
+    assert!(matches!(
+        rechain(&input, &output, "alice", &key, " ", &mut meta),
+        Err(RechainError::MissingReason)
+    ));
+    assert!(!output.exists());
+
+    fs::write(&output, "").unwrap();
+    assert!(rechain(&input, &output, "alice", &key, "fix", &mut meta).is_err());
+    assert!(meta.entries().unwrap().is_empty());
+}
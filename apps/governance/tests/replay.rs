@@ -0,0 +1,80 @@
+//! Replaying the audit log reproduces live governance state.
+
+use openlora_core::AdapterId;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+use openlora_governance::quarantine::QuarantineList;
+use openlora_governance::replay::{replay, Discrepancy};
+use serde_json::json;
+
+fn adapter(id: &str) -> AuditTarget {
+    AuditTarget::Adapter(AdapterId::new(id).unwrap())
+}
+
+#[test]
+fn replayed_state_matches_live_state() {
+    let dir = tempfile::tempdir().unwrap();
+    let audit_path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(audit_path.clone()).unwrap();
+    let mut list = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+
+    for id in ["a", "b", "c"] {
+        log.append(AuditEventType::AdapterActivated, "ops", Some(&adapter(id)), json!({}))
+            .unwrap();
+    }
+    list.quarantine(&mut log, "a", "bad eval", "ops").unwrap();
+    list.quarantine(&mut log, "b", "drift", "ops").unwrap();
+    list.release(&mut log, "b", "ops").unwrap();
+    log.append(AuditEventType::AdapterDestroyed, "ops", Some(&adapter("c")), json!({}))
+        .unwrap();
+    log.append(AuditEventType::Unknown("future_event".into()), "ops", None, json!({}))
+        .unwrap();
+
+    let mut gov = Governance::new(KillSwitch::new(vec!["alice".into()]), log);
+    gov.kill("alice", KillReason::ManualTrigger { operator: "alice".into(), reason: None }, Vec::new())
+        .unwrap();
+
+    let state = replay(gov.audit_log().unwrap()).unwrap();
+    assert!(state.killed);
+    assert_eq!(state.kill_activations, 1);
+    assert_eq!(state.quarantined.get("a").map(String::as_str), Some("bad eval"));
+    assert!(!state.quarantined.contains_key("b"));
+    assert!(state.destroyed.contains("c"));
+    // Release lifts quarantine but does not reactivate.
+    assert!(state.active.is_empty());
+    assert_eq!(state.unknown_events, 1);
+
+    assert!(state.compare(&gov.kill_switch().state(), &list).is_empty());
+
+    gov.reset(&["alice".to_string()]).unwrap();
+    let state = replay(gov.audit_log().unwrap()).unwrap();
+    assert!(!state.killed);
+    assert!(state.compare(&gov.kill_switch().state(), &list).is_empty());
+}
+
+#[test]
+fn compare_reports_drift_from_the_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut list = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+    list.quarantine(&mut log, "a", "bad eval", "ops").unwrap();
+
+    // The list and kill state change without going through the log.
+    let other = dir.path().join("other.jsonl");
+    let mut stray = AuditLog::open(other).unwrap();
+    list.quarantine(&mut stray, "x", "manual", "ops").unwrap();
+    let mut ks = KillSwitch::new(vec!["alice".into()]);
+    ks.activate("alice", KillReason::ManualTrigger { operator: "alice".into(), reason: None }, Vec::new())
+        .unwrap();
+
+    let state = replay(&log).unwrap();
+    let found = state.compare(&ks.state(), &list);
+    assert_eq!(
+        found,
+        vec![
+            Discrepancy::KillState { replayed: false, live: true },
+            Discrepancy::QuarantinedOnlyInList("x".into()),
+        ]
+    );
+}
@@ -0,0 +1,74 @@
+//! Reset approvers must be checkable: configured operators or sessions.
+
+use chrono::Duration;
+use clap::Parser;
+use openlora_governance::cli::Cli;
+use openlora_governance::config::{ConfigError, GovernanceConfig};
+use openlora_governance::killswitch::{EscalationPolicy, KillReason, KillSwitch, KillSwitchError};
+
+fn reason() -> KillReason {
+    KillReason::ManualTrigger {
+        operator: "drill".into(),
+        reason: None,
+    }
+}
+
+/// A switch escalated by two activations within the hour.
+fn escalated(operators: Vec<String>) -> KillSwitch {
+    let mut ks = KillSwitch::new(operators);
+    ks.set_escalation_policy(Some(EscalationPolicy {
+        max_activations: 1,
+        window: Duration::hours(1),
+        reset_quorum: 2,
+    }));
+    ks.activate("alice", reason(), Vec::new()).unwrap();
+    ks.reset("alice").unwrap();
+    ks.activate("alice", reason(), Vec::new()).unwrap();
+    assert!(ks.is_escalated());
+    ks
+}
+
+#[test]
+fn approvers_are_refused_without_configured_operators() {
+    let config = GovernanceConfig::default();
+    assert!(matches!(
+        config.reset_operators("alice".into(), vec!["bob".into()]),
+        Err(ConfigError::Invalid(_))
+    ));
+    assert_eq!(config.reset_operators("alice".into(), Vec::new()).unwrap(), ["alice"]);
+}
+
+#[test]
+fn configured_operators_check_every_approver() {
+    let config: GovernanceConfig = toml::from_str(r#"authorized_operators = ["alice", "bob"]"#).unwrap();
+    let operators = config.reset_operators("alice".into(), vec!["bob".into()]).unwrap();
+    assert_eq!(operators, ["alice", "bob"]);
+    let mut ks = escalated(config.authorized_operators.clone());
+    ks.reset_with_quorum(&operators).unwrap();
+
+    let mut ks = escalated(config.authorized_operators.clone());
+    let operators = config.reset_operators("alice".into(), vec!["mallory".into()]).unwrap();
+    assert!(matches!(
+        ks.reset_with_quorum(&operators),
+        Err(KillSwitchError::Unauthorized(ref op)) if op == "mallory"
+    ));
+    assert!(ks.is_active());
+}
+
+#[test]
+fn each_approver_can_present_a_session_instead() {
+    let mut ks = escalated(vec!["alice".into(), "bob".into()]);
+    let alice = ks.issue_session("alice", Duration::minutes(5)).unwrap();
+    let bob = ks.issue_session("bob", Duration::minutes(5)).unwrap();
+
+    assert!(matches!(
+        ks.reset_with_sessions(std::slice::from_ref(&alice.token)),
+        Err(KillSwitchError::Escalated { .. })
+    ));
+    ks.reset_with_sessions(&[alice.token, bob.token]).unwrap();
+    assert!(!ks.is_active());
+
+    // Named approvers and sessions do not mix on the command line.
+    let mixed = Cli::try_parse_from(["openlora-gov", "reset", "--session", "t", "--approver", "bob"]);
+    assert!(mixed.is_err());
+}
@@ -0,0 +1,61 @@
+//! A reset reports when the switch was activated and how long it was down.
+
+use chrono::{Duration, TimeZone, Utc};
+use openlora_governance::clock::{Clock, ManualClock};
+use openlora_governance::killswitch::{KillReason, KillState, KillSwitch, KillSwitchError};
+
+fn reason() -> KillReason {
+    KillReason::ManualTrigger { operator: "ops".to_string(), reason: None }
+}
+
+fn clock() -> ManualClock {
+    ManualClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+}
+
+#[test]
+fn downtime_runs_from_the_most_recent_activation() {
+    let clock = clock();
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]).with_clock(clock.clone());
+
+    let first = ks.activate("ops", reason(), Vec::new()).unwrap();
+    clock.advance(Duration::minutes(10));
+    let outcome = ks.reset("ops").unwrap();
+    assert_eq!(outcome.activated_at, Some(first.timestamp));
+    assert_eq!(outcome.downtime, Some(Duration::minutes(10)));
+
+    clock.advance(Duration::hours(1));
+    let second = ks.activate("ops", reason(), Vec::new()).unwrap();
+    clock.advance(Duration::minutes(30));
+    let outcome = ks.reset("ops").unwrap();
+    assert_eq!(outcome.activated_at, Some(second.timestamp));
+    assert_eq!(outcome.downtime, Some(Duration::minutes(30)));
+    assert_eq!(outcome.reset_at, clock.now());
+
+    assert!(matches!(ks.reset("ops"), Err(KillSwitchError::NotActive)));
+}
+
+#[test]
+fn persisted_state_keeps_the_activation_time() {
+    let clock = clock();
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]).with_clock(clock.clone());
+    let event = ks.activate("ops", reason(), Vec::new()).unwrap();
+    let state = ks.state();
+    drop(ks);
+
+    clock.advance(Duration::hours(2));
+    let mut restarted = KillSwitch::from_state(vec!["ops".to_string()], state).with_clock(clock.clone());
+    let outcome = restarted.reset("ops").unwrap();
+    assert_eq!(outcome.activated_at, Some(event.timestamp));
+    assert_eq!(outcome.downtime, Some(Duration::hours(2)));
+}
+
+#[test]
+fn an_unknown_activation_time_is_not_guessed() {
+    // Active, but the event that activated it was never persisted.
+    let state: KillState = serde_json::from_str(r#"{ "active": true, "events": [] }"#).unwrap();
+    let mut ks = KillSwitch::from_state(vec!["ops".to_string()], state).with_clock(clock());
+
+    let outcome = ks.reset("ops").unwrap();
+    assert_eq!(outcome.activated_at, None);
+    assert_eq!(outcome.downtime, None);
+}
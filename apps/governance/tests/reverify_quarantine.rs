@@ -0,0 +1,97 @@
+//! Quarantines over a signature are re-verified, and may self-heal.
+
+use chrono::{DateTime, TimeZone, Utc};
+use ed25519_dalek::SigningKey;
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::clock::ManualClock;
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::KillSwitch;
+use openlora_governance::quarantine::QuarantineList;
+use openlora_governance::replay::replay;
+use openlora_governance::reverify::{Reverification, Reverifier};
+use openlora_governance::signatures::{sign_ed25519, SignatureError, SignatureVerifier};
+use serde_json::json;
+use std::path::Path;
+
+const CONTENT: &[u8] = b"adapter weights";
+
+/// The kill-switch clock's time, at which every record is stamped.
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+}
+
+fn setup(dir: &Path, signing_key: SigningKey, auto_release: bool) -> (Governance, QuarantineList) {
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("release", SigningKey::from_bytes(&[7; 32]).verifying_key());
+    let signature = sign_ed25519(CONTENT, "release", &signing_key, None);
+    let reverifier =
+        Reverifier::new(verifier, move |_: &str| Ok((CONTENT.to_vec(), signature.clone()))).with_auto_release(auto_release);
+
+    let mut gov = Governance::new(
+        KillSwitch::new(vec!["ops".to_string()]).with_clock(ManualClock::new(start())),
+        AuditLog::open(dir.join("audit.jsonl")).unwrap(),
+    );
+    gov.set_reverifier(reverifier);
+    (gov, QuarantineList::open(dir.join("quarantine.json")).unwrap())
+}
+
+fn tags(gov: &Governance) -> Vec<String> {
+    let entries = gov.audit_log().unwrap().entries().unwrap();
+    entries.iter().map(|entry| entry.event_type.tag().to_string()).collect()
+}
+
+#[test]
+fn a_signature_that_now_verifies_is_released() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut gov, mut list) = setup(dir.path(), SigningKey::from_bytes(&[7; 32]), true);
+
+    let quarantined = gov.quarantine(&mut list, "fraud-v2", "Signature check timed out", "ops").unwrap();
+    let reverification = quarantined.reverification.unwrap();
+    assert!(reverification.audit.is_recorded());
+    assert!(reverification.outcome.is_released());
+    assert!(matches!(reverification.outcome, Reverification::Verified { ref signer_id, .. } if signer_id == "release"));
+
+    assert!(!list.is_quarantined("fraud-v2"));
+    assert_eq!(tags(&gov), vec!["AdapterQuarantined", "SignatureVerified", "AdapterReleased"]);
+    assert!(replay(gov.audit_log().unwrap()).unwrap().quarantined.is_empty());
+}
+
+#[test]
+fn without_auto_release_a_verified_adapter_stays_quarantined() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut gov, mut list) = setup(dir.path(), SigningKey::from_bytes(&[7; 32]), false);
+
+    let quarantined = gov.quarantine(&mut list, "fraud-v2", "bad signature", "ops").unwrap();
+    let reverification = quarantined.reverification.unwrap();
+    assert!(matches!(reverification.outcome, Reverification::Verified { released: None, .. }));
+    assert!(list.is_quarantined("fraud-v2"));
+    assert_eq!(tags(&gov), vec!["AdapterQuarantined", "SignatureVerified"]);
+}
+
+#[test]
+fn a_genuine_failure_stays_quarantined_and_is_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut gov, mut list) = setup(dir.path(), SigningKey::from_bytes(&[8; 32]), true);
+
+    let quarantined = gov.quarantine(&mut list, "fraud-v2", "bad signature", "ops").unwrap();
+    let reverification = quarantined.reverification.unwrap();
+    assert!(matches!(reverification.outcome, Reverification::Failed(SignatureError::InvalidSignature)));
+    assert!(list.is_quarantined("fraud-v2"));
+
+    let entries = gov.audit_log().unwrap().entries().unwrap();
+    assert!(matches!(entries[1].event_type, AuditEventType::SignatureFailed));
+    assert_eq!(entries[1].target_id.as_deref(), Some("fraud-v2"));
+    assert_eq!(entries[1].details["quarantine_entry_id"], quarantined.record.audit_entry_id);
+    assert_eq!(entries[1].details["occurred_at"], json!(start()));
+}
+
+#[test]
+fn other_quarantines_are_not_reverified() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut gov, mut list) = setup(dir.path(), SigningKey::from_bytes(&[7; 32]), true);
+
+    let quarantined = gov.quarantine(&mut list, "fraud-v2", "reward hacking", "ops").unwrap();
+    assert!(quarantined.reverification.is_none());
+    assert!(list.is_quarantined("fraud-v2"));
+    assert_eq!(tags(&gov), vec!["AdapterQuarantined"]);
+}
@@ -0,0 +1,138 @@
+//! Revoked signatures and content are rejected regardless of validity.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::revocation::{RevocationError, RevocationList, Revoked};
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, SignatureEncoding, SignatureError, SignatureVerifier};
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, RwLock};
+
+#[test]
+fn revocations_apply_at_runtime_and_are_audited() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let list_path = dir.path().join("revocations.json");
+    let list = Arc::new(RwLock::new(RevocationList::open(list_path.clone()).unwrap()));
+
+    let mut verifier = SignatureVerifier::new(vec!["alice".into()]);
+    verifier.set_allow_legacy_sha256(true);
+    verifier.set_revocation_list(Arc::clone(&list));
+    let signature = verifier.sign(b"weights", "alice");
+    assert!(verifier.verify(b"weights", &signature).unwrap());
+
+    // Revoking through the shared list affects the next verification.
+    list.write()
+        .unwrap()
+        .revoke(&mut audit, Revoked::Signature(signature.value.clone()), "backdoor", "ops")
+        .unwrap();
+    assert!(matches!(
+        verifier.verify(b"weights", &signature),
+        Err(SignatureError::Revoked(Revoked::Signature(_)))
+    ));
+
+    // Checked before the crypto: even a malformed signature is reported as revoked.
+    let mut garbled = signature.clone();
+    garbled.algorithm = "ed25519".into();
+    assert!(matches!(verifier.verify(b"weights", &garbled), Err(SignatureError::Revoked(_))));
+
+    // Content revocation catches a fresh signature over the same bytes.
+    let content_hash = format!("{:x}", Sha256::digest(b"weights"));
+    list.write()
+        .unwrap()
+        .revoke(&mut audit, Revoked::Content(content_hash.clone()), "poisoned", "ops")
+        .unwrap();
+    let resigned = verifier.sign(b"weights", "alice");
+    match verifier.verify(b"weights", &resigned) {
+        Err(SignatureError::Revoked(Revoked::Content(hash))) => assert_eq!(hash, content_hash),
+        other => panic!("expected revoked content, got {:?}", other),
+    }
+    assert!(verifier.verify(b"other weights", &verifier.sign(b"other weights", "alice")).unwrap());
+
+    // Each revocation is an audit entry, and the list persists.
+    let entries = audit.entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| matches!(e.event_type, AuditEventType::SignatureRevoked)));
+    let reopened = RevocationList::open(list_path).unwrap();
+    assert_eq!(reopened.len(), 2);
+    assert_eq!(
+        reopened.get(&Revoked::Content(content_hash.clone())).unwrap().audit_entry_id,
+        entries[1].id
+    );
+
+    let mut reopened = reopened;
+    assert!(matches!(
+        reopened.revoke(&mut audit, Revoked::Content(content_hash), "again", "ops"),
+        Err(RevocationError::AlreadyRevoked(_))
+    ));
+}
+
+#[test]
+fn revocations_match_however_the_value_is_written() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let list = Arc::new(RwLock::new(RevocationList::open(dir.path().join("revocations.json")).unwrap()));
+    let key = SigningKey::from_bytes(&[3; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    verifier.set_revocation_list(Arc::clone(&list));
+
+    // Revoked as upper-case hex; re-encoding as base64 does not evade it.
+    let signature = sign_ed25519(b"weights", "alice", &key, None);
+    list.write()
+        .unwrap()
+        .revoke(&mut audit, Revoked::Signature(signature.value.to_ascii_uppercase()), "backdoor", "ops")
+        .unwrap();
+    let base64 = signature.clone().encoded_as(SignatureEncoding::Base64).unwrap();
+    match verifier.verify(b"weights", &base64) {
+        Err(SignatureError::Revoked(Revoked::Signature(value))) => assert_eq!(value, signature.value),
+        other => panic!("expected revoked signature, got {:?}", other),
+    }
+    assert!(matches!(
+        list.write().unwrap().revoke(&mut audit, Revoked::signature(&base64).unwrap(), "again", "ops"),
+        Err(RevocationError::AlreadyRevoked(_))
+    ));
+
+    // Revoked from a base64 signature file; the hex form is caught.
+    let other = sign_ed25519(b"other weights", "alice", &key, None);
+    let revoked = Revoked::signature(&other.clone().encoded_as(SignatureEncoding::Base64).unwrap()).unwrap();
+    list.write().unwrap().revoke(&mut audit, revoked, "backdoor", "ops").unwrap();
+    assert!(matches!(verifier.verify(b"other weights", &other), Err(SignatureError::Revoked(_))));
+
+    // Content hashes are compared without regard to case.
+    let content_hash = format!("{:X}", Sha256::digest(b"more weights"));
+    list.write()
+        .unwrap()
+        .revoke(&mut audit, Revoked::Content(content_hash.clone()), "poisoned", "ops")
+        .unwrap();
+    let fresh = sign_ed25519(b"more weights", "alice", &key, None);
+    match verifier.verify(b"more weights", &fresh) {
+        Err(SignatureError::Revoked(Revoked::Content(hash))) => assert_eq!(hash, content_hash.to_ascii_lowercase()),
+        other => panic!("expected revoked content, got {:?}", other),
+    }
+    assert!(list.read().unwrap().get(&Revoked::Content(content_hash)).is_some());
+}
+
+#[test]
+fn older_lists_are_normalized_on_open() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("revocations.json");
+    let key = SigningKey::from_bytes(&[3; 32]);
+    let signature = sign_ed25519(b"weights", "alice", &key, None);
+    let base64 = signature.clone().encoded_as(SignatureEncoding::Base64).unwrap();
+    let record = serde_json::json!({
+        "reason": "backdoor",
+        "revoked_at": "2024-01-01T00:00:00Z",
+        "revoked_by": "ops",
+        "audit_entry_id": "e1",
+    });
+    let content_hash = format!("{:X}", Sha256::digest(b"weights"));
+    let stored = serde_json::json!({
+        "signatures": { base64.value: record },
+        "content_hashes": { content_hash: record },
+    });
+    std::fs::write(&path, stored.to_string()).unwrap();
+
+    let list = RevocationList::open(path).unwrap();
+    assert!(list.is_signature_revoked(&signature.decoded_value().unwrap()));
+    assert!(list.is_content_revoked(&format!("{:x}", Sha256::digest(b"weights"))));
+}
@@ -0,0 +1,79 @@
+//! Session-authorized kill-switch actions and their audit trail.
+
+use chrono::Duration;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{KillReason, KillState, KillSwitch, KillSwitchError};
+
+fn reason() -> KillReason {
+    KillReason::ManualTrigger {
+        operator: "drill".into(),
+        reason: None,
+    }
+}
+
+#[test]
+fn sessions_authorize_until_they_expire() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut gov = Governance::new(KillSwitch::new(vec!["alice".into(), "bob".into()]), log);
+
+    let session = gov.issue_session("alice", Duration::minutes(5)).unwrap().outcome;
+    let killed = gov.kill_with_session(&session.token, reason(), Vec::new(), None).unwrap();
+    assert_eq!(killed.outcome.triggered_by, "alice");
+    gov.reset_with_sessions(std::slice::from_ref(&session.token)).unwrap();
+
+    let expired = gov.issue_session("bob", Duration::zero()).unwrap().outcome;
+    match gov.kill_with_session(&expired.token, reason(), Vec::new(), None) {
+        Err(KillSwitchError::SessionExpired { session, operator, .. }) => {
+            assert_eq!(session, expired.id);
+            assert_eq!(operator, "bob");
+        }
+        other => panic!("expected SessionExpired, got {:?}", other),
+    }
+    assert!(!gov.kill_switch().is_active());
+
+    assert!(gov.end_session(&session.id, "alice").is_some());
+    assert!(matches!(
+        gov.kill_with_session(&session.token, reason(), Vec::new(), None),
+        Err(KillSwitchError::UnknownSession)
+    ));
+
+    let log = gov.audit_log().unwrap();
+    log.verify_integrity().unwrap();
+    let events: Vec<String> = log
+        .entries_for(&AuditTarget::Operator("bob".into()))
+        .unwrap()
+        .iter()
+        .map(|e| e.event_type.tag().to_string())
+        .collect();
+    assert_eq!(events, ["SessionIssued", "SessionExpired"]);
+    let entries = log.entries().unwrap();
+    assert!(entries
+        .iter()
+        .any(|e| matches!(e.event_type, AuditEventType::SessionEnded)));
+    // Tokens are secrets and never reach the log.
+    let raw = std::fs::read_to_string(dir.path().join("audit.jsonl")).unwrap();
+    assert!(!raw.contains(&session.token));
+}
+
+#[test]
+fn sessions_survive_persistence_without_their_tokens() {
+    let mut ks = KillSwitch::new(vec!["alice".into()]);
+    assert!(matches!(
+        ks.issue_session("mallory", Duration::minutes(5)),
+        Err(KillSwitchError::Unauthorized(_))
+    ));
+    let session = ks.issue_session("alice", Duration::minutes(5)).unwrap();
+
+    let saved = serde_json::to_string(&ks.state()).unwrap();
+    assert!(!saved.contains(&session.token));
+    drop(ks);
+
+    let state: KillState = serde_json::from_str(&saved).unwrap();
+    let mut restored = KillSwitch::from_state(vec!["alice".into()], state);
+    restored
+        .activate_with_session(&session.token, reason(), Vec::new())
+        .unwrap();
+    restored.reset_with_sessions(&[session.token]).unwrap();
+}
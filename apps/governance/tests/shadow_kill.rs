@@ -0,0 +1,78 @@
+//! Shadow mode records would-be kills without enforcing them.
+
+use chrono::Duration;
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::governance::{AuditStatus, Governance};
+use openlora_governance::killswitch::{Activation, KillReason, KillSwitch};
+use openlora_governance::replay::replay;
+
+fn manual() -> KillReason {
+    KillReason::ManualTrigger { operator: "alice".to_string(), reason: None }
+}
+
+fn operators() -> Vec<String> {
+    vec!["alice".to_string(), "bob".to_string()]
+}
+
+#[test]
+fn shadow_activations_are_recorded_but_not_enforced() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut ks = KillSwitch::new(operators());
+    ks.set_shadow_mode(true);
+    assert!(ks.activate("mallory", manual(), Vec::new()).is_err());
+
+    let event = ks.activate("alice", manual(), vec!["fraud-v2".to_string()]).unwrap();
+    assert!(event.shadow);
+    let cosigned = ks.activate_cosigned("alice", "bob", manual(), Vec::new()).unwrap();
+    assert!(cosigned.shadow);
+    assert!(!ks.is_active() && !ks.state().active);
+    assert!(ks.begin_operation().is_some());
+    assert_eq!(ks.get_events().len(), 2);
+    assert_eq!(ks.activation_rate(Duration::hours(1)), 0);
+    assert!(ks.unacknowledged().is_empty());
+
+    let mut gov = Governance::new(ks, AuditLog::open(dir.path().join("audit.jsonl")).unwrap());
+    gov.kill("alice", manual(), vec!["fraud-v2".to_string()]).unwrap();
+    let log = gov.audit_log().unwrap();
+    let entry = log.entries().unwrap().pop().unwrap();
+    assert!(matches!(entry.event_type, AuditEventType::KillSwitchActivated));
+    assert_eq!(entry.details["shadow"], true);
+    let state = replay(log).unwrap();
+    assert!(!state.killed);
+    assert_eq!(state.kill_activations, 0);
+}
+
+#[test]
+fn shadow_idempotency_keys_do_not_block_a_real_activation() {
+    let mut ks = KillSwitch::new(operators());
+    ks.set_shadow_mode(true);
+    let first = ks.activate_idempotent("alice", manual(), Vec::new(), Some("incident-7")).unwrap();
+    let retried = ks.activate_idempotent("alice", manual(), Vec::new(), Some("incident-7")).unwrap();
+    assert!(matches!(retried, Activation::Replayed(ref event) if event.id == first.event().id));
+
+    ks.set_shadow_mode(false);
+    let real = ks.activate_idempotent("alice", manual(), Vec::new(), Some("incident-7")).unwrap();
+    assert!(matches!(real, Activation::Activated(ref event) if !event.shadow));
+    assert!(ks.is_active());
+    assert_eq!(ks.activation_rate(Duration::hours(1)), 1);
+
+    // A shadow kill while active leaves the real activation current.
+    ks.set_shadow_mode(true);
+    ks.activate("alice", manual(), vec!["chat-v1".to_string()]).unwrap();
+    let reset = ks.reset("alice").unwrap();
+    assert_eq!(reset.activated_at, Some(real.event().timestamp));
+}
+
+#[test]
+fn acknowledgements_refer_to_the_enforced_activation() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut ks = KillSwitch::new(operators());
+    let real = ks.activate("alice", manual(), vec!["fraud-v2".to_string()]).unwrap();
+    ks.set_shadow_mode(true);
+    ks.activate("alice", manual(), vec!["fraud-v2".to_string()]).unwrap();
+    assert_eq!(ks.current_event().map(|event| &event.id), Some(&real.id));
+
+    let mut gov = Governance::new(ks, AuditLog::open(dir.path().join("audit.jsonl")).unwrap());
+    let ack = gov.acknowledge_stopped("inference", "fraud-v2").unwrap();
+    assert!(matches!(ack.audit, AuditStatus::Recorded(ref entry) if entry.details["kill_id"] == real.id));
+}
@@ -0,0 +1,48 @@
+//! Signatures record the hash of the content they cover.
+
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, Signature, SignatureError, SignatureVerifier};
+use sha2::{Digest, Sha256};
+
+#[test]
+fn content_hash_is_checked_before_the_signature() {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+
+    let signature = sign_ed25519(b"adapter weights", "alice", &key, None);
+    assert_eq!(
+        signature.content_hash.as_deref(),
+        Some(hex::encode(Sha256::digest(b"adapter weights")).as_str())
+    );
+    assert!(verifier.verify(b"adapter weights", &signature).unwrap());
+
+    match verifier.verify(b"other weights", &signature) {
+        Err(SignatureError::ContentMismatch { expected, actual }) => {
+            assert_eq!(Some(expected), signature.content_hash);
+            assert_eq!(actual, hex::encode(Sha256::digest(b"other weights")));
+        }
+        other => panic!("expected ContentMismatch, got {:?}", other),
+    }
+
+    // Pointing the hash at other content does not make the signature cover it.
+    let mut retargeted = signature.clone();
+    retargeted.content_hash = Some(hex::encode(Sha256::digest(b"other weights")));
+    assert!(!verifier.verify(b"other weights", &retargeted).unwrap());
+}
+
+#[test]
+fn signatures_without_a_content_hash_still_verify() {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+
+    let mut signature = sign_ed25519(b"adapter weights", "alice", &key, None);
+    signature.content_hash = None;
+    let json = serde_json::to_string(&signature).unwrap();
+    assert!(!json.contains("content_hash"));
+
+    let legacy: Signature = serde_json::from_str(&json).unwrap();
+    assert!(verifier.verify(b"adapter weights", &legacy).unwrap());
+    assert!(!verifier.verify(b"other weights", &legacy).unwrap());
+}
@@ -0,0 +1,71 @@
+//! Signing is recorded in the audit log, not only verification.
+
+use clap::Parser;
+use ed25519_dalek::SigningKey;
+use openlora_core::AdapterId;
+use openlora_governance::audit::{AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::cli::{Cli, Commands};
+use openlora_governance::signatures::{sign_ed25519_audited, SignatureVerifier, ED25519_ALGORITHM};
+use serde_json::json;
+
+#[test]
+fn signing_appends_signature_created() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let mut verifier = SignatureVerifier::new(vec!["release".to_string()]);
+    verifier.set_allow_legacy_sha256(true);
+    let adapter = AuditTarget::Adapter(AdapterId::new("adapter-1").unwrap());
+
+    let signature = verifier
+        .sign_audited(&mut audit, &b"weights"[..], "release", Some(&adapter))
+        .unwrap();
+    assert!(verifier.verify(b"weights", &signature).unwrap());
+
+    let entries = audit.entries_for(&adapter).unwrap();
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert!(matches!(entry.event_type, AuditEventType::SignatureCreated));
+    assert_eq!(entry.actor, "release");
+    assert_eq!(entry.details["signer"], json!("release"));
+    assert_eq!(entry.details["target"], json!(adapter));
+    assert_eq!(entry.details["signature"], json!(signature.value));
+    assert!(audit.verify_integrity().unwrap());
+}
+
+#[test]
+fn without_a_target_the_entry_is_about_the_content() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let verifier = SignatureVerifier::new(vec!["release".to_string()]);
+
+    let signature = verifier.sign_audited(&mut audit, &b"weights"[..], "release", None).unwrap();
+    let content = AuditTarget::Content(signature.content_hash.clone().unwrap());
+    assert_eq!(audit.entries_for(&content).unwrap().len(), 1);
+}
+
+#[test]
+fn a_key_signature_is_recorded_and_verifies_without_opting_in_to_legacy() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut audit = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    let key = SigningKey::from_bytes(&[7; 32]);
+
+    let signature = sign_ed25519_audited(&mut audit, &b"weights"[..], "release", &key, None).unwrap();
+    assert_eq!(signature.algorithm, ED25519_ALGORITHM);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("release", key.verifying_key());
+    assert!(verifier.verify(b"weights", &signature).unwrap());
+
+    let content = AuditTarget::Content(signature.content_hash.clone().unwrap());
+    let entries = audit.entries_for(&content).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert!(matches!(entries[0].event_type, AuditEventType::SignatureCreated));
+    assert_eq!(entries[0].details["signature"], json!(signature.value));
+}
+
+#[test]
+fn the_cli_signs_with_a_key_unless_legacy_is_asked_for() {
+    let parse = |args: &[&str]| Cli::try_parse_from([&["openlora-gov", "sign", "-a", "adapter.bin", "-s", "release"], args].concat());
+    assert!(matches!(parse(&[]).unwrap().command, Commands::Sign { key: None, legacy: false, .. }));
+    assert!(matches!(parse(&["--legacy"]).unwrap().command, Commands::Sign { legacy: true, .. }));
+    assert!(parse(&["--legacy", "--key", "release.key"]).is_err());
+}
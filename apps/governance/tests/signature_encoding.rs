@@ -0,0 +1,83 @@
+//! Detached signature values may be hex or base64.
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, Signature, SignatureEncoding, SignatureError, SignatureVerifier};
+
+#[test]
+fn base64_values_are_decoded_before_verification() {
+    let key = SigningKey::from_bytes(&[5; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+
+    let signature = sign_ed25519(b"adapter weights", "alice", &key, None);
+    let raw = hex::decode(&signature.value).unwrap();
+    let external = signature.clone().encoded_as(SignatureEncoding::Base64).unwrap();
+    assert_eq!(external.value, BASE64_STANDARD.encode(&raw));
+    assert_eq!(external.decoded_value().unwrap(), raw);
+    assert!(verifier.verify(b"adapter weights", &external).unwrap());
+    let mut forged = external.clone();
+    forged.value = BASE64_STANDARD.encode([0u8; 64]);
+    assert!(!verifier.verify(b"adapter weights", &forged).unwrap());
+
+    let json = serde_json::to_value(&external).unwrap();
+    assert_eq!(json["encoding"], "base64");
+    let parsed: Signature = serde_json::from_value(json).unwrap();
+    assert!(verifier.verify(b"adapter weights", &parsed).unwrap());
+
+    // Hex stays the default and is not written out.
+    assert!(serde_json::to_value(&signature).unwrap().get("encoding").is_none());
+    let back = external.encoded_as(SignatureEncoding::Hex).unwrap();
+    assert_eq!(back.value, signature.value);
+}
+
+#[test]
+fn malformed_values_are_rejected_with_an_encoding_error() {
+    let key = SigningKey::from_bytes(&[5; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    let signature = sign_ed25519(b"adapter weights", "alice", &key, None);
+
+    let mut bad_base64 = signature.clone();
+    bad_base64.encoding = Some(SignatureEncoding::Base64);
+    bad_base64.value = "not*base64".to_string();
+    let err = verifier.verify(b"adapter weights", &bad_base64).unwrap_err();
+    assert!(matches!(err, SignatureError::InvalidEncoding(_)));
+    assert!(err.to_string().contains("base64"), "{}", err);
+
+    let mut short = signature.clone();
+    short.value = "abcd".to_string();
+    assert!(matches!(
+        verifier.verify(b"adapter weights", &short),
+        Err(SignatureError::InvalidEncoding(_))
+    ));
+
+    let mut bad_hex = signature;
+    bad_hex.value = "zz".repeat(64);
+    assert!(matches!(
+        verifier.verify(b"adapter weights", &bad_hex),
+        Err(SignatureError::InvalidEncoding(_))
+    ));
+}
+
+#[test]
+fn cached_verdicts_are_keyed_by_decoded_value() {
+    let key = SigningKey::from_bytes(&[5; 32]);
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key.verifying_key());
+    verifier.set_cache_capacity(16);
+
+    let signature = sign_ed25519(b"adapter weights", "alice", &key, None);
+    assert!(verifier.verify(b"adapter weights", &signature).unwrap());
+
+    // The same hex text is also valid base64, for 96 other bytes.
+    let reread = Signature {
+        encoding: Some(SignatureEncoding::Base64),
+        ..signature
+    };
+    assert!(matches!(
+        verifier.verify(b"adapter weights", &reread),
+        Err(SignatureError::InvalidEncoding(_))
+    ));
+    assert_eq!(verifier.cache_stats().hits, 0);
+}
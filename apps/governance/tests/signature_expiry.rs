@@ -0,0 +1,66 @@
+//! A provenance signature must not have expired by the next entry's timestamp.
+
+use chrono::{DateTime, Duration, Utc};
+use ed25519_dalek::SigningKey;
+use openlora_core::ProvenanceOperation;
+use openlora_governance::clock::ManualClock;
+use openlora_governance::signatures::{sign_ed25519, ProvenanceEntry, SignatureError, SignatureVerifier};
+
+fn key() -> SigningKey {
+    SigningKey::from_bytes(&[7; 32])
+}
+
+fn entry(version: u32, operation: ProvenanceOperation, timestamp: DateTime<Utc>, parent: Option<&ProvenanceEntry>) -> ProvenanceEntry {
+    let mut entry = ProvenanceEntry {
+        adapter_id: "adapter-1".to_string(),
+        version,
+        operation,
+        actor: "trainer".to_string(),
+        timestamp,
+        signature: None,
+        parent_hash: parent.map(|p| p.hash.clone()),
+        content_hash: None,
+        hash: String::new(),
+    };
+    entry.hash = entry.compute_hash();
+    entry
+}
+
+/// A signed `Created` entry followed by a `Trained` entry `after` its
+/// signature expires, or an hour later if the signature never expires.
+fn chain(valid_for: Option<Duration>, after: Duration) -> Vec<ProvenanceEntry> {
+    let mut created = entry(1, ProvenanceOperation::Created, Utc::now(), None);
+    let signature = sign_ed25519(&created.canonical_bytes(), "trainer", &key(), valid_for);
+    let next_at = signature.expires_at.unwrap_or(created.timestamp + Duration::hours(1)) + after;
+    created.signature = Some(signature);
+    let trained = entry(2, ProvenanceOperation::Trained, next_at, Some(&created));
+    vec![created, trained]
+}
+
+/// A verifier whose clock reads the time of the chain's last entry.
+fn verifier(chain: &[ProvenanceEntry]) -> SignatureVerifier {
+    let clock = ManualClock::new(chain.last().unwrap().timestamp);
+    let mut verifier = SignatureVerifier::new(Vec::new()).with_clock(clock);
+    verifier.add_signer_key("trainer", key().verifying_key());
+    verifier
+}
+
+#[test]
+fn the_next_entry_may_be_recorded_exactly_at_expiry() {
+    let chain = chain(Some(Duration::minutes(30)), Duration::zero());
+    assert_eq!(chain[1].timestamp, chain[0].signature.as_ref().unwrap().expires_at.unwrap());
+    assert!(verifier(&chain).verify_provenance(&chain).unwrap());
+}
+
+#[test]
+fn one_second_past_expiry_is_a_stale_signature() {
+    let chain = chain(Some(Duration::minutes(30)), Duration::seconds(1));
+    assert!(matches!(verifier(&chain).verify_provenance(&chain), Err(SignatureError::Expired)));
+}
+
+#[test]
+fn a_signature_without_expiry_never_goes_stale() {
+    let chain = chain(None, Duration::days(3650));
+    assert!(chain[0].signature.as_ref().unwrap().expires_at.is_none());
+    assert!(verifier(&chain).verify_provenance(&chain).unwrap());
+}
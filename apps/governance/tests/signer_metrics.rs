@@ -0,0 +1,96 @@
+//! Per-signer verification counts.
+
+use chrono::{Duration, TimeZone, Utc};
+use openlora_core::ProvenanceOperation;
+use openlora_governance::signatures::{ProvenanceEntry, SignatureError, SignatureVerifier, SignerReport, SignerStats};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn outcomes_are_counted_per_signer_across_threads() {
+    let mut verifier = SignatureVerifier::new(vec!["alice".to_string(), "bob".to_string()]);
+    verifier.set_allow_legacy_sha256(true);
+    let verifier = Arc::new(verifier);
+    let good = verifier.sign(b"weights", "alice");
+    let unknown = verifier.sign(b"weights", "mallory");
+    let mut bad = good.clone();
+    bad.value = "0".repeat(16);
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let verifier = Arc::clone(&verifier);
+            let (good, bad, unknown) = (good.clone(), bad.clone(), unknown.clone());
+            thread::spawn(move || {
+                for _ in 0..25 {
+                    assert!(verifier.verify(b"weights", &good).unwrap());
+                    assert!(!verifier.verify(b"weights", &bad).unwrap());
+                    assert!(verifier.verify(b"weights", &unknown).is_err());
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    let report = verifier.report();
+    let alice = report.get("alice").unwrap();
+    assert_eq!((alice.verified, alice.failed, alice.total()), (100, 100, 200));
+    assert_eq!(alice.failure_rate(), 0.5);
+    assert_eq!(report.get("mallory").unwrap().unknown_signer, 100);
+    assert!(report.get("bob").is_none());
+}
+
+#[test]
+fn expired_provenance_signatures_are_counted() {
+    let verifier = SignatureVerifier::new(vec!["alice".to_string()]);
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut chain: Vec<ProvenanceEntry> = Vec::new();
+    for (version, operation) in [(1, ProvenanceOperation::Created), (2, ProvenanceOperation::Trained)] {
+        let mut entry = ProvenanceEntry {
+            adapter_id: "adapter-1".to_string(),
+            version,
+            operation,
+            actor: "alice".to_string(),
+            timestamp: start + Duration::days(version as i64),
+            signature: None,
+            parent_hash: chain.last().map(|e| e.hash.clone()),
+            content_hash: None,
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        verifier.sign_provenance(&mut entry, "alice", Some(Duration::hours(1)));
+        entry.signature.as_mut().unwrap().expires_at = Some(start);
+        chain.push(entry);
+    }
+
+    assert!(matches!(verifier.verify_provenance(&chain), Err(SignatureError::Expired)));
+    assert_eq!(verifier.report().get("alice").unwrap().expired, 1);
+}
+
+#[test]
+fn reports_merge_and_persist() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("signer-metrics.json");
+    assert!(SignerReport::load(&path).unwrap().is_empty());
+
+    let mut verifier = SignatureVerifier::new(vec!["alice".to_string()]);
+    verifier.set_allow_legacy_sha256(true);
+    let signature = verifier.sign(b"weights", "alice");
+    verifier.verify(b"weights", &signature).unwrap();
+
+    let mut report = SignerReport::load(&path).unwrap();
+    report.merge(&verifier.report());
+    report.merge(&verifier.report());
+    report.save(&path).unwrap();
+
+    let loaded = SignerReport::load(&path).unwrap();
+    assert_eq!(loaded, report);
+    assert_eq!(
+        loaded.get("alice"),
+        Some(&SignerStats {
+            verified: 2,
+            ..SignerStats::default()
+        })
+    );
+}
@@ -0,0 +1,73 @@
+//! Affected adapters confirm they stopped; stragglers are escalated once.
+
+use chrono::{Duration, Utc};
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::clock::ManualClock;
+use openlora_governance::governance::{AuditStatus, Governance};
+use openlora_governance::killswitch::{KillReason, KillState, KillSwitch, KillSwitchError};
+
+fn manual() -> KillReason {
+    KillReason::ManualTrigger { operator: "alice".to_string(), reason: None }
+}
+
+fn adapters() -> Vec<String> {
+    vec!["fraud-v2".to_string(), "chat-v1".to_string(), "search-v3".to_string()]
+}
+
+#[test]
+fn acknowledgements_track_stragglers_and_persist() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut ks = KillSwitch::new(vec!["alice".to_string()]);
+    assert!(matches!(ks.acknowledge_stopped("fraud-v2"), Err(KillSwitchError::NotActive)));
+
+    ks.activate("alice", manual(), adapters()).unwrap();
+    assert_eq!(ks.unacknowledged().len(), 3);
+    assert!(ks.acknowledge_stopped("fraud-v2").unwrap());
+    assert!(!ks.acknowledge_stopped("fraud-v2").unwrap());
+    assert!(matches!(ks.acknowledge_stopped("other"), Err(KillSwitchError::NotAffected(_))));
+    assert_eq!(ks.unacknowledged(), vec!["chat-v1".to_string(), "search-v3".to_string()]);
+
+    let path = dir.path().join("state.json");
+    ks.state().save(&path).unwrap();
+    let restored = KillSwitch::from_state(vec!["alice".to_string()], KillState::load(&path).unwrap());
+    assert_eq!(restored.unacknowledged().len(), 2);
+    drop(restored);
+
+    ks.reset("alice").unwrap();
+    assert!(ks.unacknowledged().is_empty());
+}
+
+#[test]
+fn stragglers_are_escalated_once_after_the_timeout() {
+    let dir = tempfile::tempdir().unwrap();
+    let clock = ManualClock::new(Utc::now());
+    let ks = KillSwitch::new(vec!["alice".to_string()]).with_clock(clock.clone());
+    let mut gov = Governance::new(ks, AuditLog::open(dir.path().join("audit.jsonl")).unwrap());
+
+    gov.kill("alice", manual(), adapters()).unwrap();
+    let ack = gov.acknowledge_stopped("inference", "fraud-v2").unwrap();
+    assert!(ack.outcome && ack.audit.is_recorded());
+    let repeat = gov.acknowledge_stopped("inference", "fraud-v2").unwrap();
+    assert!(!repeat.outcome && matches!(repeat.audit, AuditStatus::Replayed));
+
+    let timeout = Duration::minutes(5);
+    clock.advance(Duration::minutes(4));
+    assert!(gov.escalate_unacknowledged("watchdog", timeout).is_none());
+    gov.acknowledge_stopped("inference", "chat-v1").unwrap();
+
+    clock.advance(Duration::minutes(2));
+    let overdue = gov.escalate_unacknowledged("watchdog", timeout).unwrap();
+    assert_eq!(overdue.outcome.unacknowledged, vec!["search-v3".to_string()]);
+    assert!(gov.escalate_unacknowledged("watchdog", timeout).is_none());
+
+    let log = gov.audit_log().unwrap();
+    let entries = log.entries().unwrap();
+    let types: Vec<&str> = entries.iter().map(|e| e.event_type.tag()).collect();
+    assert_eq!(
+        types,
+        vec!["KillSwitchActivated", "AdapterDeactivated", "AdapterDeactivated", "KillStopOverdue"]
+    );
+    assert!(matches!(entries[3].event_type, AuditEventType::KillStopOverdue));
+    assert_eq!(entries[3].details["unacknowledged"], serde_json::json!(["search-v3"]));
+    assert_eq!(entries[3].details["timeout_secs"], 300);
+}
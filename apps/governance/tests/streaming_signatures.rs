@@ -0,0 +1,82 @@
+//! Signing and verifying streamed content uses constant memory.
+
+use openlora_governance::signatures::{SignatureError, SignatureVerifier};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Read;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Tracks live and peak heap usage of this test binary.
+struct Counting;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let live = LIVE.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(live, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Deterministic pseudo-random bytes, generated on the fly.
+struct Synthetic {
+    remaining: u64,
+    state: u64,
+}
+
+impl Synthetic {
+    fn new(len: u64) -> Self {
+        Self {
+            remaining: len,
+            state: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+}
+
+impl Read for Synthetic {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.remaining as usize);
+        for byte in &mut buf[..n] {
+            self.state ^= self.state << 13;
+            self.state ^= self.state >> 7;
+            self.state ^= self.state << 17;
+            *byte = self.state as u8;
+        }
+        self.remaining -= n as u64;
+        Ok(n)
+    }
+}
+
+#[test]
+fn large_stream_is_hashed_in_constant_memory() {
+    const LEN: u64 = 32 * 1024 * 1024;
+    let mut verifier = SignatureVerifier::new(vec!["alice".into()]);
+    verifier.set_allow_legacy_sha256(true);
+
+    PEAK.store(LIVE.load(Ordering::SeqCst), Ordering::SeqCst);
+    let baseline = LIVE.load(Ordering::SeqCst);
+
+    let signature = verifier.sign_reader(Synthetic::new(LEN), "alice").unwrap();
+    assert!(verifier.verify_reader(Synthetic::new(LEN), &signature).unwrap());
+
+    // Streaming needs a copy buffer, not the content.
+    let growth = PEAK.load(Ordering::SeqCst) - baseline;
+    assert!(growth < 1024 * 1024, "peak heap grew by {} bytes", growth);
+
+    // Different content of the same length does not verify.
+    let mut other = Synthetic::new(LEN);
+    other.state = 1;
+    assert!(matches!(
+        verifier.verify_reader(other, &signature),
+        Err(SignatureError::ContentMismatch { .. })
+    ));
+}
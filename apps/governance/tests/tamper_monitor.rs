@@ -0,0 +1,62 @@
+//! Audit-log tampering records an incident and activates the kill-switch.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+use openlora_governance::monitor::{TamperMonitor, AUDIT_INTEGRITY_SOURCE};
+use serde_json::json;
+use std::fs;
+
+/// A log of three entries whose second entry was edited in place.
+fn tampered_log(dir: &tempfile::TempDir) -> AuditLog {
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    for i in 0..3 {
+        log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": i })).unwrap();
+    }
+    fs::write(&path, fs::read_to_string(&path).unwrap().replace("\"n\":1", "\"n\":10")).unwrap();
+    AuditLog::open(path).unwrap()
+}
+
+#[test]
+fn an_intact_log_needs_no_response() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({})).unwrap();
+    let incidents = AuditLog::open(dir.path().join("incidents.jsonl")).unwrap();
+    let mut monitor = TamperMonitor::new(incidents, "audit-monitor");
+    let mut ks = KillSwitch::new(vec!["alice".to_string()]);
+
+    assert!(monitor.check(&log, &mut ks).unwrap().is_none());
+    assert!(!ks.is_active());
+}
+
+#[test]
+fn tampering_kills_even_when_the_monitor_is_not_an_authorized_operator() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = tampered_log(&dir);
+    let incidents_path = dir.path().join("incidents.jsonl");
+    let mut monitor = TamperMonitor::new(AuditLog::open(incidents_path.clone()).unwrap(), "audit-monitor");
+    let mut ks = KillSwitch::new(vec!["alice".to_string(), "bob".to_string()]);
+
+    let response = monitor.check(&log, &mut ks).unwrap().expect("tampering detected");
+    let incident = response.incident.unwrap();
+    let event = response.kill.unwrap();
+    assert!(ks.is_active());
+    assert_eq!(event.triggered_by, "audit-monitor");
+    match event.reason {
+        KillReason::ExternalSignal { ref source, ref message } => {
+            assert_eq!(source, AUDIT_INTEGRITY_SOURCE);
+            assert_eq!(*message, response.message);
+        }
+        ref other => panic!("expected ExternalSignal, got {:?}", other),
+    }
+    assert_eq!(ks.get_events().len(), 1);
+
+    // The incident is in the separate log, which still verifies.
+    let incidents = AuditLog::open(incidents_path).unwrap();
+    incidents.verify_integrity().unwrap();
+    let recorded = incidents.entries().unwrap();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].id, incident.id);
+    assert!(matches!(recorded[0].event_type, AuditEventType::AuditIntegrityFailed));
+}
@@ -0,0 +1,123 @@
+//! Trusted signers from a remote registry, cached with a TTL.
+
+use chrono::{Duration, TimeZone, Utc};
+use ed25519_dalek::SigningKey;
+use openlora_governance::clock::{Clock, ManualClock};
+use openlora_governance::signatures::sign_ed25519;
+use openlora_governance::trust::{RemoteSigner, TrustError, TrustFetcher, TrustRegistry, TrustSet, TrustSource};
+use std::cell::{Cell, RefCell};
+
+/// Serves a fixed response and counts requests.
+struct FakeRegistry {
+    response: RefCell<Result<Vec<u8>, String>>,
+    requests: Cell<usize>,
+}
+
+impl FakeRegistry {
+    fn serving(set: &TrustSet) -> Self {
+        Self {
+            response: RefCell::new(Ok(serde_json::to_vec(set).unwrap())),
+            requests: Cell::new(0),
+        }
+    }
+
+    fn serve(&self, set: &TrustSet) {
+        *self.response.borrow_mut() = Ok(serde_json::to_vec(set).unwrap());
+    }
+
+    fn go_down(&self) {
+        *self.response.borrow_mut() = Err("connection refused".to_string());
+    }
+}
+
+impl TrustFetcher for FakeRegistry {
+    fn fetch(&self, _url: &str) -> Result<Vec<u8>, TrustError> {
+        self.requests.set(self.requests.get() + 1);
+        self.response.borrow().clone().map_err(TrustError::Request)
+    }
+}
+
+const URL: &str = "https://trust.example.org/signers.json";
+
+fn keys() -> (SigningKey, SigningKey) {
+    (SigningKey::from_bytes(&[7; 32]), SigningKey::from_bytes(&[9; 32]))
+}
+
+fn signed_set(registry: &SigningKey, signer: &SigningKey, issued_days: i64) -> TrustSet {
+    let issued_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(issued_days);
+    TrustSet::sign(vec![RemoteSigner::new("release", &signer.verifying_key())], issued_at, registry)
+}
+
+#[test]
+fn fetched_sets_are_cached_for_their_ttl() {
+    let dir = tempfile::tempdir().unwrap();
+    let (registry_key, signer_key) = keys();
+    let clock = ManualClock::new(Utc::now());
+    let registry = TrustRegistry::new(URL, registry_key.verifying_key(), dir.path().join("cache.json"), Duration::hours(1))
+        .with_clock(clock.clone());
+    let server = FakeRegistry::serving(&signed_set(&registry_key, &signer_key, 0));
+
+    let (set, source) = registry.resolve(&server).unwrap();
+    assert_eq!(source, TrustSource::Remote);
+    let verifier = set.signature_verifier().unwrap();
+    let signature = sign_ed25519(b"adapter", "release", &signer_key, None);
+    assert!(verifier.verify(b"adapter", &signature).unwrap());
+
+    clock.advance(Duration::minutes(30));
+    let (_, source) = registry.resolve(&server).unwrap();
+    assert!(matches!(source, TrustSource::Cached { .. }));
+    assert_eq!(server.requests.get(), 1);
+
+    clock.advance(Duration::hours(1));
+    server.serve(&signed_set(&registry_key, &signer_key, 1));
+    assert_eq!(registry.resolve(&server).unwrap().1, TrustSource::Remote);
+    assert_eq!(server.requests.get(), 2);
+}
+
+#[test]
+fn an_unreachable_registry_falls_back_to_the_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let (registry_key, signer_key) = keys();
+    let clock = ManualClock::new(Utc::now());
+    let registry = TrustRegistry::new(URL, registry_key.verifying_key(), dir.path().join("cache.json"), Duration::hours(1))
+        .with_clock(clock.clone());
+    let server = FakeRegistry::serving(&signed_set(&registry_key, &signer_key, 0));
+
+    server.go_down();
+    assert!(matches!(registry.resolve(&server), Err(TrustError::Unavailable(_))));
+
+    server.serve(&signed_set(&registry_key, &signer_key, 0));
+    let fetched_at = clock.now();
+    registry.resolve(&server).unwrap();
+    clock.advance(Duration::days(3));
+    server.go_down();
+    let (set, source) = registry.resolve(&server).unwrap();
+    assert_eq!(set.signers[0].id, "release");
+    assert!(matches!(source, TrustSource::Fallback { fetched_at: at, ref error } if at == fetched_at && error.contains("refused")));
+}
+
+#[test]
+fn forged_or_replayed_sets_are_refused_without_falling_back() {
+    let dir = tempfile::tempdir().unwrap();
+    let (registry_key, signer_key) = keys();
+    let clock = ManualClock::new(Utc::now());
+    let registry = TrustRegistry::new(URL, registry_key.verifying_key(), dir.path().join("cache.json"), Duration::hours(1))
+        .with_clock(clock.clone());
+    let server = FakeRegistry::serving(&signed_set(&registry_key, &signer_key, 5));
+    registry.resolve(&server).unwrap();
+    clock.advance(Duration::hours(2));
+
+    // Someone in the middle adds their own signer.
+    let mut forged = signed_set(&registry_key, &signer_key, 6);
+    forged.signers.push(RemoteSigner::new("mallory", &SigningKey::from_bytes(&[1; 32]).verifying_key()));
+    server.serve(&forged);
+    assert!(matches!(registry.resolve(&server), Err(TrustError::InvalidSignature)));
+
+    // Signed by someone other than the pinned registry key.
+    server.serve(&signed_set(&SigningKey::from_bytes(&[2; 32]), &signer_key, 6));
+    assert!(matches!(registry.resolve(&server), Err(TrustError::InvalidSignature)));
+
+    // A genuine but older set, e.g. from before a revocation.
+    server.serve(&signed_set(&registry_key, &signer_key, 1));
+    assert!(matches!(registry.resolve(&server), Err(TrustError::Rollback { .. })));
+}
@@ -0,0 +1,64 @@
+//! Signers are checked against their trust timeline at signing time.
+
+mod common;
+
+use chrono::{Duration, Utc};
+use common::verifier;
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, SignatureError, TrustInterval};
+
+#[test]
+fn revocation_keeps_earlier_signatures_valid() {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let mut verifier = verifier(&[("alice", 7)]);
+
+    let signature = sign_ed25519(b"weights", "alice", &key, None);
+    verifier.revoke_signer("alice", signature.signed_at + Duration::seconds(1));
+    assert!(verifier.verify(b"weights", &signature).unwrap());
+
+    // A signature made at or after revocation is refused before any check.
+    verifier.revoke_signer("alice", signature.signed_at);
+    match verifier.verify(b"weights", &signature) {
+        Err(SignatureError::SignerNotTrustedAtTime { signer, signed_at }) => {
+            assert_eq!(signer, "alice");
+            assert_eq!(signed_at, signature.signed_at);
+        }
+        other => panic!("expected SignerNotTrustedAtTime, got {:?}", other),
+    }
+}
+
+#[test]
+fn signatures_before_trust_began_are_refused() {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let mut verifier = verifier(&[("alice", 7)]);
+
+    let signature = sign_ed25519(b"weights", "alice", &key, None);
+    verifier.set_trust_timeline(
+        "alice",
+        vec![TrustInterval {
+            from: Some(Utc::now() + Duration::hours(1)),
+            until: None,
+        }],
+    );
+    assert!(!verifier.is_trusted_at("alice", &signature.signed_at));
+    assert!(matches!(
+        verifier.verify(b"weights", &signature),
+        Err(SignatureError::SignerNotTrustedAtTime { .. })
+    ));
+
+    // Re-trusted for an earlier window that covers the signature.
+    verifier.set_trust_timeline(
+        "alice",
+        vec![
+            TrustInterval {
+                from: None,
+                until: Some(signature.signed_at + Duration::seconds(1)),
+            },
+            TrustInterval {
+                from: Some(Utc::now() + Duration::hours(1)),
+                until: None,
+            },
+        ],
+    );
+    assert!(verifier.verify(b"weights", &signature).unwrap());
+}
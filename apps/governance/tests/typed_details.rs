@@ -0,0 +1,50 @@
+//! Checking stored details against the shape of their event type.
+
+use openlora_governance::audit::{AuditError, AuditEventType, AuditLog, AuditTarget};
+use openlora_governance::governance::Governance;
+use openlora_governance::killswitch::{KillReason, KillSwitch};
+use openlora_governance::quarantine::QuarantineList;
+use serde_json::json;
+
+#[test]
+fn details_written_by_the_facade_match_their_schemas() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut gov = Governance::new(KillSwitch::new(vec!["ops".to_string()]), AuditLog::open(path.clone()).unwrap());
+    let mut list = QuarantineList::open(dir.path().join("quarantine.json")).unwrap();
+
+    gov.quarantine(&mut list, "adapter-1", "drift", "ops").unwrap();
+    gov.kill("ops", KillReason::ManualTrigger { operator: "ops".to_string(), reason: None }, Vec::new()).unwrap();
+    gov.reset(&["ops".to_string()]).unwrap();
+    let session = gov.issue_session("ops", chrono::Duration::minutes(5)).unwrap();
+    gov.end_session(&session.outcome.id, "ops").unwrap();
+
+    let mut log = AuditLog::open(path).unwrap();
+    log.set_details_validation(true);
+    assert!(log.verify_integrity().unwrap());
+}
+
+#[test]
+fn a_consistent_entry_with_the_wrong_shape_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut log = AuditLog::open(dir.path().join("audit.jsonl")).unwrap();
+    log.append(AuditEventType::PolicyEvaluated, "ops", None, json!({ "anything": true })).unwrap();
+    log.append(
+        AuditEventType::KillSwitchActivated,
+        "ops",
+        Some(&AuditTarget::KillSwitch(None)),
+        json!({ "affected_adapters": [] }),
+    )
+    .unwrap();
+
+    // The chain itself is intact.
+    assert!(log.verify_integrity().unwrap());
+
+    log.set_details_validation(true);
+    match log.verify_integrity() {
+        Err(AuditError::DetailsSchemaMismatch { line, event_type }) => {
+            assert_eq!((line, event_type.as_str()), (2, "KillSwitchActivated"));
+        }
+        other => panic!("expected DetailsSchemaMismatch, got {:?}", other),
+    }
+}
@@ -0,0 +1,47 @@
+//! Adapter, model and run ids are validated however they are built.
+
+use openlora_core::{AdapterId, IdError, ModelId, RunId, MAX_ID_LEN};
+
+#[test]
+fn well_formed_ids_are_accepted() {
+    for id in ["a", "adapter-1", "org:team.model_v2", &"x".repeat(MAX_ID_LEN)] {
+        assert_eq!(AdapterId::new(id).unwrap().as_str(), id);
+        assert_eq!(id.parse::<ModelId>().unwrap().to_string(), id);
+        assert_eq!(RunId::try_from(id.to_string()).unwrap().as_ref(), id);
+    }
+}
+
+#[test]
+fn malformed_ids_are_rejected_with_the_reason() {
+    let kind = "adapter id";
+    assert_eq!(AdapterId::new(""), Err(IdError::Empty { kind }));
+    assert_eq!(
+        AdapterId::new("x".repeat(MAX_ID_LEN + 1)),
+        Err(IdError::TooLong { kind, len: MAX_ID_LEN + 1, max: MAX_ID_LEN })
+    );
+    assert_eq!(AdapterId::new("-adapter"), Err(IdError::InvalidStart { kind }));
+    assert_eq!(AdapterId::new("adapter/../etc"), Err(IdError::InvalidChar { kind, ch: '/' }));
+    assert_eq!(AdapterId::new("adapter\n1"), Err(IdError::InvalidChar { kind, ch: '\n' }));
+    assert_eq!(AdapterId::new("adapter 1"), Err(IdError::InvalidChar { kind, ch: ' ' }));
+    assert_eq!(ModelId::new(""), Err(IdError::Empty { kind: "model id" }));
+    assert_eq!(RunId::new("run\u{0}"), Err(IdError::InvalidChar { kind: "run id", ch: '\u{0}' }));
+}
+
+#[test]
+fn serde_round_trips_valid_ids_as_plain_strings() {
+    let id = AdapterId::new("adapter-1").unwrap();
+    assert_eq!(serde_json::to_string(&id).unwrap(), "\"adapter-1\"");
+    assert_eq!(serde_json::from_str::<AdapterId>("\"adapter-1\"").unwrap(), id);
+    assert_eq!(serde_json::from_str::<RunId>("\"run:42\"").unwrap(), RunId::new("run:42").unwrap());
+}
+
+#[test]
+fn serde_rejects_what_new_rejects() {
+    for json in ["\"\"", "\"-adapter\"", "\"a/b\"", "\"a\\u0000b\""] {
+        let err = serde_json::from_str::<AdapterId>(json).unwrap_err();
+        assert!(err.to_string().contains("adapter id"), "{}: {}", json, err);
+    }
+    let too_long = serde_json::to_string(&"m".repeat(MAX_ID_LEN + 1)).unwrap();
+    assert!(serde_json::from_str::<ModelId>(&too_long).is_err());
+    assert!(serde_json::from_str::<RunId>("42").is_err());
+}
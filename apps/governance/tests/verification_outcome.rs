@@ -0,0 +1,35 @@
+//! A valid signature is classified against the content's creator.
+
+use ed25519_dalek::SigningKey;
+use openlora_governance::signatures::{sign_ed25519, SignatureVerifier, VerificationOutcome};
+
+fn verifier() -> SignatureVerifier {
+    let mut verifier = SignatureVerifier::new(vec!["alice".to_string()]);
+    verifier.add_signer_key("alice", SigningKey::from_bytes(&[7; 32]).verifying_key());
+    verifier
+}
+
+#[test]
+fn the_outcome_depends_on_who_created_the_content() {
+    let verifier = verifier();
+    let signature = sign_ed25519(b"weights", "alice", &SigningKey::from_bytes(&[7; 32]), None);
+
+    let outcome = |creator| verifier.verify_detailed(b"weights", &signature, creator).unwrap();
+    assert_eq!(outcome(Some("bob")), VerificationOutcome::TrustedExternal);
+    assert_eq!(outcome(Some("alice")), VerificationOutcome::SelfSigned);
+    assert_eq!(outcome(None), VerificationOutcome::UnknownCreator);
+    assert!(outcome(None).is_valid());
+}
+
+#[test]
+fn an_invalid_signature_fails_whoever_the_creator_is() {
+    let verifier = verifier();
+    // Made with a key other than the one registered for alice.
+    let signature = sign_ed25519(b"weights", "alice", &SigningKey::from_bytes(&[8; 32]), None);
+
+    for creator in [None, Some("alice"), Some("bob")] {
+        let outcome = verifier.verify_detailed(b"weights", &signature, creator).unwrap();
+        assert_eq!(outcome, VerificationOutcome::Failed);
+        assert!(!outcome.is_valid());
+    }
+}
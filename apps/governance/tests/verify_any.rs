@@ -0,0 +1,39 @@
+//! Accepting content signed by any one of several release signers.
+
+use openlora_governance::signatures::{SignatureError, SignatureVerifier};
+
+#[test]
+fn first_valid_signature_names_the_signer() {
+    let mut verifier = SignatureVerifier::new(vec!["release-a".to_string(), "release-b".to_string()]);
+    verifier.set_allow_legacy_sha256(true);
+    let by_a = verifier.sign(b"adapter", "release-a");
+    let by_b = verifier.sign(b"adapter", "release-b");
+    let untrusted = verifier.sign(b"adapter", "mallory");
+
+    assert_eq!(verifier.verify_any(b"adapter", &[by_b.clone(), by_a.clone()]).unwrap(), "release-b");
+    assert_eq!(verifier.verify_any(b"adapter", &[untrusted, by_a]).unwrap(), "release-a");
+}
+
+#[test]
+fn every_failure_is_reported_when_none_verify() {
+    let mut verifier = SignatureVerifier::new(vec!["release-a".to_string()]);
+    verifier.set_allow_legacy_sha256(true);
+    let mut tampered = verifier.sign(b"adapter", "release-a");
+    tampered.content_hash = None;
+    tampered.value = "0".repeat(16);
+    let untrusted = verifier.sign(b"adapter", "mallory");
+
+    match verifier.verify_any(b"adapter", &[tampered, untrusted]) {
+        Err(SignatureError::NoneVerified { failures }) => {
+            assert_eq!(failures.len(), 2);
+            assert_eq!(failures[0].0, "release-a");
+            assert!(matches!(failures[0].1, SignatureError::InvalidSignature));
+            assert_eq!(failures[1].0, "mallory");
+            assert!(matches!(failures[1].1, SignatureError::UnknownSigner(_)));
+        }
+        other => panic!("expected NoneVerified, got {:?}", other),
+    }
+
+    let none = verifier.verify_any(b"adapter", &[]).unwrap_err();
+    assert!(none.to_string().contains("no signatures given"), "{}", none);
+}
@@ -0,0 +1,77 @@
+//! The bounded verification cache: hits, eviction and invalidation.
+
+mod common;
+
+use common::key;
+use openlora_governance::signatures::{sign_ed25519, CacheStats, Signature, SignatureVerifier};
+
+fn verifier(capacity: usize) -> SignatureVerifier {
+    let mut verifier = SignatureVerifier::new(Vec::new());
+    verifier.add_signer_key("alice", key(1).verifying_key());
+    verifier.set_cache_capacity(capacity);
+    verifier
+}
+
+fn signed(content: &'static [u8]) -> (&'static [u8], Signature) {
+    (content, sign_ed25519(content, "alice", &key(1), None))
+}
+
+fn stats(hits: u64, misses: u64, len: usize) -> CacheStats {
+    CacheStats { hits, misses, len }
+}
+
+#[test]
+fn repeated_verifications_hit_the_cache() {
+    let verifier = verifier(4);
+    let (content, signature) = signed(b"adapter-1");
+
+    assert!(verifier.verify(content, &signature).unwrap());
+    assert_eq!(verifier.cache_stats(), stats(0, 1, 1));
+    assert!(verifier.verify(content, &signature).unwrap());
+    assert!(verifier.verify(content, &signature).unwrap());
+    assert_eq!(verifier.cache_stats(), stats(2, 1, 1));
+}
+
+#[test]
+fn the_least_recently_used_result_is_evicted() {
+    let verifier = verifier(2);
+    let [a, b, c] = [signed(b"adapter-a"), signed(b"adapter-b"), signed(b"adapter-c")];
+    let verify = |(content, signature): &(&[u8], Signature)| verifier.verify(content, signature).unwrap();
+
+    verify(&a);
+    verify(&b);
+    verify(&a);
+    verify(&c);
+    assert_eq!(verifier.cache_stats(), stats(1, 3, 2));
+
+    // b was least recently used, so it went; a is still cached.
+    verify(&a);
+    assert_eq!(verifier.cache_stats(), stats(2, 3, 2));
+    verify(&b);
+    assert_eq!(verifier.cache_stats(), stats(2, 4, 2));
+}
+
+#[test]
+fn changing_a_signer_key_drops_cached_verdicts() {
+    let mut verifier = verifier(4);
+    let content: &[u8] = b"adapter-1";
+    let signature = sign_ed25519(content, "alice", &key(2), None);
+
+    // Signed with a key alice has not registered yet.
+    assert!(!verifier.verify(content, &signature).unwrap());
+    assert_eq!(verifier.cache_stats().len, 1);
+
+    verifier.add_signer_key("alice", key(2).verifying_key());
+    assert_eq!(verifier.cache_stats().len, 0);
+    assert!(verifier.verify(content, &signature).unwrap());
+    assert_eq!(verifier.cache_stats(), stats(0, 2, 1));
+}
+
+#[test]
+fn a_zero_capacity_disables_caching() {
+    let verifier = verifier(0);
+    let (content, signature) = signed(b"adapter-1");
+    assert!(verifier.verify(content, &signature).unwrap());
+    assert!(verifier.verify(content, &signature).unwrap());
+    assert_eq!(verifier.cache_stats(), CacheStats::default());
+}
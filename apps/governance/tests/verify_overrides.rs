@@ -0,0 +1,44 @@
+//! Recovery verification past an allowlisted known-bad entry.
+
+use openlora_governance::audit::{AuditEventType, AuditLog};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs;
+
+#[test]
+fn the_chain_continues_from_the_corrected_hash() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    let mut log = AuditLog::open(path.clone()).unwrap();
+    for i in 0..5 {
+        log.append(AuditEventType::PolicyEvaluated, "alice", None, json!({ "n": i })).unwrap();
+    }
+    let entries = log.entries().unwrap();
+
+    // Entry 2 is damaged: its details and stored hash no longer match.
+    let mut lines: Vec<String> = fs::read_to_string(&path).unwrap().lines().map(String::from).collect();
+    lines[2] = lines[2]
+        .replace("\"n\":2", "\"n\":20")
+        .replace(&format!("\"hash\":\"{}\"", entries[2].hash), "\"hash\":\"0bad\"");
+    fs::write(&path, lines.join("\n") + "\n").unwrap();
+    let log = AuditLog::open(path).unwrap();
+    assert!(log.verify_integrity().is_err());
+    assert!(log.verify_integrity_with_overrides(&HashMap::new()).is_err());
+
+    let overrides = HashMap::from([
+        (entries[2].id.clone(), entries[2].hash.clone()),
+        ("no-such-entry".to_string(), "abc".to_string()),
+    ]);
+    let verified = log.verify_integrity_with_overrides(&overrides).unwrap();
+    assert_eq!(verified.verified, 4);
+    assert_eq!(verified.applied.len(), 1);
+    let applied = &verified.applied[0];
+    assert_eq!((applied.line, applied.entry_id.as_str()), (3, entries[2].id.as_str()));
+    assert_eq!(applied.stored_hash, "0bad");
+    assert_eq!(applied.corrected_hash, entries[2].hash);
+    assert_eq!(verified.unused, vec!["no-such-entry"]);
+
+    // A wrong correction breaks the next link.
+    let wrong = HashMap::from([(entries[2].id.clone(), "0bad".to_string())]);
+    assert!(log.verify_integrity_with_overrides(&wrong).is_err());
+}
@@ -0,0 +1,73 @@
+//! Waiting on persisted kill-switch state, as `watch-killswitch` does.
+
+use clap::Parser;
+use openlora_governance::cli::{Cli, Commands};
+use openlora_governance::killswitch::{KillReason, KillState, KillSwitch};
+use std::time::{Duration, Instant};
+
+const POLL: Duration = Duration::from_millis(10);
+
+#[test]
+fn a_timeout_without_change_gives_up() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("killswitch.json");
+
+    let started = Instant::now();
+    let deadline = Some(started + Duration::from_millis(50));
+    assert!(KillState::wait_for_change(&path, false, POLL, deadline).unwrap().is_none());
+    assert!(started.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn an_activation_and_a_reset_each_end_the_wait() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("killswitch.json");
+    let writer_path = path.clone();
+
+    // Another process activates the switch and persists it.
+    let writer = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(30));
+        let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+        let reason = KillReason::ManualTrigger { operator: "ops".into(), reason: None };
+        ks.activate("ops", reason, Vec::new()).unwrap();
+        ks.state().save(&writer_path).unwrap();
+    });
+    let deadline = Some(Instant::now() + Duration::from_secs(10));
+    let state = KillState::wait_for_change(&path, false, POLL, deadline).unwrap().expect("activation seen");
+    writer.join().unwrap();
+    assert!(state.active);
+    assert_eq!(state.events.last().unwrap().triggered_by, "ops");
+
+    KillState { active: false, ..state }.save(&path).unwrap();
+    let state = KillState::wait_for_change(&path, true, POLL, deadline).unwrap().expect("reset seen");
+    assert!(!state.active);
+}
+
+#[test]
+fn saving_replaces_the_state_without_leaving_a_temporary_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("killswitch.json");
+    let mut ks = KillSwitch::new(vec!["ops".to_string()]);
+    ks.state().save(&path).unwrap();
+
+    let reason = KillReason::ManualTrigger { operator: "ops".into(), reason: None };
+    ks.activate("ops", reason, Vec::new()).unwrap();
+    ks.state().save(&path).unwrap();
+
+    assert!(KillState::load(&path).unwrap().active);
+    assert!(!path.with_extension("tmp").exists());
+}
+
+#[test]
+fn unreadable_state_is_an_error_not_a_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("killswitch.json");
+    std::fs::write(&path, "{ not json").unwrap();
+    assert!(KillState::wait_for_change(&path, false, POLL, None).is_err());
+}
+
+#[test]
+fn follow_and_timeout_are_parsed() {
+    let cli = Cli::try_parse_from(["openlora-gov", "watch-killswitch", "--follow", "--timeout", "30"]).unwrap();
+    assert!(matches!(cli.command, Commands::WatchKillswitch { follow: true, timeout: Some(30), poll_ms: 500 }));
+}
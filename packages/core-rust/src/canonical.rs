@@ -0,0 +1,58 @@
+//! Canonical CBOR
+//!
+//! Deterministic CBOR encoding (RFC 8949 §4.2.1) of signed objects, so
+//! verifiers in any language can reproduce the exact bytes that were signed:
+//! shortest-form integers and floats, definite lengths, and map keys sorted
+//! by their encoded bytes.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use ciborium::value::Value;
+use serde::Serialize;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CanonicalError {
+    #[error("Cannot encode as CBOR: {0}")]
+    Encode(String),
+}
+
+/// Encode `value` as canonical CBOR.
+pub fn to_canonical_cbor<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, CanonicalError> {
+    let value = Value::serialized(value).map_err(|e| CanonicalError::Encode(e.to_string()))?;
+    encode(&canonicalize(value)?)
+}
+
+/// Timestamp form used in canonical encodings: RFC 3339, UTC `Z`, nanoseconds.
+pub fn canonical_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+fn encode(value: &Value) -> Result<Vec<u8>, CanonicalError> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes).map_err(|e| CanonicalError::Encode(e.to_string()))?;
+    Ok(bytes)
+}
+
+fn canonicalize(value: Value) -> Result<Value, CanonicalError> {
+    Ok(match value {
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(canonicalize)
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Map(entries) => {
+            let mut keyed = entries
+                .into_iter()
+                .map(|(k, v)| {
+                    let k = canonicalize(k)?;
+                    Ok((encode(&k)?, k, canonicalize(v)?))
+                })
+                .collect::<Result<Vec<_>, CanonicalError>>()?;
+            keyed.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Map(keyed.into_iter().map(|(_, k, v)| (k, v)).collect())
+        }
+        Value::Tag(tag, inner) => Value::Tag(tag, Box::new(canonicalize(*inner)?)),
+        other => other,
+    })
+}
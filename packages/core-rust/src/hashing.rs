@@ -0,0 +1,73 @@
+//! Hashing
+//!
+//! SHA-256 helpers shared by signing, verification, provenance and the
+//! audit chain, so every hash is built, encoded and truncated one way.
+//! Hashes are lowercase hex; provenance links and legacy (v1) audit
+//! links use the first [`SHORT_HASH_LEN`] characters.
+
+use sha2::{Digest, Sha256};
+
+/// Hex characters kept in a truncated chain hash.
+pub const SHORT_HASH_LEN: usize = 16;
+
+/// SHA-256 of `bytes`, hex encoded.
+pub fn content_hash(bytes: &[u8]) -> String {
+    Hasher::new().update(bytes).finish()
+}
+
+/// Incremental SHA-256.
+///
+/// Fields are either appended raw with [`Hasher::update`] or framed with
+/// their length by [`Hasher::field`], which keeps different splits of
+/// the same bytes from colliding. Also an [`std::io::Write`] sink, for
+/// streamed content.
+#[derive(Debug, Clone, Default)]
+pub struct Hasher(Sha256);
+
+impl Hasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a domain-separated hash, prefixed with `tag`.
+    pub fn domain(tag: &str) -> Self {
+        Self::new().update(tag.as_bytes())
+    }
+
+    /// Append `bytes` as they are.
+    pub fn update(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.0.update(bytes);
+        self
+    }
+
+    /// Append `bytes` after their length as a big-endian `u64`.
+    pub fn field(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        let bytes = bytes.as_ref();
+        self.0.update((bytes.len() as u64).to_be_bytes());
+        self.0.update(bytes);
+        self
+    }
+
+    /// The full digest, hex encoded.
+    pub fn finish(self) -> String {
+        format!("{:x}", self.0.finalize())
+    }
+
+    /// The first [`SHORT_HASH_LEN`] hex characters of the digest.
+    pub fn finish_short(self) -> String {
+        let mut hash = self.finish();
+        hash.truncate(SHORT_HASH_LEN);
+        hash
+    }
+}
+
+impl std::io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
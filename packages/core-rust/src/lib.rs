@@ -3,6 +3,8 @@
 //! Safety-critical types for governance, kill-switches,
 //! signatures, and audit logging.
 
+pub mod canonical;
+pub mod hashing;
 pub mod types;
 
 pub use types::*;
@@ -4,24 +4,162 @@
 //! These types define governance, kill-switch, signatures,
 //! and audit structures. Rust owns authority, not learning.
 
+use crate::canonical::{canonical_timestamp, to_canonical_cbor};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 // =============================================================================
 // Identifiers
 // =============================================================================
 
-/// Unique identifier for an adapter.
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
-pub struct AdapterId(pub String);
+/// Maximum length of an identifier, in bytes.
+pub const MAX_ID_LEN: usize = 128;
 
-/// Unique identifier for a model.
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
-pub struct ModelId(pub String);
+/// Why a string is not a valid identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum IdError {
+    #[error("{kind} must not be empty")]
+    Empty { kind: &'static str },
+    #[error("{kind} is {len} bytes long (max {max})")]
+    TooLong {
+        kind: &'static str,
+        len: usize,
+        max: usize,
+    },
+    #[error("{kind} must start with an ASCII letter or digit")]
+    InvalidStart { kind: &'static str },
+    #[error("{kind} contains invalid character {ch:?}")]
+    InvalidChar { kind: &'static str, ch: char },
+    #[error("{kind} must not start or end with whitespace")]
+    SurroundingWhitespace { kind: &'static str },
+}
+
+/// Check an identifier: 1 to [`MAX_ID_LEN`] ASCII letters, digits,
+/// `.`, `_`, `-` or `:`, starting with a letter or digit.
+fn validate_id(kind: &'static str, id: &str) -> Result<(), IdError> {
+    let Some(first) = id.chars().next() else {
+        return Err(IdError::Empty { kind });
+    };
+    if id.len() > MAX_ID_LEN {
+        return Err(IdError::TooLong {
+            kind,
+            len: id.len(),
+            max: MAX_ID_LEN,
+        });
+    }
+    if !first.is_ascii_alphanumeric() {
+        return Err(IdError::InvalidStart { kind });
+    }
+    if let Some(ch) = id
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | ':')))
+    {
+        return Err(IdError::InvalidChar { kind, ch });
+    }
+    Ok(())
+}
+
+/// Check the id of a principal, such as an operator or signer.
+///
+/// Looser than adapter ids, since principals are often e-mail addresses
+/// or display names: any printable text of 1 to [`MAX_ID_LEN`] bytes
+/// without surrounding whitespace. Rejects the degenerate ids that could
+/// otherwise match an accidental entry in an allowlist.
+pub fn validate_principal_id(kind: &'static str, id: &str) -> Result<(), IdError> {
+    if id.trim().is_empty() {
+        return Err(IdError::Empty { kind });
+    }
+    if id.len() > MAX_ID_LEN {
+        return Err(IdError::TooLong {
+            kind,
+            len: id.len(),
+            max: MAX_ID_LEN,
+        });
+    }
+    if id.trim() != id {
+        return Err(IdError::SurroundingWhitespace { kind });
+    }
+    if let Some(ch) = id.chars().find(|c| c.is_control()) {
+        return Err(IdError::InvalidChar { kind, ch });
+    }
+    Ok(())
+}
+
+/// Identifier newtype whose value is validated on construction and
+/// deserialization; serialized as a plain string.
+macro_rules! validated_id {
+    ($(#[$meta:meta])* $name:ident, $kind:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(id: impl Into<String>) -> Result<Self, IdError> {
+                let id = id.into();
+                validate_id($kind, &id)?;
+                Ok(Self(id))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = IdError;
+
+            fn try_from(id: String) -> Result<Self, IdError> {
+                Self::new(id)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = IdError;
+
+            fn from_str(id: &str) -> Result<Self, IdError> {
+                Self::new(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
 
-/// Unique identifier for an experiment run.
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
-pub struct RunId(pub String);
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+validated_id!(
+    /// Unique identifier for an adapter.
+    AdapterId,
+    "adapter id"
+);
+
+validated_id!(
+    /// Unique identifier for a model.
+    ModelId,
+    "model id"
+);
+
+validated_id!(
+    /// Unique identifier for an experiment run.
+    RunId,
+    "run id"
+);
 
 // =============================================================================
 // Governance Types
@@ -34,6 +172,9 @@ pub struct Signature {
     pub value: Vec<u8>,
     pub signer_id: String,
     pub signed_at: DateTime<Utc>,
+    /// End of the signature's validity window, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 /// A single entry in the provenance chain.
@@ -47,8 +188,31 @@ pub struct ProvenanceEntry {
     pub signature: Option<Signature>,
 }
 
+impl ProvenanceEntry {
+    /// Canonical CBOR of the signed fields (everything but the signature).
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        #[derive(Serialize)]
+        struct Signed<'a> {
+            adapter_id: &'a str,
+            version: u32,
+            operation: &'static str,
+            actor: &'a str,
+            timestamp: String,
+        }
+
+        to_canonical_cbor(&Signed {
+            adapter_id: self.adapter_id.as_str(),
+            version: self.version,
+            operation: self.operation.tag(),
+            actor: &self.actor,
+            timestamp: canonical_timestamp(&self.timestamp),
+        })
+        .expect("string and integer fields always encode")
+    }
+}
+
 /// Type of provenance operation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ProvenanceOperation {
     Created,
     Trained,
@@ -57,6 +221,25 @@ pub enum ProvenanceOperation {
     Transferred,
 }
 
+impl ProvenanceOperation {
+    /// Canonical tag, as written on disk.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            ProvenanceOperation::Created => "Created",
+            ProvenanceOperation::Trained => "Trained",
+            ProvenanceOperation::Merged => "Merged",
+            ProvenanceOperation::Cloned => "Cloned",
+            ProvenanceOperation::Transferred => "Transferred",
+        }
+    }
+}
+
+impl std::fmt::Display for ProvenanceOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.tag())
+    }
+}
+
 /// Full provenance chain for an adapter.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProvenanceChain {
@@ -82,13 +265,75 @@ pub enum KillReason {
     ProvenanceViolation { adapter_id: AdapterId },
     /// External safety signal.
     ExternalSignal { source: String, message: String },
+    /// Reason from a newer build, preserved verbatim.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// Outcome of one rule during a policy evaluation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RuleOutcome {
+    /// The rule applied and was satisfied.
+    Passed,
+    /// The rule applied and was not satisfied.
+    Failed,
+    /// The rule did not apply to this operation.
+    Skipped,
+}
+
+/// One rule considered during a policy evaluation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RuleEvaluation {
+    pub rule: String,
+    pub outcome: RuleOutcome,
+    /// What the rule saw, e.g. the value it compared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Rules considered for a decision, in evaluation order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvaluationTrace {
+    pub rules: Vec<RuleEvaluation>,
+}
+
+impl EvaluationTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append the outcome of `rule`.
+    pub fn record(&mut self, rule: &str, outcome: RuleOutcome, detail: Option<String>) {
+        self.rules.push(RuleEvaluation {
+            rule: rule.to_string(),
+            outcome,
+            detail,
+        });
+    }
+
+    /// Whether no rule that applied failed.
+    pub fn all_passed(&self) -> bool {
+        self.rules.iter().all(|r| r.outcome != RuleOutcome::Failed)
+    }
+
+    /// Rules with the given outcome.
+    pub fn with_outcome(&self, outcome: RuleOutcome) -> impl Iterator<Item = &RuleEvaluation> {
+        self.rules.iter().filter(move |r| r.outcome == outcome)
+    }
 }
 
 /// Result of a governance decision.
+///
+/// Also reads the unit form `"Allow"` written before allow decisions
+/// carried a trace, as an allow with an empty trace.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "DecisionRepr")]
 pub enum GovernanceDecision {
-    /// Allow the operation.
-    Allow,
+    /// Allow the operation, with the rules that let it through.
+    Allow {
+        #[serde(default)]
+        trace: EvaluationTrace,
+    },
     /// Deny the operation with reason.
     Deny { reason: String },
     /// Quarantine the adapter.
@@ -105,6 +350,54 @@ pub enum GovernanceDecision {
     Kill { reason: KillReason },
 }
 
+/// Wire form of [`GovernanceDecision`], accepting the legacy unit allow.
+#[derive(Deserialize)]
+#[serde(rename = "GovernanceDecision")]
+enum DecisionRepr {
+    Allow {
+        #[serde(default)]
+        trace: EvaluationTrace,
+    },
+    Deny {
+        reason: String,
+    },
+    Quarantine {
+        adapter_id: AdapterId,
+        reason: String,
+    },
+    Destroy {
+        adapter_id: AdapterId,
+        reason: String,
+    },
+    Kill {
+        reason: KillReason,
+    },
+    #[serde(untagged)]
+    LegacyAllow(LegacyAllow),
+}
+
+#[derive(Deserialize)]
+enum LegacyAllow {
+    Allow,
+}
+
+impl From<DecisionRepr> for GovernanceDecision {
+    fn from(repr: DecisionRepr) -> Self {
+        match repr {
+            DecisionRepr::Allow { trace } => Self::Allow { trace },
+            DecisionRepr::LegacyAllow(LegacyAllow::Allow) => Self::Allow {
+                trace: EvaluationTrace::default(),
+            },
+            DecisionRepr::Deny { reason } => Self::Deny { reason },
+            DecisionRepr::Quarantine { adapter_id, reason } => {
+                Self::Quarantine { adapter_id, reason }
+            }
+            DecisionRepr::Destroy { adapter_id, reason } => Self::Destroy { adapter_id, reason },
+            DecisionRepr::Kill { reason } => Self::Kill { reason },
+        }
+    }
+}
+
 // =============================================================================
 // Audit Types
 // =============================================================================
@@ -119,11 +412,15 @@ pub enum AuditEventType {
     AdapterDestroyed,
     KillSwitchActivated,
     KillSwitchReset,
+    SignatureCreated,
     SignatureVerified,
     SignatureFailed,
     ProvenanceChecked,
     PolicyEvaluated,
     AccessDenied,
+    /// Event type from a newer build, preserved verbatim.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// An immutable audit log entry.